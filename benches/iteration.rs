@@ -0,0 +1,357 @@
+//! Benchmarks for the four options in "Iterating over More Complex Data with
+//! `<For/>`", which back the comparison at the end of that chapter.
+//!
+//! Each option is set up the way the chapter's component sets it up, but with
+//! [`ROWS`] rows rather than three. There's no DOM outside the browser, so the
+//! text node in each row is stood in for by a `Memo` that reads whatever the
+//! text node would read. After an update, reading every row only reruns the
+//! memos whose dependencies changed, just as only those text nodes would be
+//! updated in the browser. Option 1 replaces rows instead of updating them, so
+//! its rows are rendered again whenever their key changes.
+//!
+//! Run with `cargo bench --bench iteration`, or with `cargo xtask bench-table`
+//! to update the chapter's table as well.
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use leptos::prelude::*;
+use reactive_stores::{Store, StoreFieldIterator};
+use std::collections::HashMap;
+
+const ROWS: usize = 1000;
+
+#[derive(Debug, Clone)]
+struct DatabaseEntry {
+    key: String,
+    value: i32,
+}
+
+fn entries() -> Vec<DatabaseEntry> {
+    (0..ROWS)
+        .map(|idx| DatabaseEntry {
+            key: format!("row-{idx}"),
+            value: idx as i32,
+        })
+        .collect()
+}
+
+/// One of the options, set up with [`ROWS`] rows.
+trait Variant {
+    const NAME: &'static str;
+
+    fn new() -> Self;
+    /// Renders the whole list to HTML.
+    fn render(&self) -> String;
+    /// Doubles the value of every row.
+    fn update_all(&self);
+    /// Doubles the value of the first row.
+    fn update_one(&self);
+    /// Brings every row up to date, as the browser would after an update.
+    fn read(&mut self) -> usize;
+}
+
+/// Option 1: a key that includes the value.
+struct ChangeKey {
+    data: RwSignal<Vec<DatabaseEntry>>,
+    rows: HashMap<(String, i32), String>,
+}
+
+impl Variant for ChangeKey {
+    const NAME: &'static str = "change_key";
+
+    fn new() -> Self {
+        let mut this = ChangeKey {
+            data: RwSignal::new(entries()),
+            rows: HashMap::new(),
+        };
+        this.read();
+        this
+    }
+
+    fn render(&self) -> String {
+        let data = self.data;
+        view! {
+            <For
+                each=move || data.get()
+                key=|state| (state.key.clone(), state.value)
+                let:child
+            >
+                <p>{child.value}</p>
+            </For>
+        }
+        .to_html()
+    }
+
+    fn update_all(&self) {
+        self.data.update(|data| {
+            for row in data {
+                row.value *= 2;
+            }
+        });
+    }
+
+    fn update_one(&self) {
+        self.data.update(|data| data[0].value *= 2);
+    }
+
+    fn read(&mut self) -> usize {
+        // like `<For/>`, keep the rows whose keys are unchanged and render the rest
+        let mut rows = HashMap::with_capacity(ROWS);
+        for child in self.data.get() {
+            let key = (child.key.clone(), child.value);
+            let row = self
+                .rows
+                .remove(&key)
+                .unwrap_or_else(|| view! { <p>{child.value}</p> }.to_html());
+            rows.insert(key, row);
+        }
+        self.rows = rows;
+        self.rows.len()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SignalEntry {
+    key: String,
+    value: RwSignal<i32>,
+}
+
+/// Option 2: a signal for the value of each row.
+struct NestedSignals {
+    data: ReadSignal<Vec<SignalEntry>>,
+    text: Vec<Memo<String>>,
+}
+
+impl Variant for NestedSignals {
+    const NAME: &'static str = "nested_signals";
+
+    fn new() -> Self {
+        let (data, _) = signal(
+            entries()
+                .into_iter()
+                .map(|row| SignalEntry {
+                    key: row.key,
+                    value: RwSignal::new(row.value),
+                })
+                .collect::<Vec<_>>(),
+        );
+        let text = data
+            .get()
+            .into_iter()
+            .map(|row| Memo::new(move |_| row.value.get().to_string()))
+            .collect();
+        NestedSignals { data, text }
+    }
+
+    fn render(&self) -> String {
+        let data = self.data;
+        view! {
+            <For
+                each=move || data.get()
+                key=|state| state.key.clone()
+                let:child
+            >
+                <p>{child.value}</p>
+            </For>
+        }
+        .to_html()
+    }
+
+    fn update_all(&self) {
+        self.data.with(|data| {
+            for row in data {
+                row.value.update(|value| *value *= 2);
+            }
+        });
+    }
+
+    fn update_one(&self) {
+        self.data
+            .with(|data| data[0].value.update(|value| *value *= 2));
+    }
+
+    fn read(&mut self) -> usize {
+        self.text.iter().map(|text| text.with(String::len)).sum()
+    }
+}
+
+/// Option 3: a memo for the value of each row.
+struct MemoizedSlices {
+    data: RwSignal<Vec<DatabaseEntry>>,
+    text: Vec<Memo<String>>,
+}
+
+impl Variant for MemoizedSlices {
+    const NAME: &'static str = "memoized_slices";
+
+    fn new() -> Self {
+        let data = RwSignal::new(entries());
+        let text = (0..ROWS)
+            .map(|index| {
+                let value = Memo::new(move |_| {
+                    data.with(|data| data.get(index).map(|d| d.value).unwrap_or(0))
+                });
+                Memo::new(move |_| value.get().to_string())
+            })
+            .collect();
+        MemoizedSlices { data, text }
+    }
+
+    fn render(&self) -> String {
+        let data = self.data;
+        view! {
+            <For
+                each=move || data.get().into_iter().enumerate()
+                key=|(_, state)| state.key.clone()
+                children=move |(index, _)| {
+                    let value = Memo::new(move |_| {
+                        data.with(|data| data.get(index).map(|d| d.value).unwrap_or(0))
+                    });
+                    view! {
+                        <p>{value}</p>
+                    }
+                }
+            />
+        }
+        .to_html()
+    }
+
+    fn update_all(&self) {
+        self.data.update(|data| {
+            for row in data {
+                row.value *= 2;
+            }
+        });
+    }
+
+    fn update_one(&self) {
+        self.data.update(|data| data[0].value *= 2);
+    }
+
+    fn read(&mut self) -> usize {
+        self.text.iter().map(|text| text.with(String::len)).sum()
+    }
+}
+
+#[derive(Store, Debug, Clone)]
+struct Data {
+    #[store(key: String = |row| row.key.clone())]
+    rows: Vec<StoreEntry>,
+}
+
+#[derive(Store, Debug, Clone)]
+struct StoreEntry {
+    key: String,
+    value: i32,
+}
+
+/// Option 4: a store.
+struct Stores {
+    data: Store<Data>,
+    text: Vec<Memo<String>>,
+}
+
+impl Variant for Stores {
+    const NAME: &'static str = "stores";
+
+    fn new() -> Self {
+        let data = Store::new(Data {
+            rows: entries()
+                .into_iter()
+                .map(|row| StoreEntry {
+                    key: row.key,
+                    value: row.value,
+                })
+                .collect(),
+        });
+        let text = data
+            .rows()
+            .iter_unkeyed()
+            .map(|row| {
+                let value = row.value();
+                Memo::new(move |_| value.get().to_string())
+            })
+            .collect();
+        Stores { data, text }
+    }
+
+    fn render(&self) -> String {
+        let data = self.data;
+        view! {
+            <For
+                each=move || data.rows()
+                key=|row| row.read().key.clone()
+                children=|child| {
+                    let value = child.value();
+                    view! { <p>{move || value.get()}</p> }
+                }
+            />
+        }
+        .to_html()
+    }
+
+    fn update_all(&self) {
+        for row in self.data.rows().iter_unkeyed() {
+            *row.value().write() *= 2;
+        }
+    }
+
+    fn update_one(&self) {
+        if let Some(row) = self.data.rows().iter_unkeyed().next() {
+            *row.value().write() *= 2;
+        }
+    }
+
+    fn read(&mut self) -> usize {
+        self.text.iter().map(|text| text.with(String::len)).sum()
+    }
+}
+
+fn bench<O: Variant>(c: &mut Criterion) {
+    let setup = || {
+        let owner = Owner::new();
+        let mut option = owner.with(O::new);
+        // bring every row up to date before the update being measured
+        owner.with(|| option.read());
+        (owner, option)
+    };
+
+    c.bench_function(&format!("render/{}", O::NAME), |b| {
+        let (owner, option) = setup();
+        b.iter(|| owner.with(|| black_box(option.render())));
+    });
+    c.bench_function(&format!("update_all/{}", O::NAME), |b| {
+        b.iter_batched(
+            setup,
+            |(owner, mut option)| {
+                let rows = owner.with(|| {
+                    option.update_all();
+                    option.read()
+                });
+                // returned so that cleaning up isn't measured
+                (owner, option, rows)
+            },
+            BatchSize::SmallInput,
+        );
+    });
+    c.bench_function(&format!("update_one/{}", O::NAME), |b| {
+        b.iter_batched(
+            setup,
+            |(owner, mut option)| {
+                let rows = owner.with(|| {
+                    option.update_one();
+                    option.read()
+                });
+                (owner, option, rows)
+            },
+            BatchSize::SmallInput,
+        );
+    });
+}
+
+criterion_group! {
+    name = iteration;
+    // each sample sets up a thousand rows, so keep the number of them down
+    config = Criterion::default().sample_size(30);
+    targets = bench::<ChangeKey>, bench::<NestedSignals>, bench::<MemoizedSlices>, bench::<Stores>
+}
+criterion_main!(iteration);
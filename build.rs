@@ -0,0 +1,27 @@
+//! Generates the gRPC client in "Calling gRPC Services", with the `grpc`
+//! feature.
+
+fn main() {
+    println!("cargo::rerun-if-changed=build.rs");
+    #[cfg(feature = "grpc")]
+    grpc();
+}
+
+#[cfg(feature = "grpc")]
+fn grpc() {
+    let proto = "src/async/grpc/inventory.proto";
+    println!("cargo::rerun-if-changed={proto}");
+
+    // `protox` parses the `.proto` file in Rust, so `protoc` doesn't need to
+    // be installed to build the book
+    let files = protox::compile([proto], ["src/async/grpc"]).unwrap();
+
+    // the generated server is only used by the tests, which run natively
+    let native = std::env::var("CARGO_CFG_TARGET_ARCH").unwrap() != "wasm32";
+    tonic_prost_build::configure()
+        .build_server(native)
+        // the client is given its channel, instead of connecting itself
+        .build_transport(false)
+        .compile_fds(files)
+        .unwrap();
+}
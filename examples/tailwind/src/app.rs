@@ -0,0 +1,167 @@
+//! The components, which are the same however the app is built.
+//!
+//! Tailwind doesn't read Rust. It scans this file for anything that looks
+//! like a class name, and generates CSS for each one it recognizes, so a
+//! class only works if it's written out in full somewhere in here. The tests
+//! below render every state of every component, and check that each class in
+//! the HTML is.
+
+use leptos::prelude::*;
+
+// ANCHOR: status
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Status {
+    Running,
+    Paused,
+    Failed,
+}
+
+impl Status {
+    pub const ALL: [Status; 3] = [Status::Running, Status::Paused, Status::Failed];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Status::Running => "Running",
+            Status::Paused => "Paused",
+            Status::Failed => "Failed",
+        }
+    }
+
+    /// The colors of this status's badge. Each class is written out in full,
+    /// rather than pieced together from the color's name, so that Tailwind
+    /// finds it.
+    pub fn colors(self) -> &'static str {
+        match self {
+            Status::Running => "bg-green-100 text-green-800",
+            Status::Paused => "bg-yellow-100 text-yellow-800",
+            Status::Failed => "bg-red-100 text-red-800",
+        }
+    }
+}
+
+#[component]
+pub fn Badge(#[prop(into)] status: Signal<Status>) -> impl IntoView {
+    view! {
+        <span class=move || {
+            format!("rounded-full px-2 py-1 text-xs font-medium {}", status.get().colors())
+        }>{move || status.get().label()}</span>
+    }
+}
+// ANCHOR_END: status
+
+// ANCHOR: toggle
+#[component]
+pub fn Toggle(on: RwSignal<bool>, label: &'static str) -> impl IntoView {
+    view! {
+        <button
+            class="relative h-6 w-11 rounded-full transition-colors focus-visible:outline-2"
+            // a class like `hover:bg-sky-700` can't be written after `class:`,
+            // so each group of classes is given as strings instead, which is
+            // also where Tailwind will find them
+            class=(["bg-sky-600", "hover:bg-sky-700"], move || on.get())
+            class=(["bg-gray-300", "hover:bg-gray-400"], move || !on.get())
+            aria-pressed=move || on.get().to_string()
+            aria-label=label
+            on:click=move |_| on.update(|on| *on = !*on)
+        >
+            <span
+                class="absolute top-1 left-1 size-4 rounded-full bg-white transition-transform"
+                class=("translate-x-5", move || on.get())
+            ></span>
+        </button>
+    }
+}
+// ANCHOR_END: toggle
+
+// ANCHOR: app
+#[component]
+pub fn App() -> impl IntoView {
+    let paused = RwSignal::new(false);
+    let status = Signal::derive(move || {
+        if paused.get() {
+            Status::Paused
+        } else {
+            Status::Running
+        }
+    });
+
+    view! {
+        <main class="mx-auto my-0 max-w-3xl p-6 text-center">
+            <h1 class="p-6 text-4xl">"Welcome to Leptos with Tailwind"</h1>
+            <div class="flex items-center justify-center gap-4">
+                <Badge status/>
+                <Toggle on=paused label="Pause"/>
+            </div>
+        </main>
+    }
+}
+// ANCHOR_END: app
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    /// The classes in every `class` attribute in `html`.
+    fn classes(html: &str) -> BTreeSet<String> {
+        html.split(" class=\"")
+            .skip(1)
+            .filter_map(|rest| rest.split('"').next())
+            .flat_map(|classes| classes.split_whitespace())
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Everything in this file that could be a class name, as Tailwind sees
+    /// it: the text between spaces, quotes and brackets.
+    fn candidates() -> BTreeSet<&'static str> {
+        include_str!("app.rs")
+            .split(|c: char| c.is_whitespace() || "\"'`(){}<>=,;".contains(c))
+            .collect()
+    }
+
+    /// Every state each component can be rendered in.
+    fn rendered() -> String {
+        let mut html = view! { <App/> }.to_html();
+        for status in Status::ALL {
+            html.push_str(&view! { <Badge status/> }.to_html());
+        }
+        for on in [false, true] {
+            let on = RwSignal::new(on);
+            html.push_str(&view! { <Toggle on label="Test"/> }.to_html());
+        }
+        html
+    }
+
+    #[test]
+    fn every_class_is_written_out_in_full() {
+        let owner = Owner::new();
+        let classes = owner.with(|| classes(&rendered()));
+        assert!(classes.contains("bg-red-100"));
+        assert!(classes.contains("hover:bg-sky-700"));
+
+        let candidates = candidates();
+        let missing = classes
+            .iter()
+            .filter(|class| !candidates.contains(class.as_str()))
+            .collect::<Vec<_>>();
+        assert!(missing.is_empty(), "Tailwind won't find {missing:?}");
+    }
+
+    #[test]
+    fn the_toggle_switches_its_classes() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let off = RwSignal::new(false);
+            let html = view! { <Toggle on=off label="Test"/> }.to_html();
+            assert!(classes(&html).contains("bg-gray-300"));
+            assert!(!classes(&html).contains("translate-x-5"));
+
+            let on = RwSignal::new(true);
+            let html = view! { <Toggle on label="Test"/> }.to_html();
+            assert!(classes(&html).contains("bg-sky-600"));
+            assert!(classes(&html).contains("translate-x-5"));
+            assert!(!classes(&html).contains("bg-gray-300"));
+        });
+    }
+}
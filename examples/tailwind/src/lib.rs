@@ -0,0 +1,57 @@
+//! The app in "Interlude: Styling".
+//!
+//! The same components are built two ways. Trunk builds the binary with the
+//! `csr` feature, and renders everything in the browser. cargo-leptos builds
+//! the binary with `ssr`, as a server that renders the page, and this
+//! library with `hydrate`, as the WASM that takes it over in the browser.
+//! Either way, Tailwind generates the CSS from the classes in `app.rs`.
+
+pub mod app;
+
+use app::App;
+use leptos::prelude::*;
+
+// ANCHOR: shell
+/// The HTML document that [`App`] is rendered into on the server.
+pub fn shell(options: LeptosOptions) -> impl IntoView {
+    view! {
+        <!DOCTYPE html>
+        <html lang="en">
+            <head>
+                <meta charset="utf-8"/>
+                // the CSS that cargo-leptos generated with Tailwind, named
+                // after the `output-name` in `Cargo.toml`
+                <link rel="stylesheet" href="/pkg/tailwind-example.css"/>
+                <HydrationScripts options/>
+            </head>
+            <body>
+                <App/>
+            </body>
+        </html>
+    }
+}
+// ANCHOR_END: shell
+
+/// Serves [`App`] with Axum.
+#[cfg(feature = "ssr")]
+pub fn router(options: LeptosOptions) -> axum::Router {
+    use leptos_axum::{generate_route_list, LeptosRoutes};
+
+    let routes = generate_route_list(App);
+    axum::Router::new()
+        .leptos_routes(&options, routes, {
+            let options = options.clone();
+            move || shell(options.clone())
+        })
+        // serves the WASM and CSS that cargo-leptos builds into `site-root`
+        .fallback(leptos_axum::file_and_error_handler(shell))
+        .with_state(options)
+}
+
+/// Called by the JavaScript that cargo-leptos generates, to hydrate the page
+/// the server rendered.
+#[cfg(feature = "hydrate")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+pub fn hydrate() {
+    leptos::mount::hydrate_body(App);
+}
@@ -0,0 +1,25 @@
+//! The server, when cargo-leptos builds it with `ssr`, or the whole app,
+//! when Trunk builds it with `csr`.
+
+#[cfg(feature = "ssr")]
+#[tokio::main]
+async fn main() {
+    use leptos::config::get_configuration;
+
+    // cargo-leptos passes the settings in `[package.metadata.leptos]` on in
+    // environment variables
+    let options = get_configuration(None).unwrap().leptos_options;
+    let listener = tokio::net::TcpListener::bind(options.site_addr)
+        .await
+        .unwrap();
+    let app = tailwind_example::router(options);
+    axum::serve(listener, app).await.unwrap();
+}
+
+#[cfg(not(feature = "ssr"))]
+fn main() {
+    // without `csr`, this is the binary cargo-leptos would build for the
+    // browser, which it never does: it hydrates with the library instead
+    #[cfg(feature = "csr")]
+    leptos::mount::mount_to_body(tailwind_example::app::App);
+}
@@ -0,0 +1,5 @@
+fn main() {
+    // generates the context that `tauri::generate_context!` reads
+    #[cfg(feature = "tauri")]
+    tauri_build::build();
+}
@@ -0,0 +1,100 @@
+//! The frontend, which runs in Tauri's webview as an ordinary CSR app.
+//!
+//! This compiles natively as well, so that the workspace builds, but
+//! `invoke` only exists inside Tauri.
+
+use leptos::{prelude::*, task::spawn_local};
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+// ANCHOR: invoke
+#[wasm_bindgen]
+extern "C" {
+    // with `withGlobalTauri`, Tauri's JavaScript API is on `window.__TAURI__`;
+    // `catch` turns a rejected promise, or a missing function, into an `Err`
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"], catch)]
+    async fn invoke(cmd: &str, args: JsValue) -> Result<JsValue, JsValue>;
+}
+// ANCHOR_END: invoke
+
+// ANCHOR: bindings
+/// The arguments to a command, which are sent as an object with a key for
+/// each of the command's parameters.
+#[derive(Serialize)]
+struct PathArgs<'a> {
+    path: &'a str,
+}
+
+#[derive(Serialize)]
+struct NameArgs<'a> {
+    name: &'a str,
+}
+
+/// Invokes the `greet` command.
+pub async fn greet(name: &str) -> Result<String, String> {
+    let args = serde_wasm_bindgen::to_value(&NameArgs { name }).unwrap();
+    let greeting = invoke("greet", args).await.map_err(describe)?;
+    serde_wasm_bindgen::from_value(greeting).map_err(|e| e.to_string())
+}
+
+/// Invokes the `count_words` command.
+pub async fn count_words(path: &str) -> Result<usize, String> {
+    let args = serde_wasm_bindgen::to_value(&PathArgs { path }).unwrap();
+    let count = invoke("count_words", args).await.map_err(describe)?;
+    serde_wasm_bindgen::from_value(count).map_err(|e| e.to_string())
+}
+
+/// The message in whatever `invoke` rejected with: the command's `Err`, or
+/// a JavaScript error if there's no such command.
+fn describe(error: JsValue) -> String {
+    error.as_string().unwrap_or_else(|| format!("{error:?}"))
+}
+// ANCHOR_END: bindings
+
+// ANCHOR: app
+#[component]
+pub fn App() -> impl IntoView {
+    let (name, set_name) = signal(String::new());
+    let (greeting, set_greeting) = signal(String::new());
+
+    let on_greet = move |_| {
+        let name = name.get();
+        // `invoke` returns a JavaScript promise, which isn't `Send`, so the
+        // result is awaited locally and put into a signal
+        spawn_local(async move {
+            let message = greet(&name).await.unwrap_or_else(|e| e);
+            set_greeting.set(message);
+        });
+    };
+
+    // or, an action keeps the latest result in a signal of its own
+    let count = Action::new_local(|path: &String| {
+        let path = path.clone();
+        async move { count_words(&path).await }
+    });
+    let (path, set_path) = signal(String::new());
+
+    view! {
+        <h1>"Word Counter"</h1>
+        <p>
+            <input type="text" placeholder="Your name" bind:value=(name, set_name)/>
+            <button on:click=on_greet>"Greet"</button>
+        </p>
+        <p>{greeting}</p>
+        <p>
+            <input type="text" placeholder="Path to a file" bind:value=(path, set_path)/>
+            <button on:click=move |_| {
+                count.dispatch_local(path.get());
+            }>"Count"</button>
+        </p>
+        <p>
+            {move || match count.value().get() {
+                None if count.pending().get() => "Counting...".to_string(),
+                None => String::new(),
+                Some(Ok(words)) => format!("{words} words"),
+                Some(Err(e)) => e,
+            }}
+        </p>
+    }
+}
+// ANCHOR_END: app
@@ -0,0 +1,8 @@
+//! The desktop app's entry point, which Tauri builds natively.
+
+// no console window alongside the app on Windows, in release builds
+#![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
+
+fn main() {
+    tauri_example::run()
+}
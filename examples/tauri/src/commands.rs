@@ -0,0 +1,55 @@
+//! The commands the frontend can invoke, which run natively, outside the
+//! webview.
+//!
+//! With the `tauri` feature, each of these is also a Tauri command. Without
+//! it, they're ordinary functions, which is how the tests below call them.
+
+use std::fs;
+
+// ANCHOR: commands
+/// Greets someone by name.
+#[cfg_attr(feature = "tauri", tauri::command)]
+pub fn greet(name: &str) -> String {
+    format!("Hello, {name}! You've been greeted from Rust.")
+}
+
+/// Counts the words in the file at `path`.
+///
+/// The webview can't read files by itself, which is the point of doing it
+/// here. An `Err` rejects the promise in JavaScript, so it has to be
+/// serializable: a `String` is the simplest choice.
+#[cfg_attr(feature = "tauri", tauri::command)]
+pub fn count_words(path: &str) -> Result<usize, String> {
+    let text = fs::read_to_string(path).map_err(|e| format!("couldn't read {path}: {e}"))?;
+    Ok(text.split_whitespace().count())
+}
+// ANCHOR_END: commands
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn greets_by_name() {
+        assert_eq!(
+            greet("Alice"),
+            "Hello, Alice! You've been greeted from Rust."
+        );
+    }
+
+    #[test]
+    fn counts_the_words_in_a_file() {
+        let path = env::temp_dir().join("tauri-example-count-words.txt");
+        fs::write(&path, "the quick brown\nfox  jumps\n").unwrap();
+        assert_eq!(count_words(path.to_str().unwrap()), Ok(5));
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn reports_files_that_cannot_be_read() {
+        let path = env::temp_dir().join("tauri-example-missing.txt");
+        let error = count_words(path.to_str().unwrap()).unwrap_err();
+        assert!(error.starts_with("couldn't read"), "{error}");
+    }
+}
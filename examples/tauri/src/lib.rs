@@ -0,0 +1,25 @@
+//! The app in "Desktop with Tauri".
+//!
+//! A Tauri app has two halves: a frontend, which is a CSR Leptos app running
+//! in a webview, and the native side, which runs the commands the frontend
+//! invokes. Both live in this crate, and only the native side needs the
+//! `tauri` feature, so the commands are tested without Tauri's system
+//! dependencies.
+
+pub mod app;
+pub mod commands;
+
+// ANCHOR: run
+/// Opens the app's window, with the commands it can invoke.
+#[cfg(feature = "tauri")]
+pub fn run() {
+    tauri::Builder::default()
+        // every command the frontend can call has to be listed here
+        .invoke_handler(tauri::generate_handler![
+            commands::greet,
+            commands::count_words
+        ])
+        .run(tauri::generate_context!())
+        .expect("error while running the app");
+}
+// ANCHOR_END: run
@@ -0,0 +1,7 @@
+//! The frontend's entry point, which Trunk builds to WASM.
+
+use tauri_example::app::App;
+
+fn main() {
+    leptos::mount::mount_to_body(App)
+}
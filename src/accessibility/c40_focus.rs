@@ -0,0 +1,84 @@
+//! Code for "Managing Focus on Navigation".
+
+use leptos::{html, prelude::*};
+use leptos_router::{
+    components::{Route, Router, Routes, A},
+    hooks::use_location,
+    path,
+};
+use wasm_bindgen::JsCast;
+use web_sys::HtmlElement;
+
+// ANCHOR: use_focus_on_navigation
+/// Moves the focus to the `<h1>` inside `main` after each navigation, but
+/// not when the page first loads.
+pub fn use_focus_on_navigation(main: NodeRef<html::Main>) {
+    let pathname = use_location().pathname;
+
+    Effect::new(move |previous: Option<String>| {
+        let path = pathname.get();
+        if previous.is_some_and(|previous| previous != path) {
+            // the new route is rendered once the location has changed, so
+            // wait until just before the next repaint, when it's there
+            request_animation_frame(move || {
+                let heading = main
+                    .try_get_untracked()
+                    .flatten()
+                    .and_then(|main| main.query_selector("h1").ok().flatten())
+                    .and_then(|heading| heading.dyn_into::<HtmlElement>().ok());
+                if let Some(heading) = heading {
+                    _ = heading.focus();
+                }
+            });
+        }
+        path
+    });
+}
+// ANCHOR_END: use_focus_on_navigation
+
+// ANCHOR: page
+/// The heading every page starts with. `tabindex="-1"` lets it be focused
+/// from code, without adding it to the order of the Tab key.
+#[component]
+fn Page(title: &'static str, children: Children) -> impl IntoView {
+    view! {
+        <h1 tabindex="-1">{title}</h1>
+        {children()}
+    }
+}
+// ANCHOR_END: page
+
+// ANCHOR: app
+#[component]
+pub fn App() -> impl IntoView {
+    view! {
+        <Router>
+            <Layout/>
+        </Router>
+    }
+}
+
+/// Inside the `<Router/>`, so that it can read the location.
+#[component]
+fn Layout() -> impl IntoView {
+    let main = NodeRef::<html::Main>::new();
+    use_focus_on_navigation(main);
+
+    view! {
+        <nav>
+            <A href="/">"Home"</A>
+            <A href="/about">"About"</A>
+        </nav>
+        <main node_ref=main>
+            <Routes fallback=|| view! { <Page title="Not Found">"There's nothing here."</Page> }>
+                <Route path=path!("/") view=|| view! {
+                    <Page title="Home">"Welcome!"</Page>
+                }/>
+                <Route path=path!("/about") view=|| view! {
+                    <Page title="About">"All about us."</Page>
+                }/>
+            </Routes>
+        </main>
+    }
+}
+// ANCHOR_END: app
@@ -0,0 +1,71 @@
+//! Code for "Accessible Form Errors".
+
+use leptos::{ev::SubmitEvent, html, prelude::*};
+
+// ANCHOR: validate
+fn email_error(email: &str) -> Option<&'static str> {
+    if email.is_empty() {
+        Some("Enter your email address.")
+    } else if !email.contains('@') {
+        Some("An email address needs an @, like name@example.com.")
+    } else {
+        None
+    }
+}
+// ANCHOR_END: validate
+
+// ANCHOR: sign_up
+#[component]
+pub fn SignUp() -> impl IntoView {
+    let input = NodeRef::<html::Input>::new();
+    let (email, set_email) = signal(String::new());
+    let (error, set_error) = signal(None::<&'static str>);
+    let (signed_up, set_signed_up) = signal(false);
+
+    let on_submit = move |ev: SubmitEvent| {
+        ev.prevent_default();
+        let new_error = email_error(&email.read());
+        set_error.set(new_error);
+        if new_error.is_some() {
+            // the focus moves to the field, so a screen reader reads its
+            // label, that it's invalid, and the error that describes it
+            if let Some(input) = input.get() {
+                _ = input.focus();
+            }
+        } else {
+            set_signed_up.set(true);
+        }
+    };
+
+    view! {
+        // `novalidate` turns off the browser's own messages, which can't be
+        // styled, in favor of these
+        <form on:submit=on_submit novalidate>
+            <label for="email">"Email"</label>
+            <input
+                id="email"
+                type="email"
+                node_ref=input
+                bind:value=(email, set_email)
+                // once there's an error, check again as the user fixes it
+                on:input=move |_| {
+                    if error.get_untracked().is_some() {
+                        set_error.set(email_error(&email.read_untracked()));
+                    }
+                }
+                aria-invalid=move || error.get().map(|_| "true")
+                aria-describedby=move || error.get().map(|_| "email-error")
+            />
+            <Show when=move || error.get().is_some()>
+                <p id="email-error" class="error">
+                    {move || error.get()}
+                </p>
+            </Show>
+            <button type="submit">"Sign up"</button>
+        </form>
+        <Show when=move || signed_up.get()>
+            <p role="status">"Thanks for signing up!"</p>
+        </Show>
+    }
+}
+// ANCHOR_END: sign_up
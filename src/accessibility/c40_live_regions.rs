@@ -0,0 +1,71 @@
+//! Code for "Announcing Changes with Live Regions".
+
+use leptos::prelude::*;
+
+// ANCHOR: announcer
+/// Writes to the page's live region, which screen readers read out whenever
+/// its text changes.
+#[derive(Clone, Copy)]
+pub struct Announcer(RwSignal<String>);
+
+impl Announcer {
+    pub fn announce(&self, message: impl Into<String>) {
+        let (region, message) = (self.0, message.into());
+        // clear it first, and set it on the next frame, so that saying the
+        // same thing twice is still a change
+        region.set(String::new());
+        request_animation_frame(move || region.set(message));
+    }
+}
+
+pub fn use_announcer() -> Announcer {
+    expect_context()
+}
+
+/// Provides an `Announcer` to its children, and renders the live region it
+/// writes to.
+#[component]
+pub fn LiveRegion(children: Children) -> impl IntoView {
+    let message = RwSignal::new(String::new());
+    provide_context(Announcer(message));
+
+    view! {
+        {children()}
+        // rendered from the start, and empty: a screen reader only notices
+        // changes to a live region that was already on the page
+        <div class="visually-hidden" role="status" aria-live="polite" aria-atomic="true">
+            {message}
+        </div>
+    }
+}
+// ANCHOR_END: announcer
+
+// ANCHOR: cart
+#[component]
+fn Cart() -> impl IntoView {
+    let announcer = use_announcer();
+    let (items, set_items) = signal(Vec::<&'static str>::new());
+
+    let add = move |item: &'static str| {
+        set_items.write().push(item);
+        let count = items.read_untracked().len();
+        announcer.announce(format!("Added {item}. {count} items in your cart."));
+    };
+
+    view! {
+        <button on:click=move |_| add("socks")>"Add socks"</button>
+        <button on:click=move |_| add("a hat")>"Add a hat"</button>
+        // seen, but not read out: the announcement says the same thing
+        <p aria-hidden="true">"Cart: " {move || items.read().len()}</p>
+    }
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    view! {
+        <LiveRegion>
+            <Cart/>
+        </LiveRegion>
+    }
+}
+// ANCHOR_END: cart
@@ -0,0 +1,9 @@
+//! Code for "Accessibility".
+//!
+//! Everything here is about what the browser does with the page, such as
+//! where the focus is and which attributes are set, so it's tested by the
+//! DOM tests in `tests/dom/accessibility.rs`.
+
+pub mod c40_focus;
+pub mod c40_form_errors;
+pub mod c40_live_regions;
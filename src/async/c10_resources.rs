@@ -0,0 +1,179 @@
+//! Code for "Loading Data with Resources".
+//!
+//! [`load_data`] fakes its delay with the `sleep` helper in the parent
+//! module, so the examples compile (and load) in every mode.
+
+use super::sleep;
+use leptos::prelude::*;
+use std::time::Duration;
+
+// ANCHOR: app
+// ANCHOR: load_data
+// Here we define an async function
+// This could be anything: a network request, database read, etc.
+// Here, we just multiply a number by 10
+pub async fn load_data(value: i32) -> i32 {
+    // fake a one-second delay
+    sleep(Duration::from_secs(1)).await;
+    value * 10
+}
+// ANCHOR_END: load_data
+
+#[component]
+pub fn App() -> impl IntoView {
+    // this count is our synchronous, local state
+    let (count, set_count) = signal(0);
+
+    // `LocalResource::new` takes a function that returns a `Future`
+    // it tracks any signals read in that function, so
+    // whenever `count` changes, it reloads
+    let async_data = LocalResource::new(move || load_data(count.get()));
+
+    // you can also create resources that only load once
+    // `OnceResource::new` takes a single `Future`, and never reloads
+    let stable = OnceResource::new(load_data(1));
+
+    // we can access the resource values with .get()
+    // this will reactively return None before the Future has resolved
+    // and update to Some(T) when it has resolved
+    let async_result = move || {
+        async_data
+            .get()
+            .map(|value| format!("Server returned {value:?}"))
+            // This loading state will only show before the first load
+            .unwrap_or_else(|| "Loading...".into())
+    };
+
+    view! {
+        <button
+            on:click=move |_| {
+                *set_count.write() += 1;
+            }
+        >
+            "Click me"
+        </button>
+        <p>
+            <code>"stable"</code>": " {move || stable.get()}
+        </p>
+        <p>
+            <code>"count"</code>": " {count}
+        </p>
+        <p>
+            <code>"async_value"</code>": "
+            {async_result}
+        </p>
+    }
+}
+
+pub fn main() {
+    leptos::mount::mount_to_body(App)
+}
+// ANCHOR_END: app
+
+#[component]
+pub fn ResourceNew() -> impl IntoView {
+    // ANCHOR: resource_new
+    // our source signal: some synchronous, local state
+    let (count, set_count) = signal(0);
+
+    // our resource
+    let async_data = Resource::new(
+        move || count.get(),
+        // every time `count` changes, this will run
+        |value| async move {
+            leptos::logging::log!("loading data from API");
+            load_data(value).await
+        },
+    );
+    // ANCHOR_END: resource_new
+
+    view! {
+        <button on:click=move |_| *set_count.write() += 1>"+1"</button>
+        <Suspense fallback=|| view! { <p>"Loading..."</p> }>
+            <p>{move || async_data.get()}</p>
+        </Suspense>
+    }
+}
+
+#[component]
+pub fn LocalResourceNew() -> impl IntoView {
+    // ANCHOR: local_resource
+    let (count, set_count) = signal(0);
+
+    // this only ever runs in the browser, and reruns when `count` changes
+    let async_data = LocalResource::new(move || load_data(count.get()));
+    // ANCHOR_END: local_resource
+
+    view! {
+        <button on:click=move |_| *set_count.write() += 1>"+1"</button>
+        <p>{move || async_data.get()}</p>
+    }
+}
+
+#[component]
+pub fn ShowData(data: i32) -> impl IntoView {
+    view! { <p>"Data: " {data}</p> }
+}
+
+#[component]
+pub fn OnceResourceNew() -> impl IntoView {
+    // ANCHOR: once_resource
+    let once = OnceResource::new(load_data(1));
+    // ANCHOR_END: once_resource
+
+    view! {
+        <Suspense fallback=|| view! { <p>"Loading..."</p> }>
+            <p>{move || once.get()}</p>
+        </Suspense>
+    }
+}
+
+#[component]
+pub fn MatchGet() -> impl IntoView {
+    let async_data = LocalResource::new(|| load_data(1));
+
+    // ANCHOR: get_match
+    view! {
+        <h1>"My Data"</h1>
+        {move || match async_data.get() {
+            None => view! { <p>"Loading..."</p> }.into_any(),
+            Some(data) => view! { <ShowData data/> }.into_any()
+        }}
+    }
+    // ANCHOR_END: get_match
+}
+
+#[component]
+pub fn AwaitResource() -> impl IntoView {
+    let async_data = Resource::new(|| 1, load_data);
+
+    // ANCHOR: suspend
+    view! {
+        <Suspense fallback=|| view! { <p>"Loading..."</p> }>
+            {move || Suspend::new(async move {
+                // a resource is also a `Future`: inside `Suspend`,
+                // `.await` waits for it to load, then returns its value
+                let value = async_data.await;
+                view! { <p>"Server returned " {value}</p> }
+            })}
+        </Suspense>
+    }
+    // ANCHOR_END: suspend
+}
+
+#[component]
+pub fn Refetch() -> impl IntoView {
+    // ANCHOR: refetch
+    let fetches = StoredValue::new(0);
+    let async_data = LocalResource::new(move || {
+        fetches.update_value(|fetches| *fetches += 1);
+        load_data(fetches.get_value())
+    });
+
+    view! {
+        // runs the fetcher again, even though nothing it reads has changed
+        <button on:click=move |_| async_data.refetch()>"Reload"</button>
+        <p>{move || async_data.get()}</p>
+    }
+    // ANCHOR_END: refetch
+}
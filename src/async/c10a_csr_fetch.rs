@@ -0,0 +1,110 @@
+//! Code for the "Fetching Data in a CSR App" section of "Loading Data with
+//! Resources".
+//!
+//! `gloo-net` uses the browser's `fetch`, so this is tested by the DOM tests
+//! in `tests/dom/csr_fetch.rs`, which fetch `data:` URLs rather than a real
+//! API so that they don't need a network connection.
+
+use gloo_net::http::Request;
+use leptos::prelude::*;
+use serde::{de::DeserializeOwned, Deserialize};
+use std::fmt;
+
+// ANCHOR: repo
+/// The parts of a GitHub repository this example uses. Any other fields in
+/// the response are ignored.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct Repo {
+    pub full_name: String,
+    pub description: Option<String>,
+    pub stargazers_count: u32,
+}
+
+pub fn repo_url(name: &str) -> String {
+    format!("https://api.github.com/repos/{name}")
+}
+// ANCHOR_END: repo
+
+// ANCHOR: fetch_json
+/// Why a request failed.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FetchError {
+    /// The request couldn't be made, or the body wasn't the JSON expected.
+    Request(String),
+    /// The server responded, but with an error status, like 404.
+    Status(u16),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::Request(e) => write!(f, "request failed: {e}"),
+            FetchError::Status(status) => write!(f, "server responded with {status}"),
+        }
+    }
+}
+
+impl From<gloo_net::Error> for FetchError {
+    fn from(error: gloo_net::Error) -> Self {
+        // `gloo_net::Error` isn't `Clone`, which a resource's value has to be
+        FetchError::Request(error.to_string())
+    }
+}
+
+/// Fetches `url`, and parses the response as JSON.
+pub async fn fetch_json<T: DeserializeOwned>(url: &str) -> Result<T, FetchError> {
+    let response = Request::get(url).send().await?;
+    // `fetch` only fails if there's no response at all: a 404 is a success
+    if !response.ok() {
+        return Err(FetchError::Status(response.status()));
+    }
+    Ok(response.json().await?)
+}
+// ANCHOR_END: fetch_json
+
+// ANCHOR: repo_info
+#[component]
+pub fn RepoInfo(#[prop(into)] url: Signal<String>) -> impl IntoView {
+    // refetches whenever the URL changes; a `LocalResource`, because the
+    // `fetch` future isn't `Send`, and there's no server to load it on
+    let repo = LocalResource::new(move || {
+        let url = url.get();
+        async move { fetch_json::<Repo>(&url).await }
+    });
+
+    view! {
+        <Suspense fallback=|| view! { <p class="loading">"Loading..."</p> }>
+            {move || Suspend::new(async move {
+                match repo.await {
+                    Ok(repo) => {
+                        view! {
+                            <h2>{repo.full_name}</h2>
+                            <p>{repo.description.unwrap_or_default()}</p>
+                            <p class="stars">{repo.stargazers_count} " stars"</p>
+                        }
+                            .into_any()
+                    }
+                    Err(e) => view! { <p class="error">{e.to_string()}</p> }.into_any(),
+                }
+            })}
+        </Suspense>
+    }
+}
+// ANCHOR_END: repo_info
+
+// ANCHOR: app
+#[component]
+pub fn App() -> impl IntoView {
+    let (name, set_name) = signal("leptos-rs/leptos".to_string());
+
+    view! {
+        // only fetches when the input is changed, not on every keystroke
+        <input
+            type="text"
+            prop:value=name
+            on:change:target=move |ev| set_name.set(ev.target().value())
+        />
+        <RepoInfo url=Signal::derive(move || repo_url(&name.read()))/>
+    }
+}
+// ANCHOR_END: app
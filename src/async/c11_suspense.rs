@@ -0,0 +1,182 @@
+//! Code for "`<Suspense/>`".
+//!
+//! `load_a` takes one second and `load_b` takes two, so that the nested
+//! example can show the outer content before the inner content.
+
+use super::sleep;
+use leptos::prelude::*;
+use std::time::Duration;
+
+pub async fn load_a(count: i32) -> i32 {
+    sleep(Duration::from_secs(1)).await;
+    count * 10
+}
+
+pub async fn load_b(count: i32) -> i32 {
+    sleep(Duration::from_secs(2)).await;
+    count * 100
+}
+
+#[component]
+pub fn ShowA(a: i32) -> impl IntoView {
+    view! { <p>"A: " {a}</p> }
+}
+
+#[component]
+pub fn ShowB(b: i32) -> impl IntoView {
+    view! { <p>"B: " {b}</p> }
+}
+
+#[component]
+pub fn MatchBoth() -> impl IntoView {
+    // ANCHOR: match_both
+    let a = LocalResource::new(|| load_a(1));
+    let b = LocalResource::new(|| load_b(1));
+
+    view! {
+        <h1>"My Data"</h1>
+        {move || match (a.get(), b.get()) {
+            (Some(a), Some(b)) => view! {
+                <ShowA a/>
+                <ShowB b/>
+            }.into_any(),
+            _ => view! { <p>"Loading..."</p> }.into_any()
+        }}
+    }
+    // ANCHOR_END: match_both
+}
+
+#[component]
+pub fn SuspenseBoth() -> impl IntoView {
+    // ANCHOR: suspense
+    let (count, set_count) = signal(1);
+    let a = Resource::new(move || count.get(), load_a);
+    let b = Resource::new(|| 1, load_b);
+
+    view! {
+        <h1>"My Data"</h1>
+        <button on:click=move |_| *set_count.write() += 1>"Reload A"</button>
+        <Suspense
+            fallback=move || view! { <p>"Loading..."</p> }
+        >
+            <h2>"My Data"</h2>
+            <h3>"A"</h3>
+            {move || {
+                a.get()
+                    .map(|a| view! { <ShowA a/> })
+            }}
+            <h3>"B"</h3>
+            {move || {
+                b.get()
+                    .map(|b| view! { <ShowB b/> })
+            }}
+        </Suspense>
+    }
+    // ANCHOR_END: suspense
+}
+
+#[component]
+pub fn NestedSuspense() -> impl IntoView {
+    // ANCHOR: nested
+    let a = Resource::new(|| 1, load_a);
+    let b = Resource::new(|| 1, load_b);
+
+    view! {
+        <Suspense fallback=|| view! { <p>"Loading A..."</p> }>
+            // `a` is read by the outer <Suspense/>...
+            {move || a.get().map(|a| view! { <ShowA a/> })}
+            <Suspense fallback=|| view! { <p>"Loading B..."</p> }>
+                // ...but `b` is read by the inner one, so it doesn't
+                // hold up the rest of the outer one's children
+                {move || b.get().map(|b| view! { <ShowB b/> })}
+            </Suspense>
+        </Suspense>
+    }
+    // ANCHOR_END: nested
+}
+
+#[component]
+pub fn SuspendBoth() -> impl IntoView {
+    // ANCHOR: suspend
+    let a = Resource::new(|| 1, load_a);
+    let b = Resource::new(|| 1, load_b);
+
+    view! {
+        <Suspense fallback=|| view! { <p>"Loading..."</p> }>
+            {move || Suspend::new(async move {
+                // both resources are already loading, so this takes
+                // as long as the slower of the two, not the sum
+                let a = a.await;
+                let b = b.await;
+                view! {
+                    <ShowA a/>
+                    <ShowB b/>
+                }
+            })}
+        </Suspense>
+    }
+    // ANCHOR_END: suspend
+}
+
+// ANCHOR: await
+async fn fetch_monkeys(monkey: i32) -> i32 {
+    // maybe this didn't need to be async
+    monkey * 2
+}
+
+#[component]
+pub fn Monkeys() -> impl IntoView {
+    view! {
+        <Await
+            // `future` provides the `Future` to be resolved
+            future=fetch_monkeys(3)
+            // the data is bound to whatever variable name you provide
+            let:data
+        >
+            // you receive the data by reference and can use it in your view here
+            <p>{*data} " little monkeys, jumping on the bed."</p>
+        </Await>
+    }
+}
+// ANCHOR_END: await
+
+// ANCHOR: app
+pub async fn important_api_call(name: String) -> String {
+    sleep(Duration::from_secs(1)).await;
+    name.to_ascii_uppercase()
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    let (name, set_name) = signal("Bill".to_string());
+
+    // this will reload every time `name` changes
+    let async_data = Resource::new(move || name.get(), important_api_call);
+
+    view! {
+        <input
+            on:input:target=move |ev| {
+                set_name.set(ev.target().value());
+            }
+            prop:value=name
+        />
+        <p><code>"name:"</code> {name}</p>
+        <Suspense
+            // the fallback will show whenever a resource
+            // read "under" the suspense is loading
+            fallback=move || view! { <p>"Loading..."</p> }
+        >
+            // the children will be rendered once initially,
+            // and then whenever any resources has been resolved
+            <p>
+                "Your shouting name is "
+                {move || async_data.get()}
+            </p>
+        </Suspense>
+    }
+}
+
+pub fn main() {
+    leptos::mount::mount_to_body(App)
+}
+// ANCHOR_END: app
@@ -0,0 +1,105 @@
+//! Code for "`<Transition/>`".
+//!
+//! [`App`] is the tabbed example with `<Transition/>`, and [`SuspenseTabs`]
+//! is the same example with `<Suspense/>`, for comparison.
+
+use super::sleep;
+use leptos::prelude::*;
+use std::time::Duration;
+
+// ANCHOR: app
+pub async fn important_api_call(id: usize) -> String {
+    sleep(Duration::from_secs(1)).await;
+    match id {
+        0 => "Alice",
+        1 => "Bob",
+        2 => "Carol",
+        _ => "User not found",
+    }
+    .to_string()
+}
+
+#[component]
+pub fn TabButtons(tab: ReadSignal<usize>, set_tab: WriteSignal<usize>) -> impl IntoView {
+    let button = move |id: usize, label: &'static str| {
+        view! {
+            <button
+                on:click=move |_| set_tab.set(id)
+                class:selected=move || tab.get() == id
+            >
+                {label}
+            </button>
+        }
+    };
+
+    view! {
+        {button(0, "Tab A")}
+        {button(1, "Tab B")}
+        {button(2, "Tab C")}
+    }
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    let (tab, set_tab) = signal(0);
+    // ANCHOR: pending
+    let (pending, set_pending) = signal(false);
+    // ANCHOR_END: pending
+
+    // this will reload every time `tab` changes
+    let user_data = Resource::new(move || tab.get(), important_api_call);
+
+    view! {
+        <div class="buttons">
+            <TabButtons tab set_tab/>
+            // ANCHOR: pending_view
+            {move || if pending.get() {
+                "Hang on..."
+            } else {
+                "Ready."
+            }}
+            // ANCHOR_END: pending_view
+        </div>
+        // ANCHOR: transition
+        <Transition
+            // the fallback will show initially
+            // on subsequent reloads, the current child will
+            // continue showing
+            fallback=move || view! { <p>"Loading initial data..."</p> }
+            // this will be set to `true` whenever the transition is ongoing
+            set_pending
+        >
+            <p>
+                {move || user_data.get()}
+            </p>
+        </Transition>
+        // ANCHOR_END: transition
+    }
+}
+
+pub fn main() {
+    leptos::mount::mount_to_body(App)
+}
+// ANCHOR_END: app
+
+#[component]
+pub fn SuspenseTabs() -> impl IntoView {
+    let (tab, set_tab) = signal(0);
+    let user_data = Resource::new(move || tab.get(), important_api_call);
+
+    view! {
+        <div class="buttons">
+            <TabButtons tab set_tab/>
+        </div>
+        // ANCHOR: suspense
+        <Suspense
+            // the fallback will show every time the resource reloads
+            fallback=move || view! { <p>"Loading..."</p> }
+        >
+            <p>
+                {move || user_data.get()}
+            </p>
+        </Suspense>
+        // ANCHOR_END: suspense
+    }
+}
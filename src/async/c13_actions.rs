@@ -0,0 +1,141 @@
+//! Code for "Mutating Data with Actions".
+//!
+//! [`add_todo`] fakes a slow request with the same `sleep` helper as the
+//! resource examples, and returns a made-up ID rather than a UUID.
+
+use super::sleep;
+use leptos::{html::Input, prelude::*};
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+// ANCHOR: app
+static NEXT_ID: AtomicUsize = AtomicUsize::new(1);
+
+// Here we define an async function
+// This could be anything: a network request, database read, etc.
+// Think of it as a mutation: some imperative async action you run,
+// whereas a resource would be some async data you load
+// ANCHOR: add_todo
+pub async fn add_todo(text: &str) -> usize {
+    _ = text;
+    // fake a one-second delay
+    sleep(Duration::from_secs(1)).await;
+    // pretend this is a post ID or something
+    NEXT_ID.fetch_add(1, Ordering::Relaxed)
+}
+// ANCHOR_END: add_todo
+
+#[component]
+pub fn App() -> impl IntoView {
+    // an action takes an async function with single argument
+    // it can be a simple type, a struct, or ()
+    // ANCHOR: action_new
+    let add_todo_action = Action::new(|input: &String| {
+        // the input is a reference, but we need the Future to own it
+        // this is important: we need to clone and move into the Future
+        // so it has a 'static lifetime
+        let input = input.to_owned();
+        async move { add_todo(&input).await }
+    });
+    // ANCHOR_END: action_new
+
+    // actions provide a bunch of synchronous, reactive variables
+    // that tell us different things about the state of the action
+    // ANCHOR: signals
+    // MappedSignal<Option<String>>
+    let submitted = add_todo_action.input();
+    // Memo<bool>
+    let pending = add_todo_action.pending();
+    // MappedSignal<Option<usize>>
+    let todo_id = add_todo_action.value();
+    // ANCHOR_END: signals
+
+    let input_ref = NodeRef::<Input>::new();
+
+    view! {
+        // ANCHOR: form_view
+        <form
+            on:submit=move |ev| {
+                ev.prevent_default(); // don't reload the page...
+                let input = input_ref.get().expect("input to exist");
+                // ANCHOR: dispatch
+                add_todo_action.dispatch(input.value());
+                // ANCHOR_END: dispatch
+            }
+        >
+            <label>
+                "What do you need to do?"
+                <input type="text"
+                    node_ref=input_ref
+                />
+            </label>
+            <button type="submit">"Add Todo"</button>
+        </form>
+        // use our loading state
+        <p>{move || pending.get().then_some("Loading...")}</p>
+        // ANCHOR_END: form_view
+        <p>
+            "Submitted: "
+            <code>{move || format!("{:#?}", submitted.get())}</code>
+        </p>
+        <p>
+            "Pending: "
+            <code>{move || format!("{:#?}", pending.get())}</code>
+        </p>
+        <p>
+            "Todo ID: "
+            <code>{move || format!("{:#?}", todo_id.get())}</code>
+        </p>
+    }
+}
+
+pub fn main() {
+    leptos::mount::mount_to_body(App)
+}
+// ANCHOR_END: app
+
+/// Creates one action for each shape of input.
+pub fn action_inputs() {
+    // ANCHOR: action_inputs
+    // if there's a single argument, just use that
+    let action1 = Action::new(|input: &String| {
+        let input = input.clone();
+        async move { input.len() }
+    });
+
+    // if there are no arguments, use the unit type `()`
+    let action2 = Action::new(|_: &()| async { "done" });
+
+    // if there are multiple arguments, use a tuple
+    let action3 = Action::new(|input: &(usize, String)| {
+        let (count, text) = input.clone();
+        async move { text.repeat(count) }
+    });
+    // ANCHOR_END: action_inputs
+    _ = (action1, action2, action3);
+}
+
+/// Dispatches an action whose `Future` isn't `Send`.
+#[component]
+pub fn LocalAction() -> impl IntoView {
+    // ANCHOR: new_local
+    // a browser timer isn't `Send`, so `Action::new` won't accept it...
+    let save = Action::new_local(|millis: &u32| {
+        let millis = *millis;
+        async move {
+            gloo_timers::future::TimeoutFuture::new(millis).await;
+            format!("saved after {millis}ms")
+        }
+    });
+    // ...but `Action::new_local` will: it only ever runs on this thread
+    // ANCHOR_END: new_local
+
+    view! {
+        <button on:click=move |_| {
+            save.dispatch(500);
+        }>"Save"</button>
+        <p>{move || save.value().get()}</p>
+    }
+}
@@ -0,0 +1,244 @@
+//! Code for "Storing Data in the Browser with IndexedDB".
+//!
+//! IndexedDB only exists in a browser, so this is tested by the DOM tests in
+//! `tests/dom/indexed_db.rs`. It uses `web-sys` directly, rather than a
+//! wrapper crate, so that every step is visible.
+
+use js_sys::Promise;
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use wasm_bindgen::{prelude::*, JsCast};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{IdbDatabase, IdbObjectStoreParameters, IdbRequest, IdbTransactionMode};
+
+// ANCHOR: note
+/// A note, which is stored as a plain JavaScript object.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Note {
+    /// The note's key, which the database picks when the note is added.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+    pub text: String,
+    /// Notes can be looked up by tag, through an index.
+    pub tag: String,
+}
+// ANCHOR_END: note
+
+/// Something went wrong in the database.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DbError(pub String);
+
+impl fmt::Display for DbError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "database error: {}", self.0)
+    }
+}
+
+impl From<JsValue> for DbError {
+    fn from(value: JsValue) -> Self {
+        DbError(format!("{value:?}"))
+    }
+}
+
+impl From<serde_wasm_bindgen::Error> for DbError {
+    fn from(error: serde_wasm_bindgen::Error) -> Self {
+        DbError(error.to_string())
+    }
+}
+
+// ANCHOR: request
+/// Waits for a request to succeed, and returns its result.
+///
+/// IndexedDB predates promises: each request calls `onsuccess` or `onerror`
+/// when it's done. Wrapping those in a `Promise` makes it a `Future`.
+async fn finished(request: &IdbRequest) -> Result<JsValue, DbError> {
+    let promise = Promise::new(&mut |resolve, reject| {
+        request.set_onsuccess(Some(&resolve));
+        request.set_onerror(Some(&reject));
+    });
+    match JsFuture::from(promise).await {
+        Ok(_) => Ok(request.result()?),
+        Err(_) => {
+            let error = request.error().ok().flatten();
+            Err(DbError(error.map(|e| e.message()).unwrap_or_default()))
+        }
+    }
+}
+// ANCHOR_END: request
+
+// ANCHOR: open
+const DATABASE: &str = "leptos-book";
+const NOTES: &str = "notes";
+
+/// The notes in the browser's database.
+#[derive(Clone, Debug)]
+pub struct Notes(IdbDatabase);
+
+impl Notes {
+    pub async fn open() -> Result<Self, DbError> {
+        let factory = window()
+            .indexed_db()?
+            .ok_or_else(|| DbError("IndexedDB isn't available".to_string()))?;
+        let request = factory.open_with_u32(DATABASE, 1)?;
+
+        // stores and indexes can only be created here, which runs the first
+        // time the database is opened, and whenever its version goes up
+        let on_upgrade = Closure::<dyn Fn()>::new({
+            let request = request.clone();
+            move || {
+                let db = request.result().unwrap().unchecked_into::<IdbDatabase>();
+                let params = IdbObjectStoreParameters::new();
+                params.set_key_path(&"id".into());
+                params.set_auto_increment(true);
+                let store = db
+                    .create_object_store_with_optional_parameters(NOTES, &params)
+                    .unwrap();
+                store.create_index_with_str("tag", "tag").unwrap();
+            }
+        });
+        request.set_onupgradeneeded(Some(on_upgrade.as_ref().unchecked_ref()));
+
+        let db = finished(&request).await?;
+        Ok(Notes(db.unchecked_into()))
+    }
+}
+// ANCHOR_END: open
+
+// ANCHOR: queries
+impl Notes {
+    /// Adds a note, returning its new key.
+    pub async fn add(&self, note: &Note) -> Result<u32, DbError> {
+        let transaction = self
+            .0
+            .transaction_with_str_and_mode(NOTES, IdbTransactionMode::Readwrite)?;
+        let note = serde_wasm_bindgen::to_value(note)?;
+        let key = finished(&transaction.object_store(NOTES)?.add(&note)?).await?;
+        Ok(serde_wasm_bindgen::from_value(key)?)
+    }
+
+    /// Every note, in the order they were added.
+    pub async fn all(&self) -> Result<Vec<Note>, DbError> {
+        let store = self.0.transaction_with_str(NOTES)?.object_store(NOTES)?;
+        let notes = finished(&store.get_all()?).await?;
+        Ok(serde_wasm_bindgen::from_value(notes)?)
+    }
+
+    /// The notes with `tag`, found through the index rather than by looking
+    /// at every note.
+    pub async fn tagged(&self, tag: &str) -> Result<Vec<Note>, DbError> {
+        let store = self.0.transaction_with_str(NOTES)?.object_store(NOTES)?;
+        let index = store.index("tag")?;
+        let notes = finished(&index.get_all_with_key(&tag.into())?).await?;
+        Ok(serde_wasm_bindgen::from_value(notes)?)
+    }
+
+    pub async fn delete(&self, id: u32) -> Result<(), DbError> {
+        let transaction = self
+            .0
+            .transaction_with_str_and_mode(NOTES, IdbTransactionMode::Readwrite)?;
+        finished(&transaction.object_store(NOTES)?.delete(&id.into())?).await?;
+        Ok(())
+    }
+}
+// ANCHOR_END: queries
+
+// ANCHOR: app
+#[component]
+pub fn NoteApp() -> impl IntoView {
+    let (text, set_text) = signal(String::new());
+    let (tag, set_tag) = signal("work".to_string());
+    // an empty filter shows every note
+    let (filter, set_filter) = signal(String::new());
+
+    // JavaScript objects aren't `Send`, so these use the local versions
+    let add = Action::new_local(|note: &Note| {
+        let note = note.clone();
+        async move { Notes::open().await?.add(&note).await }
+    });
+    let delete = Action::new_local(|id: &u32| {
+        let id = *id;
+        async move { Notes::open().await?.delete(id).await }
+    });
+
+    // loaded in the browser, and again after every change, or a new filter
+    let notes = LocalResource::new(move || {
+        add.version().get();
+        delete.version().get();
+        let filter = filter.get();
+        async move {
+            let notes = Notes::open().await?;
+            if filter.is_empty() {
+                notes.all().await
+            } else {
+                notes.tagged(&filter).await
+            }
+        }
+    });
+
+    let on_submit = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+        add.dispatch_local(Note {
+            id: None,
+            text: text.get(),
+            tag: tag.get(),
+        });
+        set_text.set(String::new());
+    };
+
+    view! {
+        <form on:submit=on_submit>
+            <input type="text" bind:value=(text, set_text)/>
+            <select bind:value=(tag, set_tag)>
+                <option value="work">"Work"</option>
+                <option value="home">"Home"</option>
+            </select>
+            <button type="submit">"Add"</button>
+        </form>
+        <select class="filter" bind:value=(filter, set_filter)>
+            <option value="">"All"</option>
+            <option value="work">"Work"</option>
+            <option value="home">"Home"</option>
+        </select>
+        <Suspense fallback=|| view! { <p>"Loading..."</p> }>
+            {move || Suspend::new(async move {
+                match notes.await {
+                    Ok(notes) => {
+                        view! {
+                            <ul>
+                                {notes
+                                    .into_iter()
+                                    .map(|note| {
+                                        let id = note.id.unwrap_or_default();
+                                        view! {
+                                            <li>
+                                                {note.text} " (" {note.tag} ") "
+                                                <button on:click=move |_| {
+                                                    delete.dispatch_local(id);
+                                                }>"Delete"</button>
+                                            </li>
+                                        }
+                                    })
+                                    .collect_view()}
+                            </ul>
+                        }
+                            .into_any()
+                    }
+                    Err(e) => view! { <p class="error">{e.to_string()}</p> }.into_any(),
+                }
+            })}
+        </Suspense>
+    }
+}
+// ANCHOR_END: app
+
+impl Notes {
+    /// Deletes every note.
+    pub async fn clear(&self) -> Result<(), DbError> {
+        let transaction = self
+            .0
+            .transaction_with_str_and_mode(NOTES, IdbTransactionMode::Readwrite)?;
+        finished(&transaction.object_store(NOTES)?.clear()?).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,177 @@
+//! Code for "Leptos with GraphQL".
+//!
+//! Only compiled with the `graphql` feature. The query's types are generated
+//! from `graphql/schema.graphql` and `graphql/country.graphql`, next to this
+//! file. Sending the query needs a browser, but building it and handling
+//! the response don't, so the tests below check those.
+
+use super::c10a_csr_fetch::FetchError;
+use gloo_net::http::Request;
+use graphql_client::{GraphQLQuery, Response};
+use leptos::prelude::*;
+use std::fmt;
+
+// ANCHOR: query
+/// The query in `country.graphql`. The derive checks it against the schema,
+/// and generates a module named after it, `country_query`, with a type for
+/// its variables and for each level of its response.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/async/graphql/schema.graphql",
+    query_path = "src/async/graphql/country.graphql",
+    response_derives = "Clone, Debug, PartialEq"
+)]
+pub struct CountryQuery;
+// ANCHOR_END: query
+
+// ANCHOR: errors
+/// Why a query failed.
+#[derive(Clone, Debug, PartialEq)]
+pub enum GraphQLError {
+    /// The request itself failed, as in "Fetching Data in a CSR App".
+    Fetch(FetchError),
+    /// The server ran the query, and reported errors in the response.
+    Query(Vec<String>),
+}
+
+impl fmt::Display for GraphQLError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GraphQLError::Fetch(e) => e.fmt(f),
+            GraphQLError::Query(messages) => write!(f, "query failed: {}", messages.join("; ")),
+        }
+    }
+}
+
+impl From<gloo_net::Error> for GraphQLError {
+    fn from(error: gloo_net::Error) -> Self {
+        GraphQLError::Fetch(error.into())
+    }
+}
+
+/// Turns a response into its data, or its errors.
+///
+/// A GraphQL server usually responds with `200 OK` even when the query
+/// fails, and lists what went wrong in the body's `errors` instead.
+pub fn into_result<T>(response: Response<T>) -> Result<T, GraphQLError> {
+    match (response.data, response.errors) {
+        (_, Some(errors)) if !errors.is_empty() => Err(GraphQLError::Query(
+            errors.into_iter().map(|error| error.message).collect(),
+        )),
+        (Some(data), _) => Ok(data),
+        (None, _) => Err(GraphQLError::Query(vec!["no data".to_string()])),
+    }
+}
+// ANCHOR_END: errors
+
+// ANCHOR: run_query
+/// Sends any query to the GraphQL server at `url`.
+pub async fn run_query<Q: GraphQLQuery>(
+    url: &str,
+    variables: Q::Variables,
+) -> Result<Q::ResponseData, GraphQLError> {
+    // the operation's name, its text, and its variables, as JSON
+    let body = Q::build_query(variables);
+    let response = Request::post(url).json(&body)?.send().await?;
+    if !response.ok() {
+        return Err(GraphQLError::Fetch(FetchError::Status(response.status())));
+    }
+    into_result(response.json::<Response<Q::ResponseData>>().await?)
+}
+// ANCHOR_END: run_query
+
+// ANCHOR: app
+pub const COUNTRIES_API: &str = "https://countries.trevorblades.com/";
+
+#[component]
+pub fn Country(#[prop(into)] code: Signal<String>) -> impl IntoView {
+    let country = LocalResource::new(move || {
+        let variables = country_query::Variables { code: code.get() };
+        async move { run_query::<CountryQuery>(COUNTRIES_API, variables).await }
+    });
+
+    view! {
+        <Suspense fallback=|| view! { <p>"Loading..."</p> }>
+            {move || Suspend::new(async move {
+                // the response's types follow the shape of the query
+                match country.await.map(|data| data.country) {
+                    Ok(Some(country)) => {
+                        let languages = country
+                            .languages
+                            .into_iter()
+                            .map(|language| language.name)
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        view! {
+                            <h2>{country.emoji} " " {country.name}</h2>
+                            <p>"Capital: " {country.capital.unwrap_or_default()}</p>
+                            <p>"Languages: " {languages}</p>
+                        }
+                            .into_any()
+                    }
+                    // `country` is nullable in the schema, so it's an `Option`
+                    Ok(None) => view! { <p>"No such country."</p> }.into_any(),
+                    Err(e) => view! { <p class="error">{e.to_string()}</p> }.into_any(),
+                }
+            })}
+        </Suspense>
+    }
+}
+// ANCHOR_END: app
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn the_query_is_sent_with_its_variables() {
+        let body = CountryQuery::build_query(country_query::Variables {
+            code: "NO".to_string(),
+        });
+        let body = serde_json::to_value(body).unwrap();
+        assert_eq!(body["operationName"], "CountryQuery");
+        assert_eq!(body["variables"], json!({ "code": "NO" }));
+        assert!(body["query"]
+            .as_str()
+            .unwrap()
+            .contains("country(code: $code)"));
+    }
+
+    #[test]
+    fn data_is_parsed_into_the_generated_types() {
+        let response = serde_json::from_value::<Response<country_query::ResponseData>>(json!({
+            "data": {
+                "country": {
+                    "name": "Norway",
+                    "capital": "Oslo",
+                    "emoji": "🇳🇴",
+                    "languages": [
+                        { "name": "Norwegian" },
+                        { "name": "Norwegian Bokmål" },
+                    ],
+                },
+            },
+        }))
+        .unwrap();
+        let country = into_result(response).unwrap().country.unwrap();
+        assert_eq!(country.name, "Norway");
+        assert_eq!(country.capital.as_deref(), Some("Oslo"));
+        assert_eq!(country.languages.len(), 2);
+    }
+
+    #[test]
+    fn errors_in_the_body_are_errors() {
+        let response = serde_json::from_value::<Response<country_query::ResponseData>>(json!({
+            "data": null,
+            "errors": [{ "message": "Variable \"$code\" of required type \"ID!\" was not provided." }],
+        }))
+        .unwrap();
+        assert_eq!(
+            into_result(response),
+            Err(GraphQLError::Query(vec![
+                "Variable \"$code\" of required type \"ID!\" was not provided.".to_string()
+            ]))
+        );
+    }
+}
@@ -0,0 +1,164 @@
+//! Code for "Calling gRPC Services".
+//!
+//! Only compiled with the `grpc` feature. The client is generated from
+//! `grpc/inventory.proto`, next to this file, by the crate's `build.rs`.
+//! Sending a request through `tonic-web-wasm-client` needs a browser, so the
+//! tests below send it straight to the generated server instead, without any
+//! network in between.
+
+use leptos::prelude::*;
+use std::fmt;
+use tonic::{
+    client::GrpcService,
+    codegen::{Body, Bytes, StdError},
+    Code, Status,
+};
+
+// ANCHOR: include
+/// The messages and client generated from `inventory.proto`, which
+/// `build.rs` writes to Cargo's `OUT_DIR`.
+pub mod inventory {
+    tonic::include_proto!("inventory");
+}
+
+use inventory::{inventory_client::InventoryClient, StockRequest};
+// ANCHOR_END: include
+
+// ANCHOR: errors
+/// Why a call failed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GrpcError {
+    pub code: Code,
+    pub message: String,
+}
+
+impl fmt::Display for GrpcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.code.description(), self.message)
+    }
+}
+
+impl From<Status> for GrpcError {
+    fn from(status: Status) -> Self {
+        // `Status` carries metadata and details that a view has no use for
+        GrpcError {
+            code: status.code(),
+            message: status.message().to_string(),
+        }
+    }
+}
+// ANCHOR_END: errors
+
+// ANCHOR: stock
+/// Asks the inventory service how many of `sku` are in stock.
+///
+/// This works with any transport the client can use: the browser's `fetch`
+/// in the app, or the server itself in the tests.
+pub async fn stock<T>(client: &mut InventoryClient<T>, sku: String) -> Result<u32, GrpcError>
+where
+    T: GrpcService<tonic::body::Body>,
+    T::Error: Into<StdError>,
+    T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+    <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+{
+    let reply = client.get_stock(StockRequest { sku }).await?;
+    Ok(reply.into_inner().quantity)
+}
+// ANCHOR_END: stock
+
+// ANCHOR: app
+/// Where the gRPC server, or the proxy in front of it, accepts gRPC-web.
+pub const INVENTORY_API: &str = "http://localhost:50051";
+
+#[component]
+pub fn Stock(#[prop(into)] sku: Signal<String>) -> impl IntoView {
+    // the web client's futures aren't `Send`, so this is a `LocalResource`
+    let quantity = LocalResource::new(move || {
+        let sku = sku.get();
+        async move {
+            let transport = tonic_web_wasm_client::Client::new(INVENTORY_API.to_string());
+            stock(&mut InventoryClient::new(transport), sku).await
+        }
+    });
+
+    view! {
+        <Suspense fallback=|| view! { <p>"Loading..."</p> }>
+            {move || Suspend::new(async move {
+                match quantity.await {
+                    Ok(0) => view! { <p>"Out of stock."</p> }.into_any(),
+                    Ok(quantity) => view! { <p>{quantity} " in stock."</p> }.into_any(),
+                    // the server's status code says what went wrong
+                    Err(e) if e.code == Code::NotFound => {
+                        view! { <p>"No such item."</p> }.into_any()
+                    }
+                    Err(e) => view! { <p class="error">{e.to_string()}</p> }.into_any(),
+                }
+            })}
+        </Suspense>
+    }
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    let (sku, set_sku) = signal("LEP-001".to_string());
+
+    view! {
+        <input
+            type="text"
+            prop:value=sku
+            on:change:target=move |ev| set_sku.set(ev.target().value())
+        />
+        <Stock sku/>
+    }
+}
+// ANCHOR_END: app
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::inventory::{
+        inventory_server::{Inventory, InventoryServer},
+        StockReply,
+    };
+    use super::*;
+    use futures::executor::block_on;
+    use tonic::{Request, Response};
+
+    struct Warehouse;
+
+    #[tonic::async_trait]
+    impl Inventory for Warehouse {
+        async fn get_stock(
+            &self,
+            request: Request<StockRequest>,
+        ) -> Result<Response<StockReply>, Status> {
+            let sku = request.into_inner().sku;
+            let quantity = match sku.as_str() {
+                "LEP-001" => 12,
+                "LEP-002" => 0,
+                _ => return Err(Status::not_found(format!("no item {sku}"))),
+            };
+            Ok(Response::new(StockReply { sku, quantity }))
+        }
+    }
+
+    fn client() -> InventoryClient<InventoryServer<Warehouse>> {
+        InventoryClient::new(InventoryServer::new(Warehouse))
+    }
+
+    #[test]
+    fn the_reply_is_decoded() {
+        assert_eq!(block_on(stock(&mut client(), "LEP-001".into())), Ok(12));
+        assert_eq!(block_on(stock(&mut client(), "LEP-002".into())), Ok(0));
+    }
+
+    #[test]
+    fn a_status_is_an_error() {
+        assert_eq!(
+            block_on(stock(&mut client(), "LEP-999".into())),
+            Err(GrpcError {
+                code: Code::NotFound,
+                message: "no item LEP-999".to_string(),
+            })
+        );
+    }
+}
@@ -0,0 +1,128 @@
+//! Code for "Debounced Search".
+//!
+//! [`search`] fakes a slow request with the `sleep` helper in the parent
+//! module. The timers only run in a browser, so this is tested by the DOM
+//! tests in `tests/dom/debounced_search.rs`.
+
+use super::sleep;
+use futures::future::{abortable, AbortHandle};
+use leptos::prelude::*;
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+// ANCHOR: search
+const CRATES: &[&str] = &[
+    "leptos",
+    "leptos_axum",
+    "leptos_meta",
+    "leptos_router",
+    "reactive_graph",
+    "reactive_stores",
+    "server_fn",
+    "tachys",
+];
+
+/// How many searches have run to the end, rather than being cancelled.
+pub static SEARCHES_FINISHED: AtomicUsize = AtomicUsize::new(0);
+
+/// The crates whose names contain `query`, after a slow request.
+pub async fn search(query: String) -> Vec<String> {
+    sleep(Duration::from_millis(500)).await;
+    SEARCHES_FINISHED.fetch_add(1, Ordering::Relaxed);
+    CRATES
+        .iter()
+        .filter(|name| name.contains(query.as_str()))
+        .map(|name| name.to_string())
+        .collect()
+}
+// ANCHOR_END: search
+
+// ANCHOR: use_debounced
+/// A signal that follows `source`, but only once it has stopped changing for
+/// `delay`.
+pub fn use_debounced<T>(source: Signal<T>, delay: Duration) -> ReadSignal<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    let (debounced, set_debounced) = signal(source.get_untracked());
+    let timer = StoredValue::new(None::<TimeoutHandle>);
+
+    Effect::new(move |has_run: Option<()>| {
+        let value = source.get();
+        // the first run is the value `debounced` already started with
+        if has_run.is_none() {
+            return;
+        }
+        // every change restarts the timer, so only the last one gets through
+        if let Some(timer) = timer.get_value() {
+            timer.clear();
+        }
+        let handle = set_timeout_with_handle(move || set_debounced.set(value), delay);
+        timer.set_value(handle.ok());
+    });
+
+    // don't set the signal after the component that owns it is gone
+    on_cleanup(move || {
+        if let Some(timer) = timer.try_get_value().flatten() {
+            timer.clear();
+        }
+    });
+
+    debounced
+}
+// ANCHOR_END: use_debounced
+
+// ANCHOR: app
+#[component]
+pub fn App() -> impl IntoView {
+    let (query, set_query) = signal(String::new());
+    let debounced = use_debounced(query.into(), Duration::from_millis(250));
+
+    // the search that's still running, if there is one
+    let in_flight = StoredValue::new(None::<AbortHandle>);
+
+    let results = Resource::new(
+        move || debounced.get(),
+        move |query: String| {
+            // a new search makes the last one's results useless, so stop it
+            // rather than letting it finish
+            let (request, handle) = abortable(search(query.clone()));
+            if let Some(previous) = in_flight.try_update_value(|h| h.replace(handle)).flatten() {
+                previous.abort();
+            }
+            async move {
+                if query.is_empty() {
+                    return Some(vec![]);
+                }
+                // `None` if it was cancelled
+                request.await.ok()
+            }
+        },
+    );
+
+    view! {
+        <input
+            type="search"
+            placeholder="Search crates"
+            bind:value=(query, set_query)
+        />
+        // `<Transition/>` keeps the last results on the page while a new
+        // search runs
+        <Transition fallback=|| view! { <p>"Searching..."</p> }>
+            <ul>
+                {move || {
+                    results
+                        .get()
+                        .flatten()
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(|name| view! { <li>{name}</li> })
+                        .collect_view()
+                }}
+            </ul>
+        </Transition>
+    }
+}
+// ANCHOR_END: app
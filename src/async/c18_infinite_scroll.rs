@@ -0,0 +1,159 @@
+//! Code for "Infinite Scroll".
+//!
+//! Splitting the feed into pages is plain Rust, which the tests below check.
+//! Only a browser tells you when an element scrolls into view, so the
+//! component is tested by the DOM tests in `tests/dom/infinite_scroll.rs`,
+//! which stand in for the `IntersectionObserver`.
+
+use super::sleep;
+use js_sys::Array;
+use leptos::{html, prelude::*, task::spawn_local};
+use send_wrapper::SendWrapper;
+use std::time::Duration;
+use wasm_bindgen::{prelude::*, JsCast};
+use web_sys::{IntersectionObserver, IntersectionObserverEntry};
+
+// ANCHOR: load_page
+pub const PAGE_SIZE: u32 = 10;
+pub const TOTAL_ITEMS: u32 = 45;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Item {
+    pub id: u32,
+    pub title: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Page {
+    pub items: Vec<Item>,
+    /// The page after this one, or `None` if this is the last.
+    pub next: Option<u32>,
+}
+
+/// One page of the feed, counting from `0`.
+pub fn page(number: u32) -> Page {
+    let start = (number * PAGE_SIZE).min(TOTAL_ITEMS);
+    let end = (start + PAGE_SIZE).min(TOTAL_ITEMS);
+    Page {
+        items: (start..end)
+            .map(|id| Item {
+                id,
+                title: format!("Post #{}", id + 1),
+            })
+            .collect(),
+        next: (end < TOTAL_ITEMS).then_some(number + 1),
+    }
+}
+
+/// Loads a page, after a slow request.
+pub async fn load_page(number: u32) -> Page {
+    sleep(Duration::from_millis(300)).await;
+    page(number)
+}
+// ANCHOR_END: load_page
+
+// ANCHOR: use_is_visible
+/// Whether `target` is on the screen, according to an
+/// `IntersectionObserver`. It's `false` until the observer first says so,
+/// and always `false` on the server.
+pub fn use_is_visible(target: NodeRef<html::Div>) -> ReadSignal<bool> {
+    let (visible, set_visible) = signal(false);
+
+    Effect::new(move || {
+        let Some(target) = target.get() else {
+            return;
+        };
+        let on_intersect = Closure::<dyn Fn(Array)>::new(move |entries: Array| {
+            // only the latest entry matters, if several changes are batched
+            if let Some(entry) = entries.iter().last() {
+                let entry = IntersectionObserverEntry::unchecked_from_js(entry);
+                set_visible.set(entry.is_intersecting());
+            }
+        });
+        let observer = IntersectionObserver::new(on_intersect.as_ref().unchecked_ref()).unwrap();
+        observer.observe(&target);
+
+        let observer = SendWrapper::new((observer, on_intersect));
+        on_cleanup(move || observer.0.disconnect());
+    });
+
+    visible
+}
+// ANCHOR_END: use_is_visible
+
+// ANCHOR: feed
+#[component]
+pub fn Feed() -> impl IntoView {
+    // every item loaded so far, which each new page is added to the end of
+    let items = RwSignal::new(Vec::<Item>::new());
+    let next = RwSignal::new(Some(0));
+    let loading = RwSignal::new(false);
+
+    let sentinel = NodeRef::<html::Div>::new();
+    let visible = use_is_visible(sentinel);
+
+    // the observer only says when the sentinel comes into view or leaves it,
+    // so if a page is too short to push it off the screen, nothing would
+    // load the next one; running whenever `loading` changes catches that
+    Effect::new(move || {
+        if !visible.get() || loading.get() {
+            return;
+        }
+        let Some(number) = next.get() else {
+            return;
+        };
+        loading.set(true);
+        spawn_local(async move {
+            let page = load_page(number).await;
+            items.update(|items| items.extend(page.items));
+            next.set(page.next);
+            loading.set(false);
+        });
+    });
+
+    view! {
+        <ul class="feed">
+            <For each=move || items.get() key=|item| item.id let:item>
+                <li>{item.title}</li>
+            </For>
+        </ul>
+        // always rendered, so that the observer has something to watch
+        <div node_ref=sentinel class="sentinel">
+            {move || {
+                if next.get().is_none() {
+                    "That's everything!"
+                } else if loading.get() {
+                    "Loading..."
+                } else {
+                    ""
+                }
+            }}
+        </div>
+    }
+}
+// ANCHOR_END: feed
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(page: &Page) -> Vec<u32> {
+        page.items.iter().map(|item| item.id).collect()
+    }
+
+    #[test]
+    fn pages_follow_on_from_each_other() {
+        assert_eq!(ids(&page(0)), (0..10).collect::<Vec<_>>());
+        assert_eq!(page(0).next, Some(1));
+        assert_eq!(ids(&page(1))[0], 10);
+        assert_eq!(page(1).items[0].title, "Post #11");
+    }
+
+    #[test]
+    fn the_last_page_can_be_short() {
+        let last = page(4);
+        assert_eq!(ids(&last), [40, 41, 42, 43, 44]);
+        assert_eq!(last.next, None);
+        assert!(page(5).items.is_empty());
+    }
+}
@@ -0,0 +1,135 @@
+//! Code for "Optimistic Updates".
+//!
+//! [`save_done`] fakes a slow request with the `sleep` helper in the parent
+//! module, and fails while [`SERVER_DOWN`] is set, so that the rollback can
+//! be tried out (and tested) without a real server. The DOM tests in
+//! `tests/dom/optimistic_updates.rs` check both paths.
+
+use super::sleep;
+use leptos::prelude::*;
+use reactive_stores::{Field, Store};
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+
+// ANCHOR: save_done
+/// Whether the fake server is refusing requests.
+pub static SERVER_DOWN: AtomicBool = AtomicBool::new(false);
+
+/// Saves whether a todo is done, after a slow request.
+pub async fn save_done(id: usize, done: bool) -> Result<(), String> {
+    _ = (id, done);
+    sleep(Duration::from_millis(500)).await;
+    if SERVER_DOWN.load(Ordering::Relaxed) {
+        Err("the server is unavailable".to_string())
+    } else {
+        Ok(())
+    }
+}
+// ANCHOR_END: save_done
+
+// ANCHOR: store
+#[derive(Store, Debug, Clone)]
+pub struct Todos {
+    #[store(key: usize = |todo| todo.id)]
+    todos: Vec<Todo>,
+}
+
+#[derive(Store, Debug, Clone)]
+pub struct Todo {
+    id: usize,
+    title: String,
+    done: bool,
+}
+// ANCHOR_END: store
+
+// ANCHOR: todo_row
+#[component]
+pub fn TodoRow(
+    #[prop(into)] todo: Field<Todo>,
+    /// Where to say that a change couldn't be saved.
+    error: RwSignal<Option<String>>,
+) -> impl IntoView {
+    let done = todo.done();
+    // the last value the server has said yes to, which is what a failed
+    // change goes back to
+    let saved = StoredValue::new(done.get_untracked());
+
+    let toggle = Action::new(move |&checked: &bool| {
+        // this part runs as soon as the action is dispatched: change the
+        // todo straight away, as if the request had already succeeded...
+        done.set(checked);
+        error.set(None);
+
+        let id = todo.id().get_untracked();
+        async move {
+            let result = save_done(id, checked).await;
+            match &result {
+                Ok(()) => saved.set_value(checked),
+                // ...and put it back if it didn't. If the user has changed
+                // it again since, that change wins, so leave it alone
+                Err(e) => {
+                    if done.get_untracked() == checked {
+                        done.set(saved.get_value());
+                    }
+                    let title = todo.title().get_untracked();
+                    error.set(Some(format!("Couldn't save {title:?}: {e}")));
+                }
+            }
+            result
+        }
+    });
+
+    view! {
+        <li class:saving=move || toggle.pending().get()>
+            <label>
+                <input
+                    type="checkbox"
+                    prop:checked=move || done.get()
+                    on:change:target=move |ev| {
+                        toggle.dispatch(ev.target().checked());
+                    }
+                />
+                {move || todo.title().get()}
+            </label>
+        </li>
+    }
+}
+// ANCHOR_END: todo_row
+
+// ANCHOR: app
+#[component]
+pub fn App() -> impl IntoView {
+    let todos = Store::new(Todos {
+        todos: ["Buy milk", "Walk the dog", "Write the chapter"]
+            .into_iter()
+            .enumerate()
+            .map(|(id, title)| Todo {
+                id,
+                title: title.to_string(),
+                done: false,
+            })
+            .collect(),
+    });
+    let error = RwSignal::new(None::<String>);
+
+    view! {
+        <label class="server">
+            <input
+                type="checkbox"
+                on:change:target=move |ev| {
+                    SERVER_DOWN.store(ev.target().checked(), Ordering::Relaxed);
+                }
+            />
+            "Pretend the server is down"
+        </label>
+        <ul>
+            <For each=move || todos.todos() key=|todo| todo.read().id let:todo>
+                <TodoRow todo error/>
+            </For>
+        </ul>
+        <p role="alert">{move || error.get()}</p>
+    }
+}
+// ANCHOR_END: app
@@ -0,0 +1,117 @@
+//! Code for "Errors in Async Data".
+//!
+//! [`fetch_forecast`] fakes a flaky weather service with the `sleep` helper
+//! in the parent module: it fails for one city every time, and for every
+//! city while [`FAILURES_LEFT`] is above zero. The DOM tests in
+//! `tests/dom/async_errors.rs` check both kinds of failure, and recovering
+//! from them.
+
+use super::sleep;
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+// ANCHOR: fetch_forecast
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ForecastError {
+    /// Worth trying again, because it might work next time.
+    Unavailable,
+    /// Not worth trying again.
+    UnknownCity(String),
+}
+
+// implementing `Error` lets it be rendered in an `<ErrorBoundary/>`
+impl std::error::Error for ForecastError {}
+
+impl fmt::Display for ForecastError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ForecastError::Unavailable => write!(f, "The weather service is unavailable."),
+            ForecastError::UnknownCity(city) => write!(f, "There's no forecast for {city}."),
+        }
+    }
+}
+
+/// How many more requests the fake weather service will fail, whatever city
+/// they're for.
+pub static FAILURES_LEFT: AtomicUsize = AtomicUsize::new(0);
+
+/// The temperature in `city`, in °C.
+pub async fn fetch_forecast(city: String) -> Result<i32, ForecastError> {
+    sleep(Duration::from_millis(200)).await;
+    let failing = FAILURES_LEFT.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |left| {
+        left.checked_sub(1)
+    });
+    if failing.is_ok() {
+        return Err(ForecastError::Unavailable);
+    }
+    match city.as_str() {
+        "London" => Ok(14),
+        "Lisbon" => Ok(22),
+        _ => Err(ForecastError::UnknownCity(city)),
+    }
+}
+// ANCHOR_END: fetch_forecast
+
+// ANCHOR: app
+#[component]
+pub fn App() -> impl IntoView {
+    let (city, set_city) = signal("London".to_string());
+    let forecast = Resource::new(move || city.get(), fetch_forecast);
+
+    view! {
+        <select on:change:target=move |ev| set_city.set(ev.target().value())>
+            <option>"London"</option>
+            <option>"Lisbon"</option>
+            <option>"Atlantis"</option>
+        </select>
+        // the boundary goes inside `<Suspense/>`, so that loading again shows
+        // the fallback, and then either the forecast or the error
+        <Suspense fallback=|| view! { <p>"Loading..."</p> }>
+            <ErrorBoundary fallback=move |errors| {
+                // the boundary holds any kind of error, so downcast them to
+                // find out whether trying again could help
+                let retryable = Signal::derive({
+                    let errors = errors.clone();
+                    move || {
+                        errors.read().iter().any(|(_, e)| {
+                            e.downcast_ref::<ForecastError>() == Some(&ForecastError::Unavailable)
+                        })
+                    }
+                });
+                view! {
+                    <div class="error">
+                        <ul>
+                            {move || {
+                                errors
+                                    .get()
+                                    .into_iter()
+                                    .map(|(_, e)| view! { <li>{e.to_string()}</li> })
+                                    .collect_view()
+                            }}
+                        </ul>
+                        // loading the same city again replaces the `Err` with
+                        // whatever comes back, which clears the boundary if it
+                        // worked this time
+                        <Show when=move || retryable.get()>
+                            <button on:click=move |_| forecast.refetch()>"Retry"</button>
+                        </Show>
+                    </div>
+                }
+            }>
+                // an `Err` from the resource is rendered like any other `Err`,
+                // so it goes to the nearest boundary
+                {move || Suspend::new(async move {
+                    forecast
+                        .await
+                        .map(|temperature| view! { <p>"Today: " {temperature} "°C"</p> })
+                })}
+            </ErrorBoundary>
+        </Suspense>
+    }
+}
+// ANCHOR_END: app
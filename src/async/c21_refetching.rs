@@ -0,0 +1,203 @@
+//! Code for "Refetching and Invalidating Resources".
+//!
+//! The todos are kept in a `static`, behind requests that fake their delay
+//! with the `sleep` helper in the parent module, so that a test can change
+//! them "on the server" behind a component's back. The DOM tests in
+//! `tests/dom/refetching.rs` check each way of loading them again.
+
+use super::sleep;
+use leptos::{prelude::*, task::spawn_local};
+use std::{
+    future::Future,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    time::Duration,
+};
+
+// ANCHOR: todos
+static TODOS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Adds a todo straight away, the way another user might while the page is
+/// open.
+pub fn insert_todo(title: &str) {
+    TODOS.lock().unwrap().push(title.to_string());
+}
+
+pub fn remove_all_todos() {
+    TODOS.lock().unwrap().clear();
+}
+
+pub async fn list_todos() -> Vec<String> {
+    sleep(Duration::from_millis(100)).await;
+    TODOS.lock().unwrap().clone()
+}
+
+pub async fn add_todo(title: String) {
+    sleep(Duration::from_millis(100)).await;
+    insert_todo(&title);
+}
+
+pub async fn clear_todos() {
+    sleep(Duration::from_millis(100)).await;
+    remove_all_todos();
+}
+// ANCHOR_END: todos
+
+/// Renders the todos a resource has loaded.
+#[component]
+pub fn TodoList(todos: Resource<Vec<String>>) -> impl IntoView {
+    view! {
+        <Transition fallback=|| view! { <p>"Loading..."</p> }>
+            <ul>
+                {move || {
+                    todos
+                        .get()
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(|title| view! { <li>{title}</li> })
+                        .collect_view()
+                }}
+            </ul>
+        </Transition>
+    }
+}
+
+// ANCHOR: refetch
+#[component]
+pub fn Refresh() -> impl IntoView {
+    // nothing here changes, so this only loads once...
+    let todos = Resource::new(|| (), |_| list_todos());
+
+    view! {
+        // ...until it's told to load again
+        <button on:click=move |_| todos.refetch()>"Refresh"</button>
+        <TodoList todos/>
+    }
+}
+// ANCHOR_END: refetch
+
+// ANCHOR: invalidation
+#[component]
+pub fn Invalidation() -> impl IntoView {
+    let add = Action::new(|title: &String| add_todo(title.clone()));
+    // for changes that aren't made with an action, a counter of our own
+    let cleared = RwSignal::new(0);
+
+    // an action's version goes up each time it finishes, so reading it here
+    // loads the list again after every todo that's added
+    let todos = Resource::new(
+        move || (add.version().get(), cleared.get()),
+        |_| list_todos(),
+    );
+
+    let (title, set_title) = signal(String::new());
+
+    view! {
+        <form on:submit=move |ev| {
+            ev.prevent_default();
+            add.dispatch(title.get());
+            set_title.set(String::new());
+        }>
+            <input type="text" bind:value=(title, set_title)/>
+            <button type="submit">"Add"</button>
+        </form>
+        <button
+            class="clear"
+            on:click=move |_| {
+                spawn_local(async move {
+                    clear_todos().await;
+                    *cleared.write() += 1;
+                });
+            }
+        >
+            "Clear"
+        </button>
+        <TodoList todos/>
+    }
+}
+// ANCHOR_END: invalidation
+
+// ANCHOR: retry
+/// How long to wait before trying again, after `failures` failed attempts:
+/// twice as long each time, up to two seconds.
+pub fn backoff(failures: u32) -> Duration {
+    let millis = 100u64.saturating_mul(2u64.saturating_pow(failures));
+    Duration::from_millis(millis.min(2_000))
+}
+
+/// Calls `request` until it succeeds, up to `attempts` times, waiting longer
+/// after each failure. Returns the last error if it never succeeds.
+pub async fn with_retries<T, E, F, Fut>(attempts: u32, mut request: F) -> Result<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    let mut failures = 0;
+    loop {
+        match request().await {
+            Ok(value) => return Ok(value),
+            Err(e) if failures + 1 >= attempts => return Err(e),
+            Err(_) => {
+                sleep(backoff(failures)).await;
+                failures += 1;
+            }
+        }
+    }
+}
+// ANCHOR_END: retry
+
+// ANCHOR: flaky
+/// How many more requests [`count_todos`] will fail.
+pub static FAILURES_LEFT: AtomicUsize = AtomicUsize::new(0);
+/// How many requests [`count_todos`] has had, including failed ones.
+pub static ATTEMPTS: AtomicUsize = AtomicUsize::new(0);
+
+/// Counts the todos, unless the connection times out.
+pub async fn count_todos() -> Result<usize, String> {
+    sleep(Duration::from_millis(50)).await;
+    ATTEMPTS.fetch_add(1, Ordering::Relaxed);
+    let failing = FAILURES_LEFT.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |left| {
+        left.checked_sub(1)
+    });
+    if failing.is_ok() {
+        return Err("the request timed out".to_string());
+    }
+    Ok(TODOS.lock().unwrap().len())
+}
+
+#[component]
+pub fn Retrying() -> impl IntoView {
+    // the resource only sees the final result: it stays loading through
+    // every attempt, rather than showing each failure along the way
+    let count = Resource::new(|| (), |_| with_retries(4, count_todos));
+
+    view! {
+        <Suspense fallback=|| view! { <p>"Loading..."</p> }>
+            <p class="count">
+                {move || {
+                    count
+                        .get()
+                        .map(|count| match count {
+                            Ok(count) => format!("{count} todos"),
+                            Err(e) => format!("Gave up: {e}"),
+                        })
+                }}
+            </p>
+        </Suspense>
+    }
+}
+// ANCHOR_END: flaky
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_backoff_doubles_up_to_a_limit() {
+        let delays = (0..6).map(|n| backoff(n).as_millis()).collect::<Vec<_>>();
+        assert_eq!(delays, [100, 200, 400, 800, 1_600, 2_000]);
+        assert_eq!(backoff(u32::MAX), Duration::from_secs(2));
+    }
+}
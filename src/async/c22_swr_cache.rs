@@ -0,0 +1,266 @@
+//! Code for "Caching Data on the Client".
+//!
+//! Deciding whether a cached value can be used is plain Rust, which the tests
+//! below check. Loading happens in the browser, so the cache itself is tested
+//! by the DOM tests in `tests/dom/swr_cache.rs`, which count how often
+//! [`fetch_user`] is called.
+
+use super::sleep;
+use leptos::{prelude::*, task::spawn_local};
+use std::{
+    collections::HashMap,
+    future::Future,
+    hash::Hash,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+// ANCHOR: freshness
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Freshness {
+    /// Never loaded: there's nothing to show until it is.
+    Missing,
+    /// Loaded a while ago: show it, but load it again.
+    Stale,
+    /// Loaded recently enough to use as it is.
+    Fresh,
+}
+
+/// How fresh a value loaded at `loaded_at` is at `now`.
+pub fn freshness(loaded_at: Option<Duration>, now: Duration, max_age: Duration) -> Freshness {
+    match loaded_at {
+        None => Freshness::Missing,
+        Some(loaded_at) if now.saturating_sub(loaded_at) < max_age => Freshness::Fresh,
+        Some(_) => Freshness::Stale,
+    }
+}
+
+/// The time since the Unix epoch, from the browser's clock in the browser.
+/// (`std::time::Instant` and `SystemTime` panic on `wasm32-unknown-unknown`.)
+fn now() -> Duration {
+    #[cfg(target_arch = "wasm32")]
+    {
+        Duration::from_secs_f64(js_sys::Date::now() / 1000.0)
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+    }
+}
+// ANCHOR_END: freshness
+
+// ANCHOR: query_cache
+/// One cached value, and the state of loading it.
+#[derive(Clone)]
+struct Entry<V: Send + Sync + 'static> {
+    // reference-counted signals, so that they belong to the cache rather
+    // than to whichever component happened to ask for them first
+    data: ArcRwSignal<Option<V>>,
+    loading: ArcRwSignal<bool>,
+    loaded_at: Option<Duration>,
+}
+
+/// A cache of values loaded by key, which is provided as context so that
+/// every component that asks for the same key shares the same value.
+pub struct QueryCache<K: Send + Sync + 'static, V: Send + Sync + 'static> {
+    entries: StoredValue<HashMap<K, Entry<V>>>,
+    max_age: Duration,
+}
+
+impl<K: Send + Sync + 'static, V: Send + Sync + 'static> Clone for QueryCache<K, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K: Send + Sync + 'static, V: Send + Sync + 'static> Copy for QueryCache<K, V> {}
+
+impl<K, V> QueryCache<K, V>
+where
+    K: Eq + Hash + Clone + Send + Sync + 'static,
+    V: Clone + Send + Sync + 'static,
+{
+    /// A cache whose values are used as they are for `max_age` after they
+    /// were loaded, and loaded again in the background after that.
+    pub fn new(max_age: Duration) -> Self {
+        Self {
+            entries: StoredValue::new(HashMap::new()),
+            max_age,
+        }
+    }
+
+    fn entry(&self, key: &K) -> Entry<V> {
+        self.entries
+            .try_update_value(|entries| {
+                entries
+                    .entry(key.clone())
+                    .or_insert_with(|| Entry {
+                        data: ArcRwSignal::new(None),
+                        loading: ArcRwSignal::new(false),
+                        loaded_at: None,
+                    })
+                    .clone()
+            })
+            .expect("the cache outlives the components that use it")
+    }
+
+    /// Loads `key` with `fetch`, unless it's fresh or already loading.
+    fn revalidate<Fut>(&self, key: K, fetch: impl FnOnce(K) -> Fut)
+    where
+        Fut: Future<Output = V> + 'static,
+    {
+        let entry = self.entry(&key);
+        let fresh = freshness(entry.loaded_at, now(), self.max_age) == Freshness::Fresh;
+        // a second component asking for the same key while it's loading
+        // waits for the same request, rather than sending another
+        if fresh || entry.loading.get_untracked() {
+            return;
+        }
+        entry.loading.set(true);
+        let (cache, request) = (*self, fetch(key.clone()));
+        spawn_local(async move {
+            let value = request.await;
+            entry.data.set(Some(value));
+            entry.loading.set(false);
+            cache.entries.update_value(|entries| {
+                if let Some(entry) = entries.get_mut(&key) {
+                    entry.loaded_at = Some(now());
+                }
+            });
+        });
+    }
+
+    /// Forgets when `key` was loaded, so that it's loaded again the next time
+    /// it's used. Whatever was loaded is still shown until then.
+    pub fn invalidate(&self, key: &K) {
+        self.entries.update_value(|entries| {
+            if let Some(entry) = entries.get_mut(key) {
+                entry.loaded_at = None;
+            }
+        });
+    }
+}
+// ANCHOR_END: query_cache
+
+// ANCHOR: use_swr
+pub struct Swr<V: Send + Sync + 'static> {
+    /// The cached value, which may be stale, until a fresh one arrives.
+    pub data: Signal<Option<V>>,
+    /// Whether a request for it is running.
+    pub loading: Signal<bool>,
+}
+
+/// Reads the value for `key` from the cache in context, and loads it with
+/// `fetch` if it's missing or stale, whenever `key` changes.
+pub fn use_swr<K, V, Fut>(
+    key: impl Fn() -> K + Send + Sync + 'static,
+    fetch: impl Fn(K) -> Fut + 'static,
+) -> Swr<V>
+where
+    K: Eq + Hash + Clone + Send + Sync + 'static,
+    V: Clone + Send + Sync + 'static,
+    Fut: Future<Output = V> + 'static,
+{
+    let cache = expect_context::<QueryCache<K, V>>();
+    let key = Memo::new(move |_| key());
+
+    // effects only run in the browser, so this never loads on the server
+    Effect::new(move || cache.revalidate(key.get(), &fetch));
+
+    Swr {
+        data: Signal::derive(move || cache.entry(&key.get()).data.get()),
+        loading: Signal::derive(move || cache.entry(&key.get()).loading.get()),
+    }
+}
+// ANCHOR_END: use_swr
+
+// ANCHOR: app
+/// How many times [`fetch_user`] has been called.
+pub static FETCHES: AtomicUsize = AtomicUsize::new(0);
+
+pub async fn fetch_user(id: u32) -> String {
+    FETCHES.fetch_add(1, Ordering::Relaxed);
+    sleep(Duration::from_millis(200)).await;
+    match id {
+        1 => "Ada Lovelace",
+        2 => "Grace Hopper",
+        _ => "Alan Turing",
+    }
+    .to_string()
+}
+
+#[component]
+pub fn UserName(#[prop(into)] id: Signal<u32>) -> impl IntoView {
+    let user = use_swr(move || id.get(), fetch_user);
+
+    view! {
+        <span class:revalidating=move || user.loading.get() && user.data.read().is_some()>
+            {move || user.data.get().unwrap_or_else(|| "Loading...".to_string())}
+        </span>
+    }
+}
+
+/// Two components that show the same user, with the cache they share.
+#[component]
+pub fn Profiles() -> impl IntoView {
+    let (id, set_id) = signal(1);
+
+    view! {
+        <nav>
+            {[1, 2, 3]
+                .map(|n| view! { <button on:click=move |_| set_id.set(n)>"User " {n}</button> })}
+        </nav>
+        <header>"Signed in as " <UserName id/></header>
+        <main>"Profile of " <UserName id/></main>
+    }
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    provide_context(QueryCache::<u32, String>::new(Duration::from_secs(30)));
+    view! { <Profiles/> }
+}
+// ANCHOR_END: app
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAX_AGE: Duration = Duration::from_secs(30);
+
+    fn secs(secs: u64) -> Duration {
+        Duration::from_secs(secs)
+    }
+
+    #[test]
+    fn values_are_fresh_until_they_reach_the_max_age() {
+        assert_eq!(freshness(None, secs(100), MAX_AGE), Freshness::Missing);
+        assert_eq!(
+            freshness(Some(secs(100)), secs(100), MAX_AGE),
+            Freshness::Fresh
+        );
+        assert_eq!(
+            freshness(Some(secs(100)), secs(129), MAX_AGE),
+            Freshness::Fresh
+        );
+        assert_eq!(
+            freshness(Some(secs(100)), secs(130), MAX_AGE),
+            Freshness::Stale
+        );
+        // the clock going backwards doesn't make anything stale
+        assert_eq!(
+            freshness(Some(secs(100)), secs(90), MAX_AGE),
+            Freshness::Fresh
+        );
+    }
+
+    #[test]
+    fn a_max_age_of_zero_is_always_stale() {
+        assert_eq!(
+            freshness(Some(secs(100)), secs(100), Duration::ZERO),
+            Freshness::Stale
+        );
+    }
+}
@@ -0,0 +1,39 @@
+//! Code for the "Async" chapters.
+//!
+//! The examples fake slow network requests with `sleep`, which works in
+//! the browser and on the server, so they can be rendered in every mode.
+
+use std::time::Duration;
+
+pub mod c10_resources;
+pub mod c10a_csr_fetch;
+pub mod c11_suspense;
+pub mod c12_transition;
+pub mod c13_actions;
+pub mod c14_indexed_db;
+#[cfg(feature = "graphql")]
+pub mod c15_graphql;
+#[cfg(feature = "grpc")]
+pub mod c16_grpc;
+pub mod c17_debounced_search;
+pub mod c18_infinite_scroll;
+pub mod c19_optimistic_updates;
+pub mod c20_async_errors;
+pub mod c21_refetching;
+pub mod c22_swr_cache;
+
+/// Waits for `duration` with tokio on the server.
+#[cfg(feature = "ssr")]
+pub(crate) async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await;
+}
+
+/// Waits for `duration` with a browser timer.
+///
+/// Browser timers aren't `Send`, but `Resource` and `OnceResource` need
+/// `Send` futures so that they can be loaded on the server. Only one thread
+/// ever touches a browser timer, so it's safe to wrap it in a `SendWrapper`.
+#[cfg(not(feature = "ssr"))]
+pub(crate) async fn sleep(duration: Duration) {
+    send_wrapper::SendWrapper::new(gloo_timers::future::sleep(duration)).await;
+}
@@ -0,0 +1,145 @@
+//! Code for the "Working Offline" section of "Deploying CSR Apps".
+//!
+//! The service worker itself is JavaScript, in `sw.js` next to this file.
+//! Registering it only does anything in a browser, so this is tested by the
+//! DOM tests in `tests/dom/service_worker.rs`.
+
+use js_sys::Reflect;
+use leptos::{prelude::*, task::spawn_local};
+use wasm_bindgen::{prelude::*, JsCast};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{ServiceWorker, ServiceWorkerRegistration, ServiceWorkerState};
+
+// ANCHOR: status
+/// What the app knows about its service worker.
+#[derive(Clone, Copy)]
+pub struct ServiceWorkerStatus {
+    /// Whether a new version of the app has been installed, and is waiting
+    /// to take over from this one.
+    pub update_available: ReadSignal<bool>,
+    /// Why the worker couldn't be registered, if it couldn't.
+    pub error: ReadSignal<Option<String>>,
+    // a JavaScript object isn't `Send`, so this is stored locally
+    waiting: StoredValue<Option<ServiceWorker>, LocalStorage>,
+}
+// ANCHOR_END: status
+
+// ANCHOR: register
+/// Registers the service worker at `script_url`, and watches for new
+/// versions of it.
+pub fn register_service_worker(script_url: &str) -> ServiceWorkerStatus {
+    let (update_available, set_update_available) = signal(false);
+    let (error, set_error) = signal(None);
+    let waiting = StoredValue::new_local(None);
+    let status = ServiceWorkerStatus {
+        update_available,
+        error,
+        waiting,
+    };
+
+    // only pages served over HTTPS (or from localhost) have service workers
+    let navigator = window().navigator();
+    if !Reflect::has(&navigator, &"serviceWorker".into()).unwrap_or_default() {
+        set_error.set(Some("service workers aren't available".to_string()));
+        return status;
+    }
+    let container = navigator.service_worker();
+
+    // a new worker is only an update if an old one controls the page: the
+    // first one to be installed just starts caching
+    let is_update = move |worker: ServiceWorker| {
+        if window().navigator().service_worker().controller().is_some() {
+            waiting.set_value(Some(worker));
+            set_update_available.set(true);
+        }
+    };
+
+    let script_url = script_url.to_string();
+    spawn_local(async move {
+        let registration = match JsFuture::from(container.register(&script_url)).await {
+            Ok(registration) => registration.unchecked_into::<ServiceWorkerRegistration>(),
+            Err(e) => {
+                set_error.set(Some(format!("{e:?}")));
+                return;
+            }
+        };
+
+        // installed while an old version of the page was open...
+        if let Some(worker) = registration.waiting() {
+            is_update(worker);
+        }
+
+        // ...or while this one is
+        let on_update_found = Closure::<dyn Fn()>::new({
+            let registration = registration.clone();
+            move || {
+                let Some(worker) = registration.installing() else {
+                    return;
+                };
+                let on_state_change = Closure::<dyn Fn()>::new({
+                    let worker = worker.clone();
+                    move || {
+                        if worker.state() == ServiceWorkerState::Installed {
+                            is_update(worker.clone());
+                        }
+                    }
+                });
+                worker.set_onstatechange(Some(on_state_change.as_ref().unchecked_ref()));
+                on_state_change.forget();
+            }
+        });
+        registration.set_onupdatefound(Some(on_update_found.as_ref().unchecked_ref()));
+        // the registration lasts as long as the page, and so does this
+        on_update_found.forget();
+    });
+
+    status
+}
+// ANCHOR_END: register
+
+// ANCHOR: update
+impl ServiceWorkerStatus {
+    /// Tells the waiting worker to take over, and reloads the page once it
+    /// has, so that the new version of the app is loaded.
+    pub fn update(&self) {
+        let Some(worker) = self.waiting.get_value() else {
+            return;
+        };
+        let reload = Closure::once_into_js(|| {
+            _ = window().location().reload();
+        });
+        window()
+            .navigator()
+            .service_worker()
+            .set_oncontrollerchange(Some(reload.unchecked_ref()));
+        // see the `message` listener in `sw.js`
+        _ = worker.post_message(&"skipWaiting".into());
+    }
+}
+// ANCHOR_END: update
+
+// ANCHOR: app
+#[component]
+pub fn UpdateBanner() -> impl IntoView {
+    let status = expect_context::<ServiceWorkerStatus>();
+
+    view! {
+        <Show when=move || status.update_available.get()>
+            <div class="update-banner" role="status">
+                "A new version is available. "
+                <button on:click=move |_| status.update()>"Reload"</button>
+            </div>
+        </Show>
+    }
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    provide_context(register_service_worker("/sw.js"));
+
+    view! {
+        <UpdateBanner/>
+        <h1>"Works Offline"</h1>
+    }
+}
+// ANCHOR_END: app
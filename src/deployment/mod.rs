@@ -0,0 +1,3 @@
+//! Code for "Deployment".
+
+pub mod c35_service_worker;
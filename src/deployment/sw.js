@@ -0,0 +1,54 @@
+// The service worker for the app in "Working Offline". Change `CACHE` every
+// time you deploy: the browser only installs a new worker if this file has
+// changed, and the old cache is deleted once the new one takes over.
+const CACHE = "app-v1";
+
+// cache the page, and the JavaScript and WASM it links to, as soon as the
+// worker is installed
+self.addEventListener("install", (event) => {
+  event.waitUntil(
+    (async () => {
+      const html = await (await fetch("./")).text();
+      // Trunk adds a hash to each file's name, so read them from the page
+      const assets = [...html.matchAll(/href="([^"]+\.(?:js|wasm|css))"/g)].map(
+        (match) => match[1],
+      );
+      const cache = await caches.open(CACHE);
+      await cache.addAll(["./", ...assets]);
+    })(),
+  );
+});
+
+// the app sends this when the user chooses to update
+self.addEventListener("message", (event) => {
+  if (event.data === "skipWaiting") {
+    self.skipWaiting();
+  }
+});
+
+// delete the caches of older versions, and take over any open pages
+self.addEventListener("activate", (event) => {
+  event.waitUntil(
+    (async () => {
+      for (const key of await caches.keys()) {
+        if (key !== CACHE) {
+          await caches.delete(key);
+        }
+      }
+      await self.clients.claim();
+    })(),
+  );
+});
+
+// serve from the cache first, and from the network for anything else
+self.addEventListener("fetch", (event) => {
+  if (event.request.method !== "GET") {
+    return;
+  }
+  // every path is the same page, as with any CSR app
+  const request =
+    event.request.mode === "navigate" ? new Request("./") : event.request;
+  event.respondWith(
+    caches.match(request).then((cached) => cached ?? fetch(event.request)),
+  );
+});
@@ -0,0 +1,348 @@
+//! Code for "Internationalization".
+//!
+//! The translations in `locales/` are compiled into the crate, and the tests
+//! below load every one of them: they check that each locale has the same
+//! messages, and that plurals and dates come out right in each. The DOM tests
+//! in `tests/dom/i18n.rs` switch the locale in a browser.
+
+use fluent_bundle::{concurrent::FluentBundle, FluentArgs, FluentResource};
+use leptos::prelude::*;
+use leptos_meta::{provide_meta_context, Html};
+use std::{str::FromStr, sync::LazyLock};
+use unic_langid::{langid, LanguageIdentifier};
+
+// ANCHOR: locale
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    En,
+    Fr,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::En, Locale::Fr];
+
+    /// The language tag, as used in `<html lang>` and `Accept-Language`.
+    pub fn tag(self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::Fr => "fr",
+        }
+    }
+
+    /// The locale's name in its own language, which is how someone looking
+    /// for their language expects to find it.
+    pub fn name(self) -> &'static str {
+        match self {
+            Locale::En => "English",
+            Locale::Fr => "Français",
+        }
+    }
+
+    fn language_id(self) -> LanguageIdentifier {
+        match self {
+            Locale::En => langid!("en"),
+            Locale::Fr => langid!("fr"),
+        }
+    }
+
+    fn translations(self) -> &'static str {
+        match self {
+            Locale::En => include_str!("locales/en.ftl"),
+            Locale::Fr => include_str!("locales/fr.ftl"),
+        }
+    }
+
+    /// The first locale in an `Accept-Language` header (or the browser's
+    /// `navigator.languages`, joined with commas) that has translations,
+    /// ignoring regions: `fr-CA` is given French.
+    pub fn negotiate(accept_language: &str) -> Locale {
+        accept_language
+            .split(',')
+            .filter_map(|tag| tag.split(';').next())
+            .filter_map(|tag| tag.trim().split('-').next())
+            .find_map(|language| language.parse().ok())
+            .unwrap_or(Locale::En)
+    }
+}
+
+impl FromStr for Locale {
+    type Err = ();
+
+    fn from_str(tag: &str) -> Result<Self, Self::Err> {
+        Locale::ALL
+            .into_iter()
+            .find(|locale| locale.tag().eq_ignore_ascii_case(tag))
+            .ok_or(())
+    }
+}
+// ANCHOR_END: locale
+
+// ANCHOR: bundles
+// the concurrent bundle can be shared between threads, which a `static` has
+// to be, because the server renders many requests at once
+type Bundle = FluentBundle<FluentResource>;
+
+/// Parses a locale's translations. They're compiled in, so a mistake in one
+/// is a bug, which the tests below catch before it's shipped.
+fn load(locale: Locale) -> Bundle {
+    let resource = FluentResource::try_new(locale.translations().to_string())
+        .unwrap_or_else(|(_, errors)| panic!("invalid {}.ftl: {errors:?}", locale.tag()));
+    let mut bundle = FluentBundle::new_concurrent(vec![locale.language_id()]);
+    // Fluent wraps every argument in invisible marks that stop a name written
+    // right to left from jumbling the text around it. Neither of these
+    // languages is written right to left, so leave them out.
+    bundle.set_use_isolating(false);
+    bundle
+        .add_resource(resource)
+        .expect("each message is only defined once");
+    bundle
+}
+
+/// Every locale's translations, parsed the first time any of them is used.
+/// They're the same for every user, so one copy is shared by all of them.
+static BUNDLES: LazyLock<[Bundle; 2]> = LazyLock::new(|| Locale::ALL.map(load));
+
+/// Translates the message `id` into `locale`, filling in `args`.
+pub fn translate(locale: Locale, id: &str, args: Option<&FluentArgs>) -> String {
+    let bundle = &BUNDLES[locale as usize];
+    // a missing message shows its id, which is easier to spot on the page
+    // than a blank
+    let Some(pattern) = bundle.get_message(id).and_then(|message| message.value()) else {
+        return id.to_string();
+    };
+    // a missing argument is shown as `{$name}`, for the same reason
+    let mut errors = Vec::new();
+    bundle
+        .format_pattern(pattern, args, &mut errors)
+        .into_owned()
+}
+// ANCHOR_END: bundles
+
+// ANCHOR: format_date
+/// A date written the way `locale` writes it, with the month's name in that
+/// language, and the day, month and year in its order.
+pub fn format_date(locale: Locale, year: i32, month: u32, day: u32) -> String {
+    let mut args = FluentArgs::new();
+    args.set("year", year);
+    args.set("month", month);
+    args.set("day", day);
+    translate(locale, "date", Some(&args))
+}
+// ANCHOR_END: format_date
+
+// ANCHOR: i18n
+/// The user's locale, provided as context so that any component can
+/// translate its text, or change the locale for the whole app.
+#[derive(Debug, Clone, Copy)]
+pub struct I18n {
+    pub locale: RwSignal<Locale>,
+}
+
+impl I18n {
+    /// Translates `id` into the current locale. This reads the locale, so
+    /// calling it in a closure in the view translates the text again
+    /// whenever the locale changes.
+    pub fn t(&self, id: &str) -> String {
+        translate(self.locale.get(), id, None)
+    }
+
+    /// Like [`I18n::t`], for messages with arguments.
+    pub fn t_args(&self, id: &str, args: &FluentArgs) -> String {
+        translate(self.locale.get(), id, Some(args))
+    }
+}
+
+pub fn provide_i18n(locale: Locale) -> I18n {
+    let i18n = I18n {
+        locale: RwSignal::new(locale),
+    };
+    provide_context(i18n);
+    i18n
+}
+
+pub fn use_i18n() -> I18n {
+    expect_context()
+}
+// ANCHOR_END: i18n
+
+// ANCHOR: locale_select
+#[component]
+pub fn LocaleSelect() -> impl IntoView {
+    let i18n = use_i18n();
+
+    view! {
+        <label>
+            {move || i18n.t("language")} " "
+            <select
+                prop:value=move || i18n.locale.get().tag()
+                on:change:target=move |ev| {
+                    if let Ok(locale) = ev.target().value().parse() {
+                        i18n.locale.set(locale);
+                    }
+                }
+            >
+                {Locale::ALL
+                    .map(|locale| view! { <option value=locale.tag()>{locale.name()}</option> })}
+            </select>
+        </label>
+    }
+}
+// ANCHOR_END: locale_select
+
+// ANCHOR: inbox
+#[component]
+pub fn Inbox() -> impl IntoView {
+    let i18n = use_i18n();
+    let (unread, set_unread) = signal(1);
+
+    let welcome = move || {
+        let mut args = FluentArgs::new();
+        args.set("name", "Ada");
+        i18n.t_args("welcome", &args)
+    };
+    // the translation picks the singular or the plural, by the rules of the
+    // current locale, so the count is passed as a number
+    let unread_messages = move || {
+        let mut args = FluentArgs::new();
+        args.set("count", unread.get());
+        i18n.t_args("unread-messages", &args)
+    };
+    let last_seen = move || {
+        let mut args = FluentArgs::new();
+        args.set("date", format_date(i18n.locale.get(), 2024, 3, 1));
+        i18n.t_args("last-seen", &args)
+    };
+
+    view! {
+        <h1>{move || i18n.t("inbox")}</h1>
+        <p class="welcome">{welcome}</p>
+        <p class="last-seen">{last_seen}</p>
+        <p class="unread">{unread_messages}</p>
+        <button class="receive" on:click=move |_| *set_unread.write() += 1>
+            {move || i18n.t("receive")}
+        </button>
+        <button class="mark-all-read" on:click=move |_| set_unread.set(0)>
+            {move || i18n.t("mark-all-read")}
+        </button>
+    }
+}
+// ANCHOR_END: inbox
+
+// ANCHOR: app
+#[component]
+pub fn App() -> impl IntoView {
+    provide_meta_context();
+    let i18n = provide_i18n(Locale::En);
+
+    view! {
+        // screen readers pronounce the page with the voice for its language,
+        // and browsers offer to translate it if it isn't the user's
+        <Html attr:lang=move || i18n.locale.get().tag()/>
+        <LocaleSelect/>
+        <Inbox/>
+    }
+}
+// ANCHOR_END: app
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The ids of the messages in a `.ftl` file: each line that starts a
+    /// message starts with its id, where comments and continued lines don't.
+    fn message_ids(translations: &str) -> Vec<&str> {
+        translations
+            .lines()
+            .filter(|line| line.starts_with(|c: char| c.is_ascii_lowercase()))
+            .filter_map(|line| line.split_once('='))
+            .map(|(id, _)| id.trim())
+            .collect()
+    }
+
+    fn count(count: u32) -> FluentArgs<'static> {
+        let mut args = FluentArgs::new();
+        args.set("count", count);
+        args
+    }
+
+    #[test]
+    fn every_locale_translates_every_message() {
+        let english = message_ids(Locale::En.translations());
+        assert!(english.contains(&"unread-messages"));
+
+        let mut args = count(2);
+        args.set("name", "Ada");
+        args.set("date", "today");
+        args.set("year", 2024);
+        args.set("month", 3);
+        args.set("day", 1);
+
+        for locale in Locale::ALL {
+            assert_eq!(
+                message_ids(locale.translations()),
+                english,
+                "{} has different messages from en",
+                locale.tag()
+            );
+            let bundle = &BUNDLES[locale as usize];
+            for id in &english {
+                let pattern = bundle.get_message(id).unwrap().value().unwrap();
+                let mut errors = Vec::new();
+                bundle.format_pattern(pattern, Some(&args), &mut errors);
+                assert!(errors.is_empty(), "{}: {id}: {errors:?}", locale.tag());
+            }
+        }
+    }
+
+    #[test]
+    fn plurals_follow_each_locales_rules() {
+        let unread = |locale, n| translate(locale, "unread-messages", Some(&count(n)));
+
+        assert_eq!(unread(Locale::En, 0), "You have no unread messages.");
+        assert_eq!(unread(Locale::En, 1), "You have one unread message.");
+        assert_eq!(unread(Locale::En, 2), "You have 2 unread messages.");
+
+        // French uses the singular for 0 as well as 1
+        assert_eq!(unread(Locale::Fr, 0), "Vous avez 0 message non lu.");
+        assert_eq!(unread(Locale::Fr, 1), "Vous avez 1 message non lu.");
+        assert_eq!(unread(Locale::Fr, 2), "Vous avez 2 messages non lus.");
+    }
+
+    #[test]
+    fn dates_are_written_the_way_each_locale_writes_them() {
+        assert_eq!(format_date(Locale::En, 2024, 3, 1), "March 1, 2024");
+        assert_eq!(format_date(Locale::En, 2024, 12, 25), "December 25, 2024");
+        assert_eq!(format_date(Locale::Fr, 2024, 3, 1), "1er mars 2024");
+        assert_eq!(format_date(Locale::Fr, 2024, 12, 25), "25 décembre 2024");
+    }
+
+    #[test]
+    fn missing_messages_show_their_id() {
+        assert_eq!(
+            translate(Locale::Fr, "no-such-message", None),
+            "no-such-message"
+        );
+    }
+
+    #[test]
+    fn the_first_translated_language_is_used() {
+        assert_eq!(Locale::negotiate("fr-CA,fr;q=0.9,en;q=0.8"), Locale::Fr);
+        assert_eq!(Locale::negotiate("de-DE, en-GB;q=0.8"), Locale::En);
+        assert_eq!(Locale::negotiate("FR"), Locale::Fr);
+        assert_eq!(Locale::negotiate(""), Locale::En);
+    }
+
+    #[test]
+    fn text_follows_the_locale() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let i18n = provide_i18n(Locale::En);
+            let heading = Memo::new(move |_| i18n.t("inbox"));
+            assert_eq!(heading.get(), "Inbox");
+
+            i18n.locale.set(Locale::Fr);
+            assert_eq!(heading.get(), "Boîte de réception");
+            assert_eq!(use_i18n().locale.get(), Locale::Fr);
+        });
+    }
+}
@@ -0,0 +1,33 @@
+# English, which is also the locale used when the browser asks for one
+# that isn't translated.
+
+language = Language
+inbox = Inbox
+welcome = Welcome back, { $name }!
+
+# `$count` is a number, so each variant is picked by its plural category in
+# this locale. An exact number, like [0], is matched before the categories.
+unread-messages =
+    { $count ->
+        [0] You have no unread messages.
+        [one] You have one unread message.
+       *[other] You have { $count } unread messages.
+    }
+receive = Receive a message
+mark-all-read = Mark all as read
+
+last-seen = Last signed in on { $date }.
+date = { $month ->
+        [1] January
+        [2] February
+        [3] March
+        [4] April
+        [5] May
+        [6] June
+        [7] July
+        [8] August
+        [9] September
+        [10] October
+        [11] November
+       *[12] December
+    } { $day }, { $year }
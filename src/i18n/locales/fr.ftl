@@ -0,0 +1,33 @@
+# French, which treats 0 like 1 when choosing between singular and plural.
+
+language = Langue
+inbox = Boîte de réception
+welcome = Bon retour, { $name } !
+
+unread-messages =
+    { $count ->
+        [one] Vous avez { $count } message non lu.
+       *[other] Vous avez { $count } messages non lus.
+    }
+receive = Recevoir un message
+mark-all-read = Tout marquer comme lu
+
+last-seen = Dernière connexion le { $date }.
+# the first day of the month is written as an ordinal: « 1er mars »
+date = { $day ->
+        [1] 1er
+       *[other] { $day }
+    } { $month ->
+        [1] janvier
+        [2] février
+        [3] mars
+        [4] avril
+        [5] mai
+        [6] juin
+        [7] juillet
+        [8] août
+        [9] septembre
+        [10] octobre
+        [11] novembre
+       *[12] décembre
+    } { $year }
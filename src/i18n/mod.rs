@@ -0,0 +1,3 @@
+//! Code for "Internationalization".
+
+pub mod c41_i18n;
@@ -0,0 +1,104 @@
+//! Code for "Interlude: Projecting Children".
+//!
+//! The versions that don't compile are in
+//! `tests/compile_fail/projecting_children.rs` and
+//! `tests/compile_fail/projecting_owned_props.rs`.
+
+use leptos::prelude::*;
+
+// ANCHOR: stored_value
+#[component]
+pub fn LoggedIn<F, IV>(
+    fallback: F,
+    children: ChildrenFn,
+    #[prop(into)] checked: Signal<bool>,
+    #[prop(into)] logged_in: Signal<bool>,
+) -> impl IntoView
+where
+    F: Fn() -> IV + Send + Sync + 'static,
+    IV: IntoView + 'static,
+{
+    let fallback = StoredValue::new(fallback);
+    let children = StoredValue::new(children);
+
+    view! {
+        <Show
+            // wait until we've checked whether the user is logged in
+            when=move || checked.get()
+        >
+            <Show
+                when=move || logged_in.get()
+                fallback=move || fallback.with_value(|fallback| fallback())
+            >
+                {children.with_value(|children| children())}
+            </Show>
+        </Show>
+    }
+}
+// ANCHOR_END: stored_value
+
+// ANCHOR: clone
+#[component]
+pub fn App() -> impl IntoView {
+    let name = "Alice".to_string();
+    view! {
+        <Outer>
+            <Inner clone:name>
+                <Inmost name=name.clone()/>
+            </Inner>
+        </Outer>
+    }
+}
+// ANCHOR_END: clone
+
+// ANCHOR: components
+#[component]
+pub fn Outer(children: ChildrenFn) -> impl IntoView {
+    children()
+}
+
+#[component]
+pub fn Inner(children: ChildrenFn) -> impl IntoView {
+    children()
+}
+
+#[component]
+pub fn Inmost(name: String) -> impl IntoView {
+    view! {
+        <p>{name}</p>
+    }
+}
+// ANCHOR_END: components
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::render;
+
+    fn logged_in(checked: bool, logged_in: bool) -> String {
+        render(|| {
+            view! {
+                <LoggedIn
+                    checked=Signal::stored(checked)
+                    logged_in=Signal::stored(logged_in)
+                    fallback=|| view! { <p>"Please log in."</p> }
+                >
+                    <p>"Welcome back!"</p>
+                </LoggedIn>
+            }
+        })
+    }
+
+    #[test]
+    fn children_and_fallback_are_projected_through_both_shows() {
+        assert!(!logged_in(false, false).contains("<p>"));
+        assert!(logged_in(true, false).contains("<p>Please log in.</p>"));
+        assert!(logged_in(true, true).contains("<p>Welcome back!</p>"));
+    }
+
+    #[test]
+    fn clone_moves_a_clone_into_the_children() {
+        let html = render(App);
+        assert!(html.contains("<p>Alice</p>"), "{html}");
+    }
+}
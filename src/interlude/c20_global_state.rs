@@ -0,0 +1,318 @@
+//! Code for "Global State Management".
+//!
+//! [`coarse_updates`] and [`store_updates`] count how often a reader of the
+//! `name` field runs when only the `count` field changes, the same way the
+//! memo examples do, to compare one signal for the whole state with a store.
+
+use leptos::prelude::*;
+use reactive_stores::Store;
+
+// ANCHOR: lift_state_up
+/// Two siblings that share a count, which lives in their parent.
+#[component]
+pub fn LiftedCounter() -> impl IntoView {
+    // the nearest common ancestor owns the state...
+    let (count, set_count) = signal(0);
+
+    view! {
+        // ...and passes each child only what it needs
+        <SetterButton set_count/>
+        <p>"Count: " {count}</p>
+    }
+}
+// ANCHOR_END: lift_state_up
+
+// ANCHOR: app
+// Option #2: Pass Signals through Context
+//
+// In virtual DOM libraries like React, using the Context API to manage global
+// state is a bad idea: because the entire app exists in a tree, changing
+// some value provided high up in the tree can cause the whole app to render.
+//
+// In fine-grained reactive libraries like Leptos, this is simply not the case.
+// You can create a signal in the root of your app and pass it down to other
+// components using provide_context(). Changing it will only cause rerendering
+// in the specific places it is actually used, not the whole app.
+// ANCHOR: option2
+#[component]
+pub fn Option2() -> impl IntoView {
+    // here we create a signal in the root that can be consumed
+    // anywhere in the app.
+    let (count, set_count) = signal(0);
+    // we'll pass the setter to specific components,
+    // but provide the count itself to the whole app via context
+    provide_context(count);
+
+    view! {
+        <h1>"Option 2: Passing Signals"</h1>
+        // SetterButton is allowed to modify the count
+        <SetterButton set_count/>
+        // These consumers can only read from it
+        // But we could give them write access by passing `set_count` if we wanted
+        <div style="display: flex">
+            <FancyMath/>
+            <ListItems/>
+        </div>
+    }
+}
+// ANCHOR_END: option2
+
+/// A button that increments our global counter.
+#[component]
+pub fn SetterButton(set_count: WriteSignal<u32>) -> impl IntoView {
+    view! {
+        <div class="provider red">
+            <button on:click=move |_| *set_count.write() += 1>
+                "Increment Global Count"
+            </button>
+        </div>
+    }
+}
+
+// ANCHOR: fancy_math
+/// A component that does some "fancy" math with the global count
+#[component]
+pub fn FancyMath() -> impl IntoView {
+    // here we consume the global count signal with `use_context`
+    let count = use_context::<ReadSignal<u32>>()
+        // we know we just provided this in the parent component
+        .expect("there to be a `count` signal provided");
+    let is_even = move || count.get() & 1 == 0;
+
+    view! {
+        <div class="consumer blue">
+            "The number "
+            <strong>{count}</strong>
+            {move || if is_even() {
+                " is"
+            } else {
+                " is not"
+            }}
+            " even."
+        </div>
+    }
+}
+// ANCHOR_END: fancy_math
+
+/// A component that shows a list of items generated from the global count.
+#[component]
+pub fn ListItems() -> impl IntoView {
+    // again, consume the global count signal with `use_context`
+    let count = use_context::<ReadSignal<u32>>().expect("there to be a `count` signal provided");
+
+    let squares = move || {
+        (0..count.get())
+            .map(|n| view! { <li>{n}<sup>"2"</sup> " is " {n * n}</li> })
+            .collect::<Vec<_>>()
+    };
+
+    view! {
+        <div class="consumer green">
+            <ul>{squares}</ul>
+        </div>
+    }
+}
+
+// Option #3: Create a Global State Store
+//
+// You can use this approach to build a single global data structure
+// that holds the state for your whole app, and then access it through
+// the fields of a `Store`, so that changing one part of the state doesn't
+// cause parts of your app that depend on other parts of the state to change.
+
+// ANCHOR: store_struct
+#[derive(Store, Default, Clone, Debug)]
+pub struct GlobalState {
+    count: u32,
+    name: String,
+}
+// ANCHOR_END: store_struct
+
+#[component]
+pub fn Option3() -> impl IntoView {
+    // ANCHOR: provide_store
+    // we'll provide a single store that holds the whole state
+    // each component will be responsible for accessing the field it needs
+    let state = Store::new(GlobalState::default());
+    provide_context(state);
+    // ANCHOR_END: provide_store
+
+    view! {
+        <h1>"Option 3: Passing a Store"</h1>
+        <div class="red consumer" style="width: 100%">
+            <h2>"Current Global State"</h2>
+            <pre>
+                {move || {
+                    format!("{:#?}", state.get())
+                }}
+            </pre>
+        </div>
+        <div style="display: flex">
+            <GlobalStateCounter/>
+            <GlobalStateInput/>
+        </div>
+    }
+}
+
+// ANCHOR: store_counter
+/// A component that updates the count in the global state.
+#[component]
+pub fn GlobalStateCounter() -> impl IntoView {
+    let state = expect_context::<Store<GlobalState>>();
+
+    // `.count()` gives us a reactive field for this part of the store:
+    // it can be read and written like a signal, but only notifies
+    // the parts of the app that read `count`
+    let count = state.count();
+
+    view! {
+        <div class="consumer blue">
+            <button
+                on:click=move |_| {
+                    *count.write() += 1;
+                }
+            >
+                "Increment Global Count"
+            </button>
+            <br/>
+            <span>"Count is: " {move || count.get()}</span>
+        </div>
+    }
+}
+// ANCHOR_END: store_counter
+
+/// A component that updates the name in the global state.
+#[component]
+pub fn GlobalStateInput() -> impl IntoView {
+    let state = expect_context::<Store<GlobalState>>();
+
+    // this field is completely independent of the `count` field
+    // that we accessed in the other component
+    // neither of them will cause the other to rerun
+    let name = state.name();
+
+    view! {
+        <div class="consumer green">
+            <input
+                type="text"
+                prop:value=move || name.get()
+                on:input:target=move |ev| {
+                    name.set(ev.target().value());
+                }
+            />
+            <br/>
+            <span>"Name is: " {move || name.get()}</span>
+        </div>
+    }
+}
+
+// This `main` function is the entry point into the app
+// It just mounts both options to the <body>
+pub fn main() {
+    leptos::mount::mount_to_body(|| view! { <Option2/><Option3/> })
+}
+// ANCHOR_END: app
+
+// ANCHOR: struct_of_signals
+#[derive(Copy, Clone, Debug)]
+pub struct SignalState {
+    count: RwSignal<i32>,
+    name: RwSignal<String>,
+}
+
+impl SignalState {
+    pub fn new() -> Self {
+        Self {
+            count: RwSignal::new(0),
+            name: RwSignal::new("Bob".to_string()),
+        }
+    }
+}
+
+#[component]
+pub fn SignalStateApp() -> impl IntoView {
+    provide_context(SignalState::new());
+
+    view! { <SignalStateView/> }
+}
+// ANCHOR_END: struct_of_signals
+
+#[component]
+pub fn SignalStateView() -> impl IntoView {
+    let state = expect_context::<SignalState>();
+    view! { <p>{state.name} ": " {state.count}</p> }
+}
+
+impl Default for SignalState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Increments the count in a signal holding the whole state, returning how
+/// often a reader of the name ran.
+pub fn coarse_updates() -> usize {
+    let runs = StoredValue::new(0);
+    // ANCHOR: coarse
+    let state = RwSignal::new(GlobalState::default());
+
+    // this only needs the name...
+    let name = Memo::new(move |_| {
+        runs.update_value(|runs| *runs += 1);
+        state.read().name.clone()
+    });
+    name.get();
+
+    // ...but it runs again, cloning the name, whenever the count changes
+    state.update(|state| state.count += 1);
+    name.get();
+    // ANCHOR_END: coarse
+    runs.get_value()
+}
+
+/// Increments the count in a store, returning how often a reader of the
+/// name ran.
+pub fn store_updates() -> usize {
+    let runs = StoredValue::new(0);
+    // ANCHOR: fine_grained
+    let state = Store::new(GlobalState::default());
+
+    let name = Memo::new(move |_| {
+        runs.update_value(|runs| *runs += 1);
+        state.name().get()
+    });
+    name.get();
+
+    // writing to `count` doesn't notify readers of `name`
+    *state.count().write() += 1;
+    name.get();
+    // ANCHOR_END: fine_grained
+    runs.get_value()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::render;
+
+    #[test]
+    fn context_provides_the_count_to_every_consumer() {
+        let html = render(Option2);
+        assert!(html.contains("<strong>0</strong>"), "{html}");
+        assert!(html.contains(" is even."), "{html}");
+    }
+
+    #[test]
+    fn store_fields_read_their_part_of_the_state() {
+        let html = render(Option3);
+        assert!(html.contains("count: 0,"), "{html}");
+        assert!(html.contains("Count is: 0"), "{html}");
+        assert!(render(SignalStateApp).contains("<p>Bob: 0</p>"));
+    }
+
+    #[test]
+    fn stores_only_notify_readers_of_the_field_that_changed() {
+        assert_eq!(Owner::new().with(coarse_updates), 2);
+        assert_eq!(Owner::new().with(store_updates), 1);
+    }
+}
@@ -0,0 +1,89 @@
+//! Code for "Metadata".
+//!
+//! The SSR tests in `tests/ssr/tests/metadata.rs` render [`App`] into
+//! [`shell`], and check that the tags end up in the `<head>`.
+
+use leptos::prelude::*;
+use leptos_meta::{provide_meta_context, Html, Link, Meta, MetaTags, Stylesheet, Title};
+use leptos_router::{
+    components::{Route, Router, Routes},
+    hooks::use_params_map,
+    path,
+};
+
+// ANCHOR: app
+#[component]
+pub fn App() -> impl IntoView {
+    // lets the metadata components anywhere in the app reach the <head>
+    provide_meta_context();
+
+    view! {
+        // every page's title is formatted the same way
+        <Title formatter=|text| format!("{text} — My Awesome Site")/>
+        <Meta name="description" content="Everything you need to know about metadata."/>
+        <Stylesheet id="leptos" href="/pkg/site.css"/>
+        <Link rel="icon" href="/favicon.ico"/>
+        <Router>
+            <Routes fallback=|| "Not found.">
+                <Route path=path!("/") view=Home/>
+                <Route path=path!("/posts/:slug") view=Post/>
+                <Route path=path!("/he") view=Hebrew/>
+            </Routes>
+        </Router>
+    }
+}
+// ANCHOR_END: app
+
+// ANCHOR: shell
+pub fn shell(options: LeptosOptions) -> impl IntoView {
+    view! {
+        <!DOCTYPE html>
+        <html>
+            <head>
+                <meta charset="utf-8"/>
+                // the tags from all of the metadata components are rendered here
+                <MetaTags/>
+                <HydrationScripts options/>
+            </head>
+            <body>
+                <App/>
+            </body>
+        </html>
+    }
+}
+// ANCHOR_END: shell
+
+// ANCHOR: dynamic_title
+#[component]
+pub fn Home() -> impl IntoView {
+    let (count, set_count) = signal(0);
+
+    view! {
+        // a title can be reactive, like any other text...
+        <Title text=move || format!("Clicked {} times", count.get())/>
+        <button on:click=move |_| *set_count.write() += 1>"Click Me"</button>
+    }
+}
+
+#[component]
+pub fn Post() -> impl IntoView {
+    let params = use_params_map();
+    let slug = move || params.read().get("slug").unwrap_or_default();
+
+    view! {
+        // ...so it can follow the route, too
+        <Title text=move || format!("Post: {}", slug())/>
+        <h1>{slug}</h1>
+    }
+}
+// ANCHOR_END: dynamic_title
+
+#[component]
+pub fn Hebrew() -> impl IntoView {
+    view! {
+        // ANCHOR: html
+        <Html attr:lang="he" attr:dir="rtl" attr:data-theme="dark"/>
+        // ANCHOR_END: html
+        <Title text="שלום"/>
+    }
+}
@@ -0,0 +1,6 @@
+//! Code for the chapters between the book's parts: the interludes, "Global
+//! State Management", and "Metadata".
+
+pub mod c19_projecting_children;
+pub mod c20_global_state;
+pub mod c21_metadata;
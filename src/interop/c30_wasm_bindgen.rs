@@ -0,0 +1,107 @@
+//! Code for "Calling JavaScript with `wasm-bindgen`".
+//!
+//! This compiles in every mode, but the functions it imports only exist in a
+//! browser, so it's tested by the DOM tests in `tests/dom/wasm_bindgen.rs`.
+
+use leptos::prelude::*;
+use send_wrapper::SendWrapper;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::{prelude::*, JsCast};
+
+// ANCHOR: extern_block
+#[wasm_bindgen]
+extern "C" {
+    // `console.log`, which takes any number of arguments, here a single string
+    #[wasm_bindgen(js_namespace = console)]
+    pub fn log(message: &str);
+
+    // `Math.max`, under a name that makes sense in Rust
+    #[wasm_bindgen(js_namespace = Math, js_name = max)]
+    pub fn max_of_two(a: f64, b: f64) -> f64;
+}
+
+// your own JavaScript can be bundled with the wasm, and imported the same way
+#[wasm_bindgen(inline_js = r#"
+    export function greet(name) {
+        return `Hello, ${name}!`;
+    }
+
+    export function describe(point) {
+        return `(${point.x}, ${point.y})`;
+    }
+
+    export function origin() {
+        return { x: 0, y: 0 };
+    }
+"#)]
+extern "C" {
+    pub fn greet(name: &str) -> String;
+    fn describe(point: JsValue) -> String;
+    fn origin() -> JsValue;
+}
+// ANCHOR_END: extern_block
+
+// ANCHOR: call_js
+#[component]
+pub fn Greeting() -> impl IntoView {
+    let (name, set_name) = signal("world".to_string());
+
+    view! {
+        <input type="text" prop:value=name on:input:target=move |ev| set_name.set(ev.target().value())/>
+        // an imported function is called like any other Rust function
+        <p>{move || greet(&name.read())}</p>
+    }
+}
+// ANCHOR_END: call_js
+
+// ANCHOR: serde
+/// A point, which crosses into JavaScript as a plain `{ x, y }` object.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+pub fn describe_point(point: Point) -> String {
+    // Rust data becomes a `JsValue` with `to_value`...
+    let value = serde_wasm_bindgen::to_value(&point).unwrap();
+    describe(value)
+}
+
+pub fn origin_point() -> Result<Point, serde_wasm_bindgen::Error> {
+    // ...and comes back with `from_value`, which fails if the shape is wrong
+    serde_wasm_bindgen::from_value(origin())
+}
+// ANCHOR_END: serde
+
+// ANCHOR: closure
+#[component]
+pub fn Ticker(
+    /// How often the count goes up, in milliseconds.
+    #[prop(default = 1000)]
+    interval: i32,
+) -> impl IntoView {
+    let (ticks, set_ticks) = signal(0);
+
+    // JavaScript can only call a Rust closure through a `Closure`, which
+    // owns it: if the `Closure` is dropped, calling it throws an error
+    let tick = Closure::<dyn Fn()>::new(move || *set_ticks.write() += 1);
+    let handle = window()
+        .set_interval_with_callback_and_timeout_and_arguments_0(
+            tick.as_ref().unchecked_ref(),
+            interval,
+        )
+        .unwrap();
+
+    // so keep it alive until the interval is cleared, when the component is
+    // unmounted. (`Closure` isn't `Send`, but this only ever runs in the
+    // browser, so it's fine to wrap it.)
+    let tick = SendWrapper::new(tick);
+    on_cleanup(move || {
+        window().clear_interval_with_handle(handle);
+        drop(tick);
+    });
+
+    view! { <p>"Ticks: " {ticks}</p> }
+}
+// ANCHOR_END: closure
@@ -0,0 +1,138 @@
+//! Code for "Using the Browser's APIs with `web-sys`".
+//!
+//! Like the `wasm-bindgen` chapter, this only does anything in a browser, and
+//! is tested by the DOM tests in `tests/dom/web_sys.rs`.
+
+use js_sys::Array;
+use leptos::{html, prelude::*};
+use send_wrapper::SendWrapper;
+use wasm_bindgen::{prelude::*, JsCast};
+use web_sys::{IntersectionObserver, IntersectionObserverEntry, KeyboardEvent};
+
+// ANCHOR: window_document
+#[component]
+pub fn PageInfo() -> impl IntoView {
+    // `web_sys::window()` returns `None` outside of a browser...
+    let width = web_sys::window()
+        .and_then(|window| window.inner_width().ok())
+        .and_then(|width| width.as_f64())
+        .unwrap_or_default();
+    // ...while Leptos's `window()` and `document()` assume there is one, and
+    // cache the handles so they're cheap to call again
+    let title = document().title();
+
+    view! {
+        <p class="width">{width}</p>
+        <p class="title">{title}</p>
+    }
+}
+// ANCHOR_END: window_document
+
+// ANCHOR: media_query
+/// Whether the page currently matches a CSS media query, like
+/// `"(prefers-color-scheme: dark)"`.
+pub fn use_media_query(query: &str) -> ReadSignal<bool> {
+    let list = window().match_media(query).unwrap().unwrap();
+    let (matches, set_matches) = signal(list.matches());
+
+    // the list fires `change` whenever it starts or stops matching
+    let on_change = Closure::<dyn Fn()>::new({
+        let list = list.clone();
+        move || set_matches.set(list.matches())
+    });
+    list.add_event_listener_with_callback("change", on_change.as_ref().unchecked_ref())
+        .unwrap();
+
+    let list = SendWrapper::new((list, on_change));
+    on_cleanup(move || {
+        let (list, on_change) = list.take();
+        list.remove_event_listener_with_callback("change", on_change.as_ref().unchecked_ref())
+            .unwrap();
+    });
+
+    matches
+}
+
+#[component]
+pub fn ColorScheme() -> impl IntoView {
+    let dark = use_media_query("(prefers-color-scheme: dark)");
+    view! { <p>{move || if dark.get() { "dark" } else { "light" }}</p> }
+}
+// ANCHOR_END: media_query
+
+// ANCHOR: intersection_observer
+#[component]
+pub fn Reveal(children: Children) -> impl IntoView {
+    let (visible, set_visible) = signal(false);
+    let section = NodeRef::<html::Section>::new();
+
+    Effect::new(move || {
+        // the element only exists once the view has been mounted, so this
+        // runs again when the `NodeRef` is filled in
+        let Some(section) = section.get() else {
+            return;
+        };
+        let on_intersect = Closure::<dyn Fn(Array)>::new(move |entries: Array| {
+            let intersecting = entries
+                .iter()
+                .map(IntersectionObserverEntry::unchecked_from_js)
+                .any(|entry| entry.is_intersecting());
+            if intersecting {
+                set_visible.set(true);
+            }
+        });
+        let observer = IntersectionObserver::new(on_intersect.as_ref().unchecked_ref()).unwrap();
+        observer.observe(&section);
+
+        // cleanup inside an effect runs before it runs again, and when it's
+        // disposed along with the component
+        let observer = SendWrapper::new((observer, on_intersect));
+        on_cleanup(move || observer.0.disconnect());
+    });
+
+    view! {
+        <section node_ref=section class:visible=visible>
+            {children()}
+        </section>
+    }
+}
+// ANCHOR_END: intersection_observer
+
+// ANCHOR: event_listener
+#[component]
+pub fn KeyLogger() -> impl IntoView {
+    let (last_key, set_last_key) = signal(String::new());
+
+    let on_keydown = Closure::<dyn Fn(KeyboardEvent)>::new(move |ev: KeyboardEvent| {
+        set_last_key.set(ev.key());
+    });
+    window()
+        .add_event_listener_with_callback("keydown", on_keydown.as_ref().unchecked_ref())
+        .unwrap();
+
+    // a listener on the window outlives the component unless you remove it
+    let on_keydown = SendWrapper::new(on_keydown);
+    on_cleanup(move || {
+        window()
+            .remove_event_listener_with_callback("keydown", on_keydown.as_ref().unchecked_ref())
+            .unwrap();
+    });
+
+    view! { <p>"Last key: " {last_key}</p> }
+}
+// ANCHOR_END: event_listener
+
+// ANCHOR: window_event_listener
+#[component]
+pub fn KeyLoggerHelper() -> impl IntoView {
+    let (last_key, set_last_key) = signal(String::new());
+
+    // the same thing, removing the listener through the handle it returns
+    let handle = window_event_listener(leptos::ev::keydown, move |ev| {
+        set_last_key.set(ev.key());
+    });
+    on_cleanup(move || handle.remove());
+
+    view! { <p>"Last key: " {last_key}</p> }
+}
+// ANCHOR_END: window_event_listener
@@ -0,0 +1,72 @@
+//! Code for "Accessing Elements with `NodeRef`".
+//!
+//! The elements only exist in the browser, so this is tested by the DOM tests
+//! in `tests/dom/node_ref.rs`.
+
+use leptos::{html, prelude::*};
+
+// ANCHOR: node_ref
+#[component]
+pub fn NameInput() -> impl IntoView {
+    // empty until the view is mounted, and typed by the element it will hold
+    let input = NodeRef::<html::Input>::new();
+    let (name, set_name) = signal(String::new());
+
+    view! {
+        // `node_ref` fills the `NodeRef` in with the element
+        <input type="text" value="Leptos" node_ref=input/>
+        <button on:click=move |_| {
+            // `get()` returns the `web_sys::HtmlInputElement`, with all its methods
+            let input = input.get().expect("<input> should be mounted");
+            set_name.set(input.value());
+        }>"Read"</button>
+        <p>{name}</p>
+    }
+}
+// ANCHOR_END: node_ref
+
+// ANCHOR: after_mount
+#[component]
+pub fn MeasuredBox() -> impl IntoView {
+    let element = NodeRef::<html::Div>::new();
+    let (width, set_width) = signal(None::<i32>);
+
+    // effects run after the view has been mounted, so the element is there by
+    // now. (They never run on the server, where there isn't one.)
+    Effect::new(move || {
+        if let Some(element) = element.get() {
+            set_width.set(Some(element.offset_width()));
+        }
+    });
+
+    view! {
+        <div node_ref=element style="width: 120px">
+            {move || width.get().map(|width| format!("{width}px wide"))}
+        </div>
+    }
+}
+// ANCHOR_END: after_mount
+
+// ANCHOR: focus
+#[component]
+pub fn SearchBox() -> impl IntoView {
+    let input = NodeRef::<html::Input>::new();
+
+    // focus the input as soon as it's mounted...
+    Effect::new(move || {
+        if let Some(input) = input.get() {
+            _ = input.focus();
+        }
+    });
+
+    view! {
+        <input type="search" node_ref=input/>
+        // ...and whenever the button is clicked
+        <button on:click=move |_| {
+            if let Some(input) = input.get_untracked() {
+                _ = input.focus();
+            }
+        }>"Search"</button>
+    }
+}
+// ANCHOR_END: focus
@@ -0,0 +1,77 @@
+//! Code for "Custom Directives with `use:`".
+//!
+//! Directives only run in the browser, so this is tested by the DOM tests in
+//! `tests/dom/directives.rs`.
+
+use leptos::prelude::*;
+use send_wrapper::SendWrapper;
+use wasm_bindgen::{prelude::*, JsCast};
+use web_sys::{Element, MouseEvent, Node};
+
+// ANCHOR: directives
+// a directive is a function that takes the element it's used on...
+pub fn highlight(el: Element) {
+    el.class_list().add_1("highlighted").unwrap();
+}
+
+// ...and, optionally, a parameter
+pub fn tooltip(el: Element, text: String) {
+    el.set_attribute("title", &text).unwrap();
+}
+// ANCHOR_END: directives
+
+// ANCHOR: use_directive
+#[component]
+pub fn Directives() -> impl IntoView {
+    view! {
+        // a directive without a parameter is used on its own...
+        <p use:highlight>"Look at me!"</p>
+        // ...and one with a parameter takes it like an attribute
+        <button use:tooltip="Saves your work".to_string()>"Save"</button>
+    }
+}
+// ANCHOR_END: use_directive
+
+// ANCHOR: click_outside
+/// Calls `on_outside` whenever the user clicks anywhere outside of the
+/// element.
+pub fn click_outside(el: Element, on_outside: Callback<()>) {
+    let listener = Closure::<dyn Fn(MouseEvent)>::new(move |ev: MouseEvent| {
+        let target = ev
+            .target()
+            .and_then(|target| target.dyn_into::<Node>().ok());
+        if !el.contains(target.as_ref()) {
+            on_outside.run(());
+        }
+    });
+    document()
+        .add_event_listener_with_callback("click", listener.as_ref().unchecked_ref())
+        .unwrap();
+
+    // a directive runs inside the component that uses it, so it can clean up
+    // after itself when that component is unmounted
+    let listener = SendWrapper::new(listener);
+    on_cleanup(move || {
+        document()
+            .remove_event_listener_with_callback("click", listener.as_ref().unchecked_ref())
+            .unwrap();
+    });
+}
+
+#[component]
+pub fn Dropdown() -> impl IntoView {
+    let (open, set_open) = signal(true);
+
+    view! {
+        <div class="dropdown" use:click_outside=Callback::new(move |_| set_open.set(false))>
+            <button on:click=move |_| set_open.set(true)>"Menu"</button>
+            <Show when=move || open.get()>
+                <ul>
+                    <li>"Open"</li>
+                    <li>"Save"</li>
+                </ul>
+            </Show>
+        </div>
+    }
+}
+// ANCHOR_END: click_outside
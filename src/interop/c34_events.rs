@@ -0,0 +1,87 @@
+//! Code for "Custom Events".
+//!
+//! Events are only fired in the browser, so this is tested by the DOM tests in
+//! `tests/dom/custom_events.rs`.
+
+use leptos::{ev, prelude::*};
+use wasm_bindgen::JsValue;
+use web_sys::{CustomEvent, CustomEventInit, EventTarget};
+
+// ANCHOR: propagation
+#[component]
+pub fn Propagation() -> impl IntoView {
+    let (clicks, set_clicks) = signal(0);
+
+    view! {
+        // each listener is added to its own element, so clicks inside the <div>
+        // bubble up to it just as they would without Leptos...
+        <div on:click=move |_| *set_clicks.write() += 1>
+            // ...unless a listener further in stops them
+            <button class="stops" on:click=|ev| ev.stop_propagation()>"Stops here"</button>
+            <button class="bubbles">"Bubbles up"</button>
+        </div>
+        <p>"The <div> heard " {clicks} " clicks"</p>
+    }
+}
+// ANCHOR_END: propagation
+
+// ANCHOR: dispatch
+/// Fires a `color-picked` event from `target`, which bubbles up to its
+/// ancestors with the color as its `detail`.
+pub fn dispatch_color_picked(target: &EventTarget, color: &str) {
+    let init = CustomEventInit::new();
+    init.set_bubbles(true);
+    init.set_detail(&JsValue::from_str(color));
+    let event = CustomEvent::new_with_event_init_dict("color-picked", &init).unwrap();
+    target.dispatch_event(&event).unwrap();
+}
+
+#[component]
+pub fn ColorPicker() -> impl IntoView {
+    view! {
+        <div class="swatches">
+            {["red", "green", "blue"]
+                .map(|color| {
+                    view! {
+                        <button on:click=move |ev| {
+                            // the event starts at the button that was clicked
+                            dispatch_color_picked(&ev.target().unwrap(), color);
+                        }>{color}</button>
+                    }
+                })}
+        </div>
+    }
+}
+// ANCHOR_END: dispatch
+
+// ANCHOR: custom_event
+#[component]
+pub fn Palette() -> impl IntoView {
+    let (color, set_color) = signal(None::<String>);
+
+    view! {
+        // an event name Leptos doesn't know becomes `ev::Custom::new("color-picked")`,
+        // and the argument's type says which kind of event to expect
+        <div on:color-picked=move |ev: CustomEvent| set_color.set(ev.detail().as_string())>
+            <ColorPicker/>
+        </div>
+        <p>"Picked: " {move || color.get().unwrap_or_else(|| "nothing yet".into())}</p>
+    }
+}
+// ANCHOR_END: custom_event
+
+// ANCHOR: window_custom_event
+#[component]
+pub fn LastColor() -> impl IntoView {
+    let (color, set_color) = signal(String::new());
+
+    // `ev::Custom` can be used anywhere Leptos takes an event, like a listener
+    // on the window that hears every `color-picked` that bubbles up to it
+    let handle = window_event_listener(ev::Custom::<CustomEvent>::new("color-picked"), move |ev| {
+        set_color.set(ev.detail().as_string().unwrap_or_default())
+    });
+    on_cleanup(move || handle.remove());
+
+    view! { <p class="last-color">{color}</p> }
+}
+// ANCHOR_END: window_custom_event
@@ -0,0 +1,141 @@
+//! Code for "Wrapping a JavaScript Widget".
+//!
+//! Chart.js is loaded by the page, so it only exists in a browser. The DOM
+//! tests in `tests/dom/chart.rs` stand in a fake `Chart` class for it, which
+//! records how it's called.
+
+use leptos::{html, prelude::*};
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+use web_sys::HtmlCanvasElement;
+
+// ANCHOR: extern_block
+#[wasm_bindgen]
+extern "C" {
+    /// A chart drawn by Chart.js, from the global `Chart` class that its
+    /// `<script>` tag defines.
+    pub type Chart;
+
+    /// `new Chart(canvas, config)`, which draws the chart right away.
+    #[wasm_bindgen(constructor)]
+    pub fn new(canvas: &HtmlCanvasElement, config: &JsValue) -> Chart;
+
+    /// Replaces the data the chart shows. It isn't redrawn until `update`.
+    #[wasm_bindgen(method, setter)]
+    pub fn set_data(this: &Chart, data: &JsValue);
+
+    #[wasm_bindgen(method)]
+    pub fn update(this: &Chart);
+
+    /// Removes the chart's event listeners and frees its canvas.
+    #[wasm_bindgen(method)]
+    pub fn destroy(this: &Chart);
+}
+// ANCHOR_END: extern_block
+
+// ANCHOR: config
+/// The parts of Chart.js's configuration this example uses, which become a
+/// plain JavaScript object.
+#[derive(Serialize)]
+struct Config {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    data: Data,
+}
+
+#[derive(Serialize)]
+struct Data {
+    labels: Vec<String>,
+    datasets: [Dataset; 1],
+}
+
+#[derive(Serialize)]
+struct Dataset {
+    label: String,
+    data: Vec<f64>,
+}
+
+fn data(label: &str, points: &[(String, f64)]) -> Data {
+    Data {
+        labels: points.iter().map(|(label, _)| label.clone()).collect(),
+        datasets: [Dataset {
+            label: label.to_string(),
+            data: points.iter().map(|(_, value)| *value).collect(),
+        }],
+    }
+}
+// ANCHOR_END: config
+
+// ANCHOR: bar_chart
+#[component]
+pub fn BarChart(
+    /// What the bars measure, shown in the legend.
+    #[prop(into)]
+    label: String,
+    /// A label and a value for each bar.
+    #[prop(into)]
+    points: Signal<Vec<(String, f64)>>,
+) -> impl IntoView {
+    let canvas = NodeRef::<html::Canvas>::new();
+    // a JavaScript object isn't `Send`, so it's kept in local storage
+    let chart = StoredValue::new_local(None::<Chart>);
+
+    // runs once the canvas is mounted, and again whenever `points` changes
+    Effect::new(move || {
+        let data = data(&label, &points.read());
+        let Some(canvas) = canvas.get() else {
+            return;
+        };
+        chart.update_value(|chart| match chart {
+            // the chart already exists, so only its data is replaced
+            Some(chart) => {
+                chart.set_data(&serde_wasm_bindgen::to_value(&data).unwrap());
+                chart.update();
+            }
+            None => {
+                let config = Config { kind: "bar", data };
+                let config = serde_wasm_bindgen::to_value(&config).unwrap();
+                *chart = Some(Chart::new(&canvas, &config));
+            }
+        });
+    });
+
+    // Chart.js holds on to the canvas, and listens for resizes, until the
+    // chart is destroyed
+    on_cleanup(move || {
+        if let Some(chart) = chart.try_update_value(Option::take).flatten() {
+            chart.destroy();
+        }
+    });
+
+    view! {
+        // Chart.js sizes the canvas to fit its parent
+        <div class="chart">
+            <canvas node_ref=canvas></canvas>
+        </div>
+    }
+}
+// ANCHOR_END: bar_chart
+
+// ANCHOR: app
+#[component]
+pub fn App() -> impl IntoView {
+    let (points, set_points) = signal(vec![
+        ("Mon".to_string(), 3.0),
+        ("Tue".to_string(), 5.0),
+        ("Wed".to_string(), 2.0),
+    ]);
+    let (show, set_show) = signal(true);
+
+    view! {
+        <button on:click=move |_| {
+            set_points.write().push(("Next".to_string(), 4.0));
+        }>"Add a bar"</button>
+        <button on:click=move |_| set_show.update(|show| *show = !*show)>"Toggle"</button>
+        // hiding the chart unmounts it, which destroys it
+        <Show when=move || show.get()>
+            <BarChart label="Commits" points/>
+        </Show>
+    }
+}
+// ANCHOR_END: app
@@ -0,0 +1,172 @@
+//! Code for "Publishing Components as Web Components".
+//!
+//! Custom elements only exist in a browser, so this is tested by the DOM
+//! tests in `tests/dom/web_components.rs`, which add `<click-counter>`
+//! elements to the page the way a page that knows nothing about Leptos would.
+
+use leptos::{mount::mount_to, prelude::*};
+use std::{any::Any, collections::HashMap, rc::Rc};
+use wasm_bindgen::prelude::*;
+use web_sys::{CustomEvent, CustomEventInit, HtmlElement};
+
+// ANCHOR: define
+// a custom element has to be a JavaScript class that extends `HTMLElement`,
+// which Rust can't declare, so this small one calls back into Rust instead
+#[wasm_bindgen(inline_js = r#"
+    export function define(name, attributes, connect) {
+        // a name can only be defined once per page
+        if (customElements.get(name)) {
+            return;
+        }
+        customElements.define(name, class extends HTMLElement {
+            static observedAttributes = attributes;
+
+            connectedCallback() {
+                this.mounted = connect(this);
+            }
+
+            disconnectedCallback() {
+                this.mounted?.free();
+                this.mounted = undefined;
+            }
+
+            attributeChangedCallback(name, _old, value) {
+                this.mounted?.set_attribute(name, value);
+            }
+        });
+    }
+"#)]
+extern "C" {
+    fn define(
+        name: &str,
+        attributes: Vec<String>,
+        connect: &Closure<dyn Fn(HtmlElement) -> MountedElement>,
+    );
+}
+// ANCHOR_END: define
+
+// ANCHOR: host
+/// The custom element a component was mounted into.
+#[derive(Clone)]
+pub struct Host {
+    element: HtmlElement,
+    attributes: HashMap<String, ArcRwSignal<Option<String>>>,
+}
+
+impl Host {
+    /// The value of one of the element's observed attributes. Reading it
+    /// tracks it, like reading a signal, because it is one.
+    pub fn attribute(&self, name: &str) -> Option<String> {
+        self.attributes.get(name).and_then(|value| value.get())
+    }
+
+    /// Dispatches a `CustomEvent` from the element, which the page can
+    /// listen for with `addEventListener`.
+    pub fn dispatch(&self, event: &str, detail: &JsValue) {
+        let init = CustomEventInit::new();
+        init.set_bubbles(true);
+        init.set_detail(detail);
+        let event = CustomEvent::new_with_event_init_dict(event, &init).unwrap();
+        self.element.dispatch_event(&event).unwrap();
+    }
+}
+// ANCHOR_END: host
+
+// ANCHOR: mounted
+/// A component mounted into a custom element, which the element keeps until
+/// it's removed from the page.
+#[wasm_bindgen]
+pub struct MountedElement {
+    attributes: HashMap<String, ArcRwSignal<Option<String>>>,
+    // unmounts the view, and disposes of its reactive owner, when dropped
+    _handle: Box<dyn Any>,
+}
+
+#[wasm_bindgen]
+impl MountedElement {
+    pub fn set_attribute(&self, name: &str, value: Option<String>) {
+        if let Some(signal) = self.attributes.get(name) {
+            signal.set(value);
+        }
+    }
+}
+// ANCHOR_END: mounted
+
+// ANCHOR: define_element
+/// Defines a custom element called `name`, which mounts `component` into
+/// every instance of it on the page, and updates it whenever one of
+/// `attributes` changes.
+pub fn define_element<F, N>(name: &str, attributes: &[&str], component: F)
+where
+    F: Fn(Host) -> N + 'static,
+    N: IntoView + 'static,
+{
+    let observed = attributes
+        .iter()
+        .map(|name| name.to_string())
+        .collect::<Vec<_>>();
+    let component = Rc::new(component);
+
+    let connect = Closure::<dyn Fn(HtmlElement) -> MountedElement>::new({
+        let observed = observed.clone();
+        move |element: HtmlElement| {
+            // the attributes the element already has, when it's connected
+            let attributes = observed
+                .iter()
+                .map(|name| (name.clone(), ArcRwSignal::new(element.get_attribute(name))))
+                .collect::<HashMap<_, _>>();
+            let host = Host {
+                element: element.clone(),
+                attributes: attributes.clone(),
+            };
+            let component = Rc::clone(&component);
+            let handle = mount_to(element, move || component(host));
+            MountedElement {
+                attributes,
+                _handle: Box::new(handle),
+            }
+        }
+    });
+    define(name, observed, &connect);
+    // the element can be added to the page at any time, for as long as the
+    // page is open, so the closure has to last that long too
+    connect.forget();
+}
+// ANCHOR_END: define_element
+
+// ANCHOR: click_counter
+#[component]
+fn ClickCounter(host: Host) -> impl IntoView {
+    // `start` is only read once, so changing it later does nothing
+    let start = host.attribute("start").and_then(|start| start.parse().ok());
+    let (count, set_count) = signal(start.unwrap_or(0));
+    // `label` is observed, so this updates when the page changes it
+    let label = {
+        let host = host.clone();
+        move || {
+            host.attribute("label")
+                .unwrap_or_else(|| "Clicks".to_string())
+        }
+    };
+
+    view! {
+        <button on:click=move |_| {
+            *set_count.write() += 1;
+            // tell the page, which can't read the signal
+            host.dispatch("count-changed", &count.get().into());
+        }>
+            {label} ": " {count}
+        </button>
+    }
+}
+
+/// Defines `<click-counter label="..." start="...">`. Call this once, when
+/// the WASM has loaded; elements already on the page are upgraded then.
+pub fn register() {
+    define_element(
+        "click-counter",
+        &["label", "start"],
+        |host| view! { <ClickCounter host/> },
+    );
+}
+// ANCHOR_END: click_counter
@@ -0,0 +1,106 @@
+//! Code for "Adding Leptos to an Existing Page".
+//!
+//! The page is someone else's HTML, so this is tested by the DOM tests in
+//! `tests/dom/existing_page.rs`, which write that HTML into the document
+//! before mounting the widgets into it.
+
+use leptos::{mount::mount_to, prelude::*};
+use std::any::Any;
+use wasm_bindgen::JsCast;
+use web_sys::HtmlElement;
+
+// ANCHOR: widgets
+#[component]
+pub fn CartBadge(cart: ArcRwSignal<Vec<String>>) -> impl IntoView {
+    view! { <span class="badge">"Cart: " {move || cart.read().len()}</span> }
+}
+
+#[component]
+pub fn AddToCart(sku: String, cart: ArcRwSignal<Vec<String>>) -> impl IntoView {
+    let in_cart = Signal::derive({
+        let (cart, sku) = (cart.clone(), sku.clone());
+        move || cart.read().contains(&sku)
+    });
+
+    view! {
+        <button
+            disabled=in_cart
+            on:click=move |_| cart.write().push(sku.clone())
+        >
+            {move || if in_cart.get() { "In your cart" } else { "Add to cart" }}
+        </button>
+    }
+}
+// ANCHOR_END: widgets
+
+// ANCHOR: elements
+/// Every element on the page that matches `selector`.
+fn elements(selector: &str) -> Vec<HtmlElement> {
+    let nodes = document().query_selector_all(selector).unwrap();
+    (0..nodes.length())
+        .filter_map(|i| nodes.item(i))
+        .map(|node| node.unchecked_into())
+        .collect()
+}
+
+/// Mounts a view into `element`, replacing whatever the page rendered there
+/// as a placeholder.
+fn mount_into<N: IntoView + 'static>(
+    element: HtmlElement,
+    view: impl FnOnce() -> N + 'static,
+) -> Box<dyn Any> {
+    // `mount_to` adds the view after the element's children, so clear them
+    element.set_inner_html("");
+    Box::new(mount_to(element, view))
+}
+// ANCHOR_END: elements
+
+// ANCHOR: mount_widgets
+/// The widgets mounted into the page. Each one is unmounted when this is
+/// dropped.
+pub struct Widgets {
+    _handles: Vec<Box<dyn Any>>,
+}
+
+impl Widgets {
+    /// Keeps the widgets mounted for as long as the page is open.
+    pub fn forget(self) {
+        std::mem::forget(self);
+    }
+}
+
+/// Mounts the cart badge, and an "add to cart" button for each product the
+/// page lists.
+pub fn mount_widgets() -> Widgets {
+    // each mount has its own reactive owner, so they can't share a context;
+    // they share this signal instead, which they each hold a clone of
+    let cart = ArcRwSignal::new(Vec::<String>::new());
+    let mut mounted = Vec::new();
+
+    // one badge, in a place the page set aside for it...
+    if let Some(badge) = document().get_element_by_id("cart-badge") {
+        let cart = cart.clone();
+        mounted.push(mount_into(badge.unchecked_into(), move || {
+            view! { <CartBadge cart/> }
+        }));
+    }
+
+    // ...and one button for every product, whose SKU the page passes in an
+    // attribute, the same way it would pass data to any other script
+    for element in elements("[data-add-to-cart]") {
+        let sku = element
+            .get_attribute("data-add-to-cart")
+            .unwrap_or_default();
+        let cart = cart.clone();
+        mounted.push(mount_into(element, move || {
+            view! { <AddToCart sku cart/> }
+        }));
+    }
+
+    Widgets { _handles: mounted }
+}
+
+pub fn main() {
+    mount_widgets().forget();
+}
+// ANCHOR_END: mount_widgets
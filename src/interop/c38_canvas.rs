@@ -0,0 +1,144 @@
+//! Code for "Drawing on a `<canvas>`".
+//!
+//! Animation frames only happen in a browser, so this is tested by the DOM
+//! tests in `tests/dom/canvas.rs`.
+
+use leptos::{html, prelude::*};
+use send_wrapper::SendWrapper;
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+use wasm_bindgen::{prelude::*, JsCast};
+use web_sys::CanvasRenderingContext2d;
+
+// ANCHOR: animation_loop
+/// Calls `frame` before each repaint, with the time in milliseconds, until
+/// the current owner is cleaned up.
+pub fn use_animation_frame(mut frame: impl FnMut(f64) + 'static) {
+    // the callback schedules itself again for the next frame, so it needs a
+    // way to reach its own `Closure`, which this shared slot gives it
+    type Slot = Rc<RefCell<Option<Closure<dyn FnMut(f64)>>>>;
+    let slot: Slot = Rc::new(RefCell::new(None));
+    let handle = Rc::new(Cell::new(None::<i32>));
+
+    fn request(slot: &Slot) -> i32 {
+        let slot = slot.borrow();
+        let callback = slot.as_ref().expect("the loop is still running");
+        window()
+            .request_animation_frame(callback.as_ref().unchecked_ref())
+            .unwrap()
+    }
+
+    *slot.borrow_mut() = Some(Closure::new({
+        let (slot, handle) = (Rc::clone(&slot), Rc::clone(&handle));
+        move |time: f64| {
+            frame(time);
+            handle.set(Some(request(&slot)));
+        }
+    }));
+    handle.set(Some(request(&slot)));
+
+    // stop the next frame, and drop the `Closure`: it holds on to the slot
+    // that holds it, so until it's taken out, neither would ever be freed
+    let running = SendWrapper::new((slot, handle));
+    on_cleanup(move || {
+        let (slot, handle) = running.take();
+        if let Some(handle) = handle.take() {
+            _ = window().cancel_animation_frame(handle);
+        }
+        slot.borrow_mut().take();
+    });
+}
+// ANCHOR_END: animation_loop
+
+// ANCHOR: bouncing_ball
+const WIDTH: f64 = 300.0;
+const HEIGHT: f64 = 150.0;
+const RADIUS: f64 = 10.0;
+
+/// Where the ball is, and where it's going, in pixels per second.
+struct Ball {
+    x: f64,
+    y: f64,
+    dx: f64,
+    dy: f64,
+}
+
+impl Ball {
+    /// Moves the ball on by `seconds`, bouncing it off the edges.
+    fn step(&mut self, seconds: f64) {
+        self.x += self.dx * seconds;
+        self.y += self.dy * seconds;
+        if !(RADIUS..=WIDTH - RADIUS).contains(&self.x) {
+            self.dx = -self.dx;
+            self.x = self.x.clamp(RADIUS, WIDTH - RADIUS);
+        }
+        if !(RADIUS..=HEIGHT - RADIUS).contains(&self.y) {
+            self.dy = -self.dy;
+            self.y = self.y.clamp(RADIUS, HEIGHT - RADIUS);
+        }
+    }
+
+    fn draw(&self, context: &CanvasRenderingContext2d) {
+        context.clear_rect(0.0, 0.0, WIDTH, HEIGHT);
+        context.begin_path();
+        _ = context.arc(self.x, self.y, RADIUS, 0.0, std::f64::consts::TAU);
+        context.fill();
+    }
+}
+
+#[component]
+pub fn BouncingBall() -> impl IntoView {
+    let canvas = NodeRef::<html::Canvas>::new();
+    let (speed, set_speed) = signal(1.0);
+    let (paused, set_paused) = signal(false);
+    let (frames, set_frames) = signal(0u32);
+
+    // the ball changes every frame, but nothing else reads it, so it's plain
+    // data owned by the loop rather than a signal
+    let mut ball = Ball {
+        x: WIDTH / 2.0,
+        y: HEIGHT / 2.0,
+        dx: 120.0,
+        dy: 90.0,
+    };
+    let mut last = None::<f64>;
+
+    use_animation_frame(move |time| {
+        let elapsed = last.map_or(0.0, |last| (time - last) / 1000.0);
+        last = Some(time);
+        // nothing tracks reads in a frame callback, so read without tracking
+        if paused.get_untracked() {
+            return;
+        }
+        let Some(canvas) = canvas.get_untracked() else {
+            return;
+        };
+        let context = canvas
+            .get_context("2d")
+            .unwrap()
+            .unwrap()
+            .unchecked_into::<CanvasRenderingContext2d>();
+        ball.step(elapsed * speed.get_untracked());
+        ball.draw(&context);
+        *set_frames.write() += 1;
+    });
+
+    view! {
+        <canvas node_ref=canvas width=WIDTH height=HEIGHT></canvas>
+        <input
+            type="range"
+            min="0"
+            max="3"
+            step="0.5"
+            prop:value=speed
+            on:input:target=move |ev| set_speed.set(ev.target().value_as_number())
+        />
+        <button on:click=move |_| set_paused.update(|paused| *paused = !*paused)>
+            {move || if paused.get() { "Play" } else { "Pause" }}
+        </button>
+        <p>"Frames drawn: " {frames}</p>
+    }
+}
+// ANCHOR_END: bouncing_ball
@@ -0,0 +1,215 @@
+//! Code for "Keyboard Shortcuts".
+//!
+//! Parsing and matching shortcuts is plain Rust, which the tests below check.
+//! The listeners only run in a browser, so they're tested by the DOM tests in
+//! `tests/dom/shortcuts.rs`, which dispatch `keydown` events.
+
+use leptos::{ev::KeyboardEvent, html, prelude::*};
+use std::{fmt, rc::Rc, str::FromStr};
+use wasm_bindgen::JsCast;
+use web_sys::Element;
+
+// ANCHOR: shortcut
+/// A key combination, like `"/"`, `"Escape"` or `"Ctrl+K"`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Shortcut {
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+    pub meta: bool,
+    /// The `key` of the event, which is the character typed, or a name like
+    /// `Escape` or `ArrowUp`.
+    pub key: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct InvalidShortcut(pub String);
+
+impl fmt::Display for InvalidShortcut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid shortcut {:?}", self.0)
+    }
+}
+
+impl FromStr for Shortcut {
+    type Err = InvalidShortcut;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidShortcut(s.to_string());
+        // the key is whatever follows the last `+` before the final character,
+        // so that `Ctrl++` works
+        let last = s.chars().last().map_or(0, char::len_utf8);
+        let (modifiers, key) = match s[..s.len() - last].rfind('+') {
+            Some(split) => (&s[..split], &s[split + 1..]),
+            None => ("", s),
+        };
+        if key.is_empty() {
+            return Err(invalid());
+        }
+        let mut shortcut = Shortcut {
+            ctrl: false,
+            alt: false,
+            shift: false,
+            meta: false,
+            key: key.to_string(),
+        };
+        for modifier in modifiers.split('+').filter(|m| !m.is_empty()) {
+            match modifier {
+                "Ctrl" => shortcut.ctrl = true,
+                "Alt" => shortcut.alt = true,
+                "Shift" => shortcut.shift = true,
+                "Meta" => shortcut.meta = true,
+                _ => return Err(invalid()),
+            }
+        }
+        Ok(shortcut)
+    }
+}
+
+impl Shortcut {
+    /// Whether a key press matches this shortcut. Letters match in either
+    /// case, and Shift is only checked if the shortcut includes it, because
+    /// typing `?` on most keyboards already needs it.
+    pub fn matches(&self, key: &str, ctrl: bool, alt: bool, shift: bool, meta: bool) -> bool {
+        key.eq_ignore_ascii_case(&self.key)
+            && ctrl == self.ctrl
+            && alt == self.alt
+            && meta == self.meta
+            && (shift || !self.shift)
+    }
+
+    /// Shortcuts that are only a character would stop it from being typed,
+    /// so they're ignored while the user is typing in a field.
+    pub fn fires_while_typing(&self) -> bool {
+        self.ctrl || self.alt || self.meta || self.key == "Escape"
+    }
+}
+// ANCHOR_END: shortcut
+
+// ANCHOR: use_shortcut
+/// Whether the event was fired while the focus was in a text field.
+fn is_typing(ev: &KeyboardEvent) -> bool {
+    let Some(target) = ev.target().and_then(|t| t.dyn_into::<Element>().ok()) else {
+        return false;
+    };
+    matches!(target.tag_name().as_str(), "INPUT" | "TEXTAREA" | "SELECT")
+        || target.has_attribute("contenteditable")
+}
+
+/// Calls `handler` whenever `shortcut` is pressed anywhere on the page, for
+/// as long as `enabled` is `true` and the current owner is alive.
+pub fn use_shortcut(
+    shortcut: &str,
+    enabled: impl Into<Signal<bool>>,
+    handler: impl Fn(&KeyboardEvent) + 'static,
+) {
+    let shortcut = shortcut.parse::<Shortcut>().unwrap();
+    let enabled = enabled.into();
+    let handler = Rc::new(handler);
+
+    // effects only run in the browser, so this never touches `window` on the
+    // server; and it runs again whenever `enabled` changes
+    Effect::new(move || {
+        if !enabled.get() {
+            return;
+        }
+        let (shortcut, handler) = (shortcut.clone(), Rc::clone(&handler));
+        let listener = window_event_listener(leptos::ev::keydown, move |ev| {
+            let pressed = shortcut.matches(
+                &ev.key(),
+                ev.ctrl_key(),
+                ev.alt_key(),
+                ev.shift_key(),
+                ev.meta_key(),
+            );
+            if pressed && (shortcut.fires_while_typing() || !is_typing(&ev)) {
+                // stop the browser's own shortcut, like Ctrl+K's search bar
+                ev.prevent_default();
+                handler(&ev);
+            }
+        });
+        // runs before the effect runs again, and when it's disposed of along
+        // with the component
+        on_cleanup(move || listener.remove());
+    });
+}
+// ANCHOR_END: use_shortcut
+
+// ANCHOR: app
+#[component]
+pub fn App() -> impl IntoView {
+    let search = NodeRef::<html::Input>::new();
+    let (enabled, set_enabled) = signal(true);
+    let (palette_open, set_palette_open) = signal(false);
+
+    use_shortcut("/", enabled, move |_| {
+        if let Some(search) = search.get_untracked() {
+            _ = search.focus();
+        }
+    });
+    use_shortcut("Escape", enabled, move |_| {
+        set_palette_open.set(false);
+        if let Some(search) = search.get_untracked() {
+            _ = search.blur();
+        }
+    });
+    use_shortcut("Ctrl+K", enabled, move |_| {
+        set_palette_open.update(|open| *open = !*open);
+    });
+
+    view! {
+        <label>
+            <input type="checkbox" class="enabled" bind:checked=(enabled, set_enabled)/>
+            "Keyboard shortcuts"
+        </label>
+        <input type="search" class="search" placeholder="Press / to search" node_ref=search/>
+        <Show when=move || palette_open.get()>
+            <div class="palette" role="dialog">"Command palette"</div>
+        </Show>
+    }
+}
+// ANCHOR_END: app
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Shortcut {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn shortcuts_are_parsed_into_modifiers_and_a_key() {
+        let shortcut = parse("Ctrl+Shift+K");
+        assert!(shortcut.ctrl && shortcut.shift && !shortcut.alt && !shortcut.meta);
+        assert_eq!(shortcut.key, "K");
+        assert_eq!(parse("/").key, "/");
+        assert_eq!(parse("Ctrl++").key, "+");
+        assert_eq!(parse("+").key, "+");
+        assert_eq!(
+            "Hyper+K".parse::<Shortcut>(),
+            Err(InvalidShortcut("Hyper+K".into()))
+        );
+        assert!("".parse::<Shortcut>().is_err());
+    }
+
+    #[test]
+    fn modifiers_have_to_match() {
+        let ctrl_k = parse("Ctrl+K");
+        assert!(ctrl_k.matches("k", true, false, false, false));
+        assert!(ctrl_k.matches("K", true, false, true, false));
+        assert!(!ctrl_k.matches("k", false, false, false, false));
+        assert!(!ctrl_k.matches("k", true, true, false, false));
+
+        // `?` is typed with Shift, which doesn't stop it from matching
+        assert!(parse("?").matches("?", false, false, true, false));
+        assert!(!parse("Shift+A").matches("a", false, false, false, false));
+    }
+
+    #[test]
+    fn plain_characters_wait_until_the_user_stops_typing() {
+        assert!(!parse("/").fires_while_typing());
+        assert!(parse("Escape").fires_while_typing());
+        assert!(parse("Ctrl+K").fires_while_typing());
+    }
+}
@@ -0,0 +1,12 @@
+//! Code for "Integrating with JavaScript".
+
+pub mod c30_wasm_bindgen;
+pub mod c31_web_sys;
+pub mod c32_node_ref;
+pub mod c33_directives;
+pub mod c34_events;
+pub mod c35_chart;
+pub mod c36_web_components;
+pub mod c37_existing_page;
+pub mod c38_canvas;
+pub mod c39_shortcuts;
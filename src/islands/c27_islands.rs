@@ -0,0 +1,241 @@
+//! Code for "Guide: Islands".
+//!
+//! In islands mode, `#[component]`s only run on the server, so `HomePage` can
+//! read its recipes from `src/islands/recipes` directly. Only the islands are
+//! compiled into the wasm, and hydrated by [`hydrate`].
+
+use leptos::prelude::*;
+use leptos_router::{
+    components::{Route, Router, Routes},
+    path,
+};
+use serde::{Deserialize, Serialize};
+
+// ANCHOR: hydrate
+#[cfg(feature = "hydrate")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+pub fn hydrate() {
+    // each island is its own entry point, so there's no app to mount:
+    // this just finds the islands on the page and hydrates them
+    leptos::mount::hydrate_islands();
+}
+// ANCHOR_END: hydrate
+
+// ANCHOR: shell
+pub fn shell(options: LeptosOptions) -> impl IntoView {
+    view! {
+        <!DOCTYPE html>
+        <html lang="en">
+            <head>
+                <meta charset="utf-8"/>
+                // load the scripts that hydrate islands, rather than the whole page
+                <HydrationScripts options islands=true/>
+            </head>
+            <body>
+                <App/>
+            </body>
+        </html>
+    }
+}
+// ANCHOR_END: shell
+
+// ANCHOR: counter
+#[component]
+pub fn Welcome() -> impl IntoView {
+    view! {
+        <h1>"Welcome to Leptos!"</h1>
+        <Counter/>
+    }
+}
+
+#[island]
+pub fn Counter() -> impl IntoView {
+    // Creates a reactive value to update the button
+    let (count, set_count) = signal(0);
+    let on_click = move |_| *set_count.write() += 1;
+
+    view! {
+        <button on:click=on_click>"Click Me: " {count}</button>
+    }
+}
+// ANCHOR_END: counter
+
+// ANCHOR: app
+#[component]
+pub fn App() -> impl IntoView {
+    view! {
+        <Router>
+            <main style="background-color: lightblue; padding: 10px">
+                <Routes fallback=|| "Not found.">
+                    <Route path=path!("/") view=HomePage/>
+                </Routes>
+            </main>
+        </Router>
+    }
+}
+// ANCHOR_END: app
+
+// ANCHOR: home_page
+/// Renders the home page of your application.
+#[component]
+pub fn HomePage() -> impl IntoView {
+    let files = ["a.txt", "b.txt", "c.txt"];
+    let labels = files.iter().copied().map(Into::into).collect();
+    let tabs = move || {
+        files
+            .into_iter()
+            .enumerate()
+            .map(|(index, filename)| {
+                // this only runs on the server, so it can read the server's files
+                let content =
+                    std::fs::read_to_string(format!("src/islands/recipes/{filename}")).unwrap();
+                view! {
+                    <Tab index>
+                        <div style="background-color: lightblue; padding: 10px">
+                            <h2>{filename.to_string()}</h2>
+                            <p>{content}</p>
+                        </div>
+                    </Tab>
+                }
+            })
+            .collect_view()
+    };
+
+    view! {
+        <h1>"Welcome to Leptos!"</h1>
+        <p>"Click any of the tabs below to read a recipe."</p>
+        <Tabs labels>
+            <div>{tabs()}</div>
+        </Tabs>
+    }
+}
+// ANCHOR_END: home_page
+
+// ANCHOR: tabs
+// `labels` is serialized into the page, and deserialized to hydrate the island
+#[island]
+pub fn Tabs(labels: Vec<String>, children: Children) -> impl IntoView {
+    let (selected, set_selected) = signal(0);
+    provide_context(selected);
+
+    let buttons = labels
+        .into_iter()
+        .enumerate()
+        .map(|(index, label)| {
+            view! {
+                <button on:click=move |_| set_selected.set(index)>
+                    {label}
+                </button>
+            }
+        })
+        .collect_view();
+    view! {
+        <div
+            style="display: flex; width: 100%; justify-content: space-around;\
+            background-color: lightgreen; padding: 10px;"
+        >
+            {buttons}
+        </div>
+        {children()}
+    }
+}
+// ANCHOR_END: tabs
+
+// ANCHOR: tab
+#[island]
+pub fn Tab(index: usize, children: Children) -> impl IntoView {
+    // provided by the `Tabs` island this is rendered inside of
+    let selected = expect_context::<ReadSignal<usize>>();
+    view! {
+        <div
+            style:background-color="lightgreen"
+            style:padding="10px"
+            style:display=move || if selected.get() == index { "block" } else { "none" }
+        >
+            {children()}
+        </div>
+    }
+}
+// ANCHOR_END: tab
+
+/// The colour scheme of a part of the page.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum Theme {
+    Light,
+    Dark,
+}
+
+// ANCHOR: context_pitfall
+#[component]
+pub fn DarkSection(children: Children) -> impl IntoView {
+    // ❌ components only run on the server, so this context is never
+    // provided in the browser
+    provide_context(Theme::Dark);
+    view! { <section>{children()}</section> }
+}
+
+#[island]
+pub fn ThemedButton() -> impl IntoView {
+    // `Theme::Dark` on the server, but `Theme::Light` in the browser, which
+    // doesn't match the HTML the island is hydrating
+    let theme = use_context::<Theme>().unwrap_or(Theme::Light);
+    view! { <button>{format!("{theme:?}")}</button> }
+}
+// ANCHOR_END: context_pitfall
+
+// ANCHOR: context_fixed
+#[component]
+pub fn FixedDarkSection() -> impl IntoView {
+    // ✅ pass the value to the island as a prop, which is serialized with it
+    view! {
+        <section>
+            <FixedThemedButton theme=Theme::Dark/>
+        </section>
+    }
+}
+
+#[island]
+pub fn FixedThemedButton(theme: Theme) -> impl IntoView {
+    view! { <button>{format!("{theme:?}")}</button> }
+}
+// ANCHOR_END: context_fixed
+
+// the shared context that islands are rendered with is a host dev-dependency
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use crate::test_helpers::render_page;
+
+    #[test]
+    fn islands_are_marked_for_hydration() {
+        let html = render_page(Welcome);
+        assert!(html.contains("<h1>Welcome to Leptos!</h1>"), "{html}");
+        assert!(
+            html.contains(r#"<leptos-island data-component=""#),
+            "{html}"
+        );
+    }
+
+    #[test]
+    fn props_are_serialized_and_children_are_rendered_on_the_server() {
+        let html = render_page(HomePage);
+        assert!(
+            html.contains(r#"data-props="{&quot;labels&quot;:[&quot;a.txt&quot;,&quot;b.txt&quot;,&quot;c.txt&quot;]}""#),
+            "{html}"
+        );
+        assert!(html.contains("<leptos-children>"), "{html}");
+        assert!(html.contains("Toast bread. Spread butter."), "{html}");
+    }
+
+    #[test]
+    fn context_from_components_only_exists_on_the_server() {
+        let html = render_page(|| view! { <DarkSection><ThemedButton/></DarkSection> });
+        assert!(html.contains(">Dark</button>"), "{html}");
+        // the fixed version sends the theme along with its island
+        let html = render_page(FixedDarkSection);
+        assert!(
+            html.contains(r#"data-props="{&quot;theme&quot;:&quot;Dark&quot;}""#),
+            "{html}"
+        );
+    }
+}
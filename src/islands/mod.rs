@@ -0,0 +1,7 @@
+//! Code for the islands guide.
+//!
+//! `#[island]` only means something with the `islands` feature, so this is
+//! only compiled in the islands modes.
+
+#[cfg(feature = "islands")]
+pub mod c27_islands;
@@ -0,0 +1,36 @@
+//! The example code for the Leptos book.
+//!
+//! Each chapter that includes Rust code has a module here, laid out alongside
+//! the chapter's markdown. Snippets are pulled into the book with mdbook's
+//! `{{#include path/to/file.rs:anchor}}` directive, so everything the book
+//! shows is compiled (and, where possible, tested) on every build.
+//!
+//! The crate is compiled in each rendering mode (the `csr`, `ssr`, `hydrate`,
+//! and `islands` features) by `cargo run -p feature-matrix`. Modules that
+//! only make sense in some modes are gated on the corresponding features, so
+//! that server-only code is checked in the modes readers will use it in.
+//! `ssr` on its own doesn't pick a server: the examples that use one are
+//! gated on the `axum` or `actix` feature, each of which also enables `ssr`.
+//!
+//! Where the book shows a snippet both ways, a nightly variant sits next to
+//! the stable one, gated on the `nightly` feature, with the same anchor name
+//! plus a `_nightly` suffix (for example, `counter` and `counter_nightly` in
+//! [`view::c01_basic_component`]).
+
+pub mod accessibility;
+pub mod r#async;
+pub mod deployment;
+pub mod i18n;
+pub mod interlude;
+pub mod interop;
+pub mod islands;
+pub mod progressive_enhancement;
+pub mod reactivity;
+pub mod router;
+pub mod server;
+pub mod ssr;
+pub mod testing;
+pub mod view;
+
+#[cfg(test)]
+mod test_helpers;
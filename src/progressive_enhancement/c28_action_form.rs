@@ -0,0 +1,205 @@
+//! Code for "`<ActionForm/>`s".
+//!
+//! Like the examples in "Server Functions", the todos here are kept in memory
+//! on the server. The whole app works as a plain HTML form, and is enhanced
+//! once its wasm loads: `cargo xtask e2e` builds the crate with `hydrate` and
+//! checks both ways of submitting the form in a browser.
+
+use leptos::prelude::*;
+use leptos_router::{
+    components::{Route, Router, Routes},
+    path, SsrMode,
+};
+use serde::{Deserialize, Serialize};
+
+// this is only compiled into the server, along with the bodies of the
+// server functions that use it (the tests compile both, with `ssr`)
+#[cfg(feature = "ssr")]
+mod db {
+    use std::sync::{Mutex, MutexGuard};
+
+    static TODOS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    pub fn todos() -> MutexGuard<'static, Vec<String>> {
+        TODOS.lock().unwrap()
+    }
+}
+
+// ANCHOR: add_todo
+#[server]
+pub async fn add_todo(title: String) -> Result<(), ServerFnError> {
+    if title.trim().is_empty() {
+        return Err(ServerFnError::new("A todo needs a title."));
+    }
+    db::todos().push(title);
+    Ok(())
+}
+// ANCHOR_END: add_todo
+
+#[server]
+pub async fn get_todos() -> Result<Vec<String>, ServerFnError> {
+    Ok(db::todos().clone())
+}
+
+// ANCHOR: action_form
+#[component]
+pub fn NewTodo() -> impl IntoView {
+    let add_todo = ServerAction::<AddTodo>::new();
+    // holds the latest *returned* value from the server
+    let value = add_todo.value();
+    // the error, if the server returned one
+    let error = move || value.get().and_then(Result::err);
+
+    view! {
+        <ActionForm action=add_todo>
+            <label>
+                "Add a Todo"
+                // `title` matches the `title` argument to `add_todo`
+                <input type="text" name="title"/>
+            </label>
+            <input type="submit" value="Add"/>
+        </ActionForm>
+        {move || error().map(|err| view! { <p class="error">{err.to_string()}</p> })}
+        <TodoList add_todo/>
+    }
+}
+// ANCHOR_END: action_form
+
+// ANCHOR: optimistic
+#[component]
+pub fn TodoList(add_todo: ServerAction<AddTodo>) -> impl IntoView {
+    // load the todos again each time one is added
+    let todos = Resource::new(move || add_todo.version().get(), |_| get_todos());
+    // the todo that's on its way to the server, if there is one
+    let pending = move || {
+        add_todo
+            .input()
+            .get()
+            .map(|AddTodo { title }| view! { <li class="pending">{title}</li> })
+    };
+
+    view! {
+        <ul>
+            <Transition fallback=|| view! { <li>"Loading..."</li> }>
+                {move || Suspend::new(async move {
+                    todos
+                        .await
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(|title| view! { <li>{title}</li> })
+                        .collect_view()
+                })}
+                {pending}
+            </Transition>
+        </ul>
+    }
+}
+// ANCHOR_END: optimistic
+
+// ANCHOR: validation
+#[component]
+pub fn ValidatedTodo() -> impl IntoView {
+    let add_todo = ServerAction::<AddTodo>::new();
+
+    let on_submit = move |ev: leptos::ev::SubmitEvent| {
+        let data = AddTodo::from_event(&ev);
+        // silly example of validation: if the todo is "nope!", nope it
+        if data.is_err() || data.unwrap().title == "nope!" {
+            // ev.prevent_default() will prevent form submission
+            ev.prevent_default();
+        }
+    };
+
+    view! {
+        <ActionForm action=add_todo on:submit=on_submit>
+            <input type="text" name="title"/>
+            <input type="submit" value="Add"/>
+        </ActionForm>
+    }
+}
+// ANCHOR_END: validation
+
+// ANCHOR: complex_input
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct HeftyData {
+    first_name: String,
+    last_name: String,
+}
+
+#[component]
+pub fn ComplexInput() -> impl IntoView {
+    let submit = ServerAction::<VeryImportantFn>::new();
+
+    view! {
+        <ActionForm action=submit>
+            <input type="text" name="hefty_arg[first_name]" value="leptos"/>
+            <input type="text" name="hefty_arg[last_name]" value="closures-everywhere"/>
+            <input type="submit"/>
+        </ActionForm>
+    }
+}
+
+#[server]
+pub async fn very_important_fn(hefty_arg: HeftyData) -> Result<String, ServerFnError> {
+    Ok(format!("{} {}", hefty_arg.first_name, hefty_arg.last_name))
+}
+// ANCHOR_END: complex_input
+
+/// The todo list, at `/`.
+#[component]
+pub fn App() -> impl IntoView {
+    // effects only run in the browser, so this marks the page once it's
+    // hydrated, for the end-to-end tests to wait for
+    let hydrated = RwSignal::new(false);
+    Effect::new(move || hydrated.set(true));
+
+    view! {
+        <Router>
+            <Routes fallback=|| "Not found.">
+                // wait for the list before sending the page, so that it's
+                // there without JS as well
+                <Route path=path!("/") view=NewTodo ssr=SsrMode::InOrder/>
+            </Routes>
+        </Router>
+        <footer data-hydrated=move || hydrated.get().then_some("")></footer>
+    }
+}
+
+/// The HTML document that [`App`] is rendered into.
+pub fn shell(options: LeptosOptions) -> impl IntoView {
+    view! {
+        <!DOCTYPE html>
+        <html lang="en">
+            <head>
+                <meta charset="utf-8"/>
+                <HydrationScripts options/>
+            </head>
+            <body>
+                <App/>
+            </body>
+        </html>
+    }
+}
+
+/// Serves [`App`], and its server functions, with Axum.
+#[cfg(feature = "axum")]
+pub fn router(options: LeptosOptions) -> axum::Router {
+    use leptos_axum::{generate_route_list, LeptosRoutes};
+
+    let routes = generate_route_list(App);
+    axum::Router::new()
+        .leptos_routes(&options, routes, {
+            let options = options.clone();
+            move || shell(options.clone())
+        })
+        .with_state(options)
+}
+
+/// Hydrates the server-rendered [`App`]: the entry point that the scripts
+/// from [`shell`] call once the wasm has loaded.
+// in islands mode, `islands::c27_islands::hydrate` is the entry point instead
+#[cfg(all(feature = "hydrate", not(feature = "islands")))]
+#[wasm_bindgen::prelude::wasm_bindgen]
+pub fn hydrate() {
+    leptos::mount::hydrate_body(App);
+}
@@ -0,0 +1 @@
+pub mod c28_action_form;
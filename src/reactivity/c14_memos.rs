@@ -0,0 +1,149 @@
+//! Code for "Memos and Derived Signals".
+//!
+//! The snippets count how often each computation runs in a [`StoredValue`],
+//! which isn't reactive, so counting doesn't change what's being counted.
+
+use leptos::prelude::*;
+
+/// Reads a derived signal twice after one change, returning how often it ran.
+pub fn derived_closure() -> usize {
+    // ANCHOR: derived_closure
+    let (count, set_count) = signal(1);
+    let runs = StoredValue::new(0);
+
+    // a derived signal is just a closure: it runs every time it's called
+    let double_count = move || {
+        runs.update_value(|runs| *runs += 1);
+        count.get() * 2
+    };
+
+    set_count.set(2);
+    assert_eq!(double_count(), 4);
+    assert_eq!(double_count(), 4);
+    // ANCHOR_END: derived_closure
+    runs.get_value()
+}
+
+/// Reads a memo twice after one change, returning how often it ran.
+pub fn memo() -> usize {
+    // ANCHOR: memo
+    let (count, set_count) = signal(1);
+    let runs = StoredValue::new(0);
+
+    // a memo runs once per change, and caches its value for every reader
+    let double_count = Memo::new(move |_| {
+        runs.update_value(|runs| *runs += 1);
+        count.get() * 2
+    });
+
+    set_count.set(2);
+    assert_eq!(double_count.get(), 4);
+    assert_eq!(double_count.get(), 4);
+    // ANCHOR_END: memo
+    runs.get_value()
+}
+
+/// Changes a signal without changing a memo that depends on it, returning
+/// how often the memo and the computation reading the memo ran.
+pub fn memo_only_notifies_on_change() -> (usize, usize) {
+    // ANCHOR: memo_equality
+    let (name, set_name) = signal("Bob".to_string());
+    let len_runs = StoredValue::new(0);
+    let text_runs = StoredValue::new(0);
+
+    let name_len = Memo::new(move |_| {
+        len_runs.update_value(|runs| *runs += 1);
+        name.read().len()
+    });
+    let len_text = Memo::new(move |_| {
+        text_runs.update_value(|runs| *runs += 1);
+        format!("{} letters", name_len.get())
+    });
+    assert_eq!(len_text.get(), "3 letters");
+
+    // `name_len` has to re-run to find out its new value...
+    set_name.set("Tim".to_string());
+    // ...but it's still 3, so `len_text` doesn't run again
+    assert_eq!(len_text.get(), "3 letters");
+    // ANCHOR_END: memo_equality
+    (len_runs.get_value(), text_runs.get_value())
+}
+
+/// Uses the previous value of a memo to compute the next one.
+pub fn memo_previous_value() -> i32 {
+    // ANCHOR: memo_previous
+    let (value, set_value) = signal(3);
+
+    // the memo's previous value, if any, is passed as an argument
+    let highest = Memo::new(move |prev: Option<&i32>| {
+        let value = value.get();
+        prev.map_or(value, |prev| value.max(*prev))
+    });
+
+    assert_eq!(highest.get(), 3);
+    set_value.set(5);
+    assert_eq!(highest.get(), 5);
+    set_value.set(1);
+    assert_eq!(highest.get(), 5);
+    // ANCHOR_END: memo_previous
+    highest.get()
+}
+
+// ANCHOR: signal_prop
+/// Shows the double of any reactive `i32`, however it was created.
+#[component]
+pub fn DoubleOf(#[prop(into)] value: Signal<i32>) -> impl IntoView {
+    view! { <p>{move || value.get() * 2}</p> }
+}
+// ANCHOR_END: signal_prop
+
+// ANCHOR: app
+#[component]
+pub fn App() -> impl IntoView {
+    let (count, set_count) = signal(1);
+    let plus_one = Memo::new(move |_| count.get() + 1);
+
+    view! {
+        <button on:click=move |_| *set_count.write() += 1>"+1"</button>
+        // ANCHOR: signal_derive
+        // a `ReadSignal` and a `Memo` both convert into a `Signal`...
+        <DoubleOf value=count/>
+        <DoubleOf value=plus_one/>
+        // ...and `Signal::derive` turns a closure into one
+        <DoubleOf value=Signal::derive(move || count.get() * 10)/>
+        // ANCHOR_END: signal_derive
+    }
+}
+
+pub fn main() {
+    leptos::mount::mount_to_body(App)
+}
+// ANCHOR_END: app
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::render;
+
+    fn run<T>(f: impl FnOnce() -> T) -> T {
+        Owner::new().with(f)
+    }
+
+    #[test]
+    fn derived_signals_run_per_read_and_memos_per_change() {
+        assert_eq!(run(derived_closure), 2);
+        assert_eq!(run(memo), 1);
+    }
+
+    #[test]
+    fn memos_only_notify_when_their_value_changes() {
+        assert_eq!(run(memo_only_notifies_on_change), (2, 1));
+        assert_eq!(run(memo_previous_value), 5);
+    }
+
+    #[test]
+    fn every_kind_of_signal_can_be_a_signal_prop() {
+        let html = render(App);
+        assert!(html.contains("<p>2</p><p>4</p><p>20</p>"), "{html}");
+    }
+}
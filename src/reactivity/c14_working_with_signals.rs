@@ -0,0 +1,234 @@
+//! Code for "Working with Signals".
+//!
+//! The snippets are plain functions rather than components: each one runs
+//! some signal operations and returns what it read, so the tests can check
+//! that the chapter describes what the signals actually do.
+
+use leptos::prelude::*;
+
+/// Sets a signal and reads it back with the method syntax.
+#[cfg(not(feature = "nightly"))]
+pub fn get_set() -> i32 {
+    // ANCHOR: get_set
+    let (count, set_count) = signal(0);
+    set_count.set(1);
+    leptos::logging::log!("{}", count.get());
+    // ANCHOR_END: get_set
+    count.get()
+}
+
+/// Sets a signal and reads it back with the function-call syntax.
+#[cfg(feature = "nightly")]
+pub fn get_set() -> i32 {
+    // ANCHOR: get_set_nightly
+    let (count, set_count) = signal(0);
+    set_count(1);
+    leptos::logging::log!("{}", count());
+    // ANCHOR_END: get_set_nightly
+    count.get()
+}
+
+/// Clones the whole `Vec` to check whether it’s empty.
+pub fn names_clone() -> Vec<String> {
+    // ANCHOR: names_clone
+    let (names, set_names) = signal(Vec::new());
+    if names.get().is_empty() {
+        set_names.set(vec!["Alice".to_string()]);
+    }
+    // ANCHOR_END: names_clone
+    names.get()
+}
+
+/// Checks and mutates the `Vec` in place.
+pub fn names_with() -> Vec<String> {
+    // ANCHOR: names_with
+    let (names, set_names) = signal(Vec::new());
+    if names.with(|names| names.is_empty()) {
+        set_names.update(|names| names.push("Alice".to_string()));
+    }
+    // ANCHOR_END: names_with
+    names.get()
+}
+
+/// Passes `Vec::is_empty` to `.with()` directly.
+pub fn with_method() -> bool {
+    let (names, _set_names) = signal(Vec::<String>::new());
+    // ANCHOR: with_method
+    if names.with(Vec::is_empty) {
+        // ...
+    }
+    // ANCHOR_END: with_method
+    names.with(Vec::is_empty)
+}
+
+/// Reads and writes through guards, rather than closures.
+pub fn read_write_guards() -> (usize, Vec<String>) {
+    // ANCHOR: read_write_guards
+    let (names, set_names) = signal(vec!["Alice".to_string()]);
+
+    // `.read()` returns a guard that derefs to `&Vec<String>`
+    let count = names.read().len();
+
+    // `.write()` returns a guard that derefs to `&mut Vec<String>`,
+    // and notifies subscribers when it is dropped
+    set_names.write().push("Bob".to_string());
+    // ANCHOR_END: read_write_guards
+    (count, names.get())
+}
+
+/// Concatenates three signals without cloning any of them.
+pub fn three_signals() -> (String, String) {
+    // ANCHOR: three_signals
+    let (first, _) = signal("Bob".to_string());
+    let (middle, _) = signal("J.".to_string());
+    let (last, _) = signal("Smith".to_string());
+    // ANCHOR_END: three_signals
+
+    // ANCHOR: nested_with
+    let name = move || {
+        first.with(|first| {
+            middle.with(|middle| last.with(|last| format!("{first} {middle} {last}")))
+        })
+    };
+    // ANCHOR_END: nested_with
+
+    let with_guards = {
+        // ANCHOR: read_guards
+        let name = move || format!("{} {} {}", first.read(), middle.read(), last.read());
+        // ANCHOR_END: read_guards
+        name
+    };
+
+    (name(), with_guards())
+}
+
+/// Holds every setting in one signal, so reading one field tracks them all.
+pub fn coarse_signal() -> (String, usize) {
+    // ANCHOR: coarse_signal
+    #[derive(Clone, Default)]
+    struct Settings {
+        name: String,
+        dark_mode: bool,
+    }
+
+    let settings = RwSignal::new(Settings::default());
+    let greeting = Memo::new(move |_| format!("Hello, {}!", settings.read().name));
+    // ANCHOR_END: coarse_signal
+
+    let runs = RwSignal::new(0);
+    let counted = Memo::new(move |_| {
+        *runs.write() += 1;
+        settings.read().name.len()
+    });
+    counted.get();
+
+    // ANCHOR: coarse_update
+    // `greeting` doesn't use `dark_mode`, but it has to re-run anyway
+    settings.update(|settings| settings.dark_mode = true);
+    // ANCHOR_END: coarse_update
+    counted.get();
+
+    (greeting.get(), runs.get_untracked())
+}
+
+/// Gives each setting its own signal, so readers only track what they use.
+pub fn granular_signals() -> (String, usize) {
+    // ANCHOR: granular_signals
+    let name = RwSignal::new(String::new());
+    let dark_mode = RwSignal::new(false);
+    let greeting = Memo::new(move |_| format!("Hello, {}!", name.read()));
+
+    // this only notifies the things that read `dark_mode`
+    dark_mode.set(true);
+    // ANCHOR_END: granular_signals
+
+    let runs = RwSignal::new(0);
+    let counted = Memo::new(move |_| {
+        *runs.write() += 1;
+        name.read().len()
+    });
+    counted.get();
+    dark_mode.set(false);
+    counted.get();
+
+    (greeting.get(), runs.get_untracked())
+}
+
+/// Derives one value from a signal, in both ways the chapter describes.
+pub fn derived() -> (i32, i32) {
+    // ANCHOR: derived
+    let (count, set_count) = signal(1);
+    let derived_signal_double_count = move || count.get() * 2;
+    let memoized_double_count = Memo::new(move |_| count.get() * 2);
+    // ANCHOR_END: derived
+    set_count.set(2);
+    (derived_signal_double_count(), memoized_double_count.get())
+}
+
+/// Derives one value from two signals.
+pub fn full_name() -> String {
+    // ANCHOR: full_name
+    let (first_name, set_first_name) = signal("Bridget".to_string());
+    let (last_name, set_last_name) = signal("Jones".to_string());
+    let full_name = move || format!("{} {}", first_name.read(), last_name.read());
+    // ANCHOR_END: full_name
+    set_first_name.set("Mark".to_string());
+    set_last_name.set("Darcy".to_string());
+    full_name()
+}
+
+/// Updates two independent signals from one event handler.
+pub fn clear_handler() -> (i32, i32) {
+    // ANCHOR: clear_handler
+    let (age, set_age) = signal(32);
+    let (favorite_number, set_favorite_number) = signal(42);
+    // use this to handle a click on a `Clear` button
+    let clear_handler = move |_| {
+        set_age.set(0);
+        set_favorite_number.set(0);
+    };
+    // ANCHOR_END: clear_handler
+    clear_handler(());
+    (age.get(), favorite_number.get())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run<T>(f: impl FnOnce() -> T) -> T {
+        Owner::new().with(f)
+    }
+
+    #[test]
+    fn getting_and_setting() {
+        assert_eq!(run(get_set), 1);
+        assert_eq!(run(names_clone), ["Alice"]);
+        assert_eq!(run(names_with), ["Alice"]);
+        assert!(run(with_method));
+        assert_eq!(
+            run(read_write_guards),
+            (1, vec!["Alice".into(), "Bob".into()])
+        );
+    }
+
+    #[test]
+    fn nested_with_and_read_guards_agree() {
+        let (nested, guards) = run(three_signals);
+        assert_eq!(nested, "Bob J. Smith");
+        assert_eq!(guards, nested);
+    }
+
+    #[test]
+    fn coarse_signals_rerun_unrelated_readers() {
+        assert_eq!(run(coarse_signal), ("Hello, !".to_string(), 2));
+        assert_eq!(run(granular_signals), ("Hello, !".to_string(), 1));
+    }
+
+    #[test]
+    fn signals_depending_on_each_other() {
+        assert_eq!(run(derived), (4, 4));
+        assert_eq!(run(full_name), "Mark Darcy");
+        assert_eq!(run(clear_handler), (0, 0));
+    }
+}
@@ -0,0 +1,279 @@
+//! Code for "Responding to Changes with Effects".
+//!
+//! The effects write to a visible log, provided by [`Log`], rather than to
+//! the console, so that it's possible to see (and test) when they run.
+
+use leptos::{html::Input, prelude::*};
+
+// ANCHOR: app
+#[derive(Copy, Clone)]
+struct LogContext(RwSignal<Vec<String>>);
+
+/// Provides a log to its children, and shows everything they add to it.
+#[component]
+pub fn Log(children: Children) -> impl IntoView {
+    // Just making a visible log here
+    // You can ignore this...
+    let log = RwSignal::<Vec<String>>::new(vec![]);
+    let logged = move || log.read().join("\n");
+
+    // the newtype pattern isn't *necessary* here but is a good practice
+    // it avoids confusion with other possible future `RwSignal<Vec<String>>` contexts
+    // and makes it easier to refer to it
+    provide_context(LogContext(log));
+
+    view! {
+        {children()}
+        <pre>{logged}</pre>
+    }
+}
+
+pub(crate) fn log(msg: impl std::fmt::Display) {
+    let log = use_context::<LogContext>().unwrap().0;
+    log.write().push(msg.to_string());
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    view! {
+        <Log>
+            <CreateAnEffect/>
+        </Log>
+        <Log>
+            <ManualVersion/>
+        </Log>
+    }
+}
+
+#[component]
+pub fn CreateAnEffect() -> impl IntoView {
+    // ANCHOR: zero_cost
+    let (first, set_first) = signal(String::new());
+    let (last, set_last) = signal(String::new());
+    let (use_last, set_use_last) = signal(true);
+
+    // this will add the name to the log
+    // any time one of the source signals changes
+    Effect::new(move |_| {
+        log(if use_last.get() {
+            format!("{} {}", first.read(), last.read())
+        } else {
+            first.get()
+        })
+    });
+    // ANCHOR_END: zero_cost
+
+    view! {
+        <h1>
+            <code>"Effect::new"</code>
+            " Version"
+        </h1>
+        <form>
+            <label>
+                "First Name"
+                <input
+                    type="text"
+                    name="first"
+                    prop:value=first
+                    on:change:target=move |ev| set_first.set(ev.target().value())
+                />
+            </label>
+            <label>
+                "Last Name"
+                <input
+                    type="text"
+                    name="last"
+                    prop:value=last
+                    on:change:target=move |ev| set_last.set(ev.target().value())
+                />
+            </label>
+            <label>
+                "Show Last Name"
+                <input
+                    type="checkbox"
+                    name="use_last"
+                    prop:checked=use_last
+                    on:change:target=move |ev| set_use_last.set(ev.target().checked())
+                />
+            </label>
+        </form>
+    }
+}
+
+#[component]
+pub fn ManualVersion() -> impl IntoView {
+    let first = NodeRef::<Input>::new();
+    let last = NodeRef::<Input>::new();
+    let use_last = NodeRef::<Input>::new();
+
+    let mut prev_name = String::new();
+    let on_change = move |_| {
+        log("      listener");
+        let first = first.get().unwrap();
+        let last = last.get().unwrap();
+        let use_last = use_last.get().unwrap();
+        let this_one = if use_last.checked() {
+            format!("{} {}", first.value(), last.value())
+        } else {
+            first.value()
+        };
+
+        if this_one != prev_name {
+            log(&this_one);
+            prev_name = this_one;
+        }
+    };
+
+    view! {
+        <h1>"Manual Version"</h1>
+        <form on:change=on_change>
+            <label>"First Name" <input type="text" name="first" node_ref=first/></label>
+            <label>"Last Name" <input type="text" name="last" node_ref=last/></label>
+            <label>
+                "Show Last Name" <input type="checkbox" name="use_last" checked node_ref=use_last/>
+            </label>
+        </form>
+    }
+}
+
+pub fn main() {
+    leptos::mount::mount_to_body(App)
+}
+// ANCHOR_END: app
+
+#[component]
+pub fn EffectNew() -> impl IntoView {
+    // ANCHOR: effect_new
+    let (a, set_a) = signal(0);
+    let (b, set_b) = signal(0);
+
+    Effect::new(move |_| {
+        // logs "Value: 0" on the next tick, and subscribes to `a`
+        log(format!("Value: {}", a.get()));
+    });
+    // ANCHOR_END: effect_new
+
+    view! {
+        <button class="a" on:click=move |_| *set_a.write() += 1>"a: " {a}</button>
+        <button class="b" on:click=move |_| *set_b.write() += 1>"b: " {b}</button>
+    }
+}
+
+#[component]
+pub fn EffectWritesSignal() -> impl IntoView {
+    // ANCHOR: effect_writes_signal
+    let (a, set_a) = signal(1);
+
+    // ⚠️ not great
+    let (b, set_b) = signal(0);
+    Effect::new(move |_| {
+        set_b.set(a.get() * 2);
+    });
+    // ANCHOR_END: effect_writes_signal
+
+    view! {
+        <button on:click=move |_| *set_a.write() += 1>"+1"</button>
+        <p>{b}</p>
+    }
+}
+
+#[component]
+pub fn DerivedInstead() -> impl IntoView {
+    // ANCHOR: derived_instead
+    let (a, set_a) = signal(1);
+
+    // ✅ woo-hoo!
+    let b = move || a.get() * 2;
+    // ANCHOR_END: derived_instead
+
+    view! {
+        <button on:click=move |_| *set_a.write() += 1>"+1"</button>
+        <p>{b}</p>
+    }
+}
+
+#[component]
+pub fn Rendering(count: ReadSignal<i32>) -> impl IntoView {
+    // ANCHOR: rendering
+    view! {
+        <p>{count}</p>
+    }
+    // ANCHOR_END: rendering
+}
+
+/// Creates a `<p>` that shows `count`, updated by an effect by hand.
+pub fn render_by_hand() -> (leptos::web_sys::Element, WriteSignal<i32>) {
+    // ANCHOR: rendering_by_hand
+    let (count, set_count) = signal(0);
+
+    // create a DOM element
+    let p = document().create_element("p").unwrap();
+
+    // create an effect to reactively update the text
+    Effect::new({
+        let p = p.clone();
+        move |prev_value: Option<String>| {
+            // first, access the signal’s value and convert it to a string
+            let text = count.get().to_string();
+
+            // if this is different from the previous value, update the node
+            if prev_value.as_ref() != Some(&text) {
+                p.set_text_content(Some(&text));
+            }
+
+            // return this value so we can memoize the next update
+            text
+        }
+    });
+    // ANCHOR_END: rendering_by_hand
+    (p, set_count)
+}
+
+#[component]
+pub fn Watch() -> impl IntoView {
+    // ANCHOR: watch
+    let (num, set_num) = signal(0);
+
+    let effect = Effect::watch(
+        move || num.get(),
+        move |num, prev_num, _| {
+            log(format!("Number: {num}; Prev: {prev_num:?}"));
+        },
+        false,
+    );
+    // ANCHOR_END: watch
+
+    view! {
+        // ANCHOR: watch_view
+        // > "Number: 1; Prev: Some(0)" (and so on)
+        <button class="increment" on:click=move |_| *set_num.write() += 1>
+            "+1"
+        </button>
+        // after this, clicking +1 stops logging
+        <button class="stop" on:click=move |_| effect.stop()>
+            "Stop watching"
+        </button>
+        // ANCHOR_END: watch_view
+    }
+}
+
+#[component]
+pub fn Cleanup() -> impl IntoView {
+    // ANCHOR: cleanup
+    let (room, set_room) = signal("general".to_string());
+
+    Effect::new(move |_| {
+        let room = room.get();
+        log(format!("joined #{room}"));
+
+        // runs before the effect runs again, and when it's disposed of
+        on_cleanup(move || log(format!("left #{room}")));
+    });
+    // ANCHOR_END: cleanup
+
+    view! {
+        <button on:click=move |_| set_room.set("random".to_string())>
+            "Switch to #random"
+        </button>
+    }
+}
@@ -0,0 +1,120 @@
+//! Code for "Untracked Reads and Batched Updates".
+//!
+//! Most of the examples are tests, so that the chapter can show exactly how
+//! many times each effect runs. They use `ImmediateEffect::new_isomorphic`,
+//! which runs synchronously even on the server, so the count can be checked
+//! right after each write. The `<Log/>`ged effect is tested by the DOM tests
+//! in `tests/dom/effects.rs`.
+
+use super::c15_effects::log;
+use leptos::prelude::*;
+
+// ANCHOR: effects_are_batched
+#[component]
+pub fn Rename() -> impl IntoView {
+    let (first, set_first) = signal("Ferris".to_string());
+    let (last, set_last) = signal("Crab".to_string());
+
+    Effect::new(move || log(format!("{} {}", first.read(), last.read())));
+
+    view! {
+        <button on:click=move |_| {
+            // effects are scheduled to run after the current task, so by the
+            // time this one does, both names have changed: it only runs once
+            set_first.set("Gopher".to_string());
+            set_last.set("Go".to_string());
+        }>"Rename"</button>
+    }
+}
+// ANCHOR_END: effects_are_batched
+
+#[cfg(test)]
+mod tests {
+    use leptos::prelude::*;
+
+    #[test]
+    fn untracked_reads_do_not_subscribe() {
+        let owner = Owner::new();
+        owner.set();
+
+        // ANCHOR: untrack
+        let (name, set_name) = signal("Ferris".to_string());
+        let (greeting, set_greeting) = signal("Hello");
+        let runs = StoredValue::new(0);
+        let line = StoredValue::new(String::new());
+
+        let _effect = ImmediateEffect::new_isomorphic(move || {
+            // `name` is tracked, but `greeting` is only read: the effect
+            // doesn't subscribe to it
+            let greeting = untrack(move || greeting.get());
+            line.set_value(format!("{greeting}, {}!", name.read()));
+            runs.update_value(|runs| *runs += 1);
+        });
+        assert_eq!(runs.get_value(), 1);
+        assert_eq!(line.get_value(), "Hello, Ferris!");
+
+        // changing `greeting` doesn't rerun the effect...
+        set_greeting.set("Hi");
+        assert_eq!(runs.get_value(), 1);
+        assert_eq!(line.get_value(), "Hello, Ferris!");
+
+        // ...but changing `name` does, and it reads the new greeting
+        set_name.set("Gopher".to_string());
+        assert_eq!(runs.get_value(), 2);
+        assert_eq!(line.get_value(), "Hi, Gopher!");
+        // ANCHOR_END: untrack
+    }
+
+    #[test]
+    fn get_untracked_is_the_same_as_untrack() {
+        let owner = Owner::new();
+        owner.set();
+
+        // ANCHOR: get_untracked
+        let (count, set_count) = signal(0);
+        let runs = StoredValue::new(0);
+
+        let _effect = ImmediateEffect::new_isomorphic(move || {
+            // shorthand for `untrack(move || count.get())`
+            _ = count.get_untracked();
+            runs.update_value(|runs| *runs += 1);
+        });
+
+        set_count.set(1);
+        assert_eq!(runs.get_value(), 1);
+        // ANCHOR_END: get_untracked
+    }
+
+    #[test]
+    fn batched_writes_run_the_effect_once() {
+        let owner = Owner::new();
+        owner.set();
+
+        // ANCHOR: batch
+        let (count, set_count) = signal(0);
+        let runs = StoredValue::new(0);
+
+        let _effect = ImmediateEffect::new_isomorphic(move || {
+            count.track();
+            runs.update_value(|runs| *runs += 1);
+        });
+        assert_eq!(runs.get_value(), 1);
+
+        // an immediate effect runs as soon as a signal it tracks changes, so
+        // three writes run it three times...
+        *set_count.write() += 1;
+        *set_count.write() += 1;
+        *set_count.write() += 1;
+        assert_eq!(runs.get_value(), 4);
+
+        // ...unless they're batched, which holds it back until the end
+        batch(|| {
+            *set_count.write() += 1;
+            *set_count.write() += 1;
+            *set_count.write() += 1;
+        });
+        assert_eq!(runs.get_value(), 5);
+        assert_eq!(count.get_untracked(), 6);
+        // ANCHOR_END: batch
+    }
+}
@@ -0,0 +1,120 @@
+//! Code for the cleanup examples in "Interlude: Signal Ownership and `Send`".
+//!
+//! The tests show the order cleanups run in with [`Owner`]s of their own.
+//! Removing rows from a rendered view only happens in the browser, so
+//! [`Room`] is tested by the DOM tests in `tests/dom/ownership.rs`.
+
+use leptos::prelude::*;
+
+// ANCHOR: on_cleanup
+#[component]
+pub fn Member(name: String, left: WriteSignal<Vec<String>>) -> impl IntoView {
+    // registered with this component's owner, so it runs when the component
+    // is removed from the view
+    on_cleanup({
+        let name = name.clone();
+        move || left.write().push(name)
+    });
+
+    view! { <li>{name}</li> }
+}
+// ANCHOR_END: on_cleanup
+
+// ANCHOR: removed
+#[component]
+pub fn Room() -> impl IntoView {
+    let (members, set_members) = signal(vec!["Alice".to_string(), "Bob".to_string()]);
+    let (guest, set_guest) = signal(true);
+    let (left, set_left) = signal(Vec::<String>::new());
+
+    view! {
+        <button class="remove" on:click=move |_| set_members.update(|members| {
+            members.remove(0);
+        })>
+            "Remove the first member"
+        </button>
+        <button class="hide" on:click=move |_| set_guest.set(false)>"Hide the guest"</button>
+        <ul>
+            // each row of a `<For/>` has an owner, which is cleaned up when
+            // the row is removed...
+            <For each=move || members.get() key=|name| name.clone() let:name>
+                <Member name left=set_left/>
+            </For>
+            // ...and so does each branch of a `<Show/>`, when it's hidden
+            <Show when=move || guest.get()>
+                <Member name="Guest".to_string() left=set_left/>
+            </Show>
+        </ul>
+        <p class="left">{move || left.read().join(", ")}</p>
+    }
+}
+// ANCHOR_END: removed
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helpers::render;
+    use leptos::prelude::*;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn server_rendered_rows_are_cleaned_up_once_rendered() {
+        let html = render(super::Room);
+        assert!(
+            html.contains("<li>Alice</li><li>Bob</li><li>Guest</li>"),
+            "{html}"
+        );
+        // on the server, the rows of a `<For/>` aren't kept around once
+        // they've been turned into HTML, so their cleanups have already run
+        assert!(html.contains(r#"<p class="left">Alice, Bob</p>"#), "{html}");
+    }
+
+    #[test]
+    fn cleanups_run_children_first_and_only_once() {
+        // ANCHOR: order
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let logger = |msg: &'static str| {
+            let log = Arc::clone(&log);
+            move || log.lock().unwrap().push(msg)
+        };
+
+        let parent = Owner::new();
+        let _child = parent.with(|| {
+            // `on_cleanup` registers a function with the current owner...
+            on_cleanup(logger("parent"));
+
+            // ...which is the child here
+            let child = Owner::new();
+            child.with(|| on_cleanup(logger("child")));
+            child
+        });
+
+        // an owner's children are cleaned up before the owner's own cleanups run
+        parent.cleanup();
+        assert_eq!(*log.lock().unwrap(), ["child", "parent"]);
+
+        // each function only runs once, so cleaning up again does nothing...
+        parent.cleanup();
+        assert_eq!(*log.lock().unwrap(), ["child", "parent"]);
+
+        // ...but the owner can be reused, and cleaned up again
+        parent.with(|| on_cleanup(logger("parent again")));
+        drop(parent);
+        assert_eq!(*log.lock().unwrap(), ["child", "parent", "parent again"]);
+        // ANCHOR_END: order
+    }
+
+    #[test]
+    fn cleanup_needs_an_owner() {
+        // ANCHOR: no_owner
+        let ran = Arc::new(Mutex::new(false));
+        // with no owner, there's nothing to register the function with, and
+        // it never runs
+        assert!(Owner::current().is_none());
+        Owner::on_cleanup({
+            let ran = Arc::clone(&ran);
+            move || *ran.lock().unwrap() = true
+        });
+        assert!(!*ran.lock().unwrap());
+        // ANCHOR_END: no_owner
+    }
+}
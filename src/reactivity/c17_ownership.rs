@@ -0,0 +1,122 @@
+//! Code for "Interlude: Signal Ownership and `Send`".
+//!
+//! Most of the examples are tests, which create and drop their own
+//! [`Owner`]s to show how long each kind of signal lives.
+
+use leptos::prelude::*;
+
+// ANCHOR: stored_value
+#[component]
+pub fn Planets() -> impl IntoView {
+    // a `StoredValue` isn't reactive: it's just a place to keep a value that is
+    // `Copy`, like a signal, however big the value is
+    let planets = StoredValue::new(vec!["Mercury", "Venus", "Earth", "Mars"]);
+    let (index, set_index) = signal(0);
+
+    view! {
+        <button on:click=move |_| {
+            set_index.update(|index| *index = (*index + 1) % planets.with_value(Vec::len));
+        }>"Next"</button>
+        // no need to clone the `Vec` for each closure that uses it
+        <p>{move || planets.with_value(|planets| planets[index.get()])}</p>
+    }
+}
+// ANCHOR_END: stored_value
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helpers::render;
+    use leptos::prelude::*;
+    use std::{rc::Rc, thread};
+
+    #[test]
+    fn stored_values_render_like_any_other() {
+        let html = render(super::Planets);
+        assert!(html.contains("<p>Mercury</p>"), "{html}");
+    }
+
+    #[test]
+    fn arena_signals_are_disposed_with_their_owner() {
+        let app = Owner::new();
+        app.set();
+
+        // ANCHOR: arena
+        // a component runs inside an `Owner`, which owns the signals it creates
+        let component = app.child();
+        let count = component.with(|| RwSignal::new(0));
+
+        // `RwSignal` is `Copy`, because it's only an index into an arena where
+        // its value actually lives...
+        let copy = count;
+        copy.set(1);
+        assert_eq!(count.get(), 1);
+
+        // ...and that value is dropped with the owner, when the component is
+        // unmounted, however many copies of the index there are
+        drop(component);
+        assert_eq!(count.try_get(), None);
+        // ANCHOR_END: arena
+    }
+
+    #[test]
+    fn arc_signals_live_as_long_as_they_are_used() {
+        let app = Owner::new();
+        app.set();
+
+        // ANCHOR: arc
+        let component = app.child();
+        let count = component.with(|| ArcRwSignal::new(0));
+
+        // `ArcRwSignal` is only `Clone`: each clone is another reference to
+        // the same value...
+        let clone = count.clone();
+        clone.set(1);
+        assert_eq!(count.get(), 1);
+
+        // ...which lives as long as there still is one, whatever its owner
+        drop(component);
+        assert_eq!(count.get(), 1);
+        // ANCHOR_END: arc
+    }
+
+    #[test]
+    fn arc_signals_outlive_the_component_in_other_tasks() {
+        let app = Owner::new();
+        app.set();
+
+        // ANCHOR: across_tasks
+        let component = app.child();
+        let (arena, arc) = component.with(|| (RwSignal::new(0), ArcRwSignal::new(0)));
+
+        // the component starts some work in the background...
+        let worker = thread::spawn({
+            let arc = arc.clone();
+            move || arc.set(1)
+        });
+        // ...but is unmounted before it's done
+        drop(component);
+
+        worker.join().unwrap();
+        // the arena signal is gone, but the `Arc` signal is still there
+        assert_eq!(arena.try_get(), None);
+        assert_eq!(arc.get(), 1);
+        // ANCHOR_END: across_tasks
+    }
+
+    #[test]
+    fn local_signals_hold_values_that_are_not_send() {
+        let owner = Owner::new();
+        owner.set();
+
+        // ANCHOR: local
+        // `Rc` can't be sent to another thread, so `RwSignal::new` won't take it...
+        let shared = Rc::new("only on this thread");
+        // ...but `new_local` will, in exchange for the signal staying on this thread
+        let signal = RwSignal::new_local(Rc::clone(&shared));
+        let stored = StoredValue::new_local(shared);
+
+        assert_eq!(*signal.get(), "only on this thread");
+        assert_eq!(stored.with_value(Rc::strong_count), 2);
+        // ANCHOR_END: local
+    }
+}
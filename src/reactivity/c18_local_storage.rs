@@ -0,0 +1,75 @@
+//! Code for "Persisting Signals in `localStorage`".
+//!
+//! The DOM tests in `tests/dom/local_storage.rs` check that values survive
+//! the component being unmounted and mounted again, and the SSR tests in
+//! `tests/ssr/tests/local_storage.rs` check that it renders the defaults on
+//! the server, where there's no storage at all.
+
+use leptos::prelude::*;
+use std::str::FromStr;
+use web_sys::Storage;
+
+// ANCHOR: storage
+/// The browser's `localStorage`, if it can be used: a browser can refuse,
+/// for example in a private window.
+fn storage() -> Option<Storage> {
+    window().local_storage().ok().flatten()
+}
+
+fn load<T: FromStr>(key: &str) -> Option<T> {
+    storage()?.get_item(key).ok()??.parse().ok()
+}
+
+fn save(key: &str, value: &str) {
+    if let Some(storage) = storage() {
+        // this fails if the storage is full, which isn't worth a panic
+        _ = storage.set_item(key, value);
+    }
+}
+// ANCHOR_END: storage
+
+// ANCHOR: use_local_storage
+/// A signal that starts from the value saved under `key` in `localStorage`,
+/// or `default` if there isn't one, and saves every new value there.
+pub fn use_local_storage<T>(key: &'static str, default: T) -> (ReadSignal<T>, WriteSignal<T>)
+where
+    T: FromStr + ToString + Send + Sync + 'static,
+{
+    // there's no `localStorage` on the server, and while hydrating, the page
+    // has to start from the same value the server rendered: the default
+    let load_now = !is_server() && !cfg!(feature = "hydrate");
+    let initial = if load_now { load(key) } else { None };
+    let (value, set_value) = signal(initial.unwrap_or(default));
+
+    // effects only run in the browser, once hydration is done
+    Effect::new(move |has_run: Option<()>| {
+        if has_run.is_none() && !load_now {
+            if let Some(stored) = load(key) {
+                set_value.set(stored);
+            }
+        }
+        save(key, &value.read().to_string());
+    });
+
+    (value, set_value)
+}
+// ANCHOR_END: use_local_storage
+
+// ANCHOR: app
+#[component]
+pub fn Preferences() -> impl IntoView {
+    let (name, set_name) = use_local_storage("preferences.name", String::new());
+    let (visits, set_visits) = use_local_storage("preferences.visits", 0u32);
+
+    view! {
+        <input type="text" bind:value=(name, set_name)/>
+        <button on:click=move |_| *set_visits.write() += 1>"Visit"</button>
+        <p>
+            {move || {
+                let name = name.get();
+                if name.is_empty() { "Hello!".to_string() } else { format!("Hello, {name}!") }
+            }} " Visits: " {visits}
+        </p>
+    }
+}
+// ANCHOR_END: app
@@ -0,0 +1,7 @@
+pub mod c14_memos;
+pub mod c14_working_with_signals;
+pub mod c15_effects;
+pub mod c16_untrack_batch;
+pub mod c17_cleanup;
+pub mod c17_ownership;
+pub mod c18_local_storage;
@@ -0,0 +1,81 @@
+//! Code for "Defining Routes".
+
+// ANCHOR: imports
+use leptos::prelude::*;
+use leptos_router::{
+    components::{Outlet, ParentRoute, Route, Router, Routes},
+    path,
+};
+// ANCHOR_END: imports
+use leptos_router::hooks::use_params_map;
+
+// ANCHOR: app
+#[component]
+pub fn App() -> impl IntoView {
+    view! {
+        <Router>
+            <nav>
+                <a href="/">"Home"</a>
+                <a href="/users">"Users"</a>
+            </nav>
+            <main>
+                // all our routes will appear inside <main>
+                // ANCHOR: routes
+                <Routes fallback=|| "Not found.">
+                    <Route path=path!("/") view=Home/>
+                    <Route path=path!("/users") view=Users/>
+                    <Route path=path!("/users/:id") view=UserProfile/>
+                    <Route path=path!("/*any") view=|| view! { <h1>"Not Found"</h1> }/>
+                </Routes>
+                // ANCHOR_END: routes
+            </main>
+        </Router>
+    }
+}
+// ANCHOR_END: app
+
+#[component]
+pub fn Home() -> impl IntoView {
+    view! { <h1>"Home"</h1> }
+}
+
+#[component]
+pub fn Users() -> impl IntoView {
+    view! { <h1>"Users"</h1> }
+}
+
+#[component]
+pub fn UserProfile() -> impl IntoView {
+    let params = use_params_map();
+    let id = move || params.read().get("id").unwrap_or_default();
+    view! { <h1>"User " {id}</h1> }
+}
+
+fn is_loaded() -> bool {
+    true
+}
+
+// ANCHOR: conditional_outlet
+#[component]
+pub fn ConditionalOutlet() -> impl IntoView {
+    view! {
+        <Router>
+            // ✅ do this instead!
+            <Routes fallback=|| "Not found.">
+                // parent route
+                <ParentRoute path=path!("/") view=move || {
+                    view! {
+                        // only show the outlet if data have loaded
+                        <Show when=|| is_loaded() fallback=|| view! { <p>"Loading"</p> }>
+                            <Outlet/>
+                        </Show>
+                    }
+                }>
+                    // nested child route
+                    <Route path=path!("") view=Home/>
+                </ParentRoute>
+            </Routes>
+        </Router>
+    }
+}
+// ANCHOR_END: conditional_outlet
@@ -0,0 +1,46 @@
+//! Code for the "Trailing Slashes" section of "Defining Routes".
+//!
+//! There's no `TrailingSlash` setting on the router in Leptos 0.8: a trailing
+//! slash is part of a route's path, like any other segment. The SSR tests in
+//! `tests/ssr/tests/trailing_slashes.rs` request each path with and without
+//! one.
+
+use leptos::prelude::*;
+use leptos_router::{
+    components::{Redirect, Route, Router, Routes},
+    path,
+};
+
+#[component]
+pub fn App() -> impl IntoView {
+    view! {
+        <Router>
+            <main>
+                <Routes fallback=|| "Not found.">
+                    // ANCHOR: exact
+                    // matches `/about`, but not `/about/`...
+                    <Route path=path!("/about") view=|| "About"/>
+                    // ...and this matches `/docs/`, but not `/docs`
+                    <Route path=path!("/docs/") view=|| "Docs"/>
+                    // ANCHOR_END: exact
+                    // ANCHOR: redirect
+                    // `/blog/` redirects to `/blog`: this has to come first,
+                    // because `/blog/` would match the route below it, too
+                    <Route path=path!("/blog/") view=|| view! { <Redirect path="/blog"/> }/>
+                    <Route path=path!("/blog") view=|| "Blog"/>
+                    // ANCHOR_END: redirect
+                    // ANCHOR: both
+                    // the same view, whichever one is requested
+                    <Route path=path!("/contact") view=Contact/>
+                    <Route path=path!("/contact/") view=Contact/>
+                    // ANCHOR_END: both
+                </Routes>
+            </main>
+        </Router>
+    }
+}
+
+#[component]
+pub fn Contact() -> impl IntoView {
+    "Contact"
+}
@@ -0,0 +1,121 @@
+//! Code for "Params and Queries".
+//!
+//! [`App`] routes to one component for each way of reading the URL, so that
+//! each can be rendered on its own. The chapter's sandbox reuses the contact
+//! app from "Nested Routing".
+
+use leptos::prelude::*;
+use leptos_router::{
+    components::{Route, Router, Routes},
+    hooks::{use_params, use_params_map, use_query, use_query_map},
+    path,
+};
+
+// ANCHOR: params_structs
+use leptos::Params;
+use leptos_router::params::Params;
+
+#[derive(Params, PartialEq)]
+pub struct ContactParams {
+    pub id: Option<usize>,
+}
+
+#[derive(Params, PartialEq)]
+pub struct ContactSearch {
+    pub q: Option<String>,
+}
+// ANCHOR_END: params_structs
+
+// ANCHOR: required_struct
+#[derive(Params, PartialEq)]
+pub struct SearchQuery {
+    // without an `Option`, leaving out `?q=` is an error
+    pub q: String,
+}
+// ANCHOR_END: required_struct
+
+#[component]
+pub fn App() -> impl IntoView {
+    view! {
+        <Router>
+            <main>
+                <Routes fallback=|| "Not found.">
+                    <Route path=path!("/contacts/:id") view=Contact/>
+                    <Route path=path!("/untyped/contacts/:id") view=UntypedContact/>
+                    <Route path=path!("/search") view=Search/>
+                </Routes>
+            </main>
+        </Router>
+    }
+}
+
+/// Reads `/contacts/:id?q=Search` with the typed hooks.
+#[component]
+pub fn Contact() -> impl IntoView {
+    // ANCHOR: typed
+    let params = use_params::<ContactParams>();
+    let query = use_query::<ContactSearch>();
+
+    // id: || -> Option<usize>
+    let id = move || params.read().as_ref().ok().and_then(|params| params.id);
+    // ANCHOR_END: typed
+
+    let search = move || {
+        query
+            .read()
+            .as_ref()
+            .ok()
+            .and_then(|query| query.q.clone())
+            .unwrap_or_default()
+    };
+
+    // ANCHOR: invalid
+    // the `Err` case is an ID that isn't a number, like `/contacts/bob`
+    let contact = move || match &*params.read() {
+        Ok(ContactParams { id: Some(id) }) => format!("Contact #{id}"),
+        Ok(ContactParams { id: None }) => "No contact selected.".to_string(),
+        Err(e) => format!("Invalid contact ID: {e}"),
+    };
+    // ANCHOR_END: invalid
+
+    view! {
+        <h4>{contact}</h4>
+        <p class="id">{move || format!("{:?}", id())}</p>
+        <p class="search">"Search: " {search}</p>
+    }
+}
+
+/// Reads the same URL as [`Contact`] with the untyped hooks.
+#[component]
+pub fn UntypedContact() -> impl IntoView {
+    // ANCHOR: untyped
+    let params = use_params_map();
+    let query = use_query_map();
+
+    // id: || -> Option<String>
+    let id = move || params.read().get("id");
+    // ANCHOR_END: untyped
+
+    let search = move || query.read().get("q").unwrap_or_default();
+
+    view! {
+        <h4>{move || id().unwrap_or_default()}</h4>
+        <p class="search">"Search: " {search}</p>
+    }
+}
+
+/// Reads a required query field, showing an error if it's missing.
+#[component]
+pub fn Search() -> impl IntoView {
+    // ANCHOR: missing
+    let query = use_query::<SearchQuery>();
+
+    let results = move || match &*query.read() {
+        Ok(SearchQuery { q }) => format!("Results for {q:?}"),
+        // for `/search`, this is "could not find parameter q"
+        Err(e) => format!("Error: {e}"),
+    };
+    // ANCHOR_END: missing
+
+    view! { <p>{results}</p> }
+}
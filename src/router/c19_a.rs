@@ -0,0 +1,145 @@
+//! Code for "The `<A/>` Component".
+//!
+//! The contact app from "Nested Routing", with a plain `<a>` beside the
+//! relative `<A/>` links to compare how they resolve, and a sign-in form
+//! that navigates with `use_navigate`.
+
+// ANCHOR: app
+use leptos::prelude::*;
+use leptos_router::{
+    components::{Outlet, ParentRoute, Route, Router, Routes, A},
+    hooks::{use_navigate, use_params_map},
+    path, NavigateOptions,
+};
+
+#[component]
+pub fn App() -> impl IntoView {
+    view! {
+        <Router>
+            <h1>"Contact App"</h1>
+            <nav>
+                // ANCHOR: nav
+                // absolute links: `aria-current="page"` is set on the link
+                // to the current page, or to any page nested inside it
+                <A href="/">"Home"</A>
+                <A href="/contacts">"Contacts"</A>
+                <A href="/sign-in">"Sign In"</A>
+                // ANCHOR_END: nav
+            </nav>
+            <main>
+                <Routes fallback=|| "Not found.">
+                    <Route path=path!("/") view=|| view! {
+                        <h3>"Home"</h3>
+                    }/>
+                    <Route path=path!("/sign-in") view=SignIn/>
+                    <ParentRoute
+                        path=path!("/contacts")
+                        view=ContactList
+                    >
+                        <ParentRoute path=path!(":id") view=ContactInfo>
+                            <Route path=path!("") view=|| view! {
+                                <div class="tab">
+                                    "(Contact Info)"
+                                </div>
+                            }/>
+                            <Route path=path!("conversations") view=|| view! {
+                                <div class="tab">
+                                    "(Conversations)"
+                                </div>
+                            }/>
+                        </ParentRoute>
+                        <Route path=path!("") view=|| view! {
+                            <div class="select-user">
+                                "Select a user to view contact info."
+                            </div>
+                        }/>
+                    </ParentRoute>
+                </Routes>
+            </main>
+        </Router>
+    }
+}
+
+#[component]
+pub fn ContactList() -> impl IntoView {
+    view! {
+        <div class="contact-list">
+            <h3>"Contacts"</h3>
+            <div class="contact-list-contacts">
+                // relative to this route, so these go to /contacts/alice, etc.
+                <A href="alice">"Alice"</A>
+                <A href="bob">"Bob"</A>
+                <A href="steve">"Steve"</A>
+            </div>
+            <Outlet/>
+        </div>
+    }
+}
+
+#[component]
+pub fn ContactInfo() -> impl IntoView {
+    let params = use_params_map();
+    let id = move || params.read().get("id").unwrap_or_default();
+
+    let name = move || match id().as_str() {
+        "alice" => "Alice",
+        "bob" => "Bob",
+        "steve" => "Steve",
+        _ => "User not found.",
+    };
+
+    view! {
+        <h4>{name}</h4>
+        <div class="contact-info">
+            // ANCHOR: relative
+            <div class="tabs">
+                // resolved relative to /contacts/:id, so on /contacts/alice
+                // these link to /contacts/alice and /contacts/alice/conversations
+                // `exact` means the first is only active on /contacts/alice itself
+                <A href="" exact=true>"Contact Info"</A>
+                <A href="conversations">"Conversations"</A>
+                // ⚠️ the browser resolves this one relative to the current URL,
+                // so on /contacts/alice it links to /contacts/conversations
+                <a href="conversations">"Conversations?"</a>
+            </div>
+            // ANCHOR_END: relative
+            <Outlet/>
+        </div>
+    }
+}
+
+#[component]
+pub fn SignIn() -> impl IntoView {
+    // ANCHOR: navigate
+    let navigate = use_navigate();
+    let (name, set_name) = signal(String::new());
+
+    let on_submit = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+        // imagine we've checked the user's credentials here...
+        navigate(
+            &format!("/contacts/{}", name.read().to_lowercase()),
+            NavigateOptions {
+                // replace the sign-in page in the history stack, so
+                // the "back" button doesn't return to it
+                replace: true,
+                ..Default::default()
+            },
+        );
+    };
+    // ANCHOR_END: navigate
+
+    view! {
+        <form on:submit=on_submit>
+            <input type="text" bind:value=(name, set_name)/>
+            <button type="submit">"Sign In"</button>
+        </form>
+    }
+}
+// ANCHOR_END: app
+
+// ANCHOR: main
+pub fn main() {
+    leptos::mount::mount_to_body(App)
+}
+// ANCHOR_END: main
@@ -0,0 +1,218 @@
+//! Code for "The `<Form/>` Component".
+//!
+//! [`App`] is the chapter's sandbox. [`SearchApp`] routes to the search
+//! examples, which keep their state in the URL, so that each can be rendered
+//! straight from a `GET` request.
+
+// ANCHOR: app
+use leptos::prelude::*;
+use leptos_router::{
+    components::{Form, Route, Router, Routes},
+    hooks::use_query_map,
+    path,
+};
+
+#[component]
+pub fn App() -> impl IntoView {
+    view! {
+        <Router>
+            <h1><code>"<Form/>"</code></h1>
+            <main>
+                <Routes fallback=|| "Not found.">
+                    <Route path=path!("/") view=FormExample/>
+                </Routes>
+            </main>
+        </Router>
+    }
+}
+
+#[component]
+pub fn FormExample() -> impl IntoView {
+    // reactive access to URL query
+    let query = use_query_map();
+    let name = move || query.read().get("name").unwrap_or_default();
+    let number = move || query.read().get("number").unwrap_or_default();
+    let select = move || query.read().get("select").unwrap_or_default();
+
+    view! {
+        // read out the URL query strings
+        <table>
+            <tr>
+                <td><code>"name"</code></td>
+                <td>{name}</td>
+            </tr>
+            <tr>
+                <td><code>"number"</code></td>
+                <td>{number}</td>
+            </tr>
+            <tr>
+                <td><code>"select"</code></td>
+                <td>{select}</td>
+            </tr>
+        </table>
+        // <Form/> will navigate whenever submitted
+        <h2>"Manual Submission"</h2>
+        <Form method="GET" action="">
+            // input names determine query string key
+            <input type="text" name="name" value=name/>
+            <input type="number" name="number" value=number/>
+            <select name="select">
+                // `selected` will set which starts as selected
+                <option selected=move || select() == "A">
+                    "A"
+                </option>
+                <option selected=move || select() == "B">
+                    "B"
+                </option>
+                <option selected=move || select() == "C">
+                    "C"
+                </option>
+            </select>
+            // submitting should cause a client-side
+            // navigation, not a full reload
+            <input type="submit"/>
+        </Form>
+        // This <Form/> uses some JavaScript to submit
+        // on every input
+        <h2>"Automatic Submission"</h2>
+        <Form method="GET" action="">
+            <input
+                type="text"
+                name="name"
+                value=name
+                // this oninput attribute will cause the
+                // form to submit on every input to the field
+                oninput="this.form.requestSubmit()"
+            />
+            <input
+                type="number"
+                name="number"
+                value=number
+                oninput="this.form.requestSubmit()"
+            />
+            <select name="select"
+                onchange="this.form.requestSubmit()"
+            >
+                <option selected=move || select() == "A">
+                    "A"
+                </option>
+                <option selected=move || select() == "B">
+                    "B"
+                </option>
+                <option selected=move || select() == "C">
+                    "C"
+                </option>
+            </select>
+            // submitting should cause a client-side
+            // navigation, not a full reload
+            <input type="submit"/>
+        </Form>
+    }
+}
+
+pub fn main() {
+    leptos::mount::mount_to_body(App)
+}
+// ANCHOR_END: app
+
+#[component]
+pub fn SearchApp() -> impl IntoView {
+    view! {
+        <Router>
+            <main>
+                <Routes fallback=|| "Not found.">
+                    <Route path=path!("/search") view=Search/>
+                    <Route path=path!("/live") view=LiveSearch/>
+                    <Route path=path!("/plain") view=PlainSearch/>
+                </Routes>
+            </main>
+        </Router>
+    }
+}
+
+// ANCHOR: fetch_results
+/// Returns the fruits whose names contain `search`.
+pub async fn fetch_results(search: String) -> Vec<String> {
+    // imagine this is a request to a search API
+    ["apple", "banana", "cherry", "grape", "mango"]
+        .into_iter()
+        .filter(|fruit| fruit.contains(&search))
+        .map(String::from)
+        .collect()
+}
+// ANCHOR_END: fetch_results
+
+/// Shows the results of a search as a list.
+#[component]
+pub fn Results(results: Resource<Vec<String>>) -> impl IntoView {
+    view! {
+        <Transition fallback=move || ()>
+            <ul>
+                {move || results.get().map(|results| {
+                    results
+                        .into_iter()
+                        .map(|result| view! { <li>{result}</li> })
+                        .collect_view()
+                })}
+            </ul>
+        </Transition>
+    }
+}
+
+#[component]
+pub fn Search() -> impl IntoView {
+    // ANCHOR: search
+    // reactive access to URL query strings
+    let query = use_query_map();
+    // search stored as ?q=
+    let search = move || query.read().get("q").unwrap_or_default();
+    // a resource driven by the search string
+    let search_results = Resource::new(search, fetch_results);
+
+    view! {
+        <Form method="GET" action="">
+            <input type="search" name="q" value=search/>
+            <input type="submit"/>
+        </Form>
+        <Results results=search_results/>
+    }
+    // ANCHOR_END: search
+}
+
+#[component]
+pub fn LiveSearch() -> impl IntoView {
+    let query = use_query_map();
+    let search = move || query.read().get("q").unwrap_or_default();
+    let search_results = Resource::new(search, fetch_results);
+
+    // ANCHOR: live_search
+    view! {
+        // `replace` swaps out the current history entry, rather than adding
+        // a new one for every keystroke
+        <Form method="GET" action="" replace=true>
+            <input type="search" name="q" value=search
+                oninput="this.form.requestSubmit()"
+            />
+        </Form>
+        <Results results=search_results/>
+    }
+    // ANCHOR_END: live_search
+}
+
+#[component]
+pub fn PlainSearch() -> impl IntoView {
+    let query = use_query_map();
+    let search = move || query.read().get("q").unwrap_or_default();
+    let search_results = Resource::new(search, fetch_results);
+
+    // ANCHOR: plain
+    view! {
+        // the same URL, but submitting this reloads the whole page
+        <form method="GET" action="">
+            <input type="search" name="q" value=search/>
+            <input type="submit"/>
+        </form>
+        <Results results=search_results/>
+    }
+    // ANCHOR_END: plain
+}
@@ -0,0 +1,95 @@
+//! Code for "Lazy Loading and Code Splitting".
+//!
+//! Splitting the WASM binary is done by `cargo leptos --split`, but the code
+//! that marks what to split compiles in every mode, so this is gated on the
+//! `split` feature rather than on a rendering mode. The SSR tests in
+//! `tests/ssr/tests/lazy_routes.rs` check that lazy routes are rendered on the
+//! server like any other.
+
+use crate::r#async::sleep;
+use leptos::{lazy, prelude::*};
+use leptos_router::{
+    components::{Route, Router, Routes, RoutingProgress, A},
+    lazy_route, path, Lazy, LazyRoute,
+};
+use std::time::Duration;
+
+pub async fn load_downloads() -> Vec<u32> {
+    sleep(Duration::from_millis(50)).await;
+    vec![120, 80, 340, 95, 210]
+}
+
+// ANCHOR: lazy_fn
+/// The summary of a list of numbers. Imagine that this uses a big
+/// statistics crate, which only this page needs.
+#[lazy]
+pub fn summarize(numbers: Vec<u32>) -> String {
+    let total: u32 = numbers.iter().sum();
+    let max = numbers.iter().max().copied().unwrap_or_default();
+    format!("{total} downloads, at most {max} in a day")
+}
+// ANCHOR_END: lazy_fn
+
+// ANCHOR: lazy_route
+/// The data for the stats page, which starts loading as soon as the route
+/// is matched.
+#[derive(Debug)]
+pub struct StatsPage {
+    downloads: Resource<Vec<u32>>,
+}
+
+#[lazy_route]
+impl LazyRoute for StatsPage {
+    fn data() -> Self {
+        Self {
+            downloads: Resource::new(|| (), |_| load_downloads()),
+        }
+    }
+
+    // the code for this is split into a WASM file of its own, which is
+    // loaded at the same time as `data` starts loading
+    fn view(this: Self) -> AnyView {
+        let StatsPage { downloads } = this;
+        view! {
+            <h2>"Stats"</h2>
+            <Suspense fallback=|| view! { <p>"Loading stats..."</p> }>
+                {move || Suspend::new(async move {
+                    let downloads = downloads.await;
+                    // a lazy function is async, because its code might
+                    // need to be loaded first
+                    let summary = summarize(downloads).await;
+                    view! { <p>{summary}</p> }
+                })}
+            </Suspense>
+        }
+        .into_any()
+    }
+}
+// ANCHOR_END: lazy_route
+
+// ANCHOR: app
+#[component]
+pub fn App() -> impl IntoView {
+    // true while the router is loading the next page, including its code
+    let (is_routing, set_is_routing) = signal(false);
+
+    view! {
+        <Router set_is_routing>
+            <div class="routing-progress">
+                <RoutingProgress is_routing max_time=Duration::from_millis(250)/>
+            </div>
+            <nav>
+                <A href="/">"Home"</A>
+                <A href="/stats">"Stats"</A>
+            </nav>
+            <main>
+                <Routes fallback=|| "Not found.">
+                    <Route path=path!("/") view=|| view! { <h2>"Home"</h2> }/>
+                    // the view is a `Lazy`, instead of a component
+                    <Route path=path!("/stats") view={Lazy::<StatsPage>::new()}/>
+                </Routes>
+            </main>
+        </Router>
+    }
+}
+// ANCHOR_END: app
@@ -0,0 +1,122 @@
+//! Code for "Route Guards and Redirects".
+//!
+//! Both pages need someone to be logged in: one is guarded by a wrapper
+//! component, and the other by `<ProtectedRoute/>`. The SSR tests in
+//! `tests/ssr/tests/route_guards.rs` check that the server responds to both
+//! with a redirect to the login page, and the DOM tests in
+//! `tests/dom/route_guards.rs` check that the browser is sent there, and back
+//! again once the user logs in.
+
+use leptos::prelude::*;
+use leptos_router::{
+    components::{ProtectedRoute, Redirect, Route, Router, Routes, A},
+    hooks::{use_location, use_navigate, use_query_map},
+    path, NavigateOptions,
+};
+
+// ANCHOR: current_user
+/// The name of whoever is logged in, if anyone. Imagine that this is loaded
+/// from the server when the app starts.
+#[derive(Clone, Copy, Debug)]
+pub struct CurrentUser(pub RwSignal<Option<String>>);
+
+impl CurrentUser {
+    pub fn is_logged_in(&self) -> bool {
+        self.0.with(Option::is_some)
+    }
+}
+// ANCHOR_END: current_user
+
+// ANCHOR: require_auth
+/// Shows its children to users who are logged in, and sends everyone else to
+/// the login page, which sends them back here afterwards.
+#[component]
+pub fn RequireAuth(children: ChildrenFn) -> impl IntoView {
+    let user = expect_context::<CurrentUser>();
+    let location = use_location();
+
+    move || {
+        if user.is_logged_in() {
+            children().into_any()
+        } else {
+            // remember where they were going, to go back after logging in
+            let next = location.pathname.get_untracked();
+            view! { <Redirect path=format!("/login?next={next}")/> }.into_any()
+        }
+    }
+}
+// ANCHOR_END: require_auth
+
+// ANCHOR: app
+#[component]
+pub fn App() -> impl IntoView {
+    let user = CurrentUser(RwSignal::new(None));
+    provide_context(user);
+
+    view! {
+        <Router>
+            <nav>
+                <A href="/dashboard">"Dashboard"</A>
+                <A href="/settings">"Settings"</A>
+            </nav>
+            <main>
+                <Routes fallback=|| "Not found.">
+                    <Route path=path!("/") view=|| view! { <h2>"Home"</h2> }/>
+                    <Route path=path!("/login") view=Login/>
+                    // guarded by wrapping the page in a component...
+                    <Route
+                        path=path!("/dashboard")
+                        view=|| view! { <RequireAuth><Dashboard/></RequireAuth> }
+                    />
+                    // ...or by the route itself
+                    <ProtectedRoute
+                        path=path!("/settings")
+                        view=|| view! { <h2>"Settings"</h2> }
+                        condition=move || Some(user.is_logged_in())
+                        redirect_path=|| "/login"
+                    />
+                </Routes>
+            </main>
+        </Router>
+    }
+}
+// ANCHOR_END: app
+
+#[component]
+pub fn Dashboard() -> impl IntoView {
+    let user = expect_context::<CurrentUser>();
+    view! { <h2>"Welcome, " {move || user.0.get()} "!"</h2> }
+}
+
+// ANCHOR: login
+#[component]
+pub fn Login() -> impl IntoView {
+    let user = expect_context::<CurrentUser>();
+    let query = use_query_map();
+    let navigate = use_navigate();
+    let (name, set_name) = signal(String::new());
+
+    let on_submit = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+        // imagine that this checks a password on the server first
+        user.0.set(Some(name.get()));
+        // back to the page that sent them here, in place of the login page
+        let next = query.read().get("next").unwrap_or_else(|| "/".to_string());
+        navigate(
+            &next,
+            NavigateOptions {
+                replace: true,
+                ..Default::default()
+            },
+        );
+    };
+
+    view! {
+        <h2>"Log In"</h2>
+        <form on:submit=on_submit>
+            <input type="text" bind:value=(name, set_name)/>
+            <button type="submit">"Log In"</button>
+        </form>
+    }
+}
+// ANCHOR_END: login
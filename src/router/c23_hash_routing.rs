@@ -0,0 +1,149 @@
+//! Code for "Routing Without a Server".
+//!
+//! `leptos_router` always routes on the page's real path, so this is a small
+//! router of its own, which keeps the path either in the URL's hash or only in
+//! memory. The unit tests below render the app from memory, and the DOM tests
+//! in `tests/dom/hash_routing.rs` follow links that change the hash.
+
+use leptos::{ev, prelude::*};
+
+// ANCHOR: history
+/// Where the app is, for hosts that can't serve the app at any path.
+#[derive(Clone, Copy, Debug)]
+pub struct History {
+    path: RwSignal<String>,
+    in_hash: bool,
+}
+
+impl History {
+    /// Keeps the path in the hash of the URL, like `index.html#/about`. The
+    /// browser never loads another page for a change of hash, so this works
+    /// wherever the app is loaded from, and the back button still works.
+    pub fn hash() -> Self {
+        let path = RwSignal::new(hash_path());
+        // the hash changes when a link is followed, or the user goes back
+        let handle = window_event_listener(ev::hashchange, move |_| path.set(hash_path()));
+        on_cleanup(move || handle.remove());
+        Self {
+            path,
+            in_hash: true,
+        }
+    }
+
+    /// Keeps the path in memory only, starting at `path`. Nothing in the
+    /// URL changes, so there's no back button, and a reload starts over.
+    pub fn memory(path: &str) -> Self {
+        Self {
+            path: RwSignal::new(path.to_string()),
+            in_hash: false,
+        }
+    }
+
+    /// The current path, like `/about`.
+    pub fn path(&self) -> String {
+        self.path.get()
+    }
+
+    pub fn navigate(&self, path: &str) {
+        if self.in_hash {
+            // also adds an entry to the browser's history
+            _ = window().location().set_hash(path);
+        }
+        self.path.set(path.to_string());
+    }
+}
+
+/// Returns the path in the hash, without the `#`, or `/` if there isn't one.
+fn hash_path() -> String {
+    let hash = window().location().hash().unwrap_or_default();
+    match hash.strip_prefix('#') {
+        Some(path) if path.starts_with('/') => path.to_string(),
+        _ => "/".to_string(),
+    }
+}
+// ANCHOR_END: history
+
+// ANCHOR: link
+/// A link to another path in the app.
+#[component]
+pub fn Link(href: &'static str, children: Children) -> impl IntoView {
+    let history = expect_context::<History>();
+    view! {
+        // a real link for the hash, so it can be opened in a new tab
+        <a
+            href=format!("#{href}")
+            on:click=move |ev| {
+                ev.prevent_default();
+                history.navigate(href);
+            }
+        >
+            {children()}
+        </a>
+    }
+}
+// ANCHOR_END: link
+
+// ANCHOR: app
+#[component]
+pub fn App(history: History) -> impl IntoView {
+    provide_context(history);
+
+    view! {
+        <nav>
+            <Link href="/">"Home"</Link>
+            <Link href="/notes">"Notes"</Link>
+            <Link href="/settings">"Settings"</Link>
+        </nav>
+        <main>
+            // "routing" is a `match` on the path
+            {move || match history.path().as_str() {
+                "/" => view! { <h2>"Home"</h2> }.into_any(),
+                "/notes" => view! { <h2>"Notes"</h2> }.into_any(),
+                "/settings" => view! { <h2>"Settings"</h2> }.into_any(),
+                _ => view! { <h2>"Not Found"</h2> }.into_any(),
+            }}
+        </main>
+    }
+}
+// ANCHOR_END: app
+
+// ANCHOR: main
+pub fn main() {
+    // or `History::memory("/")`, for an app that shouldn't touch the URL
+    leptos::mount::mount_to_body(|| view! { <App history=History::hash()/> })
+}
+// ANCHOR_END: main
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Renders the app, without the markers between text nodes.
+    fn render(history: History) -> String {
+        view! { <App history/> }.to_html().replace("<!>", "")
+    }
+
+    #[test]
+    fn the_path_picks_the_page() {
+        let owner = Owner::new();
+        owner.with(|| {
+            assert!(render(History::memory("/")).contains("<h2>Home</h2>"));
+            assert!(render(History::memory("/notes")).contains("<h2>Notes</h2>"));
+            assert!(render(History::memory("/nowhere")).contains("<h2>Not Found</h2>"));
+        });
+    }
+
+    #[test]
+    fn navigating_changes_the_page() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let history = History::memory("/");
+            history.navigate("/settings");
+            assert_eq!(history.path(), "/settings");
+            let html = render(history);
+            assert!(html.contains("<h2>Settings</h2>"), "{html}");
+            // links point at the hash, whichever history the app uses
+            assert!(html.contains(r##"<a href="#/notes">Notes</a>"##), "{html}");
+        });
+    }
+}
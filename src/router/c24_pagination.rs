@@ -0,0 +1,125 @@
+//! Code for "Pagination in the URL".
+//!
+//! The SSR tests in `tests/ssr/tests/pagination.rs` check that the server
+//! renders whichever page the URL asks for, and the DOM tests in
+//! `tests/dom/pagination.rs` check that links, the form, and the browser's
+//! back and forward buttons all move between pages.
+
+use leptos::prelude::*;
+use leptos_router::{
+    components::{Form, Route, Router, Routes, A},
+    hooks::use_query,
+    path,
+};
+use serde::{Deserialize, Serialize};
+
+// ANCHOR: fetch_articles
+pub const PER_PAGE: usize = 5;
+pub const ARTICLES: usize = 23;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ArticlePage {
+    pub titles: Vec<String>,
+    /// How many pages there are altogether.
+    pub pages: usize,
+}
+
+/// Returns one page of articles, counting from `1`. Pages past the end are
+/// empty.
+pub async fn fetch_articles(page: usize) -> ArticlePage {
+    // imagine this is a request with `LIMIT` and `OFFSET`
+    let titles = (1..=ARTICLES)
+        .skip((page - 1).saturating_mul(PER_PAGE))
+        .take(PER_PAGE)
+        .map(|n| format!("Article {n}"))
+        .collect();
+    ArticlePage {
+        titles,
+        pages: ARTICLES.div_ceil(PER_PAGE),
+    }
+}
+// ANCHOR_END: fetch_articles
+
+#[component]
+pub fn App() -> impl IntoView {
+    view! {
+        <Router>
+            <main>
+                <Routes fallback=|| "Not found.">
+                    <Route path=path!("/articles") view=Articles/>
+                </Routes>
+            </main>
+        </Router>
+    }
+}
+
+// ANCHOR: page_query
+use leptos::Params;
+use leptos_router::params::Params;
+
+#[derive(Params, Clone, Debug, PartialEq)]
+pub struct PageQuery {
+    pub page: Option<usize>,
+}
+// ANCHOR_END: page_query
+
+#[component]
+pub fn Articles() -> impl IntoView {
+    // ANCHOR: current_page
+    let query = use_query::<PageQuery>();
+
+    // `/articles`, `?page=0` and `?page=oops` are all the first page
+    let page = move || {
+        query
+            .read()
+            .as_ref()
+            .ok()
+            .and_then(|query| query.page)
+            .unwrap_or(1)
+            .max(1)
+    };
+
+    // the URL is the only place the page is kept, so this reloads on every
+    // navigation that changes it, however that happens
+    let articles = Resource::new(page, fetch_articles);
+    // ANCHOR_END: current_page
+
+    // ANCHOR: navigation
+    let pages = move || articles.get().map_or(1, |articles| articles.pages);
+    let link = |page: usize| format!("/articles?page={page}");
+
+    view! {
+        <Transition fallback=|| view! { <p>"Loading..."</p> }>
+            <ul>
+                {move || {
+                    articles
+                        .get()
+                        .map(|articles| {
+                            articles
+                                .titles
+                                .into_iter()
+                                .map(|title| view! { <li>{title}</li> })
+                                .collect_view()
+                        })
+                }}
+            </ul>
+            <nav>
+                // links that go nowhere are left out, rather than disabled:
+                // an `<a>` can't be disabled
+                <Show when=move || { page() > 1 }>
+                    <A href=move || link(page() - 1)>"Previous"</A>
+                </Show>
+                <span class="current">{move || format!("Page {} of {}", page(), pages())}</span>
+                <Show when=move || { page() < pages() }>
+                    <A href=move || link(page() + 1)>"Next"</A>
+                </Show>
+            </nav>
+            // a `GET` form navigates to `?page=` too, with whatever was typed in
+            <Form method="GET" action="">
+                <input type="number" name="page" min="1" max=pages value=page/>
+                <button type="submit">"Go"</button>
+            </Form>
+        </Transition>
+    }
+    // ANCHOR_END: navigation
+}
@@ -0,0 +1,323 @@
+//! Code for "A Multi-Step Form".
+//!
+//! Checking each step is plain Rust, which the tests below check. The DOM
+//! tests in `tests/dom/wizard.rs` go through the steps in a browser, and the
+//! SSR tests in `tests/ssr/tests/wizard.rs` check that skipping ahead
+//! redirects.
+
+use leptos::prelude::*;
+use leptos_router::{
+    components::{Outlet, ParentRoute, Redirect, Route, Router, Routes, A},
+    hooks::{use_navigate, use_params_map},
+    path,
+};
+use reactive_stores::Store;
+use std::str::FromStr;
+
+// ANCHOR: sign_up
+/// Everything the user has typed in so far, across all the steps.
+#[derive(Store, Debug, Clone, Default)]
+pub struct SignUp {
+    pub email: String,
+    pub password: String,
+    pub name: String,
+    pub agreed: bool,
+}
+// ANCHOR_END: sign_up
+
+// ANCHOR: step
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Step {
+    Account,
+    Profile,
+    Confirm,
+}
+
+impl Step {
+    pub const ALL: [Step; 3] = [Step::Account, Step::Profile, Step::Confirm];
+
+    /// The last part of the step's URL.
+    pub fn slug(self) -> &'static str {
+        match self {
+            Step::Account => "account",
+            Step::Profile => "profile",
+            Step::Confirm => "confirm",
+        }
+    }
+
+    pub fn title(self) -> &'static str {
+        match self {
+            Step::Account => "Account",
+            Step::Profile => "Profile",
+            Step::Confirm => "Confirm",
+        }
+    }
+
+    pub fn path(self) -> String {
+        format!("/signup/{}", self.slug())
+    }
+
+    pub fn previous(self) -> Option<Step> {
+        let index = Step::ALL.iter().position(|step| *step == self)?;
+        index.checked_sub(1).map(|index| Step::ALL[index])
+    }
+
+    pub fn next(self) -> Option<Step> {
+        let index = Step::ALL.iter().position(|step| *step == self)?;
+        Step::ALL.get(index + 1).copied()
+    }
+
+    /// What's wrong with the fields on this step, if anything.
+    pub fn errors(self, form: &SignUp) -> Vec<&'static str> {
+        let mut errors = Vec::new();
+        match self {
+            Step::Account => {
+                if !form.email.contains('@') {
+                    errors.push("Enter an email address, like name@example.com.");
+                }
+                if form.password.chars().count() < 8 {
+                    errors.push("Choose a password of at least 8 characters.");
+                }
+            }
+            Step::Profile => {
+                if form.name.trim().is_empty() {
+                    errors.push("Enter your name.");
+                }
+            }
+            Step::Confirm => {
+                if !form.agreed {
+                    errors.push("Agree to the terms to create your account.");
+                }
+            }
+        }
+        errors
+    }
+}
+
+impl FromStr for Step {
+    type Err = ();
+
+    fn from_str(slug: &str) -> Result<Self, Self::Err> {
+        Step::ALL
+            .into_iter()
+            .find(|step| step.slug() == slug)
+            .ok_or(())
+    }
+}
+
+/// The first step before `step` that hasn't been filled in properly, which
+/// is where someone who skips ahead is sent back to.
+pub fn first_incomplete(step: Step, form: &SignUp) -> Option<Step> {
+    Step::ALL
+        .into_iter()
+        .take_while(|earlier| *earlier != step)
+        .find(|earlier| !earlier.errors(form).is_empty())
+}
+// ANCHOR_END: step
+
+// ANCHOR: app
+#[component]
+pub fn App() -> impl IntoView {
+    view! {
+        <Router>
+            <main>
+                <Routes fallback=|| "Not found.">
+                    <ParentRoute path=path!("/signup") view=Wizard>
+                        <Route path=path!(":step") view=CurrentStep/>
+                        <Route
+                            path=path!("")
+                            view=|| view! { <Redirect path=Step::Account.path()/> }
+                        />
+                    </ParentRoute>
+                </Routes>
+            </main>
+        </Router>
+    }
+}
+
+/// Holds the form for every step, and shows the one in the URL.
+#[component]
+pub fn Wizard() -> impl IntoView {
+    // the parent route stays mounted while the user moves between its
+    // children, so the store it provides lasts for the whole form
+    provide_context(Store::new(SignUp::default()));
+
+    view! {
+        <h2>"Sign Up"</h2>
+        <ol class="steps">
+            {Step::ALL
+                .into_iter()
+                .map(|step| view! { <li><A href=step.path()>{step.title()}</A></li> })
+                .collect_view()}
+        </ol>
+        <Outlet/>
+    }
+}
+// ANCHOR_END: app
+
+// ANCHOR: current_step
+#[component]
+pub fn CurrentStep() -> impl IntoView {
+    let form = expect_context::<Store<SignUp>>();
+    let params = use_params_map();
+
+    move || {
+        let Ok(step) = params
+            .read()
+            .get("step")
+            .unwrap_or_default()
+            .parse::<Step>()
+        else {
+            return view! { <p>"Not found."</p> }.into_any();
+        };
+        // an earlier step might not have been filled in, if the user followed
+        // a link or typed the URL, so send them back to it
+        if let Some(earlier) = form.with_untracked(|form| first_incomplete(step, form)) {
+            return view! { <Redirect path=earlier.path()/> }.into_any();
+        }
+        let fields = match step {
+            Step::Account => view! { <AccountFields/> }.into_any(),
+            Step::Profile => view! { <ProfileFields/> }.into_any(),
+            Step::Confirm => view! { <ConfirmFields/> }.into_any(),
+        };
+        view! { <StepForm step>{fields}</StepForm> }.into_any()
+    }
+}
+// ANCHOR_END: current_step
+
+// ANCHOR: step_form
+/// The form around a step's fields, which checks them before moving on.
+#[component]
+pub fn StepForm(step: Step, children: Children) -> impl IntoView {
+    let form = expect_context::<Store<SignUp>>();
+    let navigate = use_navigate();
+    let errors = RwSignal::new(Vec::new());
+    let (finished, set_finished) = signal(false);
+
+    let on_submit = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+        errors.set(form.with(|form| step.errors(form)));
+        if !errors.read().is_empty() {
+            return;
+        }
+        match step.next() {
+            Some(next) => navigate(&next.path(), Default::default()),
+            // imagine that this sends everything to the server
+            None => set_finished.set(true),
+        }
+    };
+
+    view! {
+        <form on:submit=on_submit>
+            <h3>{step.title()}</h3>
+            {children()}
+            <ul class="errors" role="alert">
+                {move || errors.get().into_iter().map(|e| view! { <li>{e}</li> }).collect_view()}
+            </ul>
+            {step.previous().map(|previous| view! { <A href=previous.path()>"Back"</A> })}
+            <button type="submit">
+                {if step.next().is_some() { "Next" } else { "Create Account" }}
+            </button>
+        </form>
+        <Show when=move || finished.get()>
+            <p class="done">"Welcome, " {move || form.name().get()} "!"</p>
+        </Show>
+    }
+}
+// ANCHOR_END: step_form
+
+// ANCHOR: fields
+#[component]
+pub fn AccountFields() -> impl IntoView {
+    let form = expect_context::<Store<SignUp>>();
+    // each field reads and writes its own part of the store, so whatever was
+    // typed is still there when the user comes back to this step
+    view! {
+        <label>"Email" <input type="email" name="email" bind:value=form.email()/></label>
+        <label>
+            "Password"
+            <input type="password" name="password" bind:value=form.password()/>
+        </label>
+    }
+}
+
+#[component]
+pub fn ProfileFields() -> impl IntoView {
+    let form = expect_context::<Store<SignUp>>();
+    view! { <label>"Name" <input type="text" name="name" bind:value=form.name()/></label> }
+}
+
+#[component]
+pub fn ConfirmFields() -> impl IntoView {
+    let form = expect_context::<Store<SignUp>>();
+    view! {
+        <dl>
+            <dt>"Email"</dt>
+            <dd>{move || form.email().get()}</dd>
+            <dt>"Name"</dt>
+            <dd>{move || form.name().get()}</dd>
+        </dl>
+        <label>
+            <input type="checkbox" name="agreed" bind:checked=form.agreed()/>
+            "I agree to the terms"
+        </label>
+    }
+}
+// ANCHOR_END: fields
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filled_in() -> SignUp {
+        SignUp {
+            email: "ada@example.com".into(),
+            password: "correct horse".into(),
+            name: "Ada".into(),
+            agreed: true,
+        }
+    }
+
+    #[test]
+    fn steps_are_found_by_their_slug_and_in_order() {
+        assert_eq!("profile".parse(), Ok(Step::Profile));
+        assert_eq!("nowhere".parse::<Step>(), Err(()));
+        assert_eq!(Step::Account.previous(), None);
+        assert_eq!(Step::Account.next(), Some(Step::Profile));
+        assert_eq!(Step::Confirm.previous(), Some(Step::Profile));
+        assert_eq!(Step::Confirm.next(), None);
+        assert_eq!(Step::Confirm.path(), "/signup/confirm");
+    }
+
+    #[test]
+    fn each_step_only_checks_its_own_fields() {
+        let form = filled_in();
+        assert!(Step::ALL.iter().all(|step| step.errors(&form).is_empty()));
+
+        let form = SignUp {
+            password: "short".into(),
+            name: " ".into(),
+            ..filled_in()
+        };
+        assert_eq!(
+            Step::Account.errors(&form),
+            ["Choose a password of at least 8 characters."]
+        );
+        assert_eq!(Step::Profile.errors(&form), ["Enter your name."]);
+        assert!(Step::Confirm.errors(&form).is_empty());
+    }
+
+    #[test]
+    fn skipping_ahead_finds_the_first_incomplete_step() {
+        let empty = SignUp::default();
+        assert_eq!(first_incomplete(Step::Account, &empty), None);
+        assert_eq!(first_incomplete(Step::Confirm, &empty), Some(Step::Account));
+
+        let form = SignUp {
+            name: String::new(),
+            ..filled_in()
+        };
+        assert_eq!(first_incomplete(Step::Profile, &form), None);
+        assert_eq!(first_incomplete(Step::Confirm, &form), Some(Step::Profile));
+    }
+}
@@ -0,0 +1,12 @@
+pub mod c16_routes;
+pub mod c16_trailing_slashes;
+pub mod c17_nested_routing;
+pub mod c18_params_queries;
+pub mod c19_a;
+pub mod c20_form;
+#[cfg(feature = "split")]
+pub mod c21_lazy_routes;
+pub mod c22_route_guards;
+pub mod c23_hash_routing;
+pub mod c24_pagination;
+pub mod c25_wizard;
@@ -0,0 +1,140 @@
+//! Code for the encodings in "Server Functions".
+//!
+//! The SSR tests in `tests/ssr/tests/encodings.rs` call each server function
+//! with a request encoded the way the client would encode it, and check what
+//! comes back, chunk by chunk for the streaming ones.
+
+use futures::StreamExt;
+use leptos::{
+    prelude::*,
+    server_fn::codec::{
+        ByteStream, Cbor, MultipartData, MultipartFormData, Streaming, StreamingText, TextStream,
+    },
+    task::spawn_local,
+};
+use serde::{Deserialize, Serialize};
+use web_sys::FormData;
+
+// ANCHOR: cbor
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Settings {
+    pub theme: String,
+    pub tags: Vec<String>,
+    pub notifications: Notifications,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Notifications {
+    pub email: bool,
+    pub push: bool,
+}
+
+// the arguments are sent as CBOR, a binary format that handles nested structs
+// and `Vec`s, and the result comes back as JSON, as usual
+#[server(input = Cbor)]
+pub async fn save_settings(settings: Settings) -> Result<String, ServerFnError> {
+    Ok(format!(
+        "Saved {} tags for the {} theme",
+        settings.tags.len(),
+        settings.theme
+    ))
+}
+// ANCHOR_END: cbor
+
+// ANCHOR: multipart
+#[server(input = MultipartFormData)]
+pub async fn upload_size(data: MultipartData) -> Result<usize, ServerFnError> {
+    // on the server, the form arrives as a stream of fields, and each field's
+    // contents as a stream of chunks, so a large file never has to be in
+    // memory all at once
+    let mut data = data.into_inner().unwrap();
+    let mut size = 0;
+    while let Some(mut field) = data.next_field().await? {
+        while let Some(chunk) = field.chunk().await? {
+            size += chunk.len();
+        }
+    }
+    Ok(size)
+}
+
+#[component]
+pub fn Upload() -> impl IntoView {
+    // `FormData` is a JS object, which isn't `Send`, so this needs a local action
+    let upload = Action::new_local(|data: &FormData| upload_size(data.clone().into()));
+
+    view! {
+        <form on:submit:target=move |ev| {
+            ev.prevent_default();
+            let data = FormData::new_with_form(&ev.target()).unwrap();
+            upload.dispatch_local(data);
+        }>
+            <input type="file" name="file"/>
+            <input type="submit" value="Upload"/>
+        </form>
+        <p>
+            {move || match upload.value().get() {
+                Some(Ok(size)) => format!("Uploaded {size} bytes"),
+                Some(Err(e)) => e.to_string(),
+                None => "Pick a file to upload".to_string(),
+            }}
+        </p>
+    }
+}
+// ANCHOR_END: multipart
+
+// ANCHOR: text_stream
+// each line is sent as soon as it's ready, rather than all at once at the end
+#[server(output = StreamingText)]
+pub async fn count_to(to: u32) -> Result<TextStream, ServerFnError> {
+    let lines = futures::stream::iter(1..=to).then(|n| async move {
+        crate::r#async::sleep(std::time::Duration::from_millis(50)).await;
+        Ok(format!("{n}\n"))
+    });
+    Ok(TextStream::new(lines))
+}
+
+#[component]
+pub fn CountTo() -> impl IntoView {
+    let (lines, set_lines) = signal(String::new());
+
+    let count = move |_| {
+        set_lines.set(String::new());
+        spawn_local(async move {
+            match count_to(5).await {
+                // the stream yields each chunk as it arrives
+                Ok(stream) => {
+                    let mut stream = stream.into_inner();
+                    while let Some(Ok(line)) = stream.next().await {
+                        set_lines.write().push_str(&line);
+                    }
+                }
+                Err(e) => set_lines.set(e.to_string()),
+            }
+        });
+    };
+
+    view! {
+        <button on:click=count>"Count to five"</button>
+        <pre>{lines}</pre>
+    }
+}
+// ANCHOR_END: text_stream
+
+// ANCHOR: byte_stream
+#[server(output = Streaming)]
+pub async fn download(size: usize) -> Result<ByteStream, ServerFnError> {
+    // anything that can be turned into `Bytes` can be streamed
+    let chunks = (0..size)
+        .step_by(1024)
+        .map(move |start| vec![b'a'; 1024.min(size - start)]);
+    Ok(ByteStream::from(futures::stream::iter(chunks)))
+}
+// ANCHOR_END: byte_stream
+
+#[component]
+pub fn App() -> impl IntoView {
+    view! {
+        <Upload/>
+        <CountTo/>
+    }
+}
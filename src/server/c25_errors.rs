@@ -0,0 +1,156 @@
+//! Code for the custom error types in "Server Functions".
+//!
+//! The SSR tests in `tests/ssr/tests/server_fn_errors.rs` call [`withdraw`]
+//! over HTTP, and check the status code and the error the client would
+//! deserialize from the response, as well as the page that renders one.
+
+use leptos::{prelude::*, server_fn::codec::JsonEncoding};
+use leptos_router::{
+    components::{Route, Router, Routes},
+    path, SsrMode,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+// ANCHOR: error_type
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum BankError {
+    InsufficientFunds { balance: u32 },
+    InvalidAmount,
+    // the errors that can happen on the way to or from the server function,
+    // like a failed request or arguments that can't be deserialized
+    ServerFnError(ServerFnErrorErr),
+}
+
+impl FromServerFnError for BankError {
+    // how the error is sent to the client, and read back there
+    type Encoder = JsonEncoding;
+
+    fn from_server_fn_error(value: ServerFnErrorErr) -> Self {
+        BankError::ServerFnError(value)
+    }
+}
+
+// implementing `Error` lets it be rendered in an `<ErrorBoundary/>`
+impl std::error::Error for BankError {}
+
+impl fmt::Display for BankError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BankError::InsufficientFunds { balance } => write!(f, "You only have ${balance}."),
+            BankError::InvalidAmount => write!(f, "You can’t withdraw nothing."),
+            BankError::ServerFnError(e) => write!(f, "Something went wrong: {e}"),
+        }
+    }
+}
+// ANCHOR_END: error_type
+
+// ANCHOR: withdraw
+// the error type can be anything that implements `FromServerFnError`
+#[server]
+pub async fn withdraw(amount: u32) -> Result<u32, BankError> {
+    const BALANCE: u32 = 100;
+
+    let result = match amount {
+        0 => Err(BankError::InvalidAmount),
+        _ if amount > BALANCE => Err(BankError::InsufficientFunds { balance: BALANCE }),
+        _ => Ok(BALANCE - amount),
+    };
+
+    // an error is sent with a `500 Internal Server Error` status by default,
+    // but `ResponseOptions` can set a more specific one
+    #[cfg(feature = "axum")]
+    if let Err(e) = &result {
+        use axum::http::StatusCode;
+
+        let status = match e {
+            BankError::InsufficientFunds { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+            BankError::InvalidAmount => StatusCode::BAD_REQUEST,
+            BankError::ServerFnError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        expect_context::<leptos_axum::ResponseOptions>().set_status(status);
+    }
+
+    result
+}
+// ANCHOR_END: withdraw
+
+// ANCHOR: error_boundary
+#[component]
+pub fn Atm() -> impl IntoView {
+    let withdraw = ServerAction::<Withdraw>::new();
+
+    view! {
+        <ActionForm action=withdraw>
+            <input type="number" name="amount"/>
+            <input type="submit" value="Withdraw"/>
+        </ActionForm>
+        <ErrorBoundary fallback=|errors| view! { <ErrorList errors/> }>
+            {move || {
+                withdraw
+                    .value()
+                    .get()
+                    .map(|res| res.map(|balance| view! { <p>"You have $" {balance} " left."</p> }))
+            }}
+        </ErrorBoundary>
+    }
+}
+
+#[component]
+pub fn ErrorList(errors: ArcRwSignal<Errors>) -> impl IntoView {
+    view! {
+        <ul class="errors">
+            {move || {
+                errors
+                    .get()
+                    .into_iter()
+                    .map(|(_, e)| {
+                        // the boundary holds any kind of error, so downcast it
+                        // to get the `BankError` back
+                        let hint = match e.downcast_ref::<BankError>() {
+                            Some(BankError::InsufficientFunds { .. }) => "Try a smaller amount.",
+                            _ => "",
+                        };
+                        view! { <li>{e.to_string()} " " {hint}</li> }
+                    })
+                    .collect_view()
+            }}
+        </ul>
+    }
+}
+// ANCHOR_END: error_boundary
+
+// ANCHOR: overdraw
+#[component]
+pub fn Overdraw() -> impl IntoView {
+    // on the server, this calls `withdraw` directly, so the status code it
+    // sets is the status of the page
+    let overdraw = Resource::new(|| (), |_| withdraw(500));
+
+    view! {
+        <Suspense>
+            <ErrorBoundary fallback=|errors| view! { <ErrorList errors/> }>
+                {move || Suspend::new(async move {
+                    overdraw.await.map(|balance| view! { <p>"You have $" {balance} " left."</p> })
+                })}
+            </ErrorBoundary>
+        </Suspense>
+    }
+}
+// ANCHOR_END: overdraw
+
+// ANCHOR: app
+#[component]
+pub fn App() -> impl IntoView {
+    view! {
+        <Router>
+            <Routes fallback=|| "Not found.">
+                <Route path=path!("/") view=Atm/>
+                // the status code is only sent with the first chunk of the
+                // response, so wait for the resource before sending it
+                <Route path=path!("/overdraw") view=Overdraw ssr=SsrMode::Async/>
+            </Routes>
+        </Router>
+    }
+}
+// ANCHOR_END: app
@@ -0,0 +1,109 @@
+//! Code for the middleware in "Server Functions".
+//!
+//! Middleware is a `tower` layer around the server integration's own request
+//! and response types, so this is gated on `axum`. The SSR tests in
+//! `tests/ssr/tests/middleware.rs` check that each layer runs, and only for
+//! the server functions it's attached to.
+
+use axum::{
+    body::Body,
+    http::{Request, Response, StatusCode},
+};
+use leptos::prelude::*;
+use std::{
+    sync::Mutex,
+    task::{Context, Poll},
+};
+use tower::{Layer, Service};
+use tower_http::validate_request::ValidateRequestHeaderLayer;
+
+static LOG: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Returns every request [`LoggingLayer`] has seen.
+pub fn logged() -> Vec<String> {
+    LOG.lock().unwrap().clone()
+}
+
+// ANCHOR: logging_layer
+/// Logs the method and path of each request, before passing it on.
+#[derive(Clone)]
+pub struct LoggingLayer;
+
+impl<S> Layer<S> for LoggingLayer {
+    type Service = Logging<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        Logging { inner }
+    }
+}
+
+#[derive(Clone)]
+pub struct Logging<S> {
+    inner: S,
+}
+
+impl<S, B> Service<Request<B>> for Logging<S>
+where
+    S: Service<Request<B>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<B>) -> Self::Future {
+        LOG.lock()
+            .unwrap()
+            .push(format!("{} {}", req.method(), req.uri().path()));
+        self.inner.call(req)
+    }
+}
+// ANCHOR_END: logging_layer
+
+// ANCHOR: middleware
+// the layer wraps this server function, and only this one
+#[server]
+#[middleware(LoggingLayer)]
+pub async fn greet(name: String) -> Result<String, ServerFnError> {
+    Ok(format!("Hello, {name}!"))
+}
+// ANCHOR_END: middleware
+
+// ANCHOR: auth
+/// Rejects requests without the right API key, before they reach the server
+/// function.
+// returning the whole response is the signature `tower-http` expects
+#[allow(clippy::result_large_err)]
+fn require_api_key(req: &mut Request<Body>) -> Result<(), Response<Body>> {
+    match req.headers().get("x-api-key") {
+        Some(key) if key == "hunter2" => Ok(()),
+        _ => Err(Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::from("Missing or invalid API key"))
+            .unwrap()),
+    }
+}
+
+// any `tower` layer works, including the ones in `tower-http`
+#[server]
+#[middleware(ValidateRequestHeaderLayer::custom(require_api_key))]
+pub async fn secret() -> Result<String, ServerFnError> {
+    Ok("The cake is a lie.".to_string())
+}
+// ANCHOR_END: auth
+
+#[component]
+pub fn App() -> impl IntoView {
+    let greet = ServerAction::<Greet>::new();
+
+    view! {
+        <ActionForm action=greet>
+            <input type="text" name="name"/>
+            <input type="submit" value="Greet"/>
+        </ActionForm>
+        <p>{move || greet.value().get().map(|res| res.unwrap_or_else(|e| e.to_string()))}</p>
+    }
+}
@@ -0,0 +1,204 @@
+//! Code for "Server Functions".
+//!
+//! The server functions here compile in every mode: with the `ssr` feature
+//! their bodies run as written, and otherwise they're replaced with stubs
+//! that call the server. The "database" they use is a list in memory, which
+//! only exists on the server, so it's gated on `ssr`.
+
+use leptos::prelude::*;
+use leptos::server_fn::codec::GetUrl;
+use leptos::task::spawn_local;
+use serde::{Deserialize, Serialize};
+
+// ANCHOR: todo
+/// A todo, as it's sent between the server and the client.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Todo {
+    pub id: usize,
+    pub title: String,
+    pub completed: bool,
+}
+// ANCHOR_END: todo
+
+// ANCHOR: db
+// this module (and anything it uses) is only compiled into the server
+#[cfg(feature = "ssr")]
+pub mod db {
+    use super::Todo;
+    use std::sync::{Mutex, MutexGuard};
+
+    static TODOS: Mutex<Vec<Todo>> = Mutex::new(Vec::new());
+
+    /// Returns the todos, standing in for a connection to a real database.
+    pub fn todos() -> MutexGuard<'static, Vec<Todo>> {
+        TODOS.lock().unwrap()
+    }
+}
+// ANCHOR_END: db
+
+// ANCHOR: add_todo
+#[server]
+pub async fn add_todo(title: String) -> Result<usize, ServerFnError> {
+    // everything in here only runs on the server, so it can use `db`
+    let mut todos = db::todos();
+    let id = todos.len();
+    todos.push(Todo {
+        id,
+        title,
+        completed: false,
+    });
+    Ok(id)
+}
+// ANCHOR_END: add_todo
+
+// ANCHOR: busy_button
+#[component]
+pub fn BusyButton() -> impl IntoView {
+    view! {
+        <button on:click=move |_| {
+            spawn_local(async {
+                _ = add_todo("So much to do!".to_string()).await;
+            });
+        }>
+            "Add Todo"
+        </button>
+    }
+}
+// ANCHOR_END: busy_button
+
+// ANCHOR: get_todos
+// arguments and return values can be any types that implement
+// `Serialize` and `Deserialize`
+#[server]
+pub async fn get_todos(completed: Option<bool>) -> Result<Vec<Todo>, ServerFnError> {
+    Ok(db::todos()
+        .iter()
+        .filter(|todo| completed.is_none_or(|completed| todo.completed == completed))
+        .cloned()
+        .collect())
+}
+// ANCHOR_END: get_todos
+
+// ANCHOR: errors
+#[server]
+pub async fn toggle_todo(id: usize) -> Result<Todo, ServerFnError> {
+    let mut todos = db::todos();
+    // turn a missing todo into an error, and return it early with `?`
+    let todo = todos
+        .get_mut(id)
+        .ok_or_else(|| ServerFnError::new(format!("no todo with ID {id}")))?;
+    todo.completed = !todo.completed;
+    Ok(todo.clone())
+}
+
+#[server]
+pub async fn rename_todo(id: String, title: String) -> Result<Todo, ServerFnError> {
+    // any `std::error::Error`, like this `ParseIntError`, converts into a
+    // `ServerFnError`, so `?` works on those too
+    let id = id.parse::<usize>()?;
+    let mut todos = db::todos();
+    let todo = todos
+        .get_mut(id)
+        .ok_or_else(|| ServerFnError::new(format!("no todo with ID {id}")))?;
+    todo.title = title;
+    Ok(todo.clone())
+}
+// ANCHOR_END: errors
+
+// ANCHOR: prefix
+// handled at `/greetings/hello`, instead of a generated path under `/api`
+#[server(prefix = "/greetings", endpoint = "hello")]
+pub async fn hello(name: String) -> Result<String, ServerFnError> {
+    Ok(format!("Hello, {name}!"))
+}
+// ANCHOR_END: prefix
+
+// ANCHOR: encoding
+// sent as a `GET` request with the arguments in the query string
+#[server(input = GetUrl)]
+pub async fn count_todos() -> Result<usize, ServerFnError> {
+    Ok(db::todos().len())
+}
+// ANCHOR_END: encoding
+
+// ANCHOR: action
+#[component]
+pub fn AddTodo() -> impl IntoView {
+    // a server function is just an async function, so an action can call it
+    let add_todo = Action::new(|title: &String| add_todo(title.clone()));
+    let (title, set_title) = signal(String::new());
+
+    view! {
+        <input type="text" bind:value=(title, set_title)/>
+        <button on:click=move |_| {
+            add_todo.dispatch(title.get());
+        }>
+            "Add Todo"
+        </button>
+        <p>
+            {move || add_todo.pending().get().then_some("Saving...")}
+        </p>
+        // the action's value is the last `Result` it returned; errors
+        // (including network errors) go to the nearest <ErrorBoundary/>
+        <ErrorBoundary fallback=|errors| view! {
+            <p class="error">
+                {move || errors.get()
+                    .into_iter()
+                    .map(|(_, e)| e.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+                }
+            </p>
+        }>
+            {move || add_todo.value().get().map(|id| {
+                id.map(|id| view! { <p>"Added todo #" {id}</p> })
+            })}
+        </ErrorBoundary>
+    }
+}
+// ANCHOR_END: action
+
+/// A page with both ways of calling [`add_todo`].
+#[component]
+pub fn App() -> impl IntoView {
+    view! {
+        <BusyButton/>
+        <AddTodo/>
+    }
+}
+
+// ANCHOR: shell
+/// The HTML document that [`App`] is rendered into, on any server.
+pub fn shell(options: LeptosOptions) -> impl IntoView {
+    view! {
+        <!DOCTYPE html>
+        <html lang="en">
+            <head>
+                <meta charset="utf-8"/>
+                <HydrationScripts options/>
+            </head>
+            <body>
+                <App/>
+            </body>
+        </html>
+    }
+}
+// ANCHOR_END: shell
+
+// ANCHOR: axum_router
+/// Serves [`App`], and its server functions, with Axum.
+#[cfg(feature = "axum")]
+pub fn router(options: LeptosOptions) -> axum::Router {
+    use leptos_axum::{generate_route_list, LeptosRoutes};
+
+    let routes = generate_route_list(App);
+    axum::Router::new()
+        // registers a handler for every server function, as well as for the
+        // routes that render the app
+        .leptos_routes(&options, routes, {
+            let options = options.clone();
+            move || shell(options.clone())
+        })
+        .with_state(options)
+}
+// ANCHOR_END: axum_router
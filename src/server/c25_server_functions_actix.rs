@@ -0,0 +1,77 @@
+//! Code for "Server Functions", served with Actix.
+//!
+//! The server functions themselves are the ones in
+//! [`super::c25_server_functions`]: with the `actix` feature, `#[server]`
+//! generates Actix handlers for them instead of Axum ones. Only the way
+//! they're added to the server is different.
+
+use super::c25_server_functions::{shell, App};
+use actix_web::web::{self, ServiceConfig};
+use leptos::prelude::*;
+use leptos_actix::{generate_route_list, LeptosRoutes};
+
+// ANCHOR: routes
+/// Adds [`App`], and its server functions, to an Actix server.
+pub fn routes(cfg: &mut ServiceConfig, options: LeptosOptions) {
+    let routes = generate_route_list(App);
+    cfg
+        // registers a handler for every server function, as well as for the
+        // routes that render the app
+        .leptos_routes(routes, {
+            let options = options.clone();
+            move || shell(options.clone())
+        })
+        .app_data(web::Data::new(options));
+}
+// ANCHOR_END: routes
+
+// ANCHOR: main
+pub async fn serve(options: LeptosOptions) -> std::io::Result<()> {
+    let addr = options.site_addr;
+    actix_web::HttpServer::new(move || {
+        let options = options.clone();
+        actix_web::App::new().configure(move |cfg| routes(cfg, options))
+    })
+    .bind(addr)?
+    .run()
+    .await
+}
+// ANCHOR_END: main
+
+#[cfg(test)]
+mod tests {
+    use super::super::c25_server_functions::{AddTodo, ToggleTodo};
+    use super::*;
+    use actix_web::{http::header::ContentType, test};
+    use leptos::server_fn::ServerFn;
+
+    #[actix_web::test]
+    async fn server_functions_are_served_by_actix() {
+        let options = LeptosOptions::builder().output_name("todos").build();
+        let app =
+            test::init_service(actix_web::App::new().configure(|cfg| routes(cfg, options))).await;
+
+        let req = test::TestRequest::post()
+            .uri(AddTodo::PATH)
+            .insert_header(ContentType::form_url_encoded())
+            .set_payload("title=Walk+the+dog")
+            .to_request();
+        let id = test::call_and_read_body(&app, req).await;
+        let id = std::str::from_utf8(&id).unwrap();
+
+        let req = test::TestRequest::post()
+            .uri(ToggleTodo::PATH)
+            .insert_header(ContentType::form_url_encoded())
+            .set_payload(format!("id={id}"))
+            .to_request();
+        let todo = test::call_and_read_body(&app, req).await;
+        assert_eq!(
+            std::str::from_utf8(&todo).unwrap(),
+            format!(r#"{{"id":{id},"title":"Walk the dog","completed":true}}"#)
+        );
+
+        let req = test::TestRequest::get().uri("/").to_request();
+        let page = test::call_and_read_body(&app, req).await;
+        assert!(std::str::from_utf8(&page).unwrap().contains("Add Todo"));
+    }
+}
@@ -0,0 +1,198 @@
+//! Code for the SQLite todo app in "Server Functions".
+//!
+//! This needs a database, so it's only compiled with the `sqlite` feature,
+//! which also enables `axum`. The SSR tests in `tests/ssr/tests/sqlite.rs`
+//! serve [`router`] with an in-memory database, and go through every way of
+//! changing the list.
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "ssr")]
+use sqlx::SqlitePool;
+
+// ANCHOR: todo
+/// A row of the `todos` table.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+// only the server reads todos from the database
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+pub struct Todo {
+    pub id: i64,
+    pub title: String,
+    pub completed: bool,
+}
+// ANCHOR_END: todo
+
+// ANCHOR: connect
+/// Opens the database at `url`, creating the `todos` table if it isn't there.
+#[cfg(feature = "ssr")]
+pub async fn connect(url: &str) -> Result<SqlitePool, sqlx::Error> {
+    let pool = SqlitePool::connect(url).await?;
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS todos (
+            id INTEGER PRIMARY KEY,
+            title TEXT NOT NULL,
+            completed BOOLEAN NOT NULL DEFAULT FALSE
+        )",
+    )
+    .execute(&pool)
+    .await?;
+    Ok(pool)
+}
+// ANCHOR_END: connect
+
+// ANCHOR: server_fns
+#[server]
+pub async fn list_todos() -> Result<Vec<Todo>, ServerFnError> {
+    // the pool is provided as context by `router`, for every request
+    let pool = expect_context::<SqlitePool>();
+    let todos = sqlx::query_as("SELECT id, title, completed FROM todos ORDER BY id")
+        .fetch_all(&pool)
+        .await?;
+    Ok(todos)
+}
+
+#[server]
+pub async fn add_todo(title: String) -> Result<(), ServerFnError> {
+    // anyone can call a server function with anything, so check the input here
+    // rather than (only) in the form
+    let title = title.trim();
+    if title.is_empty() {
+        return Err(ServerFnError::new("A todo needs a title."));
+    }
+
+    let pool = expect_context::<SqlitePool>();
+    // the arguments are bound to the `?`s, rather than formatted into the SQL
+    sqlx::query("INSERT INTO todos (title) VALUES (?)")
+        .bind(title)
+        .execute(&pool)
+        .await?;
+    Ok(())
+}
+
+#[server]
+pub async fn toggle_todo(id: i64) -> Result<(), ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+    let result = sqlx::query("UPDATE todos SET completed = NOT completed WHERE id = ?")
+        .bind(id)
+        .execute(&pool)
+        .await?;
+    found(result.rows_affected())
+}
+
+#[server]
+pub async fn delete_todo(id: i64) -> Result<(), ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+    let result = sqlx::query("DELETE FROM todos WHERE id = ?")
+        .bind(id)
+        .execute(&pool)
+        .await?;
+    found(result.rows_affected())
+}
+// ANCHOR_END: server_fns
+
+/// Turns an update or delete that didn't match any rows into an error.
+#[cfg(feature = "ssr")]
+fn found(rows: u64) -> Result<(), ServerFnError> {
+    match rows {
+        0 => Err(ServerFnError::new("There's no todo with that ID.")),
+        _ => Ok(()),
+    }
+}
+
+// ANCHOR: todo_app
+#[component]
+pub fn TodoApp() -> impl IntoView {
+    let add = ServerAction::<AddTodo>::new();
+    let toggle = ServerAction::<ToggleTodo>::new();
+    let delete = ServerAction::<DeleteTodo>::new();
+
+    // loaded again after each change, whichever action made it
+    let todos = Resource::new(
+        move || {
+            (
+                add.version().get(),
+                toggle.version().get(),
+                delete.version().get(),
+            )
+        },
+        |_| list_todos(),
+    );
+
+    view! {
+        <ActionForm action=add>
+            <input type="text" name="title"/>
+            <input type="submit" value="Add"/>
+        </ActionForm>
+        <p class="error">{move || add.value().get().and_then(Result::err).map(|e| e.to_string())}</p>
+        <Transition fallback=|| view! { <p>"Loading..."</p> }>
+            <ul>
+                {move || Suspend::new(async move {
+                    todos
+                        .await
+                        .map(|todos| {
+                            todos
+                                .into_iter()
+                                .map(|todo| view! { <TodoItem todo toggle delete/> })
+                                .collect_view()
+                        })
+                })}
+            </ul>
+        </Transition>
+    }
+}
+
+#[component]
+pub fn TodoItem(
+    todo: Todo,
+    toggle: ServerAction<ToggleTodo>,
+    delete: ServerAction<DeleteTodo>,
+) -> impl IntoView {
+    view! {
+        <li class:completed=todo.completed>
+            {todo.title}
+            // each button is a form of its own, which sends the todo's ID
+            <ActionForm action=toggle>
+                <input type="hidden" name="id" value=todo.id/>
+                <input type="submit" value=if todo.completed { "Undo" } else { "Done" }/>
+            </ActionForm>
+            <ActionForm action=delete>
+                <input type="hidden" name="id" value=todo.id/>
+                <input type="submit" value="Delete"/>
+            </ActionForm>
+        </li>
+    }
+}
+// ANCHOR_END: todo_app
+
+// ANCHOR: router
+/// Serves [`TodoApp`] with Axum, using the database in `pool`.
+#[cfg(feature = "ssr")]
+pub fn router(options: LeptosOptions, pool: SqlitePool) -> axum::Router {
+    use leptos_axum::{generate_route_list, LeptosRoutes};
+
+    let routes = generate_route_list(TodoApp);
+    axum::Router::new()
+        // runs for every page and server function, so they can all use the pool
+        .leptos_routes_with_context(&options, routes, move || provide_context(pool.clone()), {
+            let options = options.clone();
+            move || shell(options.clone())
+        })
+        .with_state(options)
+}
+// ANCHOR_END: router
+
+/// The HTML document that [`TodoApp`] is rendered into.
+pub fn shell(options: LeptosOptions) -> impl IntoView {
+    view! {
+        <!DOCTYPE html>
+        <html lang="en">
+            <head>
+                <meta charset="utf-8"/>
+                <HydrationScripts options/>
+            </head>
+            <body>
+                <TodoApp/>
+            </body>
+        </html>
+    }
+}
@@ -0,0 +1,183 @@
+//! Code for the file uploads in "Server Functions".
+//!
+//! The SSR tests in `tests/ssr/tests/uploads.rs` upload files the way a
+//! browser would, check what ends up on disk, and follow the progress of an
+//! upload while it happens.
+
+use futures::StreamExt;
+use leptos::{
+    prelude::*,
+    server_fn::codec::{MultipartData, MultipartFormData, StreamingText, TextStream},
+    task::spawn_local,
+};
+use wasm_bindgen::JsCast;
+use web_sys::{File, FormData};
+
+/// The directory [`upload_file`] saves files in. Like everything else the
+/// server functions' bodies use, it's only compiled with `ssr`.
+#[cfg(feature = "ssr")]
+pub fn upload_dir() -> std::path::PathBuf {
+    std::env::temp_dir().join("leptos-book-uploads")
+}
+
+// ANCHOR: progress
+/// How many bytes of each file have been saved so far, and whether that's all
+/// of them.
+#[cfg(feature = "ssr")]
+mod progress {
+    use std::{collections::BTreeMap, sync::Mutex};
+    use tokio::sync::watch;
+
+    pub type Progress = (usize, bool);
+
+    static FILES: Mutex<BTreeMap<String, watch::Sender<Progress>>> = Mutex::new(BTreeMap::new());
+
+    /// The progress of the upload of `name`, whether it's started yet or not,
+    /// so that the client can ask for it before it starts uploading.
+    pub fn of(name: &str) -> watch::Sender<Progress> {
+        FILES
+            .lock()
+            .unwrap()
+            .entry(name.to_string())
+            .or_insert_with(|| watch::Sender::new((0, false)))
+            .clone()
+    }
+
+    /// An upload that's in progress, which is forgotten when it's dropped.
+    pub struct Upload {
+        name: String,
+        progress: watch::Sender<Progress>,
+    }
+
+    impl Upload {
+        pub fn start(name: &str) -> Self {
+            Upload {
+                name: name.to_string(),
+                progress: of(name),
+            }
+        }
+
+        pub fn saved(&self, bytes: usize, done: bool) {
+            self.progress.send_replace((bytes, done));
+        }
+    }
+
+    impl Drop for Upload {
+        // once it's finished (or failed), the next upload with the same name
+        // starts from nothing, rather than looking finished to anyone who
+        // asks for its progress before it starts; and if this one failed,
+        // dropping its last sender ends the streams that were following it
+        fn drop(&mut self) {
+            FILES.lock().unwrap().remove(&self.name);
+        }
+    }
+}
+// ANCHOR_END: progress
+
+// ANCHOR: upload
+#[server(input = MultipartFormData)]
+pub async fn upload_file(data: MultipartData) -> Result<usize, ServerFnError> {
+    use std::path::Path;
+    use tokio::{fs, io::AsyncWriteExt};
+
+    let mut data = data.into_inner().unwrap();
+    let mut saved = 0;
+    while let Some(mut field) = data.next_field().await? {
+        // the file name comes from the client, so only use the last part of
+        // it: otherwise a name like `../../.bashrc` would be saved outside
+        // the upload directory
+        let Some(name) = field
+            .file_name()
+            .and_then(|name| Path::new(name).file_name())
+            .and_then(|name| name.to_str())
+            .map(str::to_string)
+        else {
+            continue;
+        };
+
+        let upload = progress::Upload::start(&name);
+        fs::create_dir_all(upload_dir()).await?;
+        let mut file = fs::File::create(upload_dir().join(&name)).await?;
+
+        // each chunk is written to disk as soon as it arrives, so the file
+        // is never in memory all at once
+        let mut written = 0;
+        while let Some(chunk) = field.chunk().await? {
+            file.write_all(&chunk).await?;
+            written += chunk.len();
+            upload.saved(written, false);
+        }
+        file.flush().await?;
+
+        upload.saved(written, true);
+        saved += written;
+    }
+    Ok(saved)
+}
+
+// a separate server function, which streams the progress of the upload back
+// to the client while it's still sending the file
+#[server(output = StreamingText)]
+pub async fn upload_progress(name: String) -> Result<TextStream, ServerFnError> {
+    let mut progress = progress::of(&name).subscribe();
+    // so that the progress so far is sent straight away
+    progress.mark_changed();
+
+    let lines = futures::stream::unfold(Some(progress), |progress| async move {
+        let mut progress = progress?;
+        progress.changed().await.ok()?;
+        let (bytes, done) = *progress.borrow_and_update();
+        // the stream ends after the last chunk is saved
+        Some((Ok(format!("{bytes}\n")), (!done).then_some(progress)))
+    });
+    Ok(TextStream::new(lines))
+}
+// ANCHOR_END: upload
+
+// ANCHOR: file_upload
+#[component]
+pub fn FileUpload() -> impl IntoView {
+    let (size, set_size) = signal(0.0);
+    let (uploaded, set_uploaded) = signal(0_usize);
+    let upload = Action::new_local(|data: &FormData| upload_file(data.clone().into()));
+
+    view! {
+        <form on:submit:target=move |ev| {
+            ev.prevent_default();
+            let data = FormData::new_with_form(&ev.target()).unwrap();
+            let Ok(file) = data.get("file").dyn_into::<File>() else {
+                return;
+            };
+            set_size.set(file.size());
+            set_uploaded.set(0);
+
+            // follow the progress of the upload...
+            let name = file.name();
+            spawn_local(async move {
+                if let Ok(lines) = upload_progress(name).await {
+                    let mut lines = lines.into_inner();
+                    while let Some(Ok(chunk)) = lines.next().await {
+                        // lines that arrive close together can share a chunk
+                        if let Some(bytes) = chunk.lines().last().and_then(|line| line.parse().ok()) {
+                            set_uploaded.set(bytes);
+                        }
+                    }
+                }
+            });
+            // ...while it happens
+            upload.dispatch_local(data);
+        }>
+            <input type="file" name="file"/>
+            <input type="submit" value="Upload"/>
+        </form>
+        <progress max=size value=move || uploaded.get() as f64></progress>
+        <p>
+            {move || match upload.value().get() {
+                Some(Ok(bytes)) => format!("Saved {bytes} bytes"),
+                Some(Err(e)) => e.to_string(),
+                None => format!("{} of {} bytes", uploaded.get(), size.get()),
+            }}
+        </p>
+    }
+}
+// ANCHOR_END: file_upload
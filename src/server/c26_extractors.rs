@@ -0,0 +1,94 @@
+//! Code for "Extractors".
+//!
+//! These use `leptos_axum`, so this module is only compiled with the `axum`
+//! feature. The same examples for Actix are in
+//! `c26_extractors_actix`, which is compiled with `actix` instead.
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// The query string that [`axum_extract`] reads.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Search {
+    pub q: String,
+}
+
+// ANCHOR: axum_extract
+#[server]
+pub async fn axum_extract() -> Result<String, ServerFnError> {
+    use axum::{extract::Query, http::Method};
+    use leptos_axum::extract;
+
+    // the type of each extractor tells `extract` what to pull out of the request
+    let method: Method = extract().await?;
+    let Query(search): Query<Search> = extract().await?;
+
+    Ok(format!("{method:?} and {}", search.q))
+}
+// ANCHOR_END: axum_extract
+
+// ANCHOR: headers
+#[server]
+pub async fn user_agent() -> Result<String, ServerFnError> {
+    use axum::http::{header, HeaderMap};
+    use leptos_axum::extract;
+
+    let headers: HeaderMap = extract().await?;
+    let user_agent = headers
+        .get(header::USER_AGENT)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or("unknown");
+
+    Ok(user_agent.to_string())
+}
+// ANCHOR_END: headers
+
+// ANCHOR: connect_info
+#[server]
+pub async fn client_address() -> Result<String, ServerFnError> {
+    use axum::extract::ConnectInfo;
+    use leptos_axum::extract;
+    use std::net::SocketAddr;
+
+    // this is only available if the server is started with
+    // `.into_make_service_with_connect_info::<SocketAddr>()`
+    let ConnectInfo(addr): ConnectInfo<SocketAddr> = extract().await?;
+
+    Ok(addr.ip().to_string())
+}
+// ANCHOR_END: connect_info
+
+// ANCHOR: custom_extractor
+/// An API key sent in the `x-api-key` header.
+#[cfg(feature = "ssr")]
+pub struct ApiKey(pub String);
+
+#[cfg(feature = "ssr")]
+impl<S: Send + Sync> axum::extract::FromRequestParts<S> for ApiKey {
+    type Rejection = (axum::http::StatusCode, &'static str);
+
+    async fn from_request_parts(
+        parts: &mut axum::http::request::Parts,
+        _state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        parts
+            .headers
+            .get("x-api-key")
+            .and_then(|value| value.to_str().ok())
+            .map(|key| ApiKey(key.to_string()))
+            .ok_or((axum::http::StatusCode::UNAUTHORIZED, "missing API key"))
+    }
+}
+// ANCHOR_END: custom_extractor
+
+// ANCHOR: custom
+#[server]
+pub async fn secret_count() -> Result<usize, ServerFnError> {
+    // a custom extractor works the same way as the built-in ones
+    let ApiKey(key) = leptos_axum::extract().await?;
+    if key != "hunter2" {
+        return Err(ServerFnError::new("wrong API key"));
+    }
+    Ok(42)
+}
+// ANCHOR_END: custom
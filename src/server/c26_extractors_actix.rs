@@ -0,0 +1,144 @@
+//! Code for "Extractors", with Actix.
+//!
+//! These are the examples in `c26_extractors`, using `leptos_actix`, so this
+//! module is only compiled with the `actix` feature.
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// The query string that [`actix_extract`] reads.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Search {
+    pub q: String,
+}
+
+// ANCHOR: actix_extract
+#[server]
+pub async fn actix_extract() -> Result<String, ServerFnError> {
+    use actix_web::{dev::ConnectionInfo, web::Query};
+    use leptos_actix::extract;
+
+    // the type of each extractor tells `extract` what to pull out of the request
+    let Query(search): Query<Search> = extract().await?;
+    let connection: ConnectionInfo = extract().await?;
+
+    Ok(format!(
+        "search = {}\nconnection = {connection:?}",
+        search.q
+    ))
+}
+// ANCHOR_END: actix_extract
+
+// ANCHOR: headers
+#[server]
+pub async fn user_agent() -> Result<String, ServerFnError> {
+    use actix_web::{http::header, HttpRequest};
+    use leptos_actix::extract;
+
+    // the request itself gives you access to the headers
+    let req: HttpRequest = extract().await?;
+    let user_agent = req
+        .headers()
+        .get(header::USER_AGENT)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or("unknown");
+
+    Ok(user_agent.to_string())
+}
+// ANCHOR_END: headers
+
+// ANCHOR: connect_info
+#[server]
+pub async fn client_address() -> Result<String, ServerFnError> {
+    use actix_web::dev::ConnectionInfo;
+    use leptos_actix::extract;
+
+    // unlike Axum's `ConnectInfo`, this is always available
+    let connection: ConnectionInfo = extract().await?;
+
+    Ok(connection.peer_addr().unwrap_or("unknown").to_string())
+}
+// ANCHOR_END: connect_info
+
+// ANCHOR: custom_extractor
+/// An API key sent in the `x-api-key` header.
+#[cfg(feature = "ssr")]
+pub struct ApiKey(pub String);
+
+#[cfg(feature = "ssr")]
+impl actix_web::FromRequest for ApiKey {
+    type Error = actix_web::Error;
+    type Future = std::future::Ready<Result<Self, Self::Error>>;
+
+    fn from_request(
+        req: &actix_web::HttpRequest,
+        _payload: &mut actix_web::dev::Payload,
+    ) -> Self::Future {
+        let key = req
+            .headers()
+            .get("x-api-key")
+            .and_then(|value| value.to_str().ok())
+            .map(|key| ApiKey(key.to_string()))
+            .ok_or_else(|| actix_web::error::ErrorUnauthorized("missing API key"));
+        std::future::ready(key)
+    }
+}
+// ANCHOR_END: custom_extractor
+
+#[server]
+pub async fn secret_count() -> Result<usize, ServerFnError> {
+    // a custom extractor works the same way as the built-in ones
+    let ApiKey(key) = leptos_actix::extract().await?;
+    if key != "hunter2" {
+        return Err(ServerFnError::new("wrong API key"));
+    }
+    Ok(42)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::c25_server_functions_actix::routes;
+    use super::*;
+    use actix_web::{http::header, test, App};
+    use leptos::server_fn::ServerFn;
+
+    /// Calls a server function with no arguments, returning its response.
+    async fn call(req: test::TestRequest) -> (bool, String) {
+        let options = LeptosOptions::builder().output_name("todos").build();
+        let app = test::init_service(App::new().configure(|cfg| routes(cfg, options))).await;
+        let res = test::call_service(&app, req.to_request()).await;
+        let ok = res.status().is_success();
+        let body = test::read_body(res).await;
+        (ok, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    fn post(path: &str) -> test::TestRequest {
+        test::TestRequest::post()
+            .uri(path)
+            .insert_header((header::CONTENT_TYPE, "application/x-www-form-urlencoded"))
+    }
+
+    #[actix_web::test]
+    async fn extractors_read_the_request() {
+        let (_, body) = call(post(&format!("{}?q=leptos", ActixExtract::PATH))).await;
+        assert!(
+            body.starts_with(r#""search = leptos\nconnection = "#),
+            "{body}"
+        );
+
+        let req = post(UserAgent::PATH).insert_header((header::USER_AGENT, "book-tests"));
+        assert_eq!(call(req).await, (true, r#""book-tests""#.to_string()));
+
+        let req = post(ClientAddress::PATH).peer_addr(([127, 0, 0, 1], 3000).into());
+        assert_eq!(call(req).await, (true, r#""127.0.0.1""#.to_string()));
+    }
+
+    #[actix_web::test]
+    async fn custom_extractors_can_reject_requests() {
+        let (ok, body) = call(post(SecretCount::PATH)).await;
+        assert!(!ok && body.contains("missing API key"), "{body}");
+
+        let req = post(SecretCount::PATH).insert_header(("x-api-key", "hunter2"));
+        assert_eq!(call(req).await, (true, "42".to_string()));
+    }
+}
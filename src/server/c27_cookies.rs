@@ -0,0 +1,98 @@
+//! Code for the cookies in "Responses and Redirects".
+//!
+//! Like the rest of the chapter, this uses `leptos_axum`, so it's only
+//! compiled with the `axum` feature. The SSR tests in
+//! `tests/ssr/tests/cookies.rs` check the `Set-Cookie` headers, and send the
+//! cookies back the way a browser would.
+
+use leptos::prelude::*;
+
+// ANCHOR: set_cookie
+#[server]
+pub async fn set_theme(theme: String) -> Result<(), ServerFnError> {
+    use axum::http::{header, HeaderValue};
+    use cookie::{time::Duration, Cookie, SameSite};
+    use leptos_axum::ResponseOptions;
+
+    let cookie = Cookie::build(("theme", theme))
+        // sent with requests for every page, not only to this server function
+        .path("/")
+        // can't be read by JavaScript in the page
+        .http_only(true)
+        // only sent over HTTPS (browsers make an exception for `localhost`)
+        .secure(true)
+        // not sent with requests from other sites, unless the user follows a
+        // link to this one
+        .same_site(SameSite::Lax)
+        // kept for a year, rather than until the browser is closed
+        .max_age(Duration::days(365))
+        .build();
+
+    // `append_header`, rather than `insert_header`, so that setting more
+    // than one cookie doesn't overwrite the others
+    expect_context::<ResponseOptions>().append_header(
+        header::SET_COOKIE,
+        HeaderValue::from_str(&cookie.to_string())?,
+    );
+    Ok(())
+}
+// ANCHOR_END: set_cookie
+
+// ANCHOR: clear_cookie
+#[server]
+pub async fn clear_theme() -> Result<(), ServerFnError> {
+    use axum::http::{header, HeaderValue};
+    use cookie::Cookie;
+    use leptos_axum::ResponseOptions;
+
+    // a cookie is removed by replacing it with one that has already expired,
+    // with the same name and path
+    let cookie = Cookie::build("theme").path("/").removal().build();
+    expect_context::<ResponseOptions>().append_header(
+        header::SET_COOKIE,
+        HeaderValue::from_str(&cookie.to_string())?,
+    );
+    Ok(())
+}
+// ANCHOR_END: clear_cookie
+
+// ANCHOR: read_cookie
+#[server]
+pub async fn get_theme() -> Result<String, ServerFnError> {
+    use axum::http::{header, HeaderMap};
+    use cookie::Cookie;
+
+    // the browser sends all of the cookies for the page in `Cookie` headers
+    let headers: HeaderMap = leptos_axum::extract().await?;
+    let theme = headers
+        .get_all(header::COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(Cookie::split_parse)
+        .filter_map(Result::ok)
+        .find(|cookie| cookie.name() == "theme")
+        .map(|cookie| cookie.value().to_string());
+    Ok(theme.unwrap_or_else(|| "light".to_string()))
+}
+// ANCHOR_END: read_cookie
+
+// ANCHOR: app
+#[component]
+pub fn App() -> impl IntoView {
+    let set_theme = ServerAction::<SetTheme>::new();
+    // refetched whenever the theme is set, and, on the server, read from the
+    // cookies of the request for the page
+    let theme = Resource::new(move || set_theme.version().get(), |_| get_theme());
+
+    view! {
+        <Suspense>
+            <main data-theme=move || theme.get().and_then(Result::ok)>
+                <ActionForm action=set_theme>
+                    <button name="theme" value="light">"Light"</button>
+                    <button name="theme" value="dark">"Dark"</button>
+                </ActionForm>
+            </main>
+        </Suspense>
+    }
+}
+// ANCHOR_END: app
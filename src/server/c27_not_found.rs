@@ -0,0 +1,116 @@
+//! Code for the "Not Found" section of "Responses and Redirects".
+//!
+//! The fallback handler is part of `leptos_axum`, so this is only compiled
+//! with the `axum` feature. The SSR tests in `tests/ssr/tests/not_found.rs`
+//! check the status of the response for paths without a route, and for a
+//! post that doesn't exist.
+
+use super::c27_response::NotFound;
+use crate::r#async::sleep;
+use leptos::prelude::*;
+use leptos_router::{
+    components::{Route, Router, Routes},
+    hooks::use_params_map,
+    path, SsrMode,
+};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Post {
+    pub title: String,
+    pub body: String,
+}
+
+#[server]
+pub async fn get_post(id: String) -> Result<Option<Post>, ServerFnError> {
+    // imagine that this is a database
+    sleep(Duration::from_millis(10)).await;
+    Ok((id == "1").then(|| Post {
+        title: "Hello, World!".to_string(),
+        body: "This is the only post.".to_string(),
+    }))
+}
+
+// ANCHOR: app
+#[component]
+pub fn App() -> impl IntoView {
+    view! {
+        <Router>
+            // shown for any path that doesn't match a route
+            <Routes fallback=NotFound>
+                <Route path=path!("/") view=|| view! { <h1>"Home"</h1> }/>
+                // waits for the post before it sends anything, so that the
+                // status can still be changed once it knows there isn't one
+                <Route path=path!("/posts/:id") view=PostPage ssr=SsrMode::Async/>
+            </Routes>
+        </Router>
+    }
+}
+// ANCHOR_END: app
+
+// ANCHOR: post_page
+#[component]
+pub fn PostPage() -> impl IntoView {
+    let params = use_params_map();
+    let post = Resource::new(
+        move || params.read().get("id").unwrap_or_default(),
+        get_post,
+    );
+
+    view! {
+        <Suspense fallback=|| view! { <p>"Loading..."</p> }>
+            {move || Suspend::new(async move {
+                match post.await {
+                    Ok(Some(post)) => {
+                        view! {
+                            <h1>{post.title}</h1>
+                            <article>{post.body}</article>
+                        }
+                            .into_any()
+                    }
+                    // the path matched a route, but there's nothing there
+                    Ok(None) => view! { <NotFound/> }.into_any(),
+                    Err(e) => view! { <p>{e.to_string()}</p> }.into_any(),
+                }
+            })}
+        </Suspense>
+    }
+}
+// ANCHOR_END: post_page
+
+/// The HTML document that [`App`] is rendered into.
+pub fn shell(options: LeptosOptions) -> impl IntoView {
+    view! {
+        <!DOCTYPE html>
+        <html lang="en">
+            <head>
+                <meta charset="utf-8"/>
+                <HydrationScripts options/>
+            </head>
+            <body>
+                <App/>
+            </body>
+        </html>
+    }
+}
+
+// ANCHOR: router
+/// Serves [`App`] with Axum, rendering the `<Routes/>` fallback for any other
+/// path.
+pub fn router(options: LeptosOptions) -> axum::Router {
+    use leptos_axum::{file_and_error_handler, generate_route_list, LeptosRoutes};
+
+    let routes = generate_route_list(App);
+    axum::Router::new()
+        // only handles the paths that match a `<Route/>`...
+        .leptos_routes(&options, routes, {
+            let options = options.clone();
+            move || shell(options.clone())
+        })
+        // ...so everything else is sent here, which serves the file at that
+        // path if there is one, or renders the app otherwise
+        .fallback(file_and_error_handler(shell))
+        .with_state(options)
+}
+// ANCHOR_END: router
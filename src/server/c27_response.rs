@@ -0,0 +1,93 @@
+//! Code for "Responses and Redirects".
+//!
+//! This module uses `leptos_axum`, so it's only compiled with the `axum`
+//! feature. The Actix versions are in `c27_response_actix`.
+
+use leptos::prelude::*;
+use leptos::server_fn::codec::{StreamingText, TextStream};
+use leptos_router::{
+    components::{Route, Router, Routes},
+    path,
+};
+
+// ANCHOR: tea_and_cookies
+#[server]
+pub async fn tea_and_cookies() -> Result<(), ServerFnError> {
+    use axum::http::{header, HeaderValue, StatusCode};
+    use leptos_axum::ResponseOptions;
+
+    // pull ResponseOptions from context
+    let response = expect_context::<ResponseOptions>();
+
+    // set the HTTP status code
+    response.set_status(StatusCode::IM_A_TEAPOT);
+
+    // set a cookie in the HTTP response
+    response.insert_header(header::SET_COOKIE, HeaderValue::from_static("biscuits=yes"));
+
+    Ok(())
+}
+// ANCHOR_END: tea_and_cookies
+
+// ANCHOR: redirect
+#[server]
+pub async fn login(username: String, password: String) -> Result<(), ServerFnError> {
+    // imagine that this checks the password against a database
+    if username == "alice" && password == "hunter2" {
+        // redirect to the home page
+        leptos_axum::redirect("/");
+        Ok(())
+    } else {
+        Err(ServerFnError::new("Password does not match."))
+    }
+}
+// ANCHOR_END: redirect
+
+// ANCHOR: content_type
+// `StreamingText` sends the body as it is, rather than encoding it as JSON
+#[server(output = StreamingText)]
+pub async fn export_todos() -> Result<TextStream, ServerFnError> {
+    use axum::http::{header, HeaderValue};
+    use leptos_axum::ResponseOptions;
+
+    let response = expect_context::<ResponseOptions>();
+    // replaces the default `text/plain`
+    response.insert_header(header::CONTENT_TYPE, HeaderValue::from_static("text/csv"));
+    response.insert_header(
+        header::CONTENT_DISPOSITION,
+        HeaderValue::from_static(r#"attachment; filename="todos.csv""#),
+    );
+
+    let csv = "id,title,completed\n0,Buy milk,false\n";
+    Ok(TextStream::from(futures::stream::once(async move { csv })))
+}
+// ANCHOR_END: content_type
+
+// ANCHOR: not_found
+#[component]
+pub fn NotFound() -> impl IntoView {
+    // ResponseOptions is also provided while rendering a page on the server,
+    // so components can set the status code of the response
+    #[cfg(feature = "ssr")]
+    {
+        let response = expect_context::<leptos_axum::ResponseOptions>();
+        response.set_status(axum::http::StatusCode::NOT_FOUND);
+    }
+
+    view! { <h1>"Not Found"</h1> }
+}
+// ANCHOR_END: not_found
+
+/// Routes to a home page, with [`NotFound`] for every other path.
+#[component]
+pub fn App() -> impl IntoView {
+    view! {
+        <Router>
+            <Routes fallback=NotFound>
+                <Route path=path!("/") view=|| view! { <h1>"Home"</h1> }/>
+                // the server only renders the paths it has routes for
+                <Route path=path!("/*any") view=NotFound/>
+            </Routes>
+        </Router>
+    }
+}
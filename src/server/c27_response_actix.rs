@@ -0,0 +1,95 @@
+//! Code for "Responses and Redirects", with Actix.
+//!
+//! This module uses `leptos_actix`, so it's only compiled with the `actix`
+//! feature.
+
+use leptos::prelude::*;
+
+// ANCHOR: tea_and_cookies
+#[server]
+pub async fn tea_and_cookies() -> Result<(), ServerFnError> {
+    use actix_web::{
+        cookie::Cookie,
+        http::{header, header::HeaderValue, StatusCode},
+    };
+    use leptos_actix::ResponseOptions;
+
+    // pull ResponseOptions from context
+    let response = expect_context::<ResponseOptions>();
+
+    // set the HTTP status code
+    response.set_status(StatusCode::IM_A_TEAPOT);
+
+    // set a cookie in the HTTP response
+    let cookie = Cookie::build("biscuits", "yes").finish();
+    if let Ok(cookie) = HeaderValue::from_str(&cookie.to_string()) {
+        response.insert_header(header::SET_COOKIE, cookie);
+    }
+
+    Ok(())
+}
+// ANCHOR_END: tea_and_cookies
+
+// ANCHOR: redirect
+#[server]
+pub async fn login(username: String, password: String) -> Result<(), ServerFnError> {
+    // imagine that this checks the password against a database
+    if username == "alice" && password == "hunter2" {
+        // redirect to the home page
+        leptos_actix::redirect("/");
+        Ok(())
+    } else {
+        Err(ServerFnError::new("Password does not match."))
+    }
+}
+// ANCHOR_END: redirect
+
+#[cfg(test)]
+mod tests {
+    use super::super::c25_server_functions_actix::routes;
+    use super::*;
+    use actix_web::{
+        dev::ServiceResponse,
+        http::{header, StatusCode},
+        test, App,
+    };
+    use leptos::server_fn::ServerFn;
+
+    async fn call(path: &str, form: &'static str, accept: &str) -> ServiceResponse {
+        let options = LeptosOptions::builder().output_name("todos").build();
+        let app = test::init_service(App::new().configure(|cfg| routes(cfg, options))).await;
+        let req = test::TestRequest::post()
+            .uri(path)
+            .insert_header((header::CONTENT_TYPE, "application/x-www-form-urlencoded"))
+            .insert_header((header::ACCEPT, accept))
+            .set_payload(form)
+            .to_request();
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn response_options_set_the_status_and_headers() {
+        let res = call(TeaAndCookies::PATH, "", "*/*").await;
+        assert_eq!(res.status(), StatusCode::IM_A_TEAPOT);
+        assert_eq!(
+            res.headers().get(header::SET_COOKIE).unwrap(),
+            "biscuits=yes"
+        );
+    }
+
+    #[actix_web::test]
+    async fn redirects_are_followed_by_the_browser_or_the_client() {
+        let form = "username=alice&password=hunter2";
+
+        // a plain <form> gets a real redirect...
+        let res = call(Login::PATH, form, "text/html").await;
+        assert_eq!(res.status(), StatusCode::FOUND);
+        assert_eq!(res.headers().get(header::LOCATION).unwrap(), "/");
+
+        // ...while the server function client is told where to navigate
+        let res = call(Login::PATH, form, "application/json").await;
+        assert!(res.status().is_success());
+        assert_eq!(res.headers().get(header::LOCATION).unwrap(), "/");
+        assert!(res.headers().contains_key("serverfnredirect"));
+    }
+}
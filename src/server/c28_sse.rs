@@ -0,0 +1,105 @@
+//! Code for the server-sent events in "Real-time with WebSockets".
+//!
+//! The server half is gated on `axum`. The SSR tests in
+//! `tests/ssr/tests/sse.rs` read the event stream chunk by chunk; the
+//! browser half only subscribes in the browser, so it isn't run by the tests.
+
+use leptos::prelude::*;
+use send_wrapper::SendWrapper;
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::{EventSource, MessageEvent};
+
+// ANCHOR: countdown
+#[component]
+pub fn Countdown() -> impl IntoView {
+    let (count, set_count) = signal(None::<String>);
+
+    // like a WebSocket, an `EventSource` can only be created in the browser
+    Effect::new(move || {
+        let events = EventSource::new("/countdown").unwrap();
+
+        // each `data:` is a `message` event...
+        let on_message = Closure::<dyn FnMut(_)>::new(move |ev: MessageEvent| {
+            set_count.set(ev.data().as_string());
+        });
+        events.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+        on_message.forget();
+
+        // ...unless it's given a name of its own. The browser reconnects
+        // whenever the stream ends, so close it once the last event arrives
+        let on_liftoff = Closure::<dyn FnMut(_)>::new({
+            let events = events.clone();
+            move |ev: MessageEvent| {
+                set_count.set(ev.data().as_string());
+                events.close();
+            }
+        });
+        events
+            .add_event_listener_with_callback("liftoff", on_liftoff.as_ref().unchecked_ref())
+            .unwrap();
+        on_liftoff.forget();
+
+        let events = SendWrapper::new(events);
+        on_cleanup(move || events.close());
+    });
+
+    view! { <p class="countdown">{move || count.get().unwrap_or_else(|| "Waiting...".to_string())}</p> }
+}
+// ANCHOR_END: countdown
+
+// ANCHOR: handler
+/// Counts down from 3, and then lifts off.
+#[cfg(feature = "axum")]
+pub async fn countdown() -> axum::response::Sse<
+    impl futures::Stream<Item = Result<axum::response::sse::Event, std::convert::Infallible>>,
+> {
+    use axum::response::sse::{Event, Sse};
+    use futures::StreamExt;
+    use std::time::Duration;
+
+    let events = futures::stream::iter(["3", "2", "1"])
+        .map(|n| Event::default().data(n))
+        .chain(futures::stream::once(async {
+            Event::default().event("liftoff").data("Liftoff!")
+        }))
+        .then(|event| async move {
+            crate::r#async::sleep(Duration::from_millis(100)).await;
+            Ok(event)
+        });
+    Sse::new(events)
+}
+// ANCHOR_END: handler
+
+// ANCHOR: router
+/// Serves [`Countdown`], and the events it subscribes to, with Axum.
+#[cfg(feature = "axum")]
+pub fn router(options: LeptosOptions) -> axum::Router {
+    use axum::routing::get;
+    use leptos_axum::{generate_route_list, LeptosRoutes};
+
+    let routes = generate_route_list(Countdown);
+    axum::Router::new()
+        .route("/countdown", get(countdown))
+        .leptos_routes(&options, routes, {
+            let options = options.clone();
+            move || shell(options.clone())
+        })
+        .with_state(options)
+}
+// ANCHOR_END: router
+
+/// The HTML document that [`Countdown`] is rendered into.
+pub fn shell(options: LeptosOptions) -> impl IntoView {
+    view! {
+        <!DOCTYPE html>
+        <html lang="en">
+            <head>
+                <meta charset="utf-8"/>
+                <HydrationScripts options/>
+            </head>
+            <body>
+                <Countdown/>
+            </body>
+        </html>
+    }
+}
@@ -0,0 +1,161 @@
+//! Code for "Real-time with WebSockets".
+//!
+//! The server half is gated on `axum`. The SSR tests in
+//! `tests/ssr/tests/websockets.rs` serve [`router`] on a local port and chat
+//! through it with two clients; the browser half only connects in the
+//! browser, so it's compiled in every mode but not run by the tests.
+
+use leptos::prelude::*;
+use send_wrapper::SendWrapper;
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::{MessageEvent, WebSocket};
+
+// ANCHOR: chat
+#[component]
+pub fn Chat() -> impl IntoView {
+    let (messages, set_messages) = signal(Vec::<String>::new());
+    let (draft, set_draft) = signal(String::new());
+    // a `WebSocket` is a JS object, which isn't `Send`, so it's stored locally
+    let socket = StoredValue::new_local(None::<WebSocket>);
+
+    // effects only run in the browser, which is the only place to connect from
+    Effect::new(move || {
+        let location = window().location();
+        let scheme = if location.protocol().unwrap() == "https:" {
+            "wss"
+        } else {
+            "ws"
+        };
+        let ws = WebSocket::new(&format!("{scheme}://{}/ws", location.host().unwrap())).unwrap();
+
+        // every message in the room arrives here, including our own
+        let on_message = Closure::<dyn FnMut(_)>::new(move |ev: MessageEvent| {
+            if let Some(text) = ev.data().as_string() {
+                set_messages.write().push(text);
+            }
+        });
+        ws.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+        on_message.forget();
+
+        // disconnect when the component is removed
+        let ws_to_close = SendWrapper::new(ws.clone());
+        on_cleanup(move || _ = ws_to_close.close());
+
+        socket.set_value(Some(ws));
+    });
+
+    let send = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+        socket.with_value(|ws| {
+            if let Some(ws) = ws {
+                _ = ws.send_with_str(&draft.get());
+            }
+        });
+        set_draft.set(String::new());
+    };
+
+    view! {
+        <ul class="messages">
+            <For each=move || messages.get().into_iter().enumerate() key=|(idx, _)| *idx let:message>
+                <li>{message.1}</li>
+            </For>
+        </ul>
+        <form on:submit=send>
+            <input type="text" bind:value=(draft, set_draft)/>
+            <input type="submit" value="Send"/>
+        </form>
+    }
+}
+// ANCHOR_END: chat
+
+// ANCHOR: room
+/// Everyone connected to the chat, listening for each other's messages.
+#[cfg(feature = "axum")]
+#[derive(Clone)]
+pub struct Room(tokio::sync::broadcast::Sender<String>);
+
+#[cfg(feature = "axum")]
+impl Default for Room {
+    fn default() -> Self {
+        // keeps up to 100 messages for anyone who's slow to read them
+        Self(tokio::sync::broadcast::channel(100).0)
+    }
+}
+// ANCHOR_END: room
+
+// ANCHOR: handler
+/// Upgrades a request to `/ws` to a WebSocket connection, and adds it to the
+/// [`Room`].
+#[cfg(feature = "axum")]
+pub async fn ws_handler(
+    ws: axum::extract::WebSocketUpgrade,
+    axum::extract::State(room): axum::extract::State<Room>,
+) -> axum::response::Response {
+    // subscribe before upgrading, so that the new client gets every message
+    // sent once it's connected
+    let messages = room.0.subscribe();
+    ws.on_upgrade(move |socket| chat(socket, room, messages))
+}
+
+#[cfg(feature = "axum")]
+async fn chat(
+    mut socket: axum::extract::ws::WebSocket,
+    room: Room,
+    mut messages: tokio::sync::broadcast::Receiver<String>,
+) {
+    use axum::extract::ws::Message;
+
+    loop {
+        tokio::select! {
+            // each message from this client is sent to everyone in the room...
+            msg = socket.recv() => match msg {
+                Some(Ok(Message::Text(text))) => _ = room.0.send(text.to_string()),
+                Some(Ok(_)) => {}
+                // ...until it disconnects
+                _ => break,
+            },
+            // ...and everyone's messages are sent to this client
+            Ok(text) = messages.recv() => {
+                if socket.send(Message::Text(text.into())).await.is_err() {
+                    break;
+                }
+            }
+        }
+    }
+}
+// ANCHOR_END: handler
+
+// ANCHOR: router
+/// Serves [`Chat`], and the WebSocket it connects to, with Axum.
+#[cfg(feature = "axum")]
+pub fn router(options: LeptosOptions) -> axum::Router {
+    use axum::routing::get;
+    use leptos_axum::{generate_route_list, LeptosRoutes};
+
+    let routes = generate_route_list(Chat);
+    axum::Router::new()
+        // the WebSocket is a route of its own, next to the app's
+        .route("/ws", get(ws_handler).with_state(Room::default()))
+        .leptos_routes(&options, routes, {
+            let options = options.clone();
+            move || shell(options.clone())
+        })
+        .with_state(options)
+}
+// ANCHOR_END: router
+
+/// The HTML document that [`Chat`] is rendered into.
+pub fn shell(options: LeptosOptions) -> impl IntoView {
+    view! {
+        <!DOCTYPE html>
+        <html lang="en">
+            <head>
+                <meta charset="utf-8"/>
+                <HydrationScripts options/>
+            </head>
+            <body>
+                <Chat/>
+            </body>
+        </html>
+    }
+}
@@ -0,0 +1,164 @@
+//! Code for "Sessions and Authentication".
+//!
+//! Sessions are kept by `tower-sessions`, a layer around the Axum router, so
+//! this is only compiled with the `axum` feature. The SSR tests in
+//! `tests/ssr/tests/sessions.rs` log in, send the session cookie back with
+//! each request the way a browser would, and log out again, and
+//! `tests/e2e/tests/sessions.rs` does the same in a real browser.
+
+use leptos::prelude::*;
+use leptos_router::{
+    components::{ProtectedRoute, Route, Router, Routes},
+    path, SsrMode,
+};
+use tower_sessions::Session;
+
+/// The key the logged-in user's name is stored under in the session.
+const USER: &str = "user";
+
+// ANCHOR: login
+#[server]
+pub async fn login(username: String, password: String) -> Result<(), ServerFnError> {
+    // imagine that this checks a hashed password in a database
+    if (username.as_str(), password.as_str()) != ("alice", "hunter2") {
+        return Err(ServerFnError::new("Wrong username or password."));
+    }
+
+    // the session for this request, from the layer in `router`
+    let session: Session = leptos_axum::extract().await?;
+    // a new ID for the session, so that an ID someone else saw before the
+    // user logged in can't be used to act as them afterwards
+    session.cycle_id().await?;
+    session.insert(USER, username).await?;
+
+    leptos_axum::redirect("/account");
+    Ok(())
+}
+// ANCHOR_END: login
+
+// ANCHOR: current_user
+#[server]
+pub async fn current_user() -> Result<Option<String>, ServerFnError> {
+    let session: Session = leptos_axum::extract().await?;
+    Ok(session.get(USER).await?)
+}
+// ANCHOR_END: current_user
+
+// ANCHOR: logout
+#[server]
+pub async fn logout() -> Result<(), ServerFnError> {
+    let session: Session = leptos_axum::extract().await?;
+    // deletes the session from the store, and its cookie from the browser
+    session.flush().await?;
+
+    leptos_axum::redirect("/");
+    Ok(())
+}
+// ANCHOR_END: logout
+
+// ANCHOR: app
+#[component]
+pub fn App() -> impl IntoView {
+    let login = ServerAction::<Login>::new();
+    let logout = ServerAction::<Logout>::new();
+    // checked again whenever someone logs in or out
+    let user = Resource::new(
+        move || (login.version().get(), logout.version().get()),
+        |_| current_user(),
+    );
+
+    view! {
+        <Router>
+            <Routes fallback=|| "Not found.">
+                <Route path=path!("/") view=move || view! { <LoginForm login/> }/>
+                <ProtectedRoute
+                    path=path!("/account")
+                    view=move || view! { <Account user logout/> }
+                    // `None` while the user is loading, and then whether
+                    // anyone is logged in
+                    condition=move || user.get().map(|user| matches!(user, Ok(Some(_))))
+                    redirect_path=|| "/"
+                    // waits for the user before sending the response, so the
+                    // redirect can be a real one
+                    ssr=SsrMode::Async
+                />
+            </Routes>
+        </Router>
+    }
+}
+// ANCHOR_END: app
+
+// ANCHOR: forms
+#[component]
+pub fn LoginForm(login: ServerAction<Login>) -> impl IntoView {
+    view! {
+        <ActionForm action=login>
+            <input type="text" name="username" placeholder="Username"/>
+            <input type="password" name="password" placeholder="Password"/>
+            <input type="submit" value="Log in"/>
+        </ActionForm>
+        <p class="error">
+            {move || login.value().get().and_then(Result::err).map(|e| e.to_string())}
+        </p>
+    }
+}
+
+#[component]
+pub fn Account(
+    user: Resource<Result<Option<String>, ServerFnError>>,
+    logout: ServerAction<Logout>,
+) -> impl IntoView {
+    view! {
+        <p>"Welcome back, " {move || user.get().and_then(Result::ok).flatten()} "!"</p>
+        <ActionForm action=logout>
+            <input type="submit" value="Log out"/>
+        </ActionForm>
+    }
+}
+// ANCHOR_END: forms
+
+// ANCHOR: router
+/// Serves [`App`] with Axum, keeping each visitor's session in memory.
+pub fn router(options: LeptosOptions) -> axum::Router {
+    use leptos_axum::{generate_route_list, LeptosRoutes};
+    use tower_sessions::{
+        cookie::time::Duration, cookie::SameSite, Expiry, MemoryStore, SessionManagerLayer,
+    };
+
+    // a real app would keep sessions in a database, so that they outlive a
+    // restart of the server
+    let store = MemoryStore::default();
+    let sessions = SessionManagerLayer::new(store)
+        // the session cookie is sent when the user follows a link from
+        // another site, so that they're still logged in
+        .with_same_site(SameSite::Lax)
+        // and they're logged out after a week without a visit
+        .with_expiry(Expiry::OnInactivity(Duration::days(7)));
+
+    let routes = generate_route_list(App);
+    axum::Router::new()
+        .leptos_routes(&options, routes, {
+            let options = options.clone();
+            move || shell(options.clone())
+        })
+        // the layer wraps every route, including the server functions
+        .layer(sessions)
+        .with_state(options)
+}
+// ANCHOR_END: router
+
+/// The HTML document that [`App`] is rendered into.
+pub fn shell(options: LeptosOptions) -> impl IntoView {
+    view! {
+        <!DOCTYPE html>
+        <html lang="en">
+            <head>
+                <meta charset="utf-8"/>
+                <HydrationScripts options/>
+            </head>
+            <body>
+                <App/>
+            </body>
+        </html>
+    }
+}
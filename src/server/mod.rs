@@ -0,0 +1,34 @@
+//! Code for the "Working with the Server" chapters.
+//!
+//! Server functions work the same way with any server integration, but
+//! extractors, responses and middleware are specific to one. Those examples
+//! are gated on the `axum` feature, with Actix versions of the extractors and
+//! responses in the `_actix` modules, gated on `actix`. The todo app that
+//! keeps its todos in SQLite is gated on `sqlite`.
+
+pub mod c25_encodings;
+pub mod c25_errors;
+#[cfg(feature = "axum")]
+pub mod c25_middleware;
+pub mod c25_server_functions;
+#[cfg(feature = "actix")]
+pub mod c25_server_functions_actix;
+#[cfg(feature = "sqlite")]
+pub mod c25_sqlite;
+pub mod c25_uploads;
+#[cfg(feature = "axum")]
+pub mod c26_extractors;
+#[cfg(feature = "actix")]
+pub mod c26_extractors_actix;
+#[cfg(feature = "axum")]
+pub mod c27_cookies;
+#[cfg(feature = "axum")]
+pub mod c27_not_found;
+#[cfg(feature = "axum")]
+pub mod c27_response;
+#[cfg(feature = "actix")]
+pub mod c27_response_actix;
+pub mod c28_sse;
+pub mod c28_websockets;
+#[cfg(feature = "axum")]
+pub mod c29_sessions;
@@ -0,0 +1,153 @@
+//! Code for "Async Rendering and SSR “Modes”".
+//!
+//! The same page is served at a path for each mode. The integration tests in
+//! `tests/ssr/tests/ssr_modes.rs` read the response chunk by chunk, to check
+//! which chunk the `<Suspense/>` fallback and the loaded post arrive in, and
+//! that [`BlogPost`]'s blocking resource makes it into the first one.
+
+use crate::r#async::sleep;
+use leptos::prelude::*;
+use leptos_meta::{provide_meta_context, Meta, MetaTags, Title};
+use leptos_router::{
+    components::{Route, Router, Routes},
+    path, SsrMode,
+};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+pub async fn load_post() -> String {
+    sleep(Duration::from_millis(100)).await;
+    "Streaming HTML is fun.".to_string()
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PostData {
+    pub title: String,
+    pub excerpt: String,
+    pub body: String,
+}
+
+pub async fn load_post_data() -> PostData {
+    sleep(Duration::from_millis(100)).await;
+    PostData {
+        title: "Blocking Resources".to_string(),
+        excerpt: "Waiting for the data that matters.".to_string(),
+        body: "Some data is worth waiting for.".to_string(),
+    }
+}
+
+pub async fn load_comments() -> Vec<String> {
+    sleep(Duration::from_millis(200)).await;
+    vec!["Great post!".to_string(), "Thanks!".to_string()]
+}
+
+// ANCHOR: post
+#[component]
+pub fn Post() -> impl IntoView {
+    let post = Resource::new(|| (), |_| load_post());
+
+    view! {
+        <h1>"My Blog"</h1>
+        <Suspense fallback=|| view! { <p>"Loading the post..."</p> }>
+            {move || Suspend::new(async move {
+                let post = post.await;
+                view! { <article>{post}</article> }
+            })}
+        </Suspense>
+        <footer>"Thanks for reading!"</footer>
+    }
+}
+// ANCHOR_END: post
+
+// ANCHOR: blocking
+#[component]
+pub fn BlogPost() -> impl IntoView {
+    // the post sets the page's title, so the page waits for it...
+    let post = Resource::new_blocking(|| (), |_| load_post_data());
+    // ...but there's no need to wait for the comments
+    let comments = Resource::new(|| (), |_| load_comments());
+
+    view! {
+        <Suspense fallback=|| ()>
+            {move || Suspend::new(async move {
+                let post = post.await;
+                view! {
+                    <Title text=post.title.clone()/>
+                    <Meta name="description" content=post.excerpt/>
+                    <h1>{post.title}</h1>
+                    <article>{post.body}</article>
+                }
+            })}
+        </Suspense>
+        <Suspense fallback=|| view! { <p>"Loading comments..."</p> }>
+            {move || Suspend::new(async move {
+                let comments = comments.await;
+                view! {
+                    <ul>
+                        {comments.into_iter().map(|comment| view! { <li>{comment}</li> }).collect_view()}
+                    </ul>
+                }
+            })}
+        </Suspense>
+    }
+}
+// ANCHOR_END: blocking
+
+// ANCHOR: routes
+#[component]
+pub fn App() -> impl IntoView {
+    provide_meta_context();
+
+    view! {
+        <Router>
+            <Routes fallback=|| "Not found.">
+                // out-of-order streaming is the default...
+                <Route path=path!("/out-of-order") view=Post/>
+                // ...and each route can pick another mode
+                <Route path=path!("/in-order") view=Post ssr=SsrMode::InOrder/>
+                <Route path=path!("/async") view=Post ssr=SsrMode::Async/>
+                // ANCHOR: blocking_route
+                // the `<Suspense/>` that reads a blocking resource is
+                // rendered in place, while any others stream out of order
+                <Route path=path!("/post") view=BlogPost ssr=SsrMode::PartiallyBlocked/>
+                // ANCHOR_END: blocking_route
+            </Routes>
+        </Router>
+    }
+}
+// ANCHOR_END: routes
+
+/// The HTML document that [`App`] is rendered into.
+pub fn shell(options: LeptosOptions) -> impl IntoView {
+    view! {
+        <!DOCTYPE html>
+        <html lang="en">
+            <head>
+                <meta charset="utf-8"/>
+                <MetaTags/>
+                <HydrationScripts options/>
+            </head>
+            <body>
+                <App/>
+            </body>
+        </html>
+    }
+}
+
+// ANCHOR: leptos_routes
+/// Serves [`App`] with Axum.
+#[cfg(feature = "axum")]
+pub fn router(options: LeptosOptions) -> axum::Router {
+    use leptos_axum::{generate_route_list, LeptosRoutes};
+
+    // each route's `ssr` mode is part of the route list, so that
+    // `leptos_routes` can render it in that mode
+    let routes = generate_route_list(App);
+    axum::Router::new()
+        .leptos_routes(&options, routes, {
+            let options = options.clone();
+            move || shell(options.clone())
+        })
+        .with_state(options)
+}
+// ANCHOR_END: leptos_routes
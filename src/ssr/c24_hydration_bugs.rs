@@ -0,0 +1,51 @@
+//! Code for "Hydration Bugs _(and how to avoid them)_".
+//!
+//! The server-rendered HTML of each component here is saved in
+//! `tests/hydration/fixtures`, and the hydration tests check that the fixed
+//! versions hydrate cleanly and that the broken one doesn't.
+
+use leptos::prelude::*;
+
+// ANCHOR: mismatch
+#[component]
+pub fn MismatchedList() -> impl IntoView {
+    let data = if cfg!(target_arch = "wasm32") {
+        vec![0, 1, 2]
+    } else {
+        vec![]
+    };
+    data.into_iter()
+        .map(|value| view! { <span>{value}</span> })
+        .collect_view()
+}
+// ANCHOR_END: mismatch
+
+// ANCHOR: mismatch_fixed
+#[component]
+pub fn MatchedList() -> impl IntoView {
+    // both the server and the client start with the same, empty list
+    let (data, set_data) = signal(Vec::new());
+
+    // effects only run in the browser, after hydration has finished
+    Effect::new(move |_| set_data.set(vec![0, 1, 2]));
+
+    view! {
+        <For each=move || data.get() key=|value| *value let:value>
+            <span>{value}</span>
+        </For>
+    }
+}
+// ANCHOR_END: mismatch_fixed
+
+// ANCHOR: client_only_effect
+#[component]
+pub fn StorageLogger() -> impl IntoView {
+    Effect::new(move |_| {
+        // there's no `window` on the server, but this only runs in the browser
+        let storage = window().local_storage();
+        leptos::logging::log!("{storage:?}");
+    });
+
+    view! { <p>"Check the console!"</p> }
+}
+// ANCHOR_END: client_only_effect
@@ -0,0 +1,163 @@
+//! Code for "Static Site Generation".
+//!
+//! Pre-rendered pages are written to disk by `leptos_axum`, so this is only
+//! compiled with the `axum` feature. The SSR tests in
+//! `tests/ssr/tests/static_site_generation.rs` generate the pages into a
+//! temporary `site_root`, check what's in the files, and then serve them.
+
+use leptos::prelude::*;
+use leptos_router::{
+    components::{Route, Router, Routes},
+    hooks::use_params_map,
+    path,
+    static_routes::{StaticParamsMap, StaticRoute},
+    SsrMode,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Post {
+    pub slug: String,
+    pub title: String,
+    pub body: String,
+}
+
+/// The blog's posts, as `(slug, title, body)`. Imagine that these are
+/// Markdown files in a directory, or rows in a database.
+const POSTS: &[(&str, &str, &str)] = &[
+    ("hello-world", "Hello, World!", "This is the first post."),
+    ("static-pages", "Static Pages", "Rendered ahead of time."),
+];
+
+pub async fn list_slugs() -> Vec<String> {
+    POSTS.iter().map(|(slug, ..)| slug.to_string()).collect()
+}
+
+pub async fn load_post(slug: String) -> Option<Post> {
+    POSTS
+        .iter()
+        .find(|(s, ..)| *s == slug)
+        .map(|(slug, title, body)| Post {
+            slug: slug.to_string(),
+            title: title.to_string(),
+            body: body.to_string(),
+        })
+}
+
+// ANCHOR: app
+#[component]
+pub fn App() -> impl IntoView {
+    view! {
+        <Router>
+            <Routes fallback=|| "Not found.">
+                // a route without params is rendered once, to `index.html`
+                <Route path=path!("/") view=HomePage ssr=SsrMode::Static(StaticRoute::new())/>
+                // a route with params is rendered once for each value of
+                // them that `prerender_params` returns
+                <Route
+                    path=path!("/posts/:slug")
+                    view=BlogPost
+                    ssr=SsrMode::Static(
+                        StaticRoute::new()
+                            .prerender_params(|| async move {
+                                let mut params = StaticParamsMap::new();
+                                params.insert("slug", list_slugs().await);
+                                params
+                            }),
+                    )
+                />
+            </Routes>
+        </Router>
+    }
+}
+// ANCHOR_END: app
+
+// ANCHOR: pages
+#[component]
+pub fn HomePage() -> impl IntoView {
+    view! {
+        <h1>"My Blog"</h1>
+        <ul>
+            {POSTS
+                .iter()
+                .map(|(slug, title, _)| {
+                    view! {
+                        <li>
+                            <a href=format!("/posts/{slug}")>{*title}</a>
+                        </li>
+                    }
+                })
+                .collect_view()}
+        </ul>
+    }
+}
+
+#[component]
+pub fn BlogPost() -> impl IntoView {
+    let params = use_params_map();
+    let post = Resource::new(
+        move || params.read().get("slug").unwrap_or_default(),
+        load_post,
+    );
+
+    // a static page is only written once everything on it has loaded, so
+    // there's no fallback in the file
+    view! {
+        <Suspense fallback=|| ()>
+            {move || Suspend::new(async move {
+                match post.await {
+                    Some(post) => {
+                        view! {
+                            <h1>{post.title}</h1>
+                            <article>{post.body}</article>
+                        }
+                            .into_any()
+                    }
+                    None => view! { <p>"No such post."</p> }.into_any(),
+                }
+            })}
+        </Suspense>
+    }
+}
+// ANCHOR_END: pages
+
+/// The HTML document that [`App`] is rendered into.
+pub fn shell(options: LeptosOptions) -> impl IntoView {
+    view! {
+        <!DOCTYPE html>
+        <html lang="en">
+            <head>
+                <meta charset="utf-8"/>
+                <HydrationScripts options/>
+            </head>
+            <body>
+                <App/>
+            </body>
+        </html>
+    }
+}
+
+// ANCHOR: generate
+/// Writes every static page of [`App`] to `options.site_root`, then serves
+/// them with Axum.
+pub async fn router(options: LeptosOptions) -> axum::Router {
+    use leptos_axum::{generate_route_list_with_ssg, LeptosRoutes};
+
+    // the static routes are rendered with the whole shell, not just `App`
+    let (routes, static_routes) = generate_route_list_with_ssg({
+        let options = options.clone();
+        move || shell(options.clone())
+    });
+    // renders each page, and writes it to an `.html` file in the site root
+    static_routes.generate(&options).await;
+
+    // the static routes are served from those files, rather than rendered
+    // again for each request
+    axum::Router::new()
+        .leptos_routes(&options, routes, {
+            let options = options.clone();
+            move || shell(options.clone())
+        })
+        .with_state(options)
+}
+// ANCHOR_END: generate
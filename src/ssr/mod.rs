@@ -0,0 +1,4 @@
+pub mod c23_ssr_modes;
+pub mod c24_hydration_bugs;
+#[cfg(feature = "axum")]
+pub mod c25_static_site_generation;
@@ -0,0 +1,25 @@
+//! Helpers shared by the unit tests of the chapters' modules.
+
+use leptos::prelude::*;
+
+// ANCHOR: render
+/// Renders a view to HTML, as the server would, without the `<!>` markers
+/// that let the browser tell adjacent text nodes apart when it hydrates.
+pub fn render<V: IntoView>(view: impl FnOnce() -> V) -> String {
+    Owner::new()
+        .with(|| view().into_view().to_html())
+        .replace("<!>", "")
+}
+// ANCHOR_END: render
+
+/// Renders a view the way the server renders a whole page, with the shared
+/// context that islands and their children are serialized into.
+#[cfg(all(feature = "islands", not(target_arch = "wasm32")))]
+pub fn render_page<V: IntoView>(view: impl FnOnce() -> V) -> String {
+    use hydration_context::SsrSharedContext;
+    use std::sync::Arc;
+
+    Owner::new_root(Some(Arc::new(SsrSharedContext::new())))
+        .with(|| view().into_view().to_html())
+        .replace("<!>", "")
+}
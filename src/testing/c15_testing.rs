@@ -0,0 +1,144 @@
+//! Code for "Testing Your Components".
+//!
+//! The chapter's tests are real: the unit tests are below, the browser tests
+//! are in `tests/dom/testing.rs`, and the end-to-end test in
+//! `tests/e2e/tests/todos.rs` drives the [`TodoApp`] that `cargo xtask e2e`
+//! builds into `target/e2e/todos`.
+
+use leptos::prelude::*;
+
+/// One item on the list.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Todo {
+    pub id: usize,
+    pub title: String,
+    pub completed: bool,
+}
+
+// ANCHOR: embedded
+#[component]
+pub fn EmbeddedTodoApp() -> impl IntoView {
+    let (todos, _set_todos) = signal(vec![Todo {
+        id: 0,
+        title: "Write tests".to_string(),
+        completed: false,
+    }]);
+    // ⚠️ this is hard to test because it's embedded in the component
+    let num_remaining =
+        move || todos.with(|todos| todos.iter().filter(|todo| !todo.completed).count());
+
+    view! { <p>{num_remaining} " remaining"</p> }
+}
+// ANCHOR_END: embedded
+
+// ANCHOR: todos
+/// The list, with everything you can do to it.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Todos(pub Vec<Todo>);
+
+impl Todos {
+    pub fn num_remaining(&self) -> usize {
+        self.0.iter().filter(|todo| !todo.completed).count()
+    }
+
+    /// Adds a todo to the end of the list, unless its title is blank.
+    pub fn add(&mut self, title: &str) {
+        let title = title.trim();
+        if !title.is_empty() {
+            let id = self.0.last().map_or(0, |todo| todo.id + 1);
+            self.0.push(Todo {
+                id,
+                title: title.to_string(),
+                completed: false,
+            });
+        }
+    }
+
+    pub fn toggle(&mut self, id: usize) {
+        if let Some(todo) = self.0.iter_mut().find(|todo| todo.id == id) {
+            todo.completed = !todo.completed;
+        }
+    }
+}
+// ANCHOR_END: todos
+
+// ANCHOR: todo_app
+#[component]
+pub fn TodoApp() -> impl IntoView {
+    let (todos, set_todos) = signal(Todos::default());
+    let (title, set_title) = signal(String::new());
+    // ✅ this has a test associated with it
+    let num_remaining = move || todos.with(Todos::num_remaining);
+
+    view! {
+        <form on:submit=move |ev| {
+            ev.prevent_default();
+            set_todos.write().add(&title.read());
+            set_title.set(String::new());
+        }>
+            <input type="text" prop:value=title on:input:target=move |ev| set_title.set(ev.target().value())/>
+            <button type="submit">"Add"</button>
+        </form>
+        <ul>
+            <For each=move || todos.get().0 key=|todo| (todo.id, todo.completed) let:todo>
+                <li class:completed=todo.completed>
+                    <label>
+                        <input
+                            type="checkbox"
+                            prop:checked=todo.completed
+                            on:change=move |_| set_todos.write().toggle(todo.id)
+                        />
+                        {todo.title}
+                    </label>
+                </li>
+            </For>
+        </ul>
+        <p class="remaining">{num_remaining} " remaining"</p>
+    }
+}
+// ANCHOR_END: todo_app
+
+// ANCHOR: unit_tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::render;
+
+    fn todos(titles: &[&str]) -> Todos {
+        let mut todos = Todos::default();
+        for title in titles {
+            todos.add(title);
+        }
+        todos
+    }
+
+    #[test]
+    fn completed_todos_are_not_remaining() {
+        let mut todos = todos(&["Buy bread", "Buy milk", "Buy eggs"]);
+        assert_eq!(todos.num_remaining(), 3);
+
+        todos.toggle(1);
+        assert_eq!(todos.num_remaining(), 2);
+        todos.toggle(1);
+        assert_eq!(todos.num_remaining(), 3);
+    }
+
+    #[test]
+    fn blank_todos_are_not_added() {
+        let todos = todos(&["Buy bread", "  ", ""]);
+        assert_eq!(todos.0.len(), 1);
+        assert_eq!(todos.0[0].title, "Buy bread");
+    }
+
+    // components can be tested without a browser, too, by rendering them to HTML
+    #[test]
+    fn the_app_starts_out_empty() {
+        let html = render(TodoApp);
+        assert!(html.contains("<ul></ul>"), "{html}");
+        assert!(
+            html.contains(r#"<p class="remaining">0 remaining</p>"#),
+            "{html}"
+        );
+    }
+}
+// ANCHOR_END: unit_tests
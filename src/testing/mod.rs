@@ -0,0 +1,3 @@
+//! Code for "Testing Your Components".
+
+pub mod c15_testing;
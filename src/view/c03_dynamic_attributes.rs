@@ -0,0 +1,257 @@
+//! Code for "`view`: Dynamic Classes, Styles and Attributes".
+//!
+//! Each snippet is its own component, reading the count from its caller as a
+//! prop, so the tests can render it at a given count.
+
+use leptos::prelude::*;
+
+// ANCHOR: app
+#[component]
+pub fn App() -> impl IntoView {
+    let (count, set_count) = signal(0);
+
+    // a "derived signal" is a function that accesses other signals
+    // we can use this to create reactive values that depend on the
+    // values of one or more other signals
+    let double_count = move || count.get() * 2;
+
+    view! {
+        <button
+            on:click=move |_| {
+                *set_count.write() += 1;
+            }
+
+            // the class: syntax reactively updates a single class
+            // here, we'll set the `red` class when `count` is odd
+            class:red=move || count.get() % 2 == 1
+        >
+            "Click me"
+        </button>
+        // NOTE: self-closing tags like <br> need an explicit /
+        <br/>
+
+        // We'll update this progress bar every time `count` changes
+        <progress
+            // static attributes work as in HTML
+            max="50"
+
+            // passing a function to an attribute
+            // reactively sets that attribute
+            // signals are functions, so `value=count` and `value=move || count.get()`
+            // are interchangeable.
+            value=count
+        ></progress>
+        <br/>
+
+        // This progress bar will use `double_count`
+        // so it should move twice as fast!
+        <progress
+            max="50"
+            // derived signals are functions, so they can also
+            // reactively update the DOM
+            value=double_count
+        ></progress>
+        <p>"Count: " {count}</p>
+        <p>"Double Count: " {double_count}</p>
+    }
+}
+
+pub fn main() {
+    leptos::mount::mount_to_body(App)
+}
+// ANCHOR_END: app
+
+#[component]
+pub fn ClassToggle(count: ReadSignal<i32>) -> impl IntoView {
+    view! {
+        // ANCHOR: class_toggle
+        <button
+            // the class: syntax reactively updates a single class
+            // here, we'll set the `red` class when `count` is odd
+            class:red=move || count.get() % 2 == 1
+        >
+            "Click me"
+        </button>
+        // ANCHOR_END: class_toggle
+    }
+}
+
+#[component]
+pub fn ClassTuple(count: ReadSignal<i32>) -> impl IntoView {
+    view! {
+        <button
+            // ANCHOR: class_tuple
+            class=("button-20", move || count.get() % 2 == 1)
+            // ANCHOR_END: class_tuple
+        >
+            "Click me"
+        </button>
+    }
+}
+
+#[component]
+pub fn ClassList(count: ReadSignal<i32>) -> impl IntoView {
+    view! {
+        <button
+            // ANCHOR: class_list
+            // toggles both `button-20` and `rounded` together
+            class=(["button-20", "rounded"], move || count.get() % 2 == 1)
+            // ANCHOR_END: class_list
+        >
+            "Click me"
+        </button>
+    }
+}
+
+#[component]
+pub fn Styles() -> impl IntoView {
+    // ANCHOR: styles
+    let (x, set_x) = signal(0);
+    view! {
+        <button
+            on:click=move |_| {
+                *set_x.write() += 10;
+            }
+            // set the `style` attribute
+            style="position: absolute"
+            // and toggle individual CSS properties with `style:`
+            style:left=move || format!("{}px", x.get() + 100)
+            style:background-color=move || format!("rgb({}, {}, 100)", x.get(), 100)
+            style:max-width="400px"
+            // Set a CSS variable for stylesheet use
+            style=("--columns", move || x.get().to_string())
+        >
+            "Click to Move"
+        </button>
+    }
+    // ANCHOR_END: styles
+}
+
+#[component]
+pub fn Progress(count: ReadSignal<i32>) -> impl IntoView {
+    view! {
+        // ANCHOR: progress
+        <progress
+            max="50"
+            // signals are functions, so `value=count` and `value=move || count.get()`
+            // are interchangeable.
+            value=count
+        />
+        // ANCHOR_END: progress
+    }
+}
+
+#[component]
+pub fn DoubleProgress(count: ReadSignal<i32>) -> impl IntoView {
+    view! {
+        // ANCHOR: double_progress
+        <progress
+            max="50"
+            value=move || count.get() * 2
+        />
+        // ANCHOR_END: double_progress
+    }
+}
+
+#[component]
+pub fn DerivedSignal(count: ReadSignal<i32>) -> impl IntoView {
+    // ANCHOR: derived_signal
+    let double_count = move || count.get() * 2;
+
+    view! {
+        <progress
+            max="50"
+            // we use it once here
+            value=double_count
+        />
+        <p>
+            "Double Count: "
+            // and again here
+            {double_count}
+        </p>
+    }
+    // ANCHOR_END: derived_signal
+}
+
+#[component]
+pub fn PropValue() -> impl IntoView {
+    // ANCHOR: prop_value
+    let (text, set_text) = signal("Hello".to_string());
+
+    view! {
+        // `value` would only set the input's initial value, and stop
+        // applying once the user types, while `prop:value` always sets what
+        // the input currently holds
+        <input type="text" prop:value=text/>
+        <button on:click=move |_| set_text.set(String::new())>"Clear"</button>
+    }
+    // ANCHOR_END: prop_value
+}
+
+#[component]
+pub fn InnerHtml() -> impl IntoView {
+    // ANCHOR: inner_html
+    let html = "<p>This HTML will be injected.</p>";
+    view! {
+      <div inner_html=html/>
+    }
+    // ANCHOR_END: inner_html
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers;
+
+    fn render<V: IntoView>(view: impl FnOnce(ReadSignal<i32>) -> V, count: i32) -> String {
+        test_helpers::render(|| view(signal(count).0))
+    }
+
+    #[test]
+    fn classes_follow_the_count() {
+        let class_toggle = |count| view! { <ClassToggle count/> };
+        assert!(!render(class_toggle, 0).contains("red"));
+        assert_eq!(
+            render(class_toggle, 1),
+            r#"<button class="red">Click me</button>"#
+        );
+
+        let class_tuple = |count| view! { <ClassTuple count/> };
+        assert!(render(class_tuple, 1).contains(r#"class="button-20""#));
+        let class_list = |count| view! { <ClassList count/> };
+        assert!(render(class_list, 1).contains(r#"class="button-20 rounded""#));
+        assert!(!render(class_list, 2).contains("button-20"));
+    }
+
+    #[test]
+    fn styles_are_combined_into_one_attribute() {
+        let html = test_helpers::render(Styles);
+        assert!(
+            html.starts_with(
+                r#"<button style="position: absolute;left:100px;background-color:rgb(0, 100, 100);max-width:400px;--columns:0;">"#
+            ),
+            "{html}"
+        );
+    }
+
+    #[test]
+    fn attributes_and_derived_signals_set_values() {
+        assert_eq!(
+            render(|count| view! { <Progress count/> }, 5),
+            r#"<progress max="50" value="5"></progress>"#
+        );
+        assert_eq!(
+            render(|count| view! { <DoubleProgress count/> }, 5),
+            r#"<progress max="50" value="10"></progress>"#
+        );
+        let html = render(|count| view! { <DerivedSignal count/> }, 5);
+        assert!(html.contains(r#"value="10""#), "{html}");
+        assert!(html.contains("Double Count: 10"), "{html}");
+    }
+
+    #[test]
+    fn inner_html_is_not_escaped() {
+        let html = test_helpers::render(InnerHtml);
+        assert_eq!(html, "<div><p>This HTML will be injected.</p></div>");
+    }
+}
@@ -0,0 +1,224 @@
+//! Code for the generic props in "Components and Props".
+//!
+//! The mistakes the chapter warns about, like optional generic props, are in
+//! `tests/compile_fail/`, where the compiler errors are checked too.
+
+use leptos::prelude::*;
+use std::marker::PhantomData;
+
+// ANCHOR: where_clause
+#[component]
+pub fn ProgressBar<F>(#[prop(default = 100)] max: u16, progress: F) -> impl IntoView
+where
+    F: Fn() -> i32 + Send + Sync + 'static,
+{
+    view! {
+        <progress
+            max=max
+            value=progress
+        />
+    }
+}
+// ANCHOR_END: where_clause
+
+#[component]
+pub fn TwoProgressBars() -> impl IntoView {
+    // ANCHOR: use_where_clause
+    let (count, set_count) = signal(0);
+    let double_count = move || count.get() * 2;
+
+    view! {
+        <button on:click=move |_| *set_count.write() += 1>
+            "Click me"
+        </button>
+        // each closure has its own type, so `F` is a different type here...
+        <ProgressBar progress=move || count.get()/>
+        // ...than it is here
+        <ProgressBar progress=double_count/>
+    }
+    // ANCHOR_END: use_where_clause
+}
+
+// ANCHOR: inline
+#[component]
+pub fn List<T: IntoView + 'static>(items: Vec<T>) -> impl IntoView {
+    view! {
+        <ul>
+            {items.into_iter().map(|item| view! { <li>{item}</li> }).collect_view()}
+        </ul>
+    }
+}
+// ANCHOR_END: inline
+
+// ANCHOR: render_prop
+#[component]
+pub fn ListOf<T, F, IV>(items: Vec<T>, render: F) -> impl IntoView
+where
+    F: Fn(T) -> IV,
+    IV: IntoView + 'static,
+{
+    view! {
+        <ul>
+            {items.into_iter().map(|item| view! { <li>{render(item)}</li> }).collect_view()}
+        </ul>
+    }
+}
+
+#[component]
+pub fn Lists() -> impl IntoView {
+    view! {
+        // `T` is inferred from the props: `&str` here...
+        <List items=vec!["Ferris", "Gopher"]/>
+        // ...and `u8` here, with `IV` being whatever `render` returns
+        <ListOf items=vec![1, 2, 3] render=|n: u8| view! { <strong>{n * 10}</strong> }/>
+    }
+}
+// ANCHOR_END: render_prop
+
+// ANCHOR: size_of
+#[component]
+pub fn SizeOf<T: Sized>(#[prop(optional)] _ty: PhantomData<T>) -> impl IntoView {
+    std::mem::size_of::<T>()
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    view! {
+        <SizeOf<usize>/>
+        <SizeOf<String>/>
+    }
+}
+// ANCHOR_END: size_of
+
+// ANCHOR: into_signal
+#[component]
+pub fn SignalProgressBar(
+    #[prop(default = 100)] max: u16,
+    #[prop(into)] progress: Signal<i32>,
+) -> impl IntoView {
+    view! {
+        <progress
+            max=max
+            value=progress
+        />
+    }
+}
+
+#[component]
+pub fn SignalProgressBars() -> impl IntoView {
+    let (count, set_count) = signal(0);
+    let double_count = move || count.get() * 2;
+
+    view! {
+        <button on:click=move |_| *set_count.write() += 1>
+            "Click me"
+        </button>
+        // .into() converts `ReadSignal` to `Signal`
+        <SignalProgressBar progress=count/>
+        // use `Signal::derive()` to wrap a derived signal
+        <SignalProgressBar progress=Signal::derive(double_count)/>
+    }
+}
+// ANCHOR_END: into_signal
+
+// ANCHOR: generic_and_into
+#[component]
+pub fn Labeled<T: IntoView + 'static>(
+    // `into` works on a concrete type, which the caller's value is converted to...
+    #[prop(into)] label: String,
+    // ...while a generic prop is already whatever type the caller passes
+    value: T,
+) -> impl IntoView {
+    view! {
+        <p>
+            <span class="label">{label}</span>
+            {value}
+        </p>
+    }
+}
+
+#[component]
+pub fn Labels() -> impl IntoView {
+    view! {
+        <Labeled label="Name" value="Ferris"/>
+        <Labeled label=String::from("Legs") value=6/>
+    }
+}
+// ANCHOR_END: generic_and_into
+
+// ANCHOR: boxed
+#[component]
+pub fn OptionalProgressBar(
+    #[prop(optional)] progress: Option<Box<dyn Fn() -> i32 + Send + Sync>>,
+) -> impl IntoView {
+    progress.map(|progress| {
+        view! {
+            <progress
+                max=100
+                value=progress
+            />
+        }
+    })
+}
+
+#[component]
+pub fn OptionalProgressBars() -> impl IntoView {
+    view! {
+        <OptionalProgressBar/>
+        <OptionalProgressBar progress=Box::new(|| 10)/>
+    }
+}
+// ANCHOR_END: boxed
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::render;
+
+    #[test]
+    fn generic_props_take_signals_and_closures() {
+        assert_eq!(
+            render(TwoProgressBars),
+            r#"<button>Click me</button><progress max="100" value="0"></progress><progress max="100" value="0"></progress>"#
+        );
+    }
+
+    #[test]
+    fn generic_items_are_inferred_from_the_props() {
+        assert_eq!(
+            render(Lists),
+            "<ul><li>Ferris</li><li>Gopher</li></ul>\
+             <ul><li><strong>10</strong></li><li><strong>20</strong></li><li><strong>30</strong></li></ul>"
+        );
+    }
+
+    #[test]
+    fn explicit_generics_pick_the_type() {
+        let expected = format!(
+            "{}{}",
+            std::mem::size_of::<usize>(),
+            std::mem::size_of::<String>()
+        );
+        assert_eq!(render(App), expected);
+    }
+
+    #[test]
+    fn into_converts_to_the_concrete_prop_type() {
+        assert_eq!(
+            render(SignalProgressBars),
+            r#"<button>Click me</button><progress max="100" value="0"></progress><progress max="100" value="0"></progress>"#
+        );
+        assert_eq!(
+            render(Labels),
+            r#"<p><span class="label">Name</span>Ferris</p><p><span class="label">Legs</span>6</p>"#
+        );
+    }
+
+    #[test]
+    fn boxed_props_can_be_left_out() {
+        assert_eq!(
+            render(OptionalProgressBars),
+            r#"<progress max="100" value="10"></progress>"#
+        );
+    }
+}
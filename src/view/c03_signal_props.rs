@@ -0,0 +1,147 @@
+//! Code for the `Signal` props in "Components and Props".
+//!
+//! The tests render the components on the server, and check that the
+//! functions taking `impl Into<Signal<T>>` follow their arguments.
+
+use leptos::prelude::*;
+
+// ANCHOR: signal_prop
+#[component]
+pub fn Meter(
+    // anything that can be read reactively, or a plain value
+    #[prop(into)] value: Signal<f64>,
+    #[prop(into)] label: Signal<String>,
+) -> impl IntoView {
+    view! {
+        <label>
+            {label}
+            <meter min="0" max="1" value=value/>
+        </label>
+    }
+}
+// ANCHOR_END: signal_prop
+
+// ANCHOR: passing
+#[component]
+pub fn Meters() -> impl IntoView {
+    let (used, set_used) = signal(0.25_f64);
+    let total = RwSignal::new(1.0);
+    let free = Memo::new(move |_| total.get() - used.get());
+
+    view! {
+        <button on:click=move |_| set_used.update(|used| *used = (*used + 0.25).min(1.0))>
+            "Use more"
+        </button>
+        // a `ReadSignal`, an `RwSignal` or a `Memo`...
+        <Meter label="Used" value=used/>
+        <Meter label="Total" value=total/>
+        <Meter label="Free" value=free/>
+        // ...a derived signal...
+        <Meter label="Half used" value=Signal::derive(move || used.get() / 2.0)/>
+        // ...or a plain value, which never changes
+        <Meter label="Reserved" value=0.1/>
+        // the same goes for the label, which can be a `&str` or `String` too
+        <Meter label=Signal::derive(move || format!("{:.0}% used", used.get() * 100.0)) value=used/>
+    }
+}
+// ANCHOR_END: passing
+
+#[cfg(not(feature = "nightly"))]
+#[component]
+pub fn ClosureMeter() -> impl IntoView {
+    let (used, _) = signal(0.25);
+    view! {
+        // ANCHOR: closure
+        <Meter label="Half used" value=move || used.get() / 2.0/>
+        // ANCHOR_END: closure
+    }
+}
+
+// ANCHOR: generic
+#[component]
+pub fn Readout<S>(value: S) -> impl IntoView
+where
+    // any signal, of any type that can be displayed
+    S: Get + Send + Sync + 'static,
+    S::Value: std::fmt::Display,
+{
+    view! { <output>{move || value.get().to_string()}</output> }
+}
+// ANCHOR_END: generic
+
+// ANCHOR: impl_into
+/// Formats a number, reactive or not, as a percentage.
+pub fn percent(value: impl Into<Signal<f64>>) -> Signal<String> {
+    let value = value.into();
+    Signal::derive(move || format!("{:.0}%", value.get() * 100.0))
+}
+// ANCHOR_END: impl_into
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::render;
+
+    #[test]
+    fn every_kind_of_value_is_accepted() {
+        let html = render(Meters);
+        for (label, value) in [
+            ("Used", "0.25"),
+            ("Total", "1"),
+            ("Free", "0.75"),
+            ("Half used", "0.125"),
+            ("Reserved", "0.1"),
+            ("25% used", "0.25"),
+        ] {
+            let meter =
+                format!(r#"<label>{label}<meter min="0" max="1" value="{value}"></meter></label>"#);
+            assert!(html.contains(&meter), "{meter} in {html}");
+        }
+    }
+
+    #[cfg(not(feature = "nightly"))]
+    #[test]
+    fn closures_become_derived_signals() {
+        let html = render(ClosureMeter);
+        assert!(html.contains(r#"value="0.125""#), "{html}");
+    }
+
+    #[test]
+    fn generic_signal_props_take_any_signal() {
+        let html = render(|| {
+            let (count, _) = signal(3);
+            let doubled = Memo::new(move |_| count.get() * 2);
+            view! {
+                <Readout value=count/>
+                <Readout value=doubled/>
+                <Readout value=Signal::stored("plain")/>
+            }
+        });
+        assert_eq!(
+            html,
+            "<output>3</output><output>6</output><output>plain</output>"
+        );
+    }
+
+    #[test]
+    fn into_signal_arguments_stay_reactive() {
+        let owner = Owner::new();
+        owner.set();
+
+        // ANCHOR: use_impl_into
+        let (used, set_used) = signal(0.25);
+
+        let from_signal = percent(used);
+        let from_memo = percent(Memo::new(move |_| 1.0 - used.get()));
+        // `Into` has no conversion from a closure, so wrap it in a derived signal
+        let from_closure = percent(Signal::derive(move || used.get() * 2.0));
+        let from_value = percent(0.5);
+
+        set_used.set(0.3);
+        assert_eq!(from_signal.get(), "30%");
+        assert_eq!(from_memo.get(), "70%");
+        assert_eq!(from_closure.get(), "60%");
+        assert_eq!(from_value.get(), "50%");
+        // ANCHOR_END: use_impl_into
+    }
+}
@@ -0,0 +1,304 @@
+//! Code for "Iterating over More Complex Data with `<For/>`".
+//!
+//! Each option in the chapter is its own component, so that all four can be
+//! compiled side by side.
+
+use leptos::prelude::*;
+use reactive_stores::Store;
+
+// ANCHOR: database_entry
+#[derive(Debug, Clone)]
+pub struct DatabaseEntry {
+    key: String,
+    value: i32,
+}
+// ANCHOR_END: database_entry
+
+fn initial_rows() -> Vec<DatabaseEntry> {
+    vec![
+        DatabaseEntry {
+            key: "foo".to_string(),
+            value: 10,
+        },
+        DatabaseEntry {
+            key: "bar".to_string(),
+            value: 20,
+        },
+        DatabaseEntry {
+            key: "baz".to_string(),
+            value: 15,
+        },
+    ]
+}
+
+// ANCHOR: the_problem
+#[component]
+pub fn App() -> impl IntoView {
+    // start with a set of three rows
+    let (data, set_data) = signal(vec![
+        DatabaseEntry {
+            key: "foo".to_string(),
+            value: 10,
+        },
+        DatabaseEntry {
+            key: "bar".to_string(),
+            value: 20,
+        },
+        DatabaseEntry {
+            key: "baz".to_string(),
+            value: 15,
+        },
+    ]);
+    view! {
+        // when we click, update each row,
+        // doubling its value
+        <button on:click=move |_| {
+            set_data.update(|data| {
+                for row in data {
+                    row.value *= 2;
+                }
+            });
+            // log the new value of the signal
+            leptos::logging::log!("{:?}", data.get());
+        }>
+            "Update Values"
+        </button>
+        // iterate over the rows and display each value
+        <For
+            each=move || data.get()
+            key=|state| state.key.clone()
+            let:child
+        >
+            <p>{child.value}</p>
+        </For>
+    }
+}
+// ANCHOR_END: the_problem
+
+#[component]
+pub fn ChangeKey() -> impl IntoView {
+    let (data, set_data) = signal(initial_rows());
+    view! {
+        <button on:click=move |_| {
+            set_data.update(|data| {
+                for row in data {
+                    row.value *= 2;
+                }
+            });
+        }>
+            "Update Values"
+        </button>
+        // ANCHOR: change_key
+        <For
+            each=move || data.get()
+            key=|state| (state.key.clone(), state.value)
+            let:child
+        >
+            <p>{child.value}</p>
+        </For>
+        // ANCHOR_END: change_key
+    }
+}
+
+// ANCHOR: signal_entry
+#[derive(Debug, Clone)]
+pub struct SignalEntry {
+    key: String,
+    value: RwSignal<i32>,
+}
+// ANCHOR_END: signal_entry
+
+// ANCHOR: nested_signals
+#[component]
+pub fn NestedSignals() -> impl IntoView {
+    // start with a set of three rows
+    let (data, _set_data) = signal(vec![
+        SignalEntry {
+            key: "foo".to_string(),
+            value: RwSignal::new(10),
+        },
+        SignalEntry {
+            key: "bar".to_string(),
+            value: RwSignal::new(20),
+        },
+        SignalEntry {
+            key: "baz".to_string(),
+            value: RwSignal::new(15),
+        },
+    ]);
+    view! {
+        // when we click, update each row,
+        // doubling its value
+        <button on:click=move |_| {
+            data.with(|data| {
+                for row in data {
+                    row.value.update(|value| *value *= 2);
+                }
+            });
+            // log the new value of the signal
+            leptos::logging::log!("{:?}", data.get());
+        }>
+            "Update Values"
+        </button>
+        // iterate over the rows and display each value
+        <For
+            each=move || data.get()
+            key=|state| state.key.clone()
+            let:child
+        >
+            <p>{child.value}</p>
+        </For>
+    }
+}
+// ANCHOR_END: nested_signals
+
+#[component]
+pub fn MemoizedSlices() -> impl IntoView {
+    let (data, set_data) = signal(initial_rows());
+    view! {
+        <button on:click=move |_| {
+            set_data.update(|data| {
+                for row in data {
+                    row.value *= 2;
+                }
+            });
+        }>
+            "Update Values"
+        </button>
+        // ANCHOR: memoized_slices
+        <For
+            each=move || data.get().into_iter().enumerate()
+            key=|(_, state)| state.key.clone()
+            children=move |(index, _)| {
+                let value = Memo::new(move |_| {
+                    data.with(|data| data.get(index).map(|d| d.value).unwrap_or(0))
+                });
+                view! {
+                    <p>{value}</p>
+                }
+            }
+        />
+        // ANCHOR_END: memoized_slices
+    }
+}
+
+// ANCHOR: store_types
+#[derive(Store, Debug, Clone)]
+pub struct Data {
+    #[store(key: String = |row| row.key.clone())]
+    rows: Vec<StoreEntry>,
+}
+
+#[derive(Store, Debug, Clone)]
+pub struct StoreEntry {
+    key: String,
+    value: i32,
+}
+// ANCHOR_END: store_types
+
+// ANCHOR: stores
+#[component]
+pub fn StoreRows() -> impl IntoView {
+    // instead of a single signal containing the rows, we create a store for Data
+    let data = Store::new(Data {
+        rows: vec![
+            StoreEntry {
+                key: "foo".to_string(),
+                value: 10,
+            },
+            StoreEntry {
+                key: "bar".to_string(),
+                value: 20,
+            },
+            StoreEntry {
+                key: "baz".to_string(),
+                value: 15,
+            },
+        ],
+    });
+
+    view! {
+        // when we click, update each row,
+        // doubling its value
+        <button on:click=move |_| {
+            // allows iterating over the entries in an iterable store field
+            use reactive_stores::StoreFieldIterator;
+
+            // calling rows() gives us access to the rows
+            for row in data.rows().iter_unkeyed() {
+                *row.value().write() *= 2;
+            }
+            // log the new value of the signal
+            leptos::logging::log!("{:?}", data.get());
+        }>
+            "Update Values"
+        </button>
+        // iterate over the rows and display each value
+        <For
+            each=move || data.rows()
+            key=|row| row.read().key.clone()
+            children=|child| {
+                let value = child.value();
+                view! { <p>{move || value.get()}</p> }
+            }
+        />
+    }
+}
+// ANCHOR_END: stores
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::render;
+
+    fn values(html: &str) -> Vec<&str> {
+        html.split("<p>")
+            .skip(1)
+            .filter_map(|row| row.split("</p>").next())
+            .collect()
+    }
+
+    #[test]
+    fn every_option_renders_the_initial_rows() {
+        let rendered = [
+            render(App),
+            render(ChangeKey),
+            render(NestedSignals),
+            render(MemoizedSlices),
+            render(StoreRows),
+        ];
+        for html in &rendered {
+            assert_eq!(values(html), ["10", "20", "15"], "{html}");
+        }
+    }
+
+    #[test]
+    fn store_rows_update_individually() {
+        use reactive_stores::StoreFieldIterator;
+
+        let owner = Owner::new();
+        owner.with(|| {
+            let data = Store::new(Data {
+                rows: initial_rows()
+                    .into_iter()
+                    .map(|row| StoreEntry {
+                        key: row.key,
+                        value: row.value,
+                    })
+                    .collect(),
+            });
+            let first = data.rows().iter_unkeyed().next().unwrap().value();
+            for row in data.rows().iter_unkeyed() {
+                *row.value().write() *= 2;
+            }
+            assert_eq!(first.get(), 20);
+            assert_eq!(
+                data.rows()
+                    .iter_unkeyed()
+                    .map(|row| row.value().get())
+                    .collect::<Vec<_>>(),
+                [20, 40, 30]
+            );
+        });
+    }
+}
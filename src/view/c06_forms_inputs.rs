@@ -0,0 +1,281 @@
+//! Code for "Forms and Inputs".
+
+use leptos::{ev::SubmitEvent, html::Input, prelude::*};
+
+// ANCHOR: app
+#[component]
+pub fn App() -> impl IntoView {
+    view! {
+        <h2>"Controlled Component"</h2>
+        <ControlledComponent/>
+        <h2>"Uncontrolled Component"</h2>
+        <UncontrolledComponent/>
+    }
+}
+
+#[component]
+pub fn ControlledComponent() -> impl IntoView {
+    // ANCHOR: controlled
+    // create a signal to hold the value
+    let (name, set_name) = signal("Controlled".to_string());
+
+    view! {
+        <input type="text"
+            // adding :target gives us typed access to the element
+            // that is the target of the event that fires
+            on:input:target=move |ev| {
+                // .value() returns the current value of an HTML input element
+                set_name.set(ev.target().value());
+            }
+
+            // the `prop:` syntax lets you update a DOM property,
+            // rather than an attribute.
+            //
+            // IMPORTANT: the `value` *attribute* only sets the
+            // initial value, until you have made a change.
+            // The `value` *property* sets the current value.
+            // This is a quirk of the DOM; I didn't invent it.
+            // Other frameworks gloss this over; I think it's
+            // more important to give you access to the browser
+            // as it really works.
+            //
+            // tl;dr: use prop:value for form inputs
+            prop:value=name
+        />
+        <p>"Name is: " {name}</p>
+    }
+    // ANCHOR_END: controlled
+}
+
+#[component]
+pub fn UncontrolledComponent() -> impl IntoView {
+    // ANCHOR: uncontrolled_signals
+    let (name, set_name) = signal("Uncontrolled".to_string());
+
+    // we'll use a NodeRef to store a reference to the input element
+    // this will be filled when the element is created
+    let input_element: NodeRef<Input> = NodeRef::new();
+    // ANCHOR_END: uncontrolled_signals
+
+    // ANCHOR: on_submit
+    // fires when the form `submit` event happens
+    // this will store the value of the <input> in our signal
+    let on_submit = move |ev: SubmitEvent| {
+        // stop the page from reloading!
+        ev.prevent_default();
+
+        // here, we'll extract the value from the input
+        let value = input_element
+            .get()
+            // event handlers can only fire after the view
+            // is mounted to the DOM, so the `NodeRef` will be `Some`
+            .expect("<input> should be mounted")
+            // `leptos::HtmlElement<html::Input>` implements `Deref`
+            // to a `web_sys::HtmlInputElement`.
+            // this means we can call`HtmlInputElement::value()`
+            // to get the current value of the input
+            .value();
+        set_name.set(value);
+    };
+    // ANCHOR_END: on_submit
+
+    // ANCHOR: uncontrolled_view
+    view! {
+        <form on:submit=on_submit>
+            <input type="text"
+                // here, we use the `value` *attribute* to set only
+                // the initial value, letting the browser maintain
+                // the state after that
+                value=name
+
+                // store a reference to this input in `input_element`
+                node_ref=input_element
+            />
+            <input type="submit" value="Submit"/>
+        </form>
+        <p>"Name is: " {name}</p>
+    }
+    // ANCHOR_END: uncontrolled_view
+}
+
+// This `main` function is the entry point into the app
+// It just mounts our component to the <body>
+// Because we defined it as `fn App`, we can now use it in a
+// template as <App/>
+pub fn main() {
+    leptos::mount::mount_to_body(App)
+}
+// ANCHOR_END: app
+
+#[component]
+pub fn BindValue() -> impl IntoView {
+    // ANCHOR: bind_value
+    let (name, set_name) = signal("Controlled".to_string());
+    let email = RwSignal::new("".to_string());
+    let spam_me = RwSignal::new(true);
+
+    view! {
+        <input type="text"
+            bind:value=(name, set_name)
+        />
+        <input type="email"
+            bind:value=email
+        />
+        <label>
+            "Please send me lots of spam email."
+            <input type="checkbox"
+                bind:checked=spam_me
+            />
+        </label>
+        <p>"Name is: " {name}</p>
+        <p>"Email is: " {email}</p>
+        <Show when=move || spam_me.get()>
+            <p>"You’ll receive cool bonus content!"</p>
+        </Show>
+    }
+    // ANCHOR_END: bind_value
+}
+
+#[component]
+pub fn BindGroup() -> impl IntoView {
+    // ANCHOR: bind_group
+    let favorite_color = RwSignal::new("red".to_string());
+
+    view! {
+        <fieldset>
+            <legend>"Favorite color"</legend>
+            <label>
+                "Red"
+                <input type="radio" name="color" value="red"
+                    bind:group=favorite_color
+                />
+            </label>
+            <label>
+                "Green"
+                <input type="radio" name="color" value="green"
+                    bind:group=favorite_color
+                />
+            </label>
+            <label>
+                "Blue"
+                <input type="radio" name="color" value="blue"
+                    bind:group=favorite_color
+                />
+            </label>
+        </fieldset>
+        <p>"Your favorite color is " {favorite_color} "."</p>
+    }
+    // ANCHOR_END: bind_group
+}
+
+#[component]
+pub fn Checkbox() -> impl IntoView {
+    // ANCHOR: checkbox
+    let (checked, set_checked) = signal(false);
+
+    view! {
+        <input type="checkbox"
+            // `checked` has the same attribute/property split as `value`
+            on:change:target=move |ev| {
+                set_checked.set(ev.target().checked());
+            }
+            prop:checked=checked
+        />
+        <p>"Checked: " {move || checked.get().to_string()}</p>
+    }
+    // ANCHOR_END: checkbox
+}
+
+#[component]
+pub fn TextArea() -> impl IntoView {
+    let (some_value, set_some_value) = signal("Some text".to_string());
+
+    // ANCHOR: textarea
+    view! {
+        <textarea
+            prop:value=move || some_value.get()
+            on:input:target=move |ev| set_some_value.set(ev.target().value())
+        >
+            // plain-text initial value, does not change if the signal changes
+            {some_value.get_untracked()}
+        </textarea>
+    }
+    // ANCHOR_END: textarea
+}
+
+#[component]
+pub fn Select() -> impl IntoView {
+    // ANCHOR: select
+    let (value, set_value) = signal("B".to_string());
+    view! {
+        <select
+            on:change:target=move |ev| {
+                set_value.set(ev.target().value());
+            }
+        >
+            <option
+                value="A"
+                selected=move || value.get() == "A"
+            >
+                "A"
+            </option>
+            <option
+                value="B"
+                selected=move || value.get() == "B"
+            >
+                "B"
+            </option>
+        </select>
+    }
+    // ANCHOR_END: select
+}
+
+// ANCHOR: select_option
+#[component]
+pub fn SelectOptions() -> impl IntoView {
+    let (value, set_value) = signal("B".to_string());
+    view! {
+        <select
+            on:change:target=move |ev| {
+                set_value.set(ev.target().value());
+            }
+        >
+            <SelectOption value is="A"/>
+            <SelectOption value is="B"/>
+            <SelectOption value is="C"/>
+        </select>
+    }
+}
+
+#[component]
+pub fn SelectOption(is: &'static str, value: ReadSignal<String>) -> impl IntoView {
+    view! {
+        <option
+            value=is
+            selected=move || value.get() == is
+        >
+            {is}
+        </option>
+    }
+}
+// ANCHOR_END: select_option
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::render;
+
+    #[test]
+    fn the_selected_option_is_marked_on_the_server() {
+        for html in [render(Select), render(SelectOptions)] {
+            assert!(html.contains(r#"<option value="B" selected>"#), "{html}");
+            assert!(!html.contains(r#"<option value="A" selected>"#), "{html}");
+        }
+    }
+
+    #[test]
+    fn textarea_renders_its_initial_value_as_text() {
+        let html = render(TextArea);
+        assert!(html.contains(">Some text</textarea>"), "{html}");
+    }
+}
@@ -0,0 +1,315 @@
+//! Code for "Control Flow".
+//!
+//! Each snippet is its own component, reading the counter in [`Examples`] as
+//! a prop. The mismatched branches that the `.into_any()` conversion fixes
+//! are in `tests/compile_fail/branch_type_mismatch.rs`.
+
+use leptos::{either::EitherOf3, prelude::*};
+
+// ANCHOR: app
+#[component]
+pub fn App() -> impl IntoView {
+    let (value, set_value) = signal(0);
+    let is_odd = move || value.get() & 1 == 1;
+    let odd_text = move || if is_odd() { Some("How odd!") } else { None };
+
+    view! {
+        <h1>"Control Flow"</h1>
+
+        // Simple UI to update and show a value
+        <button on:click=move |_| *set_value.write() += 1>
+            "+1"
+        </button>
+        <p>"Value is: " {value}</p>
+
+        <hr/>
+
+        <h2><code>"Option<T>"</code></h2>
+        // For any `T` that implements `IntoView`,
+        // so does `Option<T>`
+
+        <p>{odd_text}</p>
+        // This means you can use `Option` methods on it
+        <p>{move || odd_text().map(|text| text.len())}</p>
+
+        <h2>"Conditional Logic"</h2>
+        // You can do dynamic conditional if-then-else
+        // logic in several ways
+        //
+        // a. An "if" expression in a function
+        //    This will simply re-render every time the value
+        //    changes, which makes it good for lightweight UI
+        <p>
+            {move || if is_odd() {
+                "Odd"
+            } else {
+                "Even"
+            }}
+        </p>
+
+        // b. Toggling some kind of class
+        //    This is smart for an element that's going to
+        //    toggled often, because it doesn't destroy
+        //    it in between states
+        //    (you can find the `hidden` class in `index.html`)
+        <p class:hidden=is_odd>"Appears if even."</p>
+
+        // c. The <Show/> component
+        //    This only renders the fallback and the child
+        //    once, lazily, and toggles between them when
+        //    needed. This makes it more efficient in many cases
+        //    than a {move || if ...} block
+        <Show when=is_odd
+            fallback=|| view! { <p>"Even steven"</p> }
+        >
+            <p>"Oddment"</p>
+        </Show>
+
+        // d. Because `bool::then()` converts a `bool` to
+        //    `Option`, you can use it to create a show/hide toggled
+        {move || is_odd().then(|| view! { <p>"Oddity!"</p> })}
+
+        <h2>"Converting between Types"</h2>
+        // e. Note: if branches return different types,
+        //    you can convert between them with
+        //    `.into_any()` or using the `Either` enums
+        //    (`Either`, `EitherOf3`, `EitherOf4`, etc.)
+        {move || match is_odd() {
+            true if value.get() == 1 => {
+                // <pre> returns HtmlElement<Pre, ..>
+                view! { <pre>"One"</pre> }.into_any()
+            },
+            false if value.get() == 2 => {
+                // <p> returns HtmlElement<P, ..>
+                // so we convert into a more generic type
+                view! { <p>"Two"</p> }.into_any()
+            }
+            _ => view! { <textarea>{value.get()}</textarea> }.into_any()
+        }}
+    }
+}
+
+pub fn main() {
+    leptos::mount::mount_to_body(App)
+}
+// ANCHOR_END: app
+
+/// The snippets from the chapter, side by side, driven by one counter.
+#[component]
+pub fn Examples() -> impl IntoView {
+    // ANCHOR: signals
+    let (value, set_value) = signal(0);
+    let is_odd = move || value.get() & 1 == 1;
+    // ANCHOR_END: signals
+
+    view! {
+        <button on:click=move |_| *set_value.write() += 1>"+1"</button>
+        <div class="if">
+            <IfExpression is_odd/>
+        </div>
+        <div class="option">
+            <OptionMessage value/>
+        </div>
+        <div class="bool-then">
+            <BoolThen value/>
+        </div>
+        <div class="match">
+            <MatchMessage value/>
+        </div>
+        <div class="over-rendering">
+            <OverRendering value/>
+        </div>
+        <div class="over-rendering-logged">
+            <OverRenderingLogged value/>
+        </div>
+        <div class="over-rendering-components">
+            <OverRenderingComponents value/>
+        </div>
+        <div class="show">
+            <ShowBigSmall value/>
+        </div>
+        <div class="into-any">
+            <IntoAny value/>
+        </div>
+        <div class="either">
+            <EitherBranches value/>
+        </div>
+    }
+}
+
+#[component]
+pub fn IfExpression(is_odd: impl Fn() -> bool + Send + Sync + 'static) -> impl IntoView {
+    // ANCHOR: if_expression
+    view! {
+        <p>
+        {move || if is_odd() {
+            "Odd"
+        } else {
+            "Even"
+        }}
+        </p>
+    }
+    // ANCHOR_END: if_expression
+}
+
+#[component]
+pub fn OptionMessage(value: ReadSignal<i32>) -> impl IntoView {
+    let is_odd = move || value.get() & 1 == 1;
+
+    // ANCHOR: option
+    let message = move || {
+        if is_odd() {
+            Some("Ding ding ding!")
+        } else {
+            None
+        }
+    };
+
+    view! {
+        <p>{message}</p>
+    }
+    // ANCHOR_END: option
+}
+
+#[component]
+pub fn BoolThen(value: ReadSignal<i32>) -> impl IntoView {
+    let is_odd = move || value.get() & 1 == 1;
+
+    // ANCHOR: bool_then
+    let message = move || is_odd().then_some("Ding ding ding!");
+    view! {
+        <p>{message}</p>
+    }
+    // ANCHOR_END: bool_then
+}
+
+#[component]
+pub fn MatchMessage(value: ReadSignal<i32>) -> impl IntoView {
+    let is_odd = move || value.get() & 1 == 1;
+
+    // ANCHOR: match
+    let message = move || match value.get() {
+        0 => "Zero",
+        1 => "One",
+        _ if is_odd() => "Odd",
+        _ => "Even",
+    };
+    view! {
+        <p>{message}</p>
+    }
+    // ANCHOR_END: match
+}
+
+#[component]
+pub fn OverRendering(value: ReadSignal<i32>) -> impl IntoView {
+    // ANCHOR: over_rendering
+    let message = move || if value.get() > 5 { "Big" } else { "Small" };
+
+    view! {
+        <p>{message}</p>
+    }
+    // ANCHOR_END: over_rendering
+}
+
+#[component]
+pub fn OverRenderingLogged(value: ReadSignal<i32>) -> impl IntoView {
+    // ANCHOR: over_rendering_log
+    let message = move || {
+        if value.get() > 5 {
+            leptos::logging::log!("{}: rendering Big", value.get());
+            "Big"
+        } else {
+            leptos::logging::log!("{}: rendering Small", value.get());
+            "Small"
+        }
+    };
+    // ANCHOR_END: over_rendering_log
+
+    view! {
+        <p>{message}</p>
+    }
+}
+
+#[component]
+pub fn Big() -> impl IntoView {
+    view! { <p>"Big"</p> }
+}
+
+#[component]
+pub fn Small() -> impl IntoView {
+    view! { <p>"Small"</p> }
+}
+
+#[component]
+pub fn OverRenderingComponents(value: ReadSignal<i32>) -> impl IntoView {
+    // ANCHOR: over_rendering_components
+    let message = move || {
+        if value.get() > 5 {
+            view! { <Big/> }.into_any()
+        } else {
+            view! { <Small/> }.into_any()
+        }
+    };
+    // ANCHOR_END: over_rendering_components
+
+    message
+}
+
+#[component]
+pub fn ShowBigSmall(value: ReadSignal<i32>) -> impl IntoView {
+    // ANCHOR: show
+    view! {
+        <Show
+            when=move || { value.get() > 5 }
+            fallback=|| view! { <Small/> }
+        >
+            <Big/>
+        </Show>
+    }
+    // ANCHOR_END: show
+}
+
+#[component]
+pub fn IntoAny(value: ReadSignal<i32>) -> impl IntoView {
+    let is_odd = move || value.get() & 1 == 1;
+
+    // ANCHOR: into_any
+    view! {
+        <main>
+            {move || match is_odd() {
+                true if value.get() == 1 => {
+                    // returns HtmlElement<Pre, ..>
+                    view! { <pre>"One"</pre> }.into_any()
+                },
+                false if value.get() == 2 => {
+                    // returns HtmlElement<P, ..>
+                    view! { <p>"Two"</p> }.into_any()
+                }
+                // returns HtmlElement<Textarea, ..>
+                _ => view! { <textarea>{value.get()}</textarea> }.into_any()
+            }}
+        </main>
+    }
+    // ANCHOR_END: into_any
+}
+
+#[component]
+pub fn EitherBranches(value: ReadSignal<i32>) -> impl IntoView {
+    let is_odd = move || value.get() & 1 == 1;
+
+    // ANCHOR: either
+    view! {
+        <main>
+            {move || match is_odd() {
+                true if value.get() == 1 => {
+                    EitherOf3::A(view! { <pre>"One"</pre> })
+                },
+                false if value.get() == 2 => {
+                    EitherOf3::B(view! { <p>"Two"</p> })
+                }
+                _ => EitherOf3::C(view! { <textarea>{value.get()}</textarea> })
+            }}
+        </main>
+    }
+    // ANCHOR_END: either
+}
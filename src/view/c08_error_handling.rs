@@ -0,0 +1,129 @@
+//! Code for "Error Handling".
+
+use leptos::prelude::*;
+
+#[component]
+pub fn ResultInput() -> impl IntoView {
+    // ANCHOR: result
+    let (value, set_value) = signal(Ok(0));
+
+    view! {
+        <label>
+            "Type a number (or not!)"
+            <input type="number"
+                // when input changes, try to parse a number from the input
+                on:input:target=move |ev| set_value.set(ev.target().value().parse::<i32>())
+            />
+            <p>
+                "You entered "
+                <strong>{value}</strong>
+            </p>
+        </label>
+    }
+    // ANCHOR_END: result
+}
+
+// ANCHOR: app
+#[component]
+pub fn App() -> impl IntoView {
+    let (value, set_value) = signal(Ok(0));
+
+    view! {
+        <h1>"Error Handling"</h1>
+        <label>
+            "Type a number (or something that's not a number!)"
+            <input type="number"
+                // when input changes, try to parse a number from the input
+                on:input:target=move |ev| set_value.set(ev.target().value().parse::<i32>())
+            />
+            // ANCHOR: error_boundary
+            // If an `Err(_) has been rendered inside the <ErrorBoundary/>,
+            // the fallback will be displayed. Otherwise, the children of the
+            // <ErrorBoundary/> will be displayed.
+            <ErrorBoundary
+                // the fallback receives a signal containing current errors
+                fallback=|errors| view! {
+                    <div class="error">
+                        <p>"Not a number! Errors: "</p>
+                        // we can render a list of errors
+                        // as strings, if we'd like
+                        <ul>
+                            {move || errors.get()
+                                .into_iter()
+                                .map(|(_, e)| view! { <li>{e.to_string()}</li>})
+                                .collect_view()
+                            }
+                        </ul>
+                    </div>
+                }
+            >
+                <p>
+                    "You entered "
+                    // because `value` is `Result<i32, _>`,
+                    // it will render the `i32` if it is `Ok`,
+                    // and render nothing and trigger the error boundary
+                    // if it is `Err`. It's a signal, so this will dynamically
+                    // update when `value` changes
+                    <strong>{value}</strong>
+                </p>
+            </ErrorBoundary>
+            // ANCHOR_END: error_boundary
+        </label>
+    }
+}
+
+pub fn main() {
+    leptos::mount::mount_to_body(App)
+}
+// ANCHOR_END: app
+
+// ANCHOR: nested
+#[component]
+pub fn NestedBoundaries() -> impl IntoView {
+    let (width, set_width) = signal(Ok(0));
+    let (height, set_height) = signal(Ok(0));
+
+    view! {
+        <input type="number"
+            on:input:target=move |ev| set_width.set(ev.target().value().parse::<i32>())
+        />
+        <input type="number"
+            on:input:target=move |ev| set_height.set(ev.target().value().parse::<i32>())
+        />
+        // an error anywhere inside this boundary replaces everything in it...
+        <ErrorBoundary fallback=|_| view! {
+            <p class="error">"The width isn’t a number."</p>
+        }>
+            <p>"Width: " {width}</p>
+            // ...unless a boundary closer to the error catches it first
+            <ErrorBoundary fallback=|_| view! {
+                <p class="error">"The height isn’t a number."</p>
+            }>
+                <p>"Height: " {height}</p>
+            </ErrorBoundary>
+        </ErrorBoundary>
+    }
+}
+// ANCHOR_END: nested
+
+// ANCHOR: clearing
+#[component]
+pub fn ResettableInput() -> impl IntoView {
+    let (value, set_value) = signal(Ok(0));
+
+    view! {
+        <input type="number"
+            prop:value=move || value.get().map(|n| n.to_string()).unwrap_or_default()
+            on:input:target=move |ev| set_value.set(ev.target().value().parse::<i32>())
+        />
+        <ErrorBoundary fallback=move |_| view! {
+            <p class="error">"Not a number!"</p>
+            // replacing the `Err` with an `Ok` removes its error from the
+            // boundary, which then shows its children again
+            <button on:click=move |_| set_value.set(Ok(0))>"Reset"</button>
+        }>
+            <p>"You entered " <strong>{value}</strong></p>
+        </ErrorBoundary>
+    }
+}
+// ANCHOR_END: clearing
@@ -0,0 +1,14 @@
+//! Code for "Parent-Child Communication".
+//!
+//! Each pattern in the chapter has its own `<App/>`, so each one is a module
+//! of its own, and [`all_four`] is the example that combines them.
+
+pub mod all_four;
+pub mod callback;
+pub mod callback_props;
+pub mod closure;
+pub mod context;
+pub mod event_listener;
+pub mod nested;
+pub mod prop_drilling;
+pub mod write_signal;
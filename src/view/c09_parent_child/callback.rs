@@ -0,0 +1,23 @@
+//! Pattern 2: passing a `Callback` to the child.
+
+use leptos::{ev::MouseEvent, prelude::*};
+
+// ANCHOR: callback
+#[component]
+pub fn App() -> impl IntoView {
+    let (toggled, set_toggled) = signal(false);
+    view! {
+        <p>"Toggled? " {toggled}</p>
+        <ButtonB on_click=move |_| set_toggled.update(|value| *value = !*value)/>
+    }
+}
+
+#[component]
+pub fn ButtonB(#[prop(into)] on_click: Callback<MouseEvent>) -> impl IntoView {
+    view! {
+        <button on:click=move |ev| on_click.run(ev)>
+            "Toggle"
+        </button>
+    }
+}
+// ANCHOR_END: callback
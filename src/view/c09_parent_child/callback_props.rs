@@ -0,0 +1,109 @@
+//! Pattern 2.2: callbacks that take arguments or return values.
+
+use leptos::prelude::*;
+
+// ANCHOR: arguments
+#[component]
+pub fn Calculator(
+    // a callback that takes several arguments takes them as a tuple, and
+    // the second type parameter is what it returns
+    #[prop(into)] op: Callback<(i32, i32), i32>,
+) -> impl IntoView {
+    let (a, b) = (6, 3);
+    // `.run()` calls the callback and returns its output
+    view! { <p>{a} " and " {b} " make " {op.run((a, b))}</p> }
+}
+
+#[component]
+pub fn Calculators() -> impl IntoView {
+    view! {
+        // `into` turns a closure that takes the arguments into a `Callback`...
+        <Calculator op=|a, b| a + b/>
+        // ...while `Callback::new` takes a closure that takes the tuple
+        <Calculator op=Callback::new(|(a, b)| a * b)/>
+    }
+}
+// ANCHOR_END: arguments
+
+// ANCHOR: optional
+#[component]
+pub fn Notice(
+    #[prop(optional, into)] on_dismiss: Option<Callback<()>>,
+    children: Children,
+) -> impl IntoView {
+    view! {
+        <div class="notice">
+            {children()}
+            // only offer to dismiss the notice if the parent is listening
+            {on_dismiss.map(|on_dismiss| {
+                view! { <button on:click=move |_| on_dismiss.run(())>"Dismiss"</button> }
+            })}
+        </div>
+    }
+}
+// ANCHOR_END: optional
+
+// ANCHOR: copy
+#[component]
+pub fn Stepper(value: ReadSignal<i32>, #[prop(into)] on_change: Callback<i32>) -> impl IntoView {
+    // `Callback` is `Copy`, so each event listener can use it without cloning
+    view! {
+        <button on:click=move |_| on_change.run(value.get() - 1)>"-1"</button>
+        <span>{value}</span>
+        <button on:click=move |_| on_change.run(value.get() + 1)>"+1"</button>
+    }
+}
+// ANCHOR_END: copy
+
+// ANCHOR: generic
+#[component]
+pub fn GenericStepper<F>(value: ReadSignal<i32>, on_change: F) -> impl IntoView
+where
+    F: Fn(i32) + Clone + 'static,
+{
+    // a generic closure isn't `Copy`, so it has to be cloned for the second listener
+    let decrement = on_change.clone();
+    view! {
+        <button on:click=move |_| decrement(value.get() - 1)>"-1"</button>
+        <span>{value}</span>
+        <button on:click=move |_| on_change(value.get() + 1)>"+1"</button>
+    }
+}
+// ANCHOR_END: generic
+
+#[component]
+pub fn App() -> impl IntoView {
+    let (value, set_value) = signal(0);
+    let (dismissed, set_dismissed) = signal(false);
+    view! {
+        <Calculators/>
+        <Show when=move || !dismissed.get()>
+            <Notice on_dismiss=move || set_dismissed.set(true)>"Callbacks can be optional."</Notice>
+        </Show>
+        <Stepper value on_change=move |new| set_value.set(new)/>
+        <GenericStepper value on_change=move |new| set_value.set(new)/>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::render;
+
+    #[test]
+    fn callbacks_return_their_output() {
+        assert_eq!(
+            render(Calculators),
+            "<p>6 and 3 make 9</p><p>6 and 3 make 18</p>"
+        );
+    }
+
+    #[test]
+    fn optional_callbacks_can_be_left_out() {
+        let without = render(|| view! { <Notice>"Hi"</Notice> });
+        assert_eq!(without, r#"<div class="notice">Hi</div>"#);
+
+        let with = render(|| view! { <Notice on_dismiss=|| {}>"Hi"</Notice> });
+        assert!(with.contains("<button>Dismiss</button>"), "{with}");
+    }
+}
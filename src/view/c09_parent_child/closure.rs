@@ -0,0 +1,26 @@
+//! Pattern 2.1: passing a closure to the child.
+
+use leptos::{ev::MouseEvent, prelude::*};
+
+// ANCHOR: closure
+#[component]
+pub fn App() -> impl IntoView {
+    let (toggled, set_toggled) = signal(false);
+    view! {
+        <p>"Toggled? " {toggled}</p>
+        <ButtonB on_click=move |_| set_toggled.update(|value| *value = !*value)/>
+    }
+}
+
+#[component]
+pub fn ButtonB<F>(on_click: F) -> impl IntoView
+where
+    F: Fn(MouseEvent) + 'static,
+{
+    view! {
+        <button on:click=on_click>
+            "Toggle"
+        </button>
+    }
+}
+// ANCHOR_END: closure
@@ -0,0 +1,23 @@
+//! Pattern 3: adding an event listener to the child.
+
+use leptos::prelude::*;
+
+// ANCHOR: event_listener
+#[component]
+pub fn App() -> impl IntoView {
+    let (toggled, set_toggled) = signal(false);
+    view! {
+        <p>"Toggled? " {toggled}</p>
+        // note the on:click instead of on_click
+        // this is the same syntax as an HTML element event listener
+        <ButtonC on:click=move |_| set_toggled.update(|value| *value = !*value)/>
+    }
+}
+
+#[component]
+pub fn ButtonC() -> impl IntoView {
+    view! {
+        <button>"Toggle"</button>
+    }
+}
+// ANCHOR_END: event_listener
@@ -0,0 +1,43 @@
+//! A deeply-nested component tree, before the setter reaches `<ButtonD/>`.
+
+use leptos::prelude::*;
+
+// ANCHOR: nested
+#[component]
+pub fn App() -> impl IntoView {
+    let (toggled, _set_toggled) = signal(false);
+    view! {
+        <p>"Toggled? " {toggled}</p>
+        <Layout/>
+    }
+}
+
+#[component]
+pub fn Layout() -> impl IntoView {
+    view! {
+        <header>
+            <h1>"My Page"</h1>
+        </header>
+        <main>
+            <Content/>
+        </main>
+    }
+}
+
+#[component]
+pub fn Content() -> impl IntoView {
+    view! {
+        <div class="content">
+            <ButtonD/>
+        </div>
+    }
+}
+
+#[component]
+pub fn ButtonD() -> impl IntoView {
+    // but how does this toggle `toggled`?
+    view! {
+        <button>"Toggle"</button>
+    }
+}
+// ANCHOR_END: nested
@@ -0,0 +1,46 @@
+//! Getting the setter to `<ButtonD/>` with prop drilling.
+
+use leptos::prelude::*;
+
+// ANCHOR: prop_drilling
+#[component]
+pub fn App() -> impl IntoView {
+    let (toggled, set_toggled) = signal(false);
+    view! {
+        <p>"Toggled? " {toggled}</p>
+        <Layout set_toggled/>
+    }
+}
+
+#[component]
+pub fn Layout(set_toggled: WriteSignal<bool>) -> impl IntoView {
+    view! {
+        <header>
+            <h1>"My Page"</h1>
+        </header>
+        <main>
+            <Content set_toggled/>
+        </main>
+    }
+}
+
+#[component]
+pub fn Content(set_toggled: WriteSignal<bool>) -> impl IntoView {
+    view! {
+        <div class="content">
+            <ButtonD set_toggled/>
+        </div>
+    }
+}
+
+#[component]
+pub fn ButtonD(set_toggled: WriteSignal<bool>) -> impl IntoView {
+    view! {
+        <button
+            on:click=move |_| set_toggled.update(|value| *value = !*value)
+        >
+            "Toggle"
+        </button>
+    }
+}
+// ANCHOR_END: prop_drilling
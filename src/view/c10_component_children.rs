@@ -0,0 +1,222 @@
+//! Code for "Component Children".
+
+use leptos::prelude::*;
+
+/// A `<form>` with some enhancements, which are left to the imagination.
+#[component]
+pub fn FancyForm(children: Children) -> impl IntoView {
+    view! { <form>{children()}</form> }
+}
+
+#[component]
+pub fn FancyFormUsage() -> impl IntoView {
+    // ANCHOR: fancy_form
+    view! {
+        <FancyForm>
+            <fieldset>
+                <label>
+                    "Some Input"
+                    <input type="text" name="something"/>
+                </label>
+            </fieldset>
+            <button>"Submit"</button>
+        </FancyForm>
+    }
+    // ANCHOR_END: fancy_form
+}
+
+#[component]
+pub fn Big() -> impl IntoView {
+    view! { <p>"Big"</p> }
+}
+
+#[component]
+pub fn Small() -> impl IntoView {
+    view! { <p>"Small"</p> }
+}
+
+#[component]
+pub fn ShowUsage(value: ReadSignal<i32>) -> impl IntoView {
+    // ANCHOR: show
+    view! {
+      <Show
+        // `when` is a normal prop
+        when=move || { value.get() > 5 }
+        // `fallback` is a "render prop": a function that returns a view
+        fallback=|| view! { <Small/> }
+      >
+        // `<Big/>` (and anything else here)
+        // will be given to the `children` prop
+        <Big/>
+      </Show>
+    }
+    // ANCHOR_END: show
+}
+
+// ANCHOR: app
+// Often, you want to pass some kind of child view to another
+// component. There are two basic patterns for doing this:
+// - "render props": creating a component prop that takes a function
+//   that creates a view
+// - the `children` prop: a special property that contains content
+//   passed as the children of a component in your view, not as a
+//   property
+
+#[component]
+pub fn App() -> impl IntoView {
+    let (items, _set_items) = signal(vec![0, 1, 2]);
+    let render_prop = move || {
+        // items.with(...) reacts to the value without cloning
+        // by applying a function. Here, we pass the `len` method
+        // on a `Vec<_>` directly
+        let len = move || items.with(Vec::len);
+        view! {
+            <p>"Length: " {len}</p>
+        }
+    };
+
+    view! {
+        // This component just displays the two kinds of children,
+        // embedding them in some other markup
+        <TakesChildren
+            // for component props, you can shorthand
+            // `render_prop=render_prop` => `render_prop`
+            // (this doesn't work for HTML element attributes)
+            render_prop
+        >
+            // these look just like the children of an HTML element
+            <p>"Here's a child."</p>
+            <p>"Here's another child."</p>
+        </TakesChildren>
+        <hr/>
+        // This component actually iterates over and wraps the children
+        <WrapsChildren>
+            <p>"Here's a child."</p>
+            <p>"Here's another child."</p>
+        </WrapsChildren>
+    }
+}
+
+// ANCHOR: takes_children
+/// Displays a `render_prop` and some children within markup.
+#[component]
+pub fn TakesChildren<F, IV>(
+    /// Takes a function (type F) that returns anything that can be
+    /// converted into a View (type IV)
+    render_prop: F,
+    /// `children` can take one of several different types, each of which
+    /// is a function that returns some view type
+    children: Children,
+) -> impl IntoView
+where
+    F: Fn() -> IV,
+    IV: IntoView,
+{
+    view! {
+        <h1><code>"<TakesChildren/>"</code></h1>
+        <h2>"Render Prop"</h2>
+        {render_prop()}
+        <hr/>
+        <h2>"Children"</h2>
+        {children()}
+    }
+}
+// ANCHOR_END: takes_children
+
+// ANCHOR: wraps_children
+/// Wraps each child in an `<li>` and embeds them in a `<ul>`.
+#[component]
+pub fn WrapsChildren(children: ChildrenFragment) -> impl IntoView {
+    // children() returns a `Fragment`, which has a
+    // `nodes` field that contains a Vec<AnyView>
+    // this means we can iterate over the children
+    // to create something new!
+    let children = children()
+        .nodes
+        .into_iter()
+        .map(|child| view! { <li>{child}</li> })
+        .collect::<Vec<_>>();
+
+    view! {
+        <h1><code>"<WrapsChildren/>"</code></h1>
+        // wrap our wrapped children in a UL
+        <ul>{children}</ul>
+    }
+}
+// ANCHOR_END: wraps_children
+
+pub fn main() {
+    leptos::mount::mount_to_body(App)
+}
+// ANCHOR_END: app
+
+#[component]
+pub fn TakesChildrenUsage() -> impl IntoView {
+    // ANCHOR: takes_children_usage
+    view! {
+        <TakesChildren render_prop=|| view! { <p>"Hi, there!"</p> }>
+            // these get passed to `children`
+            "Some text"
+            <span>"A span"</span>
+        </TakesChildren>
+    }
+    // ANCHOR_END: takes_children_usage
+}
+
+// ANCHOR: children_fn
+/// Shows or hides its children, recreating them each time they're shown.
+#[component]
+pub fn Collapsible(children: ChildrenFn) -> impl IntoView {
+    let (open, set_open) = signal(true);
+
+    view! {
+        <button on:click=move |_| set_open.update(|open| *open = !*open)>
+            "Toggle"
+        </button>
+        // this closure runs again every time `open` changes,
+        // so it needs to be able to call `children` more than once
+        {move || open.get().then(|| children())}
+    }
+}
+// ANCHOR_END: children_fn
+
+#[component]
+pub fn WrapsChildrenUsage() -> impl IntoView {
+    // ANCHOR: wraps_children_usage
+    view! {
+        <WrapsChildren>
+            "A"
+            "B"
+            "C"
+        </WrapsChildren>
+    }
+    // ANCHOR_END: wraps_children_usage
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::render;
+
+    #[test]
+    fn children_render_inside_the_component() {
+        let html = render(FancyFormUsage);
+        assert!(html.starts_with("<form><fieldset>"), "{html}");
+        assert!(html.ends_with("<button>Submit</button></form>"), "{html}");
+
+        let html = render(TakesChildrenUsage);
+        assert!(
+            html.ends_with("<h2>Children</h2>Some text<span>A span</span>"),
+            "{html}"
+        );
+    }
+
+    #[test]
+    fn each_child_is_wrapped() {
+        let html = render(WrapsChildrenUsage);
+        assert!(
+            html.contains("<ul><li>A</li><li>B</li><li>C</li>"),
+            "{html}"
+        );
+    }
+}
@@ -0,0 +1,138 @@
+//! Code for "No Macros: The View Builder Syntax".
+//!
+//! The tests render these, and compare them with the same views written with
+//! `view!`.
+
+use leptos::{ev, html::*, prelude::*};
+
+// ANCHOR: child
+pub fn big_bold_text() -> impl IntoView {
+    p().child((em().child("Big, "), strong().child("bold "), "text"))
+}
+// ANCHOR_END: child
+
+// ANCHOR: attr
+pub fn attributes(count: ReadSignal<i32>) -> impl IntoView {
+    p()
+        // the standard attributes have a method each...
+        .id("foo")
+        // ...and any other attribute can be set by name
+        .attr("data-count", move || count.get().to_string())
+        // these are `class:`, `style:`, and `prop:` from `view!`
+        .class(("odd", move || count.get() % 2 == 1))
+        .style((
+            "color",
+            move || if count.get() < 0 { "red" } else { "black" },
+        ))
+        .prop("title", "A paragraph")
+        .child("Count")
+}
+// ANCHOR_END: attr
+
+// ANCHOR: counter
+/// A simple counter view.
+// A component is really just a function call: it runs once to create the DOM and reactive system
+pub fn counter(initial_value: i32, step: i32) -> impl IntoView {
+    let (count, set_count) = signal(initial_value);
+
+    div().child((
+        button()
+            // typed events found in leptos::ev
+            // 1) prevent typos in event names
+            // 2) allow for correct type inference in callbacks
+            .on(ev::click, move |_| set_count.set(0))
+            .child("Clear"),
+        button()
+            .on(ev::click, move |_| *set_count.write() -= step)
+            .child("-1"),
+        span().child(("Value: ", count, "!")),
+        button()
+            .on(ev::click, move |_| *set_count.write() += step)
+            .child("+1"),
+    ))
+}
+// ANCHOR_END: counter
+
+// ANCHOR: list
+/// A list of counters, like the one in "Iteration".
+pub fn counter_list(length: usize) -> impl IntoView {
+    // plain Rust iterators can build the children, without any macro magic
+    let counters = (1..=length as i32)
+        .map(|idx| signal(idx))
+        .collect::<Vec<_>>();
+    let counter_buttons = counters
+        .into_iter()
+        .map(|(count, set_count)| {
+            li().child(
+                button()
+                    .on(ev::click, move |_| *set_count.write() += 1)
+                    .child(count),
+            )
+        })
+        .collect_view();
+
+    ul().child(counter_buttons)
+}
+// ANCHOR_END: list
+
+// ANCHOR: for_builder
+/// The same list, keyed with `<For/>`, which is a function like any other component.
+pub fn keyed_counter_list(length: usize) -> impl IntoView {
+    let (counters, _set_counters) = signal((0..length).collect::<Vec<_>>());
+
+    ul().child(For(ForProps::builder()
+        .each(move || counters.get())
+        .key(|id| *id)
+        .children(|id| li().child(id))
+        .build()))
+}
+// ANCHOR_END: for_builder
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::render;
+
+    #[test]
+    fn children_and_attributes_render_like_the_view_macro() {
+        assert_eq!(
+            render(big_bold_text),
+            render(|| view! { <p><em>"Big, "</em><strong>"bold "</strong>"text"</p> })
+        );
+
+        let html = render(|| attributes(signal(3).0));
+        assert!(
+            html.starts_with(r#"<p id="foo" data-count="3" class="odd" style="color:black;""#),
+            "{html}"
+        );
+    }
+
+    #[test]
+    fn the_counter_renders_like_the_view_macro() {
+        assert_eq!(
+            render(|| counter(10, 1)),
+            render(|| {
+                let (count, _) = signal(10);
+                view! {
+                    <div>
+                        <button>"Clear"</button>
+                        <button>"-1"</button>
+                        <span>"Value: " {count} "!"</span>
+                        <button>"+1"</button>
+                    </div>
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn lists_are_built_from_iterators() {
+        let html = render(|| counter_list(3));
+        assert_eq!(
+            html,
+            "<ul><li><button>1</button></li><li><button>2</button></li><li><button>3</button></li></ul>"
+        );
+        let html = render(|| keyed_counter_list(2));
+        assert!(html.contains("<li>0</li><li>1</li>"), "{html}");
+    }
+}
@@ -0,0 +1,65 @@
+//! Code for "Portals".
+//!
+//! `<Portal/>` only renders in the browser, so this is tested by the DOM tests
+//! in `tests/dom/portal.rs`.
+
+use leptos::{portal::Portal, prelude::*};
+
+// ANCHOR: modal
+#[component]
+pub fn ModalExample() -> impl IntoView {
+    let (open, set_open) = signal(false);
+
+    view! {
+        <div class="card" style="overflow: hidden">
+            <button on:click=move |_| set_open.set(true)>"Open the modal"</button>
+            <Show when=move || open.get()>
+                // the children are rendered at the end of `<body>`, so the
+                // card's `overflow: hidden` can't clip them
+                <Portal>
+                    <div class="modal">
+                        <p>"I’m not inside the card!"</p>
+                        <button on:click=move |_| set_open.set(false)>"Close"</button>
+                    </div>
+                </Portal>
+            </Show>
+        </div>
+    }
+}
+// ANCHOR_END: modal
+
+/// The text the [`ModalTitle`] in the portal shows.
+#[derive(Clone, Debug)]
+pub struct Title(pub &'static str);
+
+// ANCHOR: context
+#[component]
+pub fn ContextExample() -> impl IntoView {
+    provide_context(Title("Provided outside the portal"));
+
+    view! {
+        <Portal>
+            // the portal moves where the children are in the DOM, but not
+            // where they are in the component tree
+            <ModalTitle/>
+        </Portal>
+    }
+}
+
+#[component]
+pub fn ModalTitle() -> impl IntoView {
+    let title = expect_context::<Title>();
+    view! { <h2 class="modal-title">{title.0}</h2> }
+}
+// ANCHOR_END: context
+
+// ANCHOR: mount
+#[component]
+pub fn MountedElsewhere(target: web_sys::Element) -> impl IntoView {
+    view! {
+        <Portal mount=target>
+            <p class="toast">"Saved!"</p>
+        </Portal>
+    }
+}
+// ANCHOR_END: mount
@@ -0,0 +1,59 @@
+//! Code for "Animated Visibility with `<AnimatedShow/>`".
+//!
+//! The animations play out over time in the browser, so this is tested by the
+//! DOM tests in `tests/dom/animated_show.rs`.
+
+use leptos::prelude::*;
+use std::time::Duration;
+
+// ANCHOR: animated_show
+#[component]
+pub fn HoverMe() -> impl IntoView {
+    let show = RwSignal::new(false);
+
+    view! {
+        <div
+            class="hover-me"
+            on:mouseenter=move |_| show.set(true)
+            on:mouseleave=move |_| show.set(false)
+        >
+            "Hover Me"
+        </div>
+
+        <AnimatedShow
+            when=show
+            // added to the wrapping <div> as soon as `when` is true...
+            show_class="fade-in-1000"
+            // ...or false, but the children stay mounted until the delay is over,
+            // so that the animation has time to play
+            hide_class="fade-out-1000"
+            hide_delay=Duration::from_millis(1000)
+        >
+            <div class="here-i-am">"Here I Am!"</div>
+        </AnimatedShow>
+    }
+}
+// ANCHOR_END: animated_show
+
+// ANCHOR: derived
+#[component]
+pub fn UnsavedChanges() -> impl IntoView {
+    let (text, set_text) = signal(String::new());
+    let (saved, set_saved) = signal(String::new());
+    // `when` takes any `Signal<bool>`, including one derived from other signals
+    let unsaved = Signal::derive(move || *text.read() != *saved.read());
+
+    view! {
+        <textarea prop:value=text on:input:target=move |ev| set_text.set(ev.target().value())/>
+        <button on:click=move |_| set_saved.set(text.get())>"Save"</button>
+        <AnimatedShow
+            when=unsaved
+            show_class="slide-in"
+            hide_class="slide-out"
+            hide_delay=Duration::from_millis(300)
+        >
+            <p class="warning">"You have unsaved changes."</p>
+        </AnimatedShow>
+    }
+}
+// ANCHOR_END: derived
@@ -0,0 +1,151 @@
+//! Code for "Slots".
+//!
+//! The tests render the slots on the server; switching tabs is tested by the
+//! DOM tests in `tests/dom/slots.rs`.
+
+use leptos::prelude::*;
+
+// ANCHOR: slot
+// a slot is a struct, with fields for its props, just like a component's
+#[slot]
+pub struct Then {
+    children: ChildrenFn,
+}
+
+#[slot]
+pub struct ElseIf {
+    cond: Signal<bool>,
+    children: ChildrenFn,
+}
+
+#[slot]
+pub struct Fallback {
+    children: ChildrenFn,
+}
+// ANCHOR_END: slot
+
+// ANCHOR: if_component
+#[component]
+pub fn If(
+    cond: Signal<bool>,
+    // a slot prop is named after the slot, in `snake_case`...
+    then: Then,
+    // ...and can be optional, or a `Vec` to take any number of them
+    #[prop(default = vec![])] else_if: Vec<ElseIf>,
+    #[prop(optional)] fallback: Option<Fallback>,
+) -> impl IntoView {
+    move || {
+        if cond.get() {
+            (then.children)().into_any()
+        } else if let Some(else_if) = else_if.iter().find(|i| i.cond.get()) {
+            (else_if.children)().into_any()
+        } else if let Some(fallback) = &fallback {
+            (fallback.children)().into_any()
+        } else {
+            ().into_any()
+        }
+    }
+}
+// ANCHOR_END: if_component
+
+#[component]
+pub fn FizzBuzz(count: ReadSignal<u32>) -> impl IntoView {
+    // ANCHOR: use_slots
+    let is_fizz = Signal::derive(move || count.get().is_multiple_of(3));
+    let is_buzz = Signal::derive(move || count.get().is_multiple_of(5));
+    let is_fizzbuzz = Signal::derive(move || is_fizz.get() && is_buzz.get());
+
+    view! {
+        <If cond=is_fizzbuzz>
+            // the `slot` attribute passes the element to the slot prop of
+            // the same name, instead of to `children`
+            <Then slot>"FizzBuzz"</Then>
+            <ElseIf slot cond=is_fizz>"Fizz"</ElseIf>
+            <ElseIf slot cond=is_buzz>"Buzz"</ElseIf>
+            <Fallback slot>{count}</Fallback>
+        </If>
+    }
+    // ANCHOR_END: use_slots
+}
+
+// ANCHOR: tabs
+#[slot]
+pub struct Tab {
+    label: &'static str,
+    children: ChildrenFn,
+}
+
+#[component]
+pub fn Tabs(tab: Vec<Tab>) -> impl IntoView {
+    let (selected, set_selected) = signal(0);
+
+    let buttons = tab
+        .iter()
+        .enumerate()
+        .map(|(index, tab)| {
+            view! {
+                <button
+                    role="tab"
+                    class:selected=move || selected.get() == index
+                    on:click=move |_| set_selected.set(index)
+                >
+                    {tab.label}
+                </button>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <div class="tabs">
+            <div role="tablist">{buttons}</div>
+            <div role="tabpanel">{move || (tab[selected.get()].children)()}</div>
+        </div>
+    }
+}
+
+#[component]
+pub fn Settings() -> impl IntoView {
+    view! {
+        <Tabs>
+            <Tab slot label="Profile">
+                <p>"Your name and picture."</p>
+            </Tab>
+            <Tab slot label="Notifications">
+                <p>"What we email you about."</p>
+            </Tab>
+        </Tabs>
+    }
+}
+// ANCHOR_END: tabs
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::render;
+
+    #[test]
+    fn the_first_matching_slot_is_rendered() {
+        let fizz_buzz = |count| {
+            render(|| {
+                let count = signal(count).0;
+                view! { <FizzBuzz count/> }
+            })
+        };
+        assert_eq!(fizz_buzz(15), "FizzBuzz");
+        assert_eq!(fizz_buzz(9), "Fizz");
+        assert_eq!(fizz_buzz(10), "Buzz");
+        assert_eq!(fizz_buzz(7), "7");
+    }
+
+    #[test]
+    fn tabs_show_every_label_and_the_first_panel() {
+        let html = render(Settings);
+        assert!(
+            html.contains(r#"<button role="tab" class="selected">Profile</button>"#),
+            "{html}"
+        );
+        assert!(html.contains("Notifications</button>"), "{html}");
+        assert!(html.contains("Your name and picture."), "{html}");
+        assert!(!html.contains("What we email you about."), "{html}");
+    }
+}
@@ -0,0 +1,108 @@
+//! Code for "Spreading Attributes".
+//!
+//! The tests render the components on the server, where the spread attributes
+//! end up in the HTML like any others. Event listeners only run in the
+//! browser, so spreading `on:click` is tested by the DOM tests in
+//! `tests/dom/spread.rs`.
+
+use leptos::{attr::any_attribute::AnyAttribute, prelude::*};
+
+// ANCHOR: spread_element
+#[component]
+pub fn SpreadOntoElement() -> impl IntoView {
+    // `<{..} />` builds a set of attributes without an element to put them on...
+    let attrs = view! { <{..} id="greeting" class="card" data-theme="dark"/> };
+
+    view! {
+        // ...and `{..attrs}` spreads them onto one
+        <div {..attrs}>"Hello!"</div>
+    }
+}
+// ANCHOR_END: spread_element
+
+// ANCHOR: spread_component
+#[component]
+pub fn Button(children: Children) -> impl IntoView {
+    view! { <button type="button">{children()}</button> }
+}
+
+#[component]
+pub fn SpreadOntoComponent() -> impl IntoView {
+    let (clicks, set_clicks) = signal(0);
+
+    view! {
+        <Button
+            // `attr:`, `class:`, `style:` and `on:` go to the component's
+            // top-level element(s), rather than to its props...
+            on:click=move |_| *set_clicks.write() += 1
+            // ...and so does everything after `{..}`
+            {..}
+            class="primary"
+            aria-label="Count the clicks"
+        >
+            "Clicked " {clicks} " times"
+        </Button>
+    }
+}
+// ANCHOR_END: spread_component
+
+// ANCHOR: forwarding
+#[component]
+pub fn TextField(
+    label: &'static str,
+    // attributes to forward to the `<input>`, rather than the `<label>`
+    #[prop(optional)] attrs: Vec<AnyAttribute>,
+) -> impl IntoView {
+    view! {
+        <label class="text-field">
+            {label}
+            <input type="text" {..attrs}/>
+        </label>
+    }
+}
+
+#[component]
+pub fn SignUp() -> impl IntoView {
+    view! {
+        <TextField
+            label="Email"
+            // any attribute can be turned into an `AnyAttribute`, so the
+            // caller can pass whichever ones it needs to
+            attrs=vec![
+                view! { <{..} name="email" placeholder="ferris@example.com"/> }.into_any_attr(),
+                view! { <{..} required=true/> }.into_any_attr(),
+            ]
+        />
+    }
+}
+// ANCHOR_END: forwarding
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::render;
+
+    #[test]
+    fn spread_attributes_land_on_the_element() {
+        assert_eq!(
+            render(SpreadOntoElement),
+            r#"<div id="greeting" data-theme="dark" class="card">Hello!</div>"#
+        );
+    }
+
+    #[test]
+    fn attributes_after_the_marker_go_to_the_top_level_element() {
+        assert_eq!(
+            render(SpreadOntoComponent),
+            r#"<button type="button" aria-label="Count the clicks" class="primary">Clicked 0 times</button>"#
+        );
+    }
+
+    #[test]
+    fn forwarded_attributes_skip_the_wrapper() {
+        assert_eq!(
+            render(SignUp),
+            r#"<label class="text-field">Email<input type="text" name="email" placeholder="ferris@example.com" required></label>"#
+        );
+    }
+}
@@ -0,0 +1,134 @@
+//! Code for "Drag and Drop".
+//!
+//! Reordering the list is plain Rust, which the tests below check. Dragging
+//! only happens in a browser, so the event handlers are tested by the DOM
+//! tests in `tests/dom/drag_and_drop.rs`, which dispatch the same events a
+//! real drag would.
+
+use leptos::{ev::DragEvent, prelude::*};
+
+// ANCHOR: move_to
+#[derive(Clone, Debug, PartialEq)]
+pub struct Task {
+    pub id: u32,
+    pub title: String,
+}
+
+/// Moves the task with `id` to where the task with `target` is. Dragging an
+/// item down the list puts it after the target, and dragging it up puts it
+/// before, which is where it looks like it's going.
+pub fn move_to(tasks: &mut Vec<Task>, id: u32, target: u32) {
+    let from = tasks.iter().position(|task| task.id == id);
+    let to = tasks.iter().position(|task| task.id == target);
+    if let (Some(from), Some(to)) = (from, to) {
+        let task = tasks.remove(from);
+        tasks.insert(to, task);
+    }
+}
+// ANCHOR_END: move_to
+
+// ANCHOR: sortable_list
+/// The type the dragged task's ID is stored under while it's dragged.
+const TASK_ID: &str = "application/x-task-id";
+
+#[component]
+pub fn SortableList(initial: Vec<Task>) -> impl IntoView {
+    let tasks = RwSignal::new(initial);
+    // only used for styling: the drop handler reads the ID from the event
+    let dragging = RwSignal::new(None::<u32>);
+
+    view! {
+        <ul class="sortable">
+            <For each=move || tasks.get() key=|task| task.id let:task>
+                <li
+                    // links and images are draggable by default, but anything
+                    // else has to opt in
+                    draggable="true"
+                    class:dragging=move || dragging.get() == Some(task.id)
+                    on:dragstart=move |ev: DragEvent| {
+                        let data = ev.data_transfer().unwrap();
+                        _ = data.set_data(TASK_ID, &task.id.to_string());
+                        data.set_effect_allowed("move");
+                        dragging.set(Some(task.id));
+                    }
+                    // an element only accepts a drop if its `dragover` handler
+                    // cancels the event
+                    on:dragover=move |ev: DragEvent| {
+                        ev.prevent_default();
+                        ev.data_transfer().unwrap().set_drop_effect("move");
+                    }
+                    on:drop=move |ev: DragEvent| {
+                        ev.prevent_default();
+                        // anything can be dropped here, so check that it's a task
+                        let dropped = ev.data_transfer().unwrap().get_data(TASK_ID);
+                        if let Ok(id) = dropped.unwrap_or_default().parse() {
+                            tasks.update(|tasks| move_to(tasks, id, task.id));
+                        }
+                    }
+                    // fires on the dragged element, whether it was dropped or not
+                    on:dragend=move |_| dragging.set(None)
+                >
+                    {task.title}
+                </li>
+            </For>
+        </ul>
+    }
+}
+// ANCHOR_END: sortable_list
+
+// ANCHOR: app
+#[component]
+pub fn App() -> impl IntoView {
+    let initial = ["Write the chapter", "Test the example", "Publish the book"]
+        .into_iter()
+        .zip(1..)
+        .map(|(title, id)| Task {
+            id,
+            title: title.to_string(),
+        })
+        .collect();
+
+    view! { <SortableList initial/> }
+}
+// ANCHOR_END: app
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tasks(ids: &[u32]) -> Vec<Task> {
+        ids.iter()
+            .map(|&id| Task {
+                id,
+                title: format!("Task {id}"),
+            })
+            .collect()
+    }
+
+    fn ids(tasks: &[Task]) -> Vec<u32> {
+        tasks.iter().map(|task| task.id).collect()
+    }
+
+    #[test]
+    fn dragging_down_puts_the_task_after_the_target() {
+        let mut list = tasks(&[1, 2, 3, 4]);
+        move_to(&mut list, 1, 3);
+        assert_eq!(ids(&list), [2, 3, 1, 4]);
+    }
+
+    #[test]
+    fn dragging_up_puts_the_task_before_the_target() {
+        let mut list = tasks(&[1, 2, 3, 4]);
+        move_to(&mut list, 4, 2);
+        assert_eq!(ids(&list), [1, 4, 2, 3]);
+    }
+
+    #[test]
+    fn unknown_ids_leave_the_list_alone() {
+        let mut list = tasks(&[1, 2, 3]);
+        move_to(&mut list, 9, 2);
+        move_to(&mut list, 1, 9);
+        move_to(&mut list, 2, 2);
+        assert_eq!(ids(&list), [1, 2, 3]);
+    }
+}
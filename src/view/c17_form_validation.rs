@@ -0,0 +1,195 @@
+//! Code for "Validating Forms".
+//!
+//! The rules for each field are plain functions, and the fields' signals
+//! work without a browser, so the tests below check both. The DOM tests in
+//! `tests/dom/form_validation.rs` check what the user sees.
+
+use leptos::{ev::SubmitEvent, prelude::*};
+
+// ANCHOR: rules
+pub fn username_error(username: &str) -> Option<&'static str> {
+    if username.is_empty() {
+        Some("Choose a username.")
+    } else if username.chars().count() < 3 {
+        Some("Usernames are at least 3 characters long.")
+    } else if !username
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_')
+    {
+        Some("Usernames can only have letters, numbers and underscores.")
+    } else {
+        None
+    }
+}
+
+pub fn password_error(password: &str) -> Option<&'static str> {
+    if password.chars().count() < 8 {
+        Some("Passwords are at least 8 characters long.")
+    } else {
+        None
+    }
+}
+
+pub fn confirm_error(password: &str, confirm: &str) -> Option<&'static str> {
+    (password != confirm).then_some("The passwords don't match.")
+}
+
+pub fn age_error(age: &str) -> Option<&'static str> {
+    match age.trim().parse::<u32>() {
+        Err(_) => Some("Enter your age as a number."),
+        Ok(age) if age < 13 => Some("You have to be 13 or older to sign up."),
+        Ok(_) => None,
+    }
+}
+// ANCHOR_END: rules
+
+// ANCHOR: validated_field
+/// A form field's value, and what's wrong with it, if anything.
+#[derive(Clone, Copy)]
+pub struct ValidatedField {
+    pub value: RwSignal<String>,
+    /// Recalculated whenever the value, or anything else `validate` reads,
+    /// changes.
+    pub error: Memo<Option<&'static str>>,
+    /// Whether the user has left the field, after which its error is shown.
+    pub touched: RwSignal<bool>,
+}
+
+impl ValidatedField {
+    pub fn new(validate: impl Fn(&str) -> Option<&'static str> + Send + Sync + 'static) -> Self {
+        let value = RwSignal::new(String::new());
+        Self {
+            value,
+            error: Memo::new(move |_| validate(&value.read())),
+            touched: RwSignal::new(false),
+        }
+    }
+
+    /// The error to show next to the field. It's hidden until the user has
+    /// finished with the field, rather than telling them off for the first
+    /// character they type.
+    pub fn visible_error(&self) -> Option<&'static str> {
+        if self.touched.get() {
+            self.error.get()
+        } else {
+            None
+        }
+    }
+}
+// ANCHOR_END: validated_field
+
+// ANCHOR: validated_input
+#[component]
+pub fn ValidatedInput(
+    label: &'static str,
+    name: &'static str,
+    field: ValidatedField,
+    #[prop(default = "text")] kind: &'static str,
+) -> impl IntoView {
+    let error_id = format!("{name}-error");
+
+    view! {
+        <label for=name>{label}</label>
+        <input
+            id=name
+            name=name
+            type=kind
+            bind:value=field.value
+            on:blur=move |_| field.touched.set(true)
+            class:invalid=move || field.visible_error().is_some()
+            aria-invalid=move || field.visible_error().map(|_| "true")
+            aria-describedby=error_id.clone()
+        />
+        <p id=error_id class="error">{move || field.visible_error()}</p>
+    }
+}
+// ANCHOR_END: validated_input
+
+// ANCHOR: sign_up_form
+#[component]
+pub fn SignUpForm() -> impl IntoView {
+    let username = ValidatedField::new(username_error);
+    let password = ValidatedField::new(password_error);
+    // this one reads the password too, so changing the password can make it
+    // wrong (or right) without the user touching it
+    let confirm =
+        ValidatedField::new(move |confirm| confirm_error(&password.value.read(), confirm));
+    let age = ValidatedField::new(age_error);
+
+    // valid only if every field is, and recalculated whenever any of their
+    // errors change, rather than every time a character is typed
+    let is_valid = Memo::new(move |_| {
+        [username, password, confirm, age]
+            .iter()
+            .all(|field| field.error.read().is_none())
+    });
+
+    let (signed_up, set_signed_up) = signal(None::<String>);
+    let on_submit = move |ev: SubmitEvent| {
+        ev.prevent_default();
+        // the button is disabled while the form is invalid, but pressing
+        // Enter in a field submits it too, so check again here
+        if !is_valid.get_untracked() {
+            return;
+        }
+        set_signed_up.set(Some(username.value.get_untracked()));
+    };
+
+    view! {
+        <form on:submit=on_submit novalidate>
+            <ValidatedInput label="Username" name="username" field=username/>
+            <ValidatedInput label="Password" name="password" field=password kind="password"/>
+            <ValidatedInput label="Confirm password" name="confirm" field=confirm kind="password"/>
+            <ValidatedInput label="Age" name="age" field=age kind="number"/>
+            <button type="submit" disabled=move || !is_valid.get()>"Sign up"</button>
+        </form>
+        {move || signed_up.get().map(|name| view! { <p role="status">"Welcome, " {name} "!"</p> })}
+    }
+}
+// ANCHOR_END: sign_up_form
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn each_rule_explains_what_is_wrong() {
+        assert_eq!(username_error(""), Some("Choose a username."));
+        assert!(username_error("ab").is_some());
+        assert!(username_error("ada lovelace").is_some());
+        assert_eq!(username_error("ada_1815"), None);
+
+        assert!(password_error("short").is_some());
+        assert_eq!(password_error("long enough"), None);
+        assert!(confirm_error("long enough", "long enuogh").is_some());
+        assert_eq!(confirm_error("long enough", "long enough"), None);
+
+        assert_eq!(age_error("twelve"), Some("Enter your age as a number."));
+        assert!(age_error("12").is_some());
+        assert_eq!(age_error(" 36 "), None);
+    }
+
+    #[test]
+    fn errors_follow_the_values_they_depend_on() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let password = ValidatedField::new(password_error);
+            let confirm =
+                ValidatedField::new(move |confirm| confirm_error(&password.value.read(), confirm));
+
+            password.value.set("correct horse".into());
+            confirm.value.set("correct horse".into());
+            assert_eq!(password.error.get(), None);
+            assert_eq!(confirm.error.get(), None);
+
+            // changing the password makes the confirmation wrong
+            password.value.set("battery staple".into());
+            assert_eq!(confirm.error.get(), Some("The passwords don't match."));
+
+            // but it isn't shown until the user has left the field
+            assert_eq!(confirm.visible_error(), None);
+            confirm.touched.set(true);
+            assert_eq!(confirm.visible_error(), Some("The passwords don't match."));
+        });
+    }
+}
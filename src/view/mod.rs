@@ -0,0 +1,18 @@
+pub mod c01_basic_component;
+pub mod c03_dynamic_attributes;
+pub mod c03_generic_components;
+pub mod c03_signal_props;
+pub mod c04_iteration;
+pub mod c05_complex_iteration;
+pub mod c06_forms_inputs;
+pub mod c07_control_flow;
+pub mod c08_error_handling;
+pub mod c09_parent_child;
+pub mod c10_component_children;
+pub mod c11_builder;
+pub mod c12_portal;
+pub mod c13_animated_show;
+pub mod c14_slots;
+pub mod c15_spread;
+pub mod c16_drag_and_drop;
+pub mod c17_form_validation;
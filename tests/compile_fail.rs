@@ -0,0 +1,17 @@
+//! Mistakes that readers commonly make, and the errors the compiler gives
+//! for them. The `.stderr` snapshots are included in the chapters that
+//! explain them, mostly in the "Common Compiler Errors" appendix.
+//!
+//! After upgrading Leptos or Rust, regenerate the snapshots with
+//!
+//! ```sh
+//! TRYBUILD=overwrite cargo test --test compile_fail
+//! ```
+// calling a signal like a function is only an error without the `nightly` feature
+#![cfg(not(any(target_arch = "wasm32", feature = "nightly")))]
+
+#[test]
+fn common_mistakes() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/compile_fail/*.rs");
+}
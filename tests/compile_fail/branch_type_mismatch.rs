@@ -0,0 +1,30 @@
+use leptos::prelude::*;
+
+// ANCHOR: branch_type_mismatch
+#[component]
+fn OneOrTwo() -> impl IntoView {
+    let (value, _set_value) = signal(0);
+    let is_odd = move || value.get() & 1 == 1;
+
+    view! {
+        <main>
+            {move || match is_odd() {
+                true if value.get() == 1 => {
+                    // returns HtmlElement<Pre, ..>
+                    view! { <pre>"One"</pre> }
+                },
+                false if value.get() == 2 => {
+                    // returns HtmlElement<P, ..>
+                    view! { <p>"Two"</p> }
+                }
+                // returns HtmlElement<Textarea, ..>
+                _ => view! { <textarea>{value.get()}</textarea> }
+            }}
+        </main>
+    }
+}
+// ANCHOR_END: branch_type_mismatch
+
+fn main() {
+    let _ = OneOrTwo;
+}
@@ -0,0 +1,17 @@
+use leptos::prelude::*;
+
+// ANCHOR: call_signal_on_stable
+#[component]
+fn Double() -> impl IntoView {
+    let (count, _set_count) = signal(1);
+
+    // calling a signal like a function requires the `nightly` feature
+    let double = move || count() * 2;
+
+    view! { <p>{double}</p> }
+}
+// ANCHOR_END: call_signal_on_stable
+
+fn main() {
+    let _ = Double;
+}
@@ -0,0 +1,21 @@
+use leptos::prelude::*;
+
+// ANCHOR: generic_into_prop
+#[component]
+fn Labeled<T: IntoView + 'static>(
+    // `into` converts the value to `T`, but nothing else says what `T` is
+    #[prop(into)]
+    label: T,
+) -> impl IntoView {
+    view! { <span class="label">{label}</span> }
+}
+
+#[component]
+fn App() -> impl IntoView {
+    view! { <Labeled label=5/> }
+}
+// ANCHOR_END: generic_into_prop
+
+fn main() {
+    let _ = App;
+}
@@ -0,0 +1,19 @@
+use leptos::prelude::*;
+
+// ANCHOR: missing_move
+#[component]
+fn Counter() -> impl IntoView {
+    let (count, set_count) = signal(0);
+
+    view! {
+        // this closure borrows `set_count`, instead of moving it
+        <button on:click=|_| set_count.update(|n| *n += 1)>
+            {count}
+        </button>
+    }
+}
+// ANCHOR_END: missing_move
+
+fn main() {
+    let _ = Counter;
+}
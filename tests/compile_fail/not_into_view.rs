@@ -0,0 +1,19 @@
+use leptos::prelude::*;
+
+// ANCHOR: not_into_view
+struct User {
+    name: String,
+}
+
+#[component]
+fn Profile() -> impl IntoView {
+    // a plain struct doesn't know how to render itself
+    User {
+        name: "Alice".to_string(),
+    }
+}
+// ANCHOR_END: not_into_view
+
+fn main() {
+    let _ = Profile;
+}
@@ -0,0 +1,29 @@
+use leptos::prelude::*;
+
+// ANCHOR: optional_generic_prop
+#[component]
+fn ProgressBar<F: Fn() -> i32 + Send + Sync + 'static>(
+    #[prop(optional)] progress: Option<F>,
+) -> impl IntoView {
+    progress.map(|progress| {
+        view! {
+            <progress
+                max=100
+                value=progress
+            />
+        }
+    })
+}
+
+#[component]
+fn App() -> impl IntoView {
+    view! {
+        // there's no `progress` to tell the compiler what `F` is
+        <ProgressBar/>
+    }
+}
+// ANCHOR_END: optional_generic_prop
+
+fn main() {
+    let _ = App;
+}
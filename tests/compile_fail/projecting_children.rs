@@ -0,0 +1,31 @@
+use leptos::prelude::*;
+
+// ANCHOR: projecting_children
+#[component]
+pub fn LoggedIn<F, IV>(fallback: F, children: ChildrenFn) -> impl IntoView
+where
+    F: Fn() -> IV + Send + Sync + 'static,
+    IV: IntoView + 'static,
+{
+    let (checked, _) = signal(true);
+    let (logged_in, _) = signal(false);
+
+    view! {
+        <Show
+            // wait until we've checked whether the user is logged in
+            when=move || checked.get()
+        >
+            <Show
+                when=move || logged_in.get()
+                fallback=fallback
+            >
+                {children()}
+            </Show>
+        </Show>
+    }
+}
+// ANCHOR_END: projecting_children
+
+fn main() {
+    let _ = LoggedIn::<fn() -> &'static str, &'static str>;
+}
@@ -0,0 +1,36 @@
+use leptos::prelude::*;
+
+// ANCHOR: projecting_owned_props
+#[component]
+pub fn App() -> impl IntoView {
+    let name = "Alice".to_string();
+    view! {
+        <Outer>
+            <Inner>
+                <Inmost name=name.clone()/>
+            </Inner>
+        </Outer>
+    }
+}
+// ANCHOR_END: projecting_owned_props
+
+#[component]
+pub fn Outer(children: ChildrenFn) -> impl IntoView {
+    children()
+}
+
+#[component]
+pub fn Inner(children: ChildrenFn) -> impl IntoView {
+    children()
+}
+
+#[component]
+pub fn Inmost(name: String) -> impl IntoView {
+    view! {
+        <p>{name}</p>
+    }
+}
+
+fn main() {
+    let _ = App;
+}
@@ -0,0 +1,85 @@
+use crate::harness::{mount, wait, RestoreUrl};
+use leptos::{prelude::*, task::tick};
+use leptos_book::accessibility::{
+    c40_focus::App as FocusApp, c40_form_errors::SignUp, c40_live_regions::App as LiveRegionApp,
+};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn focused_text() -> String {
+    document()
+        .active_element()
+        .and_then(|element| element.text_content())
+        .unwrap_or_default()
+}
+
+#[wasm_bindgen_test]
+async fn navigating_focuses_the_new_heading() {
+    let _url = RestoreUrl::new();
+    let app = mount(FocusApp);
+    wait(20).await;
+    // loading the page doesn't move the focus
+    assert_ne!(document().active_element(), Some(app.find("h1").into()));
+
+    app.click("a[href='/about']").await;
+    wait(50).await;
+    assert_eq!(app.texts("h1"), ["About"]);
+    assert_eq!(focused_text(), "About");
+
+    app.click("a[href='/']").await;
+    wait(50).await;
+    assert_eq!(focused_text(), "Home");
+}
+
+#[wasm_bindgen_test]
+async fn announcements_are_written_to_the_live_region() {
+    let app = mount(LiveRegionApp);
+    let region = app.find("[aria-live]");
+    assert_eq!(region.get_attribute("role").as_deref(), Some("status"));
+    assert_eq!(region.text_content().unwrap(), "");
+
+    app.click("button").await;
+    wait(50).await;
+    assert_eq!(
+        region.text_content().unwrap(),
+        "Added socks. 1 items in your cart."
+    );
+
+    // the same item again is cleared and written again, so it's a change
+    app.click("button").await;
+    assert_eq!(region.text_content().unwrap(), "");
+    wait(50).await;
+    assert_eq!(
+        region.text_content().unwrap(),
+        "Added socks. 2 items in your cart."
+    );
+}
+
+#[wasm_bindgen_test]
+async fn errors_are_linked_to_their_field() {
+    let app = mount(SignUp);
+    let input = app.find("input");
+    assert_eq!(input.get_attribute("aria-invalid"), None);
+
+    app.click("button").await;
+    assert_eq!(input.get_attribute("aria-invalid").as_deref(), Some("true"));
+    assert_eq!(
+        input.get_attribute("aria-describedby").as_deref(),
+        Some("email-error")
+    );
+    assert_eq!(app.texts("#email-error"), ["Enter your email address."]);
+    assert_eq!(document().active_element(), Some(input.clone().into()));
+
+    app.fill("input", "bob", "input").await;
+    assert_eq!(
+        app.texts("#email-error"),
+        ["An email address needs an @, like name@example.com."]
+    );
+
+    // the error goes away as soon as the email is fixed
+    app.fill("input", "bob@example.com", "input").await;
+    assert_eq!(input.get_attribute("aria-invalid"), None);
+    assert!(app.texts("#email-error").is_empty());
+    app.click("button").await;
+    tick().await;
+    assert_eq!(app.texts("[role='status']"), ["Thanks for signing up!"]);
+}
@@ -0,0 +1,35 @@
+use crate::harness::{mount, wait};
+use leptos_book::r#async::c13_actions::{App, LocalAction};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+async fn dispatching_an_action_tracks_input_pending_and_value() {
+    let app = mount(App);
+    assert_eq!(
+        app.texts("p"),
+        ["", "Submitted: None", "Pending: false", "Todo ID: None"]
+    );
+
+    app.fill("input", "Buy milk", "input").await;
+    app.click("button").await;
+    let texts = app.texts("p");
+    assert_eq!(texts[0], "Loading...");
+    assert!(texts[1].contains("\"Buy milk\""), "{texts:?}");
+    assert_eq!(texts[2], "Pending: true");
+    assert_eq!(texts[3], "Todo ID: None");
+
+    wait(1_100).await;
+    let texts = app.texts("p");
+    assert_eq!(texts[..3], ["", "Submitted: None", "Pending: false"]);
+    assert!(texts[3].starts_with("Todo ID: Some("), "{texts:?}");
+}
+
+#[wasm_bindgen_test]
+async fn local_actions_can_await_non_send_futures() {
+    let app = mount(LocalAction);
+    app.click("button").await;
+    assert_eq!(app.texts("p"), [""]);
+
+    wait(600).await;
+    assert_eq!(app.texts("p"), ["saved after 500ms"]);
+}
@@ -0,0 +1,45 @@
+use crate::harness::{mount, wait};
+use leptos::task::tick;
+use leptos_book::view::c13_animated_show::{HoverMe, UnsavedChanges};
+use wasm_bindgen_test::wasm_bindgen_test;
+use web_sys::Event;
+
+#[wasm_bindgen_test]
+async fn classes_are_swapped_before_the_children_are_hidden() {
+    let app = mount(HoverMe);
+    assert!(app.texts(".here-i-am").is_empty());
+
+    let hover_me = app.find(".hover-me");
+    hover_me
+        .dispatch_event(&Event::new("mouseenter").unwrap())
+        .unwrap();
+    tick().await;
+    assert_eq!(app.texts(".fade-in-1000 .here-i-am"), ["Here I Am!"]);
+
+    // still there while it fades out
+    hover_me
+        .dispatch_event(&Event::new("mouseleave").unwrap())
+        .unwrap();
+    tick().await;
+    assert_eq!(app.texts(".fade-out-1000 .here-i-am"), ["Here I Am!"]);
+}
+
+#[wasm_bindgen_test]
+async fn derived_signals_can_drive_the_animation() {
+    let app = mount(UnsavedChanges);
+    assert!(app.texts(".warning").is_empty());
+
+    app.fill("textarea", "Draft", "input").await;
+    assert_eq!(
+        app.texts(".slide-in .warning"),
+        ["You have unsaved changes."]
+    );
+
+    app.click("button").await;
+    assert_eq!(
+        app.texts(".slide-out .warning"),
+        ["You have unsaved changes."]
+    );
+    wait(400).await;
+    assert!(app.texts(".warning").is_empty());
+}
@@ -0,0 +1,47 @@
+use crate::harness::{mount, wait};
+use leptos_book::r#async::c20_async_errors::{App, FAILURES_LEFT};
+use std::sync::atomic::Ordering;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+async fn retrying_recovers_from_a_failed_request() {
+    FAILURES_LEFT.store(1, Ordering::Relaxed);
+    let app = mount(App);
+    assert_eq!(app.texts("p"), ["Loading..."]);
+
+    wait(300).await;
+    assert_eq!(
+        app.texts(".error li"),
+        ["The weather service is unavailable."]
+    );
+
+    // the service works the second time, so retrying clears the boundary
+    app.click(".error button").await;
+    wait(300).await;
+    assert!(app.texts(".error").is_empty());
+    assert_eq!(app.texts("p"), ["Today: 14°C"]);
+}
+
+#[wasm_bindgen_test]
+async fn a_new_request_replaces_the_error() {
+    FAILURES_LEFT.store(0, Ordering::Relaxed);
+    let app = mount(App);
+    wait(300).await;
+    assert_eq!(app.texts("p"), ["Today: 14°C"]);
+
+    app.fill("select", "Atlantis", "change").await;
+    wait(300).await;
+    assert_eq!(
+        app.texts(".error li"),
+        ["There's no forecast for Atlantis."]
+    );
+
+    // trying again wouldn't help, so there's no button to...
+    assert!(app.texts(".error button").is_empty());
+
+    // ...but picking another city loads that one instead
+    app.fill("select", "Lisbon", "change").await;
+    wait(300).await;
+    assert!(app.texts(".error").is_empty());
+    assert_eq!(app.texts("p"), ["Today: 22°C"]);
+}
@@ -0,0 +1,19 @@
+use crate::harness::mount;
+use leptos_book::view::c01_basic_component::App;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+async fn only_functions_are_reactive() {
+    let app = mount(App);
+    assert_eq!(
+        app.texts("p"),
+        ["Reactive: 0", "Reactive shorthand: 0", "Not reactive: 0"]
+    );
+
+    app.click("button").await;
+    app.click("button").await;
+    assert_eq!(
+        app.texts("p"),
+        ["Reactive: 2", "Reactive shorthand: 2", "Not reactive: 0"]
+    );
+}
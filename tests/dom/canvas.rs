@@ -0,0 +1,29 @@
+use crate::harness::{mount, wait, Mounted};
+use leptos_book::interop::c38_canvas::BouncingBall;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn frames_drawn(app: &Mounted) -> u32 {
+    let text = app.texts("p").remove(0);
+    text.trim_start_matches("Frames drawn: ").parse().unwrap()
+}
+
+#[wasm_bindgen_test]
+async fn the_animation_loop_runs_until_unmounted() {
+    let app = mount(BouncingBall);
+    wait(100).await;
+    let first = frames_drawn(&app);
+    assert!(first > 0);
+    wait(100).await;
+    assert!(frames_drawn(&app) > first);
+
+    // pausing skips drawing, though the loop keeps going
+    app.click("button").await;
+    let paused = frames_drawn(&app);
+    wait(100).await;
+    assert_eq!(frames_drawn(&app), paused);
+    assert_eq!(app.texts("button"), ["Play"]);
+
+    // unmounting cancels the next frame, before the `Closure` is dropped
+    drop(app);
+    wait(50).await;
+}
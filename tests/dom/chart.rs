@@ -0,0 +1,53 @@
+use crate::harness::mount;
+use leptos::task::tick;
+use leptos_book::interop::c35_chart::App;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+/// Defines a fake `Chart` class, in place of Chart.js, which logs each call.
+fn fake_chart_js() {
+    js_sys::eval(
+        r#"
+        globalThis.chartLog = [];
+        globalThis.Chart = class {
+            constructor(canvas, config) {
+                const bars = config.data.datasets[0].data.join(",");
+                chartLog.push(`new ${config.type} on ${canvas.tagName}: ${bars}`);
+            }
+            set data(data) {
+                chartLog.push(`data: ${data.labels.join(",")}`);
+            }
+            update() {
+                chartLog.push("update");
+            }
+            destroy() {
+                chartLog.push("destroy");
+            }
+        };
+        "#,
+    )
+    .unwrap();
+}
+
+fn chart_log() -> Vec<String> {
+    let log = js_sys::eval("chartLog").unwrap();
+    serde_wasm_bindgen::from_value(log).unwrap()
+}
+
+#[wasm_bindgen_test]
+async fn the_chart_follows_the_component() {
+    fake_chart_js();
+    let app = mount(App);
+    tick().await;
+    assert_eq!(chart_log(), ["new bar on CANVAS: 3,5,2"]);
+
+    // new data updates the same chart, rather than drawing another one
+    app.click("button:nth-of-type(1)").await;
+    assert_eq!(chart_log()[1..], ["data: Mon,Tue,Wed,Next", "update"]);
+
+    // unmounting destroys it, and mounting it again draws a new one
+    app.click("button:nth-of-type(2)").await;
+    assert_eq!(chart_log().last().unwrap(), "destroy");
+    app.click("button:nth-of-type(2)").await;
+    tick().await;
+    assert_eq!(chart_log().last().unwrap(), "new bar on CANVAS: 3,5,2,4");
+}
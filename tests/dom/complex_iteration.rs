@@ -0,0 +1,42 @@
+use crate::harness::mount;
+use leptos::prelude::*;
+use leptos_book::view::c05_complex_iteration::{
+    App, ChangeKey, MemoizedSlices, NestedSignals, StoreRows,
+};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+async fn values_after_click<F, N>(f: F) -> Vec<String>
+where
+    F: FnOnce() -> N + 'static,
+    N: IntoView + 'static,
+{
+    let app = mount(f);
+    assert_eq!(app.texts("p"), ["10", "20", "15"]);
+    app.click("button").await;
+    app.texts("p")
+}
+
+#[wasm_bindgen_test]
+async fn plain_values_do_not_update() {
+    assert_eq!(values_after_click(App).await, ["10", "20", "15"]);
+}
+
+#[wasm_bindgen_test]
+async fn changing_the_key_rerenders_rows() {
+    assert_eq!(values_after_click(ChangeKey).await, ["20", "40", "30"]);
+}
+
+#[wasm_bindgen_test]
+async fn nested_signals_update() {
+    assert_eq!(values_after_click(NestedSignals).await, ["20", "40", "30"]);
+}
+
+#[wasm_bindgen_test]
+async fn memoized_slices_update() {
+    assert_eq!(values_after_click(MemoizedSlices).await, ["20", "40", "30"]);
+}
+
+#[wasm_bindgen_test]
+async fn store_fields_update() {
+    assert_eq!(values_after_click(StoreRows).await, ["20", "40", "30"]);
+}
@@ -0,0 +1,22 @@
+use crate::harness::mount;
+use leptos::prelude::*;
+use leptos_book::view::c10_component_children::Collapsible;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+async fn children_fn_can_be_called_again() {
+    let app = mount(|| {
+        view! {
+            <Collapsible>
+                <p>"Now you see me"</p>
+            </Collapsible>
+        }
+    });
+    assert_eq!(app.texts("p"), ["Now you see me"]);
+
+    app.click("button").await;
+    assert!(app.texts("p").is_empty());
+
+    app.click("button").await;
+    assert_eq!(app.texts("p"), ["Now you see me"]);
+}
@@ -0,0 +1,65 @@
+use crate::harness::mount;
+use leptos_book::view::c07_control_flow::Examples;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+async fn every_branch_follows_the_counter() {
+    let app = mount(Examples);
+    let rendered = |app: &crate::harness::Mounted| {
+        [
+            ".if",
+            ".option",
+            ".bool-then",
+            ".match",
+            ".over-rendering",
+            ".over-rendering-components",
+            ".show",
+        ]
+        .map(|selector| app.texts(selector).concat())
+    };
+    assert_eq!(
+        rendered(&app),
+        ["Even", "", "", "Zero", "Small", "Small", "Small"]
+    );
+
+    app.click("button").await;
+    assert_eq!(
+        rendered(&app),
+        [
+            "Odd",
+            "Ding ding ding!",
+            "Ding ding ding!",
+            "One",
+            "Small",
+            "Small",
+            "Small"
+        ]
+    );
+
+    for _ in 0..5 {
+        app.click("button").await;
+    }
+    assert_eq!(
+        rendered(&app),
+        ["Even", "", "", "Even", "Big", "Big", "Big"]
+    );
+}
+
+#[wasm_bindgen_test]
+async fn converted_branches_switch_elements() {
+    let app = mount(Examples);
+    for selector in [".into-any", ".either"] {
+        assert_eq!(app.texts(&format!("{selector} textarea")), ["0"]);
+    }
+
+    app.click("button").await;
+    for selector in [".into-any", ".either"] {
+        assert_eq!(app.texts(&format!("{selector} pre")), ["One"]);
+        assert!(app.texts(&format!("{selector} textarea")).is_empty());
+    }
+
+    app.click("button").await;
+    for selector in [".into-any", ".either"] {
+        assert_eq!(app.texts(&format!("{selector} p")), ["Two"]);
+    }
+}
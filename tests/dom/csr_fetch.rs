@@ -0,0 +1,55 @@
+use crate::harness::{mount, wait};
+use leptos::prelude::*;
+use leptos_book::r#async::c10a_csr_fetch::{fetch_json, FetchError, Repo, RepoInfo};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+/// A `data:` URL whose response body is `json`.
+fn json_url(json: &str) -> String {
+    format!(
+        "data:application/json,{}",
+        js_sys::encode_uri_component(json)
+    )
+}
+
+const REPO: &str = r#"{"full_name":"leptos-rs/leptos","description":"Build web apps","stargazers_count":42,"forks":7}"#;
+
+#[wasm_bindgen_test]
+async fn json_is_parsed_into_the_struct() {
+    let repo = fetch_json::<Repo>(&json_url(REPO)).await.unwrap();
+    assert_eq!(
+        repo,
+        Repo {
+            full_name: "leptos-rs/leptos".to_string(),
+            description: Some("Build web apps".to_string()),
+            stargazers_count: 42,
+        }
+    );
+}
+
+#[wasm_bindgen_test]
+async fn error_statuses_and_bad_json_are_errors() {
+    // the test runner's server responds to any other path with a 404
+    let missing = fetch_json::<Repo>("/no-such-repo.json").await;
+    assert_eq!(missing, Err(FetchError::Status(404)));
+
+    let wrong_shape = fetch_json::<Repo>(&json_url(r#"{"name":"leptos"}"#)).await;
+    assert!(
+        matches!(wrong_shape, Err(FetchError::Request(_))),
+        "{wrong_shape:?}"
+    );
+}
+
+#[wasm_bindgen_test]
+async fn the_view_shows_loading_then_the_result_or_the_error() {
+    let (url, set_url) = signal(json_url(REPO));
+    let app = mount(move || view! { <RepoInfo url/> });
+    assert_eq!(app.texts(".loading"), ["Loading..."]);
+
+    wait(50).await;
+    assert_eq!(app.texts("h2"), ["leptos-rs/leptos"]);
+    assert_eq!(app.texts(".stars"), ["42 stars"]);
+
+    set_url.set("/no-such-repo.json".to_string());
+    wait(50).await;
+    assert_eq!(app.texts(".error"), ["server responded with 404"]);
+}
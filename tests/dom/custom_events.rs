@@ -0,0 +1,31 @@
+use crate::harness::mount;
+use leptos::{prelude::*, task::tick};
+use leptos_book::interop::c34_events::{dispatch_color_picked, LastColor, Palette, Propagation};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+async fn stopped_events_skip_the_parent_listener() {
+    let app = mount(Propagation);
+    app.click(".stops").await;
+    assert_eq!(app.texts("p"), ["The <div> heard 0 clicks"]);
+    app.click(".bubbles").await;
+    assert_eq!(app.texts("p"), ["The <div> heard 1 clicks"]);
+}
+
+#[wasm_bindgen_test]
+async fn custom_events_run_their_handlers() {
+    let app = mount(Palette);
+    assert_eq!(app.texts("p"), ["Picked: nothing yet"]);
+
+    dispatch_color_picked(&app.find(".swatches"), "purple");
+    tick().await;
+    assert_eq!(app.texts("p"), ["Picked: purple"]);
+}
+
+#[wasm_bindgen_test]
+async fn custom_events_bubble_from_the_child_to_the_window() {
+    let app = mount(|| view! { <Palette/> <LastColor/> });
+    app.click(".swatches button:nth-child(2)").await;
+    assert_eq!(app.texts("p:not(.last-color)"), ["Picked: green"]);
+    assert_eq!(app.texts(".last-color"), ["green"]);
+}
@@ -0,0 +1,46 @@
+use crate::harness::{mount, wait};
+use leptos_book::r#async::c17_debounced_search::{App, SEARCHES_FINISHED};
+use std::sync::atomic::Ordering;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn finished() -> usize {
+    SEARCHES_FINISHED.load(Ordering::Relaxed)
+}
+
+#[wasm_bindgen_test]
+async fn typing_only_searches_once_it_stops() {
+    let app = mount(App);
+    let before = finished();
+
+    // each keystroke restarts the 250ms timer
+    for query in ["r", "re", "rea", "reac"] {
+        app.fill("input", query, "input").await;
+        wait(100).await;
+    }
+    wait(700).await;
+    assert_eq!(finished(), before + 1);
+    assert_eq!(app.texts("li"), ["reactive_graph", "reactive_stores"]);
+}
+
+#[wasm_bindgen_test]
+async fn a_new_search_cancels_the_last_one() {
+    let app = mount(App);
+    let before = finished();
+
+    // the first search starts, and is still running when the second one does
+    app.fill("input", "leptos", "input").await;
+    wait(350).await;
+    app.fill("input", "leptos_", "input").await;
+    wait(950).await;
+    assert_eq!(finished(), before + 1);
+    assert_eq!(
+        app.texts("li"),
+        ["leptos_axum", "leptos_meta", "leptos_router"]
+    );
+
+    // clearing the box clears the results, without a request
+    app.fill("input", "", "input").await;
+    wait(350).await;
+    assert!(app.texts("li").is_empty());
+    assert_eq!(finished(), before + 1);
+}
@@ -0,0 +1,30 @@
+use crate::harness::mount;
+use leptos::{prelude::*, task::tick};
+use leptos_book::interop::c33_directives::{Directives, Dropdown};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn directives_run_when_the_element_is_created() {
+    let app = mount(Directives);
+    assert!(app.find("p").class_list().contains("highlighted"));
+    assert_eq!(app.find("button").title(), "Saves your work");
+}
+
+#[wasm_bindgen_test]
+async fn directives_can_clean_up_after_themselves() {
+    let app = mount(Dropdown);
+    app.click(".dropdown li").await;
+    assert_eq!(app.texts("li").len(), 2);
+
+    // clicking anywhere else closes the menu
+    document().body().unwrap().click();
+    tick().await;
+    assert_eq!(app.texts("li").len(), 0);
+    app.click(".dropdown button").await;
+    assert_eq!(app.texts("li").len(), 2);
+
+    // and once it's unmounted, the listener on the document is gone
+    drop(app);
+    document().body().unwrap().click();
+    tick().await;
+}
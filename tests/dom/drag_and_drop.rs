@@ -0,0 +1,58 @@
+use crate::harness::{mount, Mounted};
+use leptos::task::tick;
+use leptos_book::view::c16_drag_and_drop::App;
+use wasm_bindgen_test::wasm_bindgen_test;
+use web_sys::{DataTransfer, DragEvent, DragEventInit};
+
+/// Fires a drag event on the `nth` item, carrying `data`, the way the
+/// browser would during a drag.
+fn drag(app: &Mounted, kind: &str, nth: usize, data: &DataTransfer) -> bool {
+    let init = DragEventInit::new();
+    init.set_bubbles(true);
+    init.set_cancelable(true);
+    init.set_data_transfer(Some(data));
+    let event = DragEvent::new_with_event_init_dict(kind, &init).unwrap();
+    let item = app.find(&format!("li:nth-child({nth})"));
+    // `false` if a handler cancelled it
+    item.dispatch_event(&event).unwrap()
+}
+
+#[wasm_bindgen_test]
+async fn dropping_a_task_moves_it() {
+    let app = mount(App);
+    assert_eq!(
+        app.texts("li"),
+        ["Write the chapter", "Test the example", "Publish the book"]
+    );
+
+    let data = DataTransfer::new().unwrap();
+    drag(&app, "dragstart", 1, &data);
+    tick().await;
+    assert_eq!(app.texts("li.dragging"), ["Write the chapter"]);
+
+    // the items accept the drop...
+    assert!(!drag(&app, "dragover", 3, &data));
+    drag(&app, "drop", 3, &data);
+    // `dragend` goes to the element that was dragged
+    drag(&app, "dragend", 1, &data);
+    tick().await;
+    // ...which moves the task, and the same <li> with it
+    assert_eq!(
+        app.texts("li"),
+        ["Test the example", "Publish the book", "Write the chapter"]
+    );
+    assert!(app.texts("li.dragging").is_empty());
+}
+
+#[wasm_bindgen_test]
+async fn other_drops_are_ignored() {
+    let app = mount(App);
+    let data = DataTransfer::new().unwrap();
+    data.set_data("text/plain", "not a task").unwrap();
+    drag(&app, "drop", 1, &data);
+    tick().await;
+    assert_eq!(
+        app.texts("li"),
+        ["Write the chapter", "Test the example", "Publish the book"]
+    );
+}
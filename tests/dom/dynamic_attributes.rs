@@ -0,0 +1,36 @@
+use crate::harness::mount;
+use leptos_book::view::c03_dynamic_attributes::{App, PropValue, Styles};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+async fn classes_and_values_follow_the_count() {
+    let app = mount(App);
+    let button = app.find("button");
+    assert!(!button.class_list().contains("red"));
+
+    app.click("button").await;
+    assert!(button.class_list().contains("red"));
+    assert_eq!(app.prop("progress", "value"), JsValue::from(1));
+    assert_eq!(app.texts("p"), ["Count: 1", "Double Count: 2"]);
+}
+
+#[wasm_bindgen_test]
+async fn style_properties_update_individually() {
+    let app = mount(Styles);
+    app.click("button").await;
+    let style = app.find("button").style();
+    assert_eq!(style.get_property_value("left").unwrap(), "110px");
+    assert_eq!(style.get_property_value("--columns").unwrap(), "10");
+    assert_eq!(style.get_property_value("position").unwrap(), "absolute");
+}
+
+#[wasm_bindgen_test]
+async fn props_set_the_current_value() {
+    let app = mount(PropValue);
+    assert_eq!(app.prop("input", "value"), "Hello");
+    // the user's typing replaces the attribute's value, but not the property's
+    app.fill("input", "Goodbye", "input").await;
+    app.click("button").await;
+    assert_eq!(app.prop("input", "value"), "");
+}
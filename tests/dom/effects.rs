@@ -0,0 +1,121 @@
+use crate::harness::mount;
+use leptos::{prelude::*, task::tick};
+use leptos_book::reactivity::{
+    c15_effects::{
+        render_by_hand, Cleanup, CreateAnEffect, DerivedInstead, EffectNew, EffectWritesSignal,
+        Log, Watch,
+    },
+    c16_untrack_batch::Rename,
+};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn lines(log: &[String]) -> Vec<&str> {
+    log[0].lines().collect()
+}
+
+#[wasm_bindgen_test]
+async fn an_effect_reruns_when_what_it_read_changes() {
+    let app = mount(|| view! { <Log><EffectNew/></Log> });
+    // effects first run on the next tick, not when they're created
+    assert_eq!(app.texts("pre"), [""]);
+    tick().await;
+    assert_eq!(lines(&app.texts("pre")), ["Value: 0"]);
+
+    app.click(".a").await;
+    tick().await;
+    assert_eq!(lines(&app.texts("pre")), ["Value: 0", "Value: 1"]);
+
+    // the effect never read `b`, so it doesn't run again
+    app.click(".b").await;
+    tick().await;
+    assert_eq!(lines(&app.texts("pre")), ["Value: 0", "Value: 1"]);
+}
+
+#[wasm_bindgen_test]
+async fn dependencies_are_dynamic() {
+    let app = mount(|| view! { <Log><CreateAnEffect/></Log> });
+    tick().await;
+    app.fill("input[name=first]", "Bridget", "change").await;
+    tick().await;
+    assert_eq!(lines(&app.texts("pre")), [" ", "Bridget "]);
+
+    app.click("input[name=use_last]").await;
+    tick().await;
+    assert_eq!(lines(&app.texts("pre")), [" ", "Bridget ", "Bridget"]);
+
+    // `last` isn't tracked while `use_last` is false
+    app.fill("input[name=last]", "Jones", "change").await;
+    tick().await;
+    assert_eq!(lines(&app.texts("pre")), [" ", "Bridget ", "Bridget"]);
+}
+
+#[wasm_bindgen_test]
+async fn writing_to_a_signal_in_an_effect_lags_behind() {
+    let effect = mount(EffectWritesSignal);
+    let derived = mount(DerivedInstead);
+    assert_eq!(effect.texts("p"), ["0"]);
+    assert_eq!(derived.texts("p"), ["2"]);
+
+    tick().await;
+    tick().await;
+    assert_eq!(effect.texts("p"), ["2"]);
+
+    effect.click("button").await;
+    derived.click("button").await;
+    tick().await;
+    assert_eq!(effect.texts("p"), ["4"]);
+    assert_eq!(derived.texts("p"), ["4"]);
+}
+
+#[wasm_bindgen_test]
+async fn an_effect_can_update_the_dom_by_hand() {
+    let owner = Owner::new();
+    let (p, set_count) = owner.with(render_by_hand);
+    tick().await;
+    assert_eq!(p.text_content().as_deref(), Some("0"));
+
+    set_count.set(5);
+    tick().await;
+    assert_eq!(p.text_content().as_deref(), Some("5"));
+}
+
+#[wasm_bindgen_test]
+async fn watch_only_runs_on_changes_until_stopped() {
+    let app = mount(|| view! { <Log><Watch/></Log> });
+    tick().await;
+    assert_eq!(app.texts("pre"), [""]);
+
+    app.click(".increment").await;
+    tick().await;
+    assert_eq!(lines(&app.texts("pre")), ["Number: 1; Prev: Some(0)"]);
+
+    app.click(".stop").await;
+    app.click(".increment").await;
+    tick().await;
+    assert_eq!(lines(&app.texts("pre")), ["Number: 1; Prev: Some(0)"]);
+}
+
+#[wasm_bindgen_test]
+async fn cleanup_runs_before_the_effect_reruns() {
+    let app = mount(|| view! { <Log><Cleanup/></Log> });
+    tick().await;
+    assert_eq!(lines(&app.texts("pre")), ["joined #general"]);
+
+    app.click("button").await;
+    tick().await;
+    assert_eq!(
+        lines(&app.texts("pre")),
+        ["joined #general", "left #general", "joined #random"]
+    );
+}
+
+#[wasm_bindgen_test]
+async fn writes_in_the_same_task_run_effects_once() {
+    let app = mount(|| view! { <Log><Rename/></Log> });
+    tick().await;
+    assert_eq!(lines(&app.texts("pre")), ["Ferris Crab"]);
+
+    app.click("button").await;
+    tick().await;
+    assert_eq!(lines(&app.texts("pre")), ["Ferris Crab", "Gopher Go"]);
+}
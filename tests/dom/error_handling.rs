@@ -0,0 +1,54 @@
+use crate::harness::mount;
+use leptos_book::view::c08_error_handling::{App, NestedBoundaries, ResettableInput, ResultInput};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+async fn an_err_renders_nothing_on_its_own() {
+    let app = mount(ResultInput);
+    app.fill("input", "42", "input").await;
+    assert_eq!(app.texts("p"), ["You entered 42"]);
+
+    // a number input has no value at all while its contents aren't a number
+    app.fill("input", "", "input").await;
+    assert_eq!(app.texts("p"), ["You entered "]);
+}
+
+#[wasm_bindgen_test]
+async fn the_boundary_shows_the_fallback_until_the_error_is_fixed() {
+    let app = mount(App);
+    assert_eq!(app.texts("strong"), ["0"]);
+
+    app.fill("input", "", "input").await;
+    assert!(app.texts("strong").is_empty());
+    assert_eq!(
+        app.texts(".error li"),
+        ["cannot parse integer from empty string"]
+    );
+
+    app.fill("input", "42", "input").await;
+    assert!(app.texts(".error").is_empty());
+    assert_eq!(app.texts("strong"), ["42"]);
+}
+
+#[wasm_bindgen_test]
+async fn errors_go_to_the_nearest_boundary() {
+    let app = mount(NestedBoundaries);
+    assert_eq!(app.texts("p"), ["Width: 0", "Height: 0"]);
+
+    app.fill("input:nth-of-type(2)", "", "input").await;
+    assert_eq!(app.texts("p"), ["Width: 0", "The height isn’t a number."]);
+
+    app.fill("input:nth-of-type(1)", "", "input").await;
+    assert_eq!(app.texts("p"), ["The width isn’t a number."]);
+}
+
+#[wasm_bindgen_test]
+async fn replacing_the_err_clears_the_boundary() {
+    let app = mount(ResettableInput);
+    app.fill("input", "", "input").await;
+    assert_eq!(app.texts("p"), ["Not a number!"]);
+
+    app.click("button").await;
+    assert_eq!(app.texts("p"), ["You entered 0"]);
+    assert_eq!(app.prop("input", "value"), "0");
+}
@@ -0,0 +1,43 @@
+use leptos::{prelude::*, task::tick};
+use leptos_book::interop::c37_existing_page::mount_widgets;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::wasm_bindgen_test;
+use web_sys::HtmlElement;
+
+#[wasm_bindgen_test]
+async fn widgets_are_mounted_into_the_existing_page() {
+    let page = document().create_element("div").unwrap();
+    document().body().unwrap().append_child(&page).unwrap();
+    page.set_inner_html(
+        r#"<header><span id="cart-badge">Cart: ?</span></header>
+        <article><h2>Socks</h2><div data-add-to-cart="socks"></div></article>
+        <article><h2>Hat</h2><div data-add-to-cart="hat"></div></article>"#,
+    );
+    let texts = |selector: &str| {
+        let nodes = page.query_selector_all(selector).unwrap();
+        (0..nodes.length())
+            .map(|i| nodes.item(i).unwrap().text_content().unwrap())
+            .collect::<Vec<_>>()
+    };
+
+    let widgets = mount_widgets();
+    // the placeholder is replaced, and the page's own HTML is left alone
+    assert_eq!(texts("#cart-badge"), ["Cart: 0"]);
+    assert_eq!(texts("h2"), ["Socks", "Hat"]);
+    assert_eq!(texts("button"), ["Add to cart", "Add to cart"]);
+
+    // separate mounts, which share the cart
+    page.query_selector("[data-add-to-cart=hat] button")
+        .unwrap()
+        .unwrap()
+        .unchecked_into::<HtmlElement>()
+        .click();
+    tick().await;
+    assert_eq!(texts("#cart-badge"), ["Cart: 1"]);
+    assert_eq!(texts("button"), ["Add to cart", "In your cart"]);
+
+    drop(widgets);
+    assert_eq!(texts("#cart-badge"), [""]);
+    assert_eq!(texts("button").len(), 0);
+    page.remove();
+}
@@ -0,0 +1,59 @@
+use crate::harness::{mount, Mounted};
+use leptos_book::view::c17_form_validation::SignUpForm;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+/// Types into a field and then leaves it, the way a user fills in a form.
+async fn enter(app: &Mounted, name: &str, value: &str) {
+    let selector = format!("input[name='{name}']");
+    app.fill(&selector, value, "input").await;
+    app.fill(&selector, value, "blur").await;
+}
+
+fn submit_disabled(app: &Mounted) -> bool {
+    app.prop("button", "disabled") == JsValue::TRUE
+}
+
+#[wasm_bindgen_test]
+async fn errors_show_once_the_user_leaves_a_field() {
+    let app = mount(SignUpForm);
+    assert!(submit_disabled(&app));
+
+    // nothing is shown while the user is still typing...
+    app.fill("input[name='username']", "a", "input").await;
+    assert_eq!(app.texts("#username-error"), [""]);
+
+    // ...only once they've moved on
+    app.fill("input[name='username']", "a", "blur").await;
+    assert_eq!(
+        app.texts("#username-error"),
+        ["Usernames are at least 3 characters long."]
+    );
+    assert!(app.find("#username").class_list().contains("invalid"));
+
+    // and it goes away as soon as it's fixed
+    app.fill("input[name='username']", "ada", "input").await;
+    assert_eq!(app.texts("#username-error"), [""]);
+    assert!(!app.find("#username").class_list().contains("invalid"));
+}
+
+#[wasm_bindgen_test]
+async fn the_form_can_only_be_submitted_once_everything_is_valid() {
+    let app = mount(SignUpForm);
+    enter(&app, "username", "ada").await;
+    enter(&app, "password", "correct horse").await;
+    enter(&app, "confirm", "correct horse").await;
+    assert!(submit_disabled(&app));
+    enter(&app, "age", "36").await;
+    assert!(!submit_disabled(&app));
+
+    // changing the password makes the confirmation wrong, without it changing
+    enter(&app, "password", "battery staple").await;
+    assert_eq!(app.texts("#confirm-error"), ["The passwords don't match."]);
+    assert!(submit_disabled(&app));
+
+    enter(&app, "confirm", "battery staple").await;
+    assert!(!submit_disabled(&app));
+    app.click("button").await;
+    assert_eq!(app.texts("[role='status']"), ["Welcome, ada!"]);
+}
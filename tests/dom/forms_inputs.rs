@@ -0,0 +1,92 @@
+use crate::harness::mount;
+use leptos_book::view::c06_forms_inputs::{
+    App, BindGroup, BindValue, Checkbox, Select, SelectOptions, TextArea,
+};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+async fn controlled_input_updates_on_every_input() {
+    let app = mount(App);
+    assert_eq!(
+        app.texts("p"),
+        ["Name is: Controlled", "Name is: Uncontrolled"]
+    );
+
+    app.fill("input", "Typed", "input").await;
+    assert_eq!(app.texts("p"), ["Name is: Typed", "Name is: Uncontrolled"]);
+}
+
+#[wasm_bindgen_test]
+async fn uncontrolled_input_updates_on_submit() {
+    let app = mount(App);
+    app.fill("form input[type=text]", "Typed", "input").await;
+    assert_eq!(
+        app.texts("p"),
+        ["Name is: Controlled", "Name is: Uncontrolled"]
+    );
+
+    app.click("input[type=submit]").await;
+    assert_eq!(app.texts("p"), ["Name is: Controlled", "Name is: Typed"]);
+}
+
+#[wasm_bindgen_test]
+async fn bind_value_and_checked_update_both_ways() {
+    let app = mount(BindValue);
+    assert_eq!(app.prop("input[type=text]", "value"), "Controlled");
+    assert_eq!(app.prop("input[type=checkbox]", "checked"), true);
+
+    app.fill("input[type=text]", "Typed", "input").await;
+    app.fill("input[type=email]", "me@example.com", "input")
+        .await;
+    assert_eq!(
+        app.texts("p"),
+        [
+            "Name is: Typed",
+            "Email is: me@example.com",
+            "You’ll receive cool bonus content!"
+        ]
+    );
+
+    app.click("input[type=checkbox]").await;
+    assert_eq!(
+        app.texts("p"),
+        ["Name is: Typed", "Email is: me@example.com"]
+    );
+}
+
+#[wasm_bindgen_test]
+async fn bind_group_tracks_the_checked_radio() {
+    let app = mount(BindGroup);
+    assert_eq!(app.prop("input[value=red]", "checked"), true);
+
+    app.click("input[value=blue]").await;
+    assert_eq!(app.prop("input[value=red]", "checked"), false);
+    assert_eq!(app.texts("p"), ["Your favorite color is blue."]);
+}
+
+#[wasm_bindgen_test]
+async fn checkbox_sets_its_signal_on_change() {
+    let app = mount(Checkbox);
+    assert_eq!(app.texts("p"), ["Checked: false"]);
+    app.click("input").await;
+    assert_eq!(app.texts("p"), ["Checked: true"]);
+    assert_eq!(app.prop("input", "checked"), true);
+}
+
+#[wasm_bindgen_test]
+async fn textarea_value_is_a_property() {
+    let app = mount(TextArea);
+    assert_eq!(app.prop("textarea", "value"), "Some text");
+    app.fill("textarea", "Other text", "input").await;
+    assert_eq!(app.prop("textarea", "value"), "Other text");
+}
+
+#[wasm_bindgen_test]
+async fn select_follows_the_selected_option() {
+    for app in [mount(Select), mount(SelectOptions)] {
+        assert_eq!(app.prop("select", "value"), "B");
+        app.fill("select", "A", "change").await;
+        assert_eq!(app.prop("option[value=A]", "selected"), true);
+        assert_eq!(app.prop("option[value=B]", "selected"), false);
+    }
+}
@@ -0,0 +1,135 @@
+use gloo_timers::future::sleep;
+use js_sys::Reflect;
+use leptos::{mount::mount_to, prelude::*, task::tick};
+use std::time::Duration;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Event, EventInit, HtmlElement};
+
+/// A component mounted into its own container in `<body>`.
+///
+/// The container is removed and the view unmounted when this is dropped.
+pub struct Mounted {
+    container: HtmlElement,
+    _handle: Box<dyn std::any::Any>,
+}
+
+// ANCHOR: mount
+/// Mounts the view into a fresh `<div>` appended to `<body>`.
+pub fn mount<F, N>(f: F) -> Mounted
+where
+    F: FnOnce() -> N + 'static,
+    N: IntoView + 'static,
+{
+    let container = document()
+        .create_element("div")
+        .unwrap()
+        .unchecked_into::<HtmlElement>();
+    document().body().unwrap().append_child(&container).unwrap();
+    let handle = mount_to(container.clone(), f);
+    Mounted {
+        container,
+        _handle: Box::new(handle),
+    }
+}
+// ANCHOR_END: mount
+
+/// Waits for `millis` milliseconds, then for any resulting updates to be
+/// applied to the DOM. This is for examples that fake a slow request.
+pub async fn wait(millis: u64) {
+    sleep(Duration::from_millis(millis)).await;
+    tick().await;
+}
+
+/// Returns the current path and query string, such as `/articles?page=2`.
+pub fn location() -> String {
+    let location = window().location();
+    location.pathname().unwrap() + &location.search().unwrap()
+}
+
+/// Returns the current path, without the query string.
+pub fn pathname() -> String {
+    window().location().pathname().unwrap()
+}
+
+/// Replaces the test runner's URL without adding a history entry, so that a
+/// router mounted afterwards starts out at `url`.
+pub fn go_to(url: &str) {
+    window()
+        .history()
+        .unwrap()
+        .replace_state_with_url(&JsValue::NULL, "", Some(url))
+        .unwrap();
+}
+
+/// Puts the test runner's page back at the URL it had when this was created,
+/// once it's dropped.
+///
+/// Create it before mounting a router, so that the router is dropped first
+/// and doesn't see the URL change back.
+pub struct RestoreUrl(String);
+
+impl RestoreUrl {
+    pub fn new() -> Self {
+        Self(window().location().href().unwrap())
+    }
+}
+
+impl Drop for RestoreUrl {
+    fn drop(&mut self) {
+        go_to(&self.0);
+    }
+}
+
+impl Mounted {
+    /// Returns the first element matching the selector.
+    ///
+    /// Panics if there isn't one.
+    pub fn find(&self, selector: &str) -> HtmlElement {
+        self.container
+            .query_selector(selector)
+            .unwrap()
+            .unwrap_or_else(|| panic!("no element matching {selector:?}"))
+            .unchecked_into()
+    }
+
+    /// Returns the text content of every element matching the selector.
+    pub fn texts(&self, selector: &str) -> Vec<String> {
+        let nodes = self.container.query_selector_all(selector).unwrap();
+        (0..nodes.length())
+            .filter_map(|idx| nodes.item(idx))
+            .map(|node| node.text_content().unwrap_or_default())
+            .collect()
+    }
+
+    /// Clicks the first element matching the selector, then waits for the
+    /// resulting updates to be applied to the DOM.
+    pub async fn click(&self, selector: &str) {
+        self.find(selector).click();
+        tick().await;
+    }
+
+    /// Sets the `value` of the first form control matching the selector, as
+    /// if the user had typed it, then fires `event` (usually `input` or
+    /// `change`) on it and waits for the updates.
+    pub async fn fill(&self, selector: &str, value: &str, event: &str) {
+        let element = self.find(selector);
+        Reflect::set(&element, &"value".into(), &value.into()).unwrap();
+        let init = EventInit::new();
+        init.set_bubbles(true);
+        let event = Event::new_with_event_init_dict(event, &init).unwrap();
+        element.dispatch_event(&event).unwrap();
+        tick().await;
+    }
+
+    /// Returns a DOM property of the first element matching the selector,
+    /// such as the current `value` or `checked` state of an input.
+    pub fn prop(&self, selector: &str, name: &str) -> JsValue {
+        Reflect::get(&self.find(selector), &name.into()).unwrap()
+    }
+}
+
+impl Drop for Mounted {
+    fn drop(&mut self) {
+        self.container.remove();
+    }
+}
@@ -0,0 +1,28 @@
+use crate::harness::{mount, wait, RestoreUrl};
+use leptos::prelude::*;
+use leptos_book::router::c23_hash_routing::{App, History};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+async fn links_and_the_hash_pick_the_page() {
+    let _url = RestoreUrl::new();
+    let location = window().location();
+    location.set_hash("").unwrap();
+    let app = mount(|| view! { <App history=History::hash()/> });
+    assert_eq!(app.texts("h2"), ["Home"]);
+
+    // following a link changes the hash, but not the path
+    let path = location.pathname().unwrap();
+    app.click("a[href='#/notes']").await;
+    assert_eq!(location.hash().unwrap(), "#/notes");
+    assert_eq!(location.pathname().unwrap(), path);
+    assert_eq!(app.texts("h2"), ["Notes"]);
+
+    // and changing the hash some other way, like going back, is noticed too
+    location.set_hash("/settings").unwrap();
+    wait(10).await;
+    assert_eq!(app.texts("h2"), ["Settings"]);
+    location.set_hash("/nowhere").unwrap();
+    wait(10).await;
+    assert_eq!(app.texts("h2"), ["Not Found"]);
+}
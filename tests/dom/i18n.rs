@@ -0,0 +1,53 @@
+use crate::harness::mount;
+use leptos::prelude::document;
+use leptos_book::i18n::c41_i18n::App;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn html_lang() -> Option<String> {
+    document().document_element().unwrap().get_attribute("lang")
+}
+
+#[wasm_bindgen_test]
+async fn switching_the_locale_translates_the_page() {
+    let app = mount(App);
+    assert_eq!(app.texts("h1"), ["Inbox"]);
+    assert_eq!(app.texts(".welcome"), ["Welcome back, Ada!"]);
+    assert_eq!(
+        app.texts(".last-seen"),
+        ["Last signed in on March 1, 2024."]
+    );
+    assert_eq!(html_lang().as_deref(), Some("en"));
+
+    app.fill("select", "fr", "change").await;
+    assert_eq!(app.texts("h1"), ["Boîte de réception"]);
+    assert_eq!(app.texts(".welcome"), ["Bon retour, Ada !"]);
+    assert_eq!(
+        app.texts(".last-seen"),
+        ["Dernière connexion le 1er mars 2024."]
+    );
+    assert_eq!(app.texts(".receive"), ["Recevoir un message"]);
+    assert_eq!(html_lang().as_deref(), Some("fr"));
+
+    // and back again
+    app.fill("select", "en", "change").await;
+    assert_eq!(app.texts("h1"), ["Inbox"]);
+    assert_eq!(html_lang().as_deref(), Some("en"));
+}
+
+#[wasm_bindgen_test]
+async fn counts_use_the_plural_rules_of_the_current_locale() {
+    let app = mount(App);
+    assert_eq!(app.texts(".unread"), ["You have one unread message."]);
+    app.click(".receive").await;
+    assert_eq!(app.texts(".unread"), ["You have 2 unread messages."]);
+    app.click(".mark-all-read").await;
+    assert_eq!(app.texts(".unread"), ["You have no unread messages."]);
+
+    // the count is kept when the locale changes, and French uses the
+    // singular for 0
+    app.fill("select", "fr", "change").await;
+    assert_eq!(app.texts(".unread"), ["Vous avez 0 message non lu."]);
+    app.click(".receive").await;
+    app.click(".receive").await;
+    assert_eq!(app.texts(".unread"), ["Vous avez 2 messages non lus."]);
+}
@@ -0,0 +1,78 @@
+use crate::harness::{mount, wait};
+use leptos_book::r#async::c14_indexed_db::{Note, NoteApp, Notes};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn note(text: &str, tag: &str) -> Note {
+    Note {
+        id: None,
+        text: text.to_string(),
+        tag: tag.to_string(),
+    }
+}
+
+fn texts(notes: &[Note]) -> Vec<&str> {
+    notes.iter().map(|note| note.text.as_str()).collect()
+}
+
+#[wasm_bindgen_test]
+async fn notes_are_stored_and_found_by_tag() {
+    let notes = Notes::open().await.unwrap();
+    notes.clear().await.unwrap();
+
+    let milk = notes.add(&note("Buy milk", "home")).await.unwrap();
+    notes.add(&note("Write report", "work")).await.unwrap();
+    notes.add(&note("Water plants", "home")).await.unwrap();
+
+    assert_eq!(
+        texts(&notes.all().await.unwrap()),
+        ["Buy milk", "Write report", "Water plants"]
+    );
+    assert_eq!(
+        texts(&notes.tagged("home").await.unwrap()),
+        ["Buy milk", "Water plants"]
+    );
+    assert!(notes.tagged("garden").await.unwrap().is_empty());
+
+    // the database picked the key, and it comes back with the note
+    notes.delete(milk).await.unwrap();
+    let home = notes.tagged("home").await.unwrap();
+    assert_eq!(texts(&home), ["Water plants"]);
+    assert!(home[0].id.is_some_and(|id| id != milk));
+
+    // and everything is still there for a new connection
+    let reopened = Notes::open().await.unwrap();
+    assert_eq!(reopened.all().await.unwrap().len(), 2);
+    notes.clear().await.unwrap();
+}
+
+#[wasm_bindgen_test]
+async fn the_list_reloads_after_each_change() {
+    Notes::open().await.unwrap().clear().await.unwrap();
+    let app = mount(NoteApp);
+    wait(50).await;
+    assert!(app.texts("li").is_empty());
+
+    app.fill("form input", "Plan trip", "input").await;
+    app.fill("form select", "home", "change").await;
+    app.click("button[type=submit]").await;
+    app.fill("form input", "Send invoice", "input").await;
+    app.fill("form select", "work", "change").await;
+    app.click("button[type=submit]").await;
+    wait(50).await;
+    assert_eq!(
+        app.texts("li"),
+        ["Plan trip (home) Delete", "Send invoice (work) Delete"]
+    );
+
+    // filtering reads from the index
+    app.fill(".filter", "work", "change").await;
+    wait(50).await;
+    assert_eq!(app.texts("li"), ["Send invoice (work) Delete"]);
+
+    app.click("li button").await;
+    wait(50).await;
+    assert!(app.texts("li").is_empty());
+
+    drop(app);
+    Notes::open().await.unwrap().clear().await.unwrap();
+}
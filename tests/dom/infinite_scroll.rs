@@ -0,0 +1,89 @@
+use crate::harness::{mount, wait};
+use leptos::task::tick;
+use leptos_book::r#async::c18_infinite_scroll::Feed;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+/// Replaces `IntersectionObserver` with a fake, whose callback the test calls
+/// itself, rather than scrolling.
+fn fake_intersection_observer() {
+    js_sys::eval(
+        r#"
+        globalThis.RealIntersectionObserver ??= IntersectionObserver;
+        globalThis.observers = [];
+        globalThis.IntersectionObserver = class {
+            constructor(callback) {
+                this.callback = callback;
+                this.targets = [];
+                this.connected = true;
+                observers.push(this);
+            }
+            observe(target) {
+                this.targets.push(target);
+            }
+            disconnect() {
+                this.connected = false;
+            }
+        };
+        "#,
+    )
+    .unwrap();
+}
+
+fn restore_intersection_observer() {
+    js_sys::eval("globalThis.IntersectionObserver = RealIntersectionObserver").unwrap();
+}
+
+/// Tells every connected observer that its targets have come into view, or
+/// gone out of it.
+fn intersect(visible: bool) {
+    js_sys::eval(&format!(
+        r#"
+        for (const observer of observers.filter(o => o.connected)) {{
+            const entries = observer.targets.map(target => ({{ target, isIntersecting: {visible} }}));
+            observer.callback(entries, observer);
+        }}
+        "#
+    ))
+    .unwrap();
+}
+
+fn connected_observers() -> f64 {
+    js_sys::eval("observers.filter(o => o.connected).length")
+        .unwrap()
+        .as_f64()
+        .unwrap()
+}
+
+#[wasm_bindgen_test]
+async fn pages_load_while_the_end_of_the_list_is_visible() {
+    fake_intersection_observer();
+    let app = mount(Feed);
+    tick().await;
+    assert!(app.texts("li").is_empty());
+
+    // the first page starts loading as soon as the sentinel is in view...
+    intersect(true);
+    tick().await;
+    assert_eq!(app.texts(".sentinel"), ["Loading..."]);
+
+    // ...and it's still in view when that page arrives, so the next one loads
+    // too, but nothing loads after the sentinel has scrolled away
+    wait(450).await;
+    assert_eq!(app.texts("li").len(), 10);
+    assert_eq!(app.texts("li")[0], "Post #1");
+    intersect(false);
+    wait(500).await;
+    assert_eq!(app.texts("li").len(), 20);
+    assert_eq!(app.texts(".sentinel"), [""]);
+
+    // scrolling back down loads the rest, and then stops
+    intersect(true);
+    wait(1200).await;
+    assert_eq!(app.texts("li").len(), 45);
+    assert_eq!(app.texts("li")[44], "Post #45");
+    assert_eq!(app.texts(".sentinel"), ["That's everything!"]);
+
+    drop(app);
+    assert_eq!(connected_observers(), 0.0);
+    restore_intersection_observer();
+}
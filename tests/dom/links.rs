@@ -0,0 +1,35 @@
+use crate::harness::{mount, pathname, wait, RestoreUrl};
+use leptos::prelude::window;
+use leptos_book::router::c19_a::App;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn history_len() -> u32 {
+    window().history().unwrap().length().unwrap()
+}
+
+#[wasm_bindgen_test]
+async fn links_and_navigate_change_the_location() {
+    let _url = RestoreUrl::new();
+    let app = mount(App);
+
+    app.click("nav a[href='/sign-in']").await;
+    wait(10).await;
+    assert_eq!(pathname(), "/sign-in");
+    assert_eq!(app.texts("a[aria-current='page']"), ["Sign In"]);
+
+    // signing in replaces the current entry, rather than adding one
+    let entries = history_len();
+    app.fill("input", "Bob", "input").await;
+    app.click("button[type='submit']").await;
+    wait(10).await;
+    assert_eq!(pathname(), "/contacts/bob");
+    assert_eq!(history_len(), entries);
+    assert_eq!(app.texts("h4"), ["Bob"]);
+
+    // relative links resolve against the route they're defined in
+    app.click(".tabs a[href='/contacts/bob/conversations']")
+        .await;
+    wait(10).await;
+    assert_eq!(pathname(), "/contacts/bob/conversations");
+    assert_eq!(app.texts(".tab"), ["(Conversations)"]);
+}
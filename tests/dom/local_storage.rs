@@ -0,0 +1,60 @@
+use crate::harness::{mount, wait};
+use leptos::prelude::*;
+use leptos_book::reactivity::c18_local_storage::Preferences;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn clear() {
+    let storage = window().local_storage().unwrap().unwrap();
+    storage.remove_item("preferences.name").unwrap();
+    storage.remove_item("preferences.visits").unwrap();
+}
+
+#[wasm_bindgen_test]
+async fn values_survive_a_remount() {
+    clear();
+    let app = mount(Preferences);
+    assert_eq!(app.texts("p"), ["Hello! Visits: 0"]);
+
+    app.fill("input", "Alice", "input").await;
+    app.click("button").await;
+    app.click("button").await;
+    wait(0).await;
+    assert_eq!(app.texts("p"), ["Hello, Alice! Visits: 2"]);
+    drop(app);
+
+    // each change was saved as it happened...
+    let storage = window().local_storage().unwrap().unwrap();
+    assert_eq!(
+        storage.get_item("preferences.name").unwrap().as_deref(),
+        Some("Alice")
+    );
+    assert_eq!(
+        storage.get_item("preferences.visits").unwrap().as_deref(),
+        Some("2")
+    );
+
+    // ...and is there from the start the next time
+    let app = mount(Preferences);
+    assert_eq!(app.texts("p"), ["Hello, Alice! Visits: 2"]);
+    assert_eq!(app.prop("input", "value"), "Alice");
+    drop(app);
+    clear();
+}
+
+#[wasm_bindgen_test]
+async fn values_that_do_not_parse_fall_back_to_the_default() {
+    clear();
+    let storage = window().local_storage().unwrap().unwrap();
+    storage.set_item("preferences.visits", "lots").unwrap();
+
+    let app = mount(Preferences);
+    assert_eq!(app.texts("p"), ["Hello! Visits: 0"]);
+    // and the default replaces it
+    wait(0).await;
+    assert_eq!(
+        storage.get_item("preferences.visits").unwrap().as_deref(),
+        Some("0")
+    );
+    drop(app);
+    clear();
+}
@@ -0,0 +1,65 @@
+//! Browser tests for the book's examples.
+//!
+//! These mount the same components the chapters include into a real DOM, and
+//! check that they behave the way the prose says they do. They only build for
+//! `wasm32`, and run in a headless browser:
+//!
+//! ```sh
+//! wasm-pack test --headless --firefox --features csr --test dom
+//! ```
+#![cfg(target_arch = "wasm32")]
+
+use wasm_bindgen_test::wasm_bindgen_test_configure;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+mod harness;
+
+mod accessibility;
+mod actions;
+mod animated_show;
+mod async_errors;
+mod basic_component;
+mod canvas;
+mod chart;
+mod complex_iteration;
+mod component_children;
+mod control_flow;
+mod csr_fetch;
+mod custom_events;
+mod debounced_search;
+mod directives;
+mod drag_and_drop;
+mod dynamic_attributes;
+mod effects;
+mod error_handling;
+mod existing_page;
+mod form_validation;
+mod forms_inputs;
+mod hash_routing;
+mod i18n;
+mod indexed_db;
+mod infinite_scroll;
+mod links;
+mod local_storage;
+mod node_ref;
+mod optimistic_updates;
+mod ownership;
+mod pagination;
+mod parent_child;
+mod portal;
+mod refetching;
+mod resources;
+mod route_guards;
+mod service_worker;
+mod shortcuts;
+mod slots;
+mod spread;
+mod suspense;
+mod swr_cache;
+mod testing;
+mod transition;
+mod wasm_bindgen;
+mod web_components;
+mod web_sys;
+mod wizard;
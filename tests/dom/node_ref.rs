@@ -0,0 +1,27 @@
+use crate::harness::mount;
+use leptos::{prelude::*, task::tick};
+use leptos_book::interop::c32_node_ref::{MeasuredBox, NameInput, SearchBox};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+async fn node_refs_hold_the_mounted_element() {
+    let app = mount(NameInput);
+    app.click("button").await;
+    assert_eq!(app.texts("p"), ["Leptos"]);
+
+    let app = mount(MeasuredBox);
+    tick().await;
+    assert_eq!(app.texts("div"), ["120px wide"]);
+}
+
+#[wasm_bindgen_test]
+async fn inputs_can_be_focused_through_their_node_ref() {
+    let app = mount(SearchBox);
+    tick().await;
+    let focused = || document().active_element().unwrap();
+    assert_eq!(focused(), *app.find("input"));
+
+    app.find("button").focus().unwrap();
+    app.click("button").await;
+    assert_eq!(focused(), *app.find("input"));
+}
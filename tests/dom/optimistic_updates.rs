@@ -0,0 +1,53 @@
+use crate::harness::{mount, wait};
+use leptos_book::r#async::c19_optimistic_updates::{App, SERVER_DOWN};
+use std::sync::atomic::Ordering;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const MILK: &str = "li:nth-child(1) input";
+
+#[wasm_bindgen_test]
+async fn changes_show_before_the_request_finishes() {
+    SERVER_DOWN.store(false, Ordering::Relaxed);
+    let app = mount(App);
+
+    app.click(MILK).await;
+    assert_eq!(app.prop(MILK, "checked"), JsValue::TRUE);
+    assert!(app.find("li:nth-child(1)").class_list().contains("saving"));
+
+    wait(600).await;
+    assert_eq!(app.prop(MILK, "checked"), JsValue::TRUE);
+    assert!(!app.find("li:nth-child(1)").class_list().contains("saving"));
+    assert_eq!(app.texts("[role='alert']"), [""]);
+}
+
+#[wasm_bindgen_test]
+async fn failed_changes_are_rolled_back() {
+    let app = mount(App);
+    app.click(".server input").await;
+    assert!(SERVER_DOWN.load(Ordering::Relaxed));
+
+    // the todo is checked straight away, as if it had worked...
+    app.click(MILK).await;
+    assert_eq!(app.prop(MILK, "checked"), JsValue::TRUE);
+    assert_eq!(app.texts("[role='alert']"), [""]);
+
+    // ...and unchecked again when the request fails
+    wait(600).await;
+    assert_eq!(app.prop(MILK, "checked"), JsValue::FALSE);
+    assert_eq!(
+        app.texts("[role='alert']"),
+        ["Couldn't save \"Buy milk\": the server is unavailable"]
+    );
+
+    // a newer change isn't undone by an older request failing
+    app.click(MILK).await;
+    wait(100).await;
+    app.click(MILK).await;
+    assert_eq!(app.prop(MILK, "checked"), JsValue::FALSE);
+    wait(600).await;
+    assert_eq!(app.prop(MILK, "checked"), JsValue::FALSE);
+
+    app.click(".server input").await;
+    assert!(!SERVER_DOWN.load(Ordering::Relaxed));
+}
@@ -0,0 +1,21 @@
+use crate::harness::mount;
+use leptos::task::tick;
+use leptos_book::reactivity::c17_cleanup::Room;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+async fn removed_rows_and_branches_are_cleaned_up() {
+    let app = mount(Room);
+    assert_eq!(app.texts("li"), ["Alice", "Bob", "Guest"]);
+    assert_eq!(app.texts(".left"), [""]);
+
+    app.click(".remove").await;
+    tick().await;
+    assert_eq!(app.texts("li"), ["Bob", "Guest"]);
+    assert_eq!(app.texts(".left"), ["Alice"]);
+
+    app.click(".hide").await;
+    tick().await;
+    assert_eq!(app.texts("li"), ["Bob"]);
+    assert_eq!(app.texts(".left"), ["Alice, Guest"]);
+}
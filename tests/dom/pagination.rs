@@ -0,0 +1,46 @@
+use crate::harness::{go_to, location, mount, wait, RestoreUrl};
+use leptos::prelude::window;
+use leptos_book::router::c24_pagination::App;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+async fn links_the_form_and_history_all_change_the_page() {
+    let _url = RestoreUrl::new();
+    go_to("/articles");
+    let app = mount(App);
+    wait(10).await;
+    assert_eq!(app.texts(".current"), ["Page 1 of 5"]);
+    assert_eq!(app.texts("li")[0], "Article 1");
+
+    // following "Next" adds a history entry for each page
+    app.click("nav a[href='/articles?page=2']").await;
+    wait(10).await;
+    assert_eq!(location(), "/articles?page=2");
+    app.click("nav a[href='/articles?page=3']").await;
+    wait(10).await;
+    assert_eq!(location(), "/articles?page=3");
+    assert_eq!(app.texts("li")[0], "Article 11");
+
+    // so the back and forward buttons move between pages, and the resource
+    // follows the URL without being told
+    let history = window().history().unwrap();
+    history.back().unwrap();
+    wait(50).await;
+    assert_eq!(location(), "/articles?page=2");
+    assert_eq!(app.texts(".current"), ["Page 2 of 5"]);
+    assert_eq!(app.texts("li")[0], "Article 6");
+    history.forward().unwrap();
+    wait(50).await;
+    assert_eq!(location(), "/articles?page=3");
+    assert_eq!(app.texts("li")[0], "Article 11");
+
+    // submitting the form is a navigation to `?page=` as well
+    app.fill("input[name='page']", "5", "input").await;
+    app.click("button[type='submit']").await;
+    wait(10).await;
+    assert_eq!(location(), "/articles?page=5");
+    assert_eq!(app.texts("li"), ["Article 21", "Article 22", "Article 23"]);
+    history.back().unwrap();
+    wait(50).await;
+    assert_eq!(app.texts(".current"), ["Page 3 of 5"]);
+}
@@ -0,0 +1,54 @@
+use crate::harness::mount;
+use leptos_book::view::c09_parent_child::{
+    all_four::App, callback, callback_props, closure, context, event_listener, prop_drilling,
+    write_signal,
+};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+async fn each_button_toggles_its_class() {
+    let app = mount(App);
+    assert_eq!(app.find("p").class_name(), "");
+
+    for (button, class) in [(1, "red"), (2, "right"), (3, "italics"), (4, "smallcaps")] {
+        app.click(&format!("button:nth-of-type({button})")).await;
+        assert!(app.find("p").class_list().contains(class), "{class}");
+    }
+
+    app.click("button:nth-of-type(1)").await;
+    assert_eq!(app.find("p").class_name(), "right italics smallcaps");
+}
+
+#[wasm_bindgen_test]
+async fn every_pattern_reaches_the_parent() {
+    let apps = [
+        mount(write_signal::App),
+        mount(callback::App),
+        mount(closure::App),
+        mount(event_listener::App),
+        mount(prop_drilling::App),
+        mount(context::App),
+    ];
+    for app in &apps {
+        assert_eq!(app.texts("p"), ["Toggled? false"]);
+        app.click("button").await;
+        assert_eq!(app.texts("p"), ["Toggled? true"]);
+    }
+}
+
+#[wasm_bindgen_test]
+async fn callbacks_with_arguments_update_the_parent() {
+    let app = mount(callback_props::App);
+    assert_eq!(app.texts("p"), ["6 and 3 make 9", "6 and 3 make 18"]);
+
+    app.click(".notice button").await;
+    assert!(app.texts(".notice").is_empty());
+
+    // both steppers show, and change, the same value
+    app.click("button:nth-of-type(2)").await;
+    assert_eq!(app.texts("span"), ["1", "1"]);
+    app.click("button:nth-of-type(4)").await;
+    assert_eq!(app.texts("span"), ["2", "2"]);
+    app.click("button:nth-of-type(3)").await;
+    assert_eq!(app.texts("span"), ["1", "1"]);
+}
@@ -0,0 +1,64 @@
+use crate::harness::mount;
+use leptos::{prelude::*, task::tick};
+use leptos_book::view::c12_portal::{ContextExample, ModalExample, MountedElsewhere};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::wasm_bindgen_test;
+use web_sys::HtmlElement;
+
+/// Finds an element anywhere in the document, not just in the mounted view.
+fn find_in_document(selector: &str) -> Option<HtmlElement> {
+    document()
+        .query_selector(selector)
+        .unwrap()
+        .map(|el| el.unchecked_into())
+}
+
+#[wasm_bindgen_test]
+async fn the_modal_is_rendered_outside_the_component() {
+    let app = mount(ModalExample);
+    app.click("button").await;
+    tick().await;
+
+    let modal = find_in_document(".modal").expect("the modal is open");
+    assert!(app.texts(".modal").is_empty());
+    assert_eq!(
+        modal.parent_element().unwrap().parent_element(),
+        document().body().map(Into::into)
+    );
+
+    // and it's removed from the body when it's closed
+    modal
+        .query_selector("button")
+        .unwrap()
+        .unwrap()
+        .unchecked_into::<HtmlElement>()
+        .click();
+    tick().await;
+    assert!(find_in_document(".modal").is_none());
+}
+
+#[wasm_bindgen_test]
+async fn context_passes_through_the_portal() {
+    let app = mount(ContextExample);
+    tick().await;
+    let title = find_in_document(".modal-title").unwrap();
+    assert_eq!(title.text_content().unwrap(), "Provided outside the portal");
+
+    // unmounting the component removes what it rendered into the portal
+    drop(app);
+    assert!(find_in_document(".modal-title").is_none());
+}
+
+#[wasm_bindgen_test]
+async fn portals_can_mount_anywhere() {
+    let target = document().create_element("aside").unwrap();
+    document().body().unwrap().append_child(&target).unwrap();
+    let app = mount({
+        let target = target.clone();
+        move || view! { <MountedElsewhere target/> }
+    });
+    tick().await;
+    assert_eq!(target.text_content().unwrap(), "Saved!");
+    drop(app);
+    target.remove();
+}
@@ -0,0 +1,81 @@
+use crate::harness::{mount, wait};
+use leptos_book::r#async::c21_refetching::{
+    insert_todo, remove_all_todos, Invalidation, Refresh, Retrying, ATTEMPTS, FAILURES_LEFT,
+};
+use std::sync::atomic::Ordering;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+async fn refetching_loads_changes_made_elsewhere() {
+    remove_all_todos();
+    let app = mount(Refresh);
+    wait(200).await;
+    assert!(app.texts("li").is_empty());
+
+    // the resource doesn't know the todos have changed...
+    insert_todo("Added in another tab");
+    wait(200).await;
+    assert!(app.texts("li").is_empty());
+
+    // ...until it's told to look again
+    app.click("button").await;
+    wait(200).await;
+    assert_eq!(app.texts("li"), ["Added in another tab"]);
+}
+
+#[wasm_bindgen_test]
+async fn mutations_invalidate_the_list() {
+    remove_all_todos();
+    let app = mount(Invalidation);
+    wait(200).await;
+
+    app.fill("input", "Buy milk", "input").await;
+    app.click("button[type='submit']").await;
+    wait(350).await;
+    assert_eq!(app.texts("li"), ["Buy milk"]);
+
+    // loading the list again picks up everything that's changed since
+    insert_todo("Added in another tab");
+    app.fill("input", "Walk the dog", "input").await;
+    app.click("button[type='submit']").await;
+    wait(350).await;
+    assert_eq!(
+        app.texts("li"),
+        ["Buy milk", "Added in another tab", "Walk the dog"]
+    );
+
+    // changes made without an action bump a version of their own
+    app.click(".clear").await;
+    wait(350).await;
+    assert!(app.texts("li").is_empty());
+}
+
+#[wasm_bindgen_test]
+async fn flaky_requests_are_retried_with_a_growing_delay() {
+    remove_all_todos();
+    insert_todo("Buy milk");
+    insert_todo("Walk the dog");
+    FAILURES_LEFT.store(2, Ordering::Relaxed);
+    let before = ATTEMPTS.load(Ordering::Relaxed);
+
+    // two failures, with 100ms and then 200ms before trying again
+    let app = mount(Retrying);
+    wait(150).await;
+    assert_eq!(app.texts("p"), ["Loading..."]);
+    wait(500).await;
+    assert_eq!(app.texts(".count"), ["2 todos"]);
+    assert_eq!(ATTEMPTS.load(Ordering::Relaxed), before + 3);
+}
+
+#[wasm_bindgen_test]
+async fn retrying_gives_up_eventually() {
+    FAILURES_LEFT.store(10, Ordering::Relaxed);
+    let before = ATTEMPTS.load(Ordering::Relaxed);
+
+    let app = mount(Retrying);
+    wait(1_100).await;
+    assert_eq!(app.texts(".count"), ["Gave up: the request timed out"]);
+    assert_eq!(ATTEMPTS.load(Ordering::Relaxed), before + 4);
+
+    FAILURES_LEFT.store(0, Ordering::Relaxed);
+}
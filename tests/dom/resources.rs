@@ -0,0 +1,57 @@
+use crate::harness::{mount, wait};
+use leptos_book::r#async::c10_resources::{App, AwaitResource, MatchGet, Refetch};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+/// Waits for `load_data`'s one-second delay, and for the view to update.
+async fn loaded() {
+    wait(1_100).await;
+}
+
+#[wasm_bindgen_test]
+async fn resources_reload_when_their_source_changes() {
+    let app = mount(App);
+    assert_eq!(
+        app.texts("p"),
+        ["stable: ", "count: 0", "async_value: Loading..."]
+    );
+
+    loaded().await;
+    assert_eq!(
+        app.texts("p"),
+        ["stable: 10", "count: 0", "async_value: Server returned 0"]
+    );
+
+    app.click("button").await;
+    loaded().await;
+    assert_eq!(
+        app.texts("p"),
+        ["stable: 10", "count: 1", "async_value: Server returned 10"]
+    );
+}
+
+#[wasm_bindgen_test]
+async fn get_is_none_until_the_resource_loads() {
+    let app = mount(MatchGet);
+    assert_eq!(app.texts("p"), ["Loading..."]);
+    loaded().await;
+    assert_eq!(app.texts("p"), ["Data: 10"]);
+}
+
+#[wasm_bindgen_test]
+async fn awaiting_in_suspend_shows_the_fallback_first() {
+    let app = mount(AwaitResource);
+    assert_eq!(app.texts("p"), ["Loading..."]);
+    loaded().await;
+    assert_eq!(app.texts("p"), ["Server returned 10"]);
+}
+
+#[wasm_bindgen_test]
+async fn refetch_reruns_the_fetcher() {
+    let app = mount(Refetch);
+    loaded().await;
+    assert_eq!(app.texts("p"), ["10"]);
+
+    app.click("button").await;
+    loaded().await;
+    assert_eq!(app.texts("p"), ["20"]);
+}
@@ -0,0 +1,39 @@
+use crate::harness::{location, mount, wait, RestoreUrl};
+use leptos_book::router::c22_route_guards::App;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+async fn guarded_pages_redirect_until_the_user_logs_in() {
+    let _url = RestoreUrl::new();
+    let app = mount(App);
+
+    // the wrapper component sends the user to log in...
+    app.click("nav a[href='/dashboard']").await;
+    wait(10).await;
+    assert_eq!(location(), "/login?next=/dashboard");
+    assert_eq!(app.texts("h2"), ["Log In"]);
+
+    // ...and the login page sends them back afterwards
+    app.fill("input", "Alice", "input").await;
+    app.click("button[type='submit']").await;
+    wait(10).await;
+    assert_eq!(location(), "/dashboard");
+    assert_eq!(app.texts("h2"), ["Welcome, Alice!"]);
+
+    // `<ProtectedRoute/>` checks the same user, so this is open now too
+    app.click("nav a[href='/settings']").await;
+    wait(10).await;
+    assert_eq!(location(), "/settings");
+    assert_eq!(app.texts("h2"), ["Settings"]);
+}
+
+#[wasm_bindgen_test]
+async fn protected_routes_redirect_without_a_user() {
+    let _url = RestoreUrl::new();
+    let app = mount(App);
+
+    app.click("nav a[href='/settings']").await;
+    wait(10).await;
+    assert_eq!(location(), "/login");
+    assert_eq!(app.texts("h2"), ["Log In"]);
+}
@@ -0,0 +1,21 @@
+use crate::harness::{mount, wait};
+use leptos::prelude::*;
+use leptos_book::deployment::c35_service_worker::{
+    register_service_worker, ServiceWorkerStatus, UpdateBanner,
+};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+async fn a_missing_worker_is_reported_without_a_banner() {
+    let owner = Owner::new();
+    let status = owner.with(|| register_service_worker("/no-such-worker.js"));
+    let app = mount(move || {
+        provide_context::<ServiceWorkerStatus>(status);
+        view! { <UpdateBanner/> }
+    });
+    // fetching the script fails, which rejects the registration
+    wait(200).await;
+    assert!(status.error.get_untracked().is_some());
+    assert!(!status.update_available.get_untracked());
+    assert!(app.texts(".update-banner").is_empty());
+}
@@ -0,0 +1,62 @@
+use crate::harness::mount;
+use leptos::{prelude::*, task::tick};
+use leptos_book::interop::c39_shortcuts::App;
+use wasm_bindgen_test::wasm_bindgen_test;
+use web_sys::{KeyboardEvent, KeyboardEventInit};
+
+/// Presses `key` with the focus on `target`, returning whether a handler
+/// cancelled it.
+fn press_on(target: &web_sys::Element, key: &str, ctrl: bool) -> bool {
+    let init = KeyboardEventInit::new();
+    init.set_key(key);
+    init.set_ctrl_key(ctrl);
+    init.set_bubbles(true);
+    init.set_cancelable(true);
+    let event = KeyboardEvent::new_with_keyboard_event_init_dict("keydown", &init).unwrap();
+    !target.dispatch_event(&event).unwrap()
+}
+
+#[wasm_bindgen_test]
+async fn shortcuts_work_anywhere_on_the_page() {
+    let app = mount(App);
+    tick().await;
+    let body = document().body().unwrap();
+    let focused = || document().active_element().unwrap();
+
+    // `/` focuses the search box, and isn't typed into it...
+    assert!(press_on(&body, "/", false));
+    assert_eq!(focused(), *app.find(".search"));
+    // ...but once it's there, `/` can be typed
+    assert!(!press_on(&app.find(".search"), "/", false));
+
+    // Ctrl+K works even while typing
+    assert!(press_on(&app.find(".search"), "k", true));
+    tick().await;
+    assert_eq!(app.texts(".palette"), ["Command palette"]);
+
+    assert!(press_on(&app.find(".search"), "Escape", false));
+    tick().await;
+    assert!(app.texts(".palette").is_empty());
+    assert_ne!(focused(), *app.find(".search"));
+}
+
+#[wasm_bindgen_test]
+async fn disabled_shortcuts_are_removed() {
+    let app = mount(App);
+    tick().await;
+    let body = document().body().unwrap();
+
+    app.click(".enabled").await;
+    assert!(!press_on(&body, "k", true));
+    tick().await;
+    assert!(app.texts(".palette").is_empty());
+
+    app.click(".enabled").await;
+    assert!(press_on(&body, "k", true));
+    tick().await;
+    assert_eq!(app.texts(".palette"), ["Command palette"]);
+
+    // and so are the listeners of an unmounted component
+    drop(app);
+    assert!(!press_on(&body, "k", true));
+}
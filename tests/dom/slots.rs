@@ -0,0 +1,14 @@
+use crate::harness::mount;
+use leptos_book::view::c14_slots::Settings;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+async fn clicking_a_tab_shows_its_panel() {
+    let app = mount(Settings);
+    assert_eq!(app.texts(".selected"), ["Profile"]);
+    assert_eq!(app.texts("[role=tabpanel] p"), ["Your name and picture."]);
+
+    app.click("[role=tab]:last-child").await;
+    assert_eq!(app.texts(".selected"), ["Notifications"]);
+    assert_eq!(app.texts("[role=tabpanel] p"), ["What we email you about."]);
+}
@@ -0,0 +1,13 @@
+use crate::harness::mount;
+use leptos_book::view::c15_spread::SpreadOntoComponent;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+async fn event_listeners_are_spread_onto_the_component() {
+    let app = mount(SpreadOntoComponent);
+    assert_eq!(app.texts("button.primary"), ["Clicked 0 times"]);
+
+    app.click("button").await;
+    app.click("button").await;
+    assert_eq!(app.texts("button.primary"), ["Clicked 2 times"]);
+}
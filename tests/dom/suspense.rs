@@ -0,0 +1,65 @@
+use crate::harness::{mount, wait};
+use leptos_book::r#async::c11_suspense::{
+    App, MatchBoth, Monkeys, NestedSuspense, SuspendBoth, SuspenseBoth,
+};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+async fn matching_waits_for_both_resources() {
+    let app = mount(MatchBoth);
+    assert_eq!(app.texts("p"), ["Loading..."]);
+    wait(1_100).await;
+    assert_eq!(app.texts("p"), ["Loading..."]);
+    wait(1_000).await;
+    assert_eq!(app.texts("p"), ["A: 10", "B: 100"]);
+}
+
+#[wasm_bindgen_test]
+async fn suspense_shows_the_fallback_while_anything_is_loading() {
+    let app = mount(SuspenseBoth);
+    assert_eq!(app.texts("p"), ["Loading..."]);
+    wait(2_100).await;
+    assert_eq!(app.texts("p"), ["A: 10", "B: 100"]);
+
+    app.click("button").await;
+    assert_eq!(app.texts("p"), ["Loading..."]);
+    wait(1_100).await;
+    assert_eq!(app.texts("p"), ["A: 20", "B: 100"]);
+}
+
+#[wasm_bindgen_test]
+async fn nested_suspense_shows_the_outer_content_first() {
+    let app = mount(NestedSuspense);
+    assert_eq!(app.texts("p"), ["Loading A..."]);
+    wait(1_100).await;
+    assert_eq!(app.texts("p"), ["A: 10", "Loading B..."]);
+    wait(1_000).await;
+    assert_eq!(app.texts("p"), ["A: 10", "B: 100"]);
+}
+
+#[wasm_bindgen_test]
+async fn suspend_awaits_both_resources() {
+    let app = mount(SuspendBoth);
+    assert_eq!(app.texts("p"), ["Loading..."]);
+    wait(2_100).await;
+    assert_eq!(app.texts("p"), ["A: 10", "B: 100"]);
+}
+
+#[wasm_bindgen_test]
+async fn await_renders_nothing_until_the_future_resolves() {
+    let app = mount(Monkeys);
+    wait(0).await;
+    assert_eq!(app.texts("p"), ["6 little monkeys, jumping on the bed."]);
+}
+
+#[wasm_bindgen_test]
+async fn the_suspense_reloads_when_the_name_changes() {
+    let app = mount(App);
+    assert_eq!(app.texts("p"), ["name: Bill", "Loading..."]);
+    wait(1_100).await;
+    assert_eq!(app.texts("p"), ["name: Bill", "Your shouting name is BILL"]);
+
+    app.fill("input", "Ted", "input").await;
+    wait(1_100).await;
+    assert_eq!(app.texts("p"), ["name: Ted", "Your shouting name is TED"]);
+}
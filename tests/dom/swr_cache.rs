@@ -0,0 +1,64 @@
+use crate::harness::{mount, wait};
+use leptos::prelude::*;
+use leptos_book::r#async::c22_swr_cache::{App, Profiles, QueryCache, FETCHES};
+use std::{sync::atomic::Ordering, time::Duration};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn fetches() -> usize {
+    FETCHES.load(Ordering::Relaxed)
+}
+
+#[wasm_bindgen_test]
+async fn cache_hits_are_shown_without_a_request() {
+    let before = fetches();
+    let app = mount(App);
+    assert_eq!(app.texts("header span"), ["Loading..."]);
+
+    // both components want the same user, which is only requested once
+    wait(300).await;
+    assert_eq!(fetches(), before + 1);
+    assert_eq!(app.texts("span"), ["Ada Lovelace", "Ada Lovelace"]);
+
+    app.click("nav button:nth-child(2)").await;
+    wait(300).await;
+    assert_eq!(fetches(), before + 2);
+    assert_eq!(app.texts("header span"), ["Grace Hopper"]);
+
+    // the first user is still fresh, so going back to them is instant
+    app.click("nav button:nth-child(1)").await;
+    assert_eq!(app.texts("span"), ["Ada Lovelace", "Ada Lovelace"]);
+    wait(300).await;
+    assert_eq!(fetches(), before + 2);
+}
+
+#[wasm_bindgen_test]
+async fn stale_values_are_shown_while_they_are_loaded_again() {
+    let before = fetches();
+    // nothing is ever fresh, so every use loads again
+    let app = mount(|| {
+        provide_context(QueryCache::<u32, String>::new(Duration::ZERO));
+        view! { <Profiles/> }
+    });
+    wait(300).await;
+    app.click("nav button:nth-child(2)").await;
+    wait(300).await;
+    assert_eq!(fetches(), before + 2);
+
+    // the stale value is shown straight away, rather than "Loading..."...
+    app.click("nav button:nth-child(1)").await;
+    wait(10).await;
+    assert_eq!(app.texts("header span"), ["Ada Lovelace"]);
+    assert!(app
+        .find("header span")
+        .class_list()
+        .contains("revalidating"));
+    assert_eq!(fetches(), before + 3);
+
+    // ...and replaced when the new one arrives
+    wait(300).await;
+    assert!(!app
+        .find("header span")
+        .class_list()
+        .contains("revalidating"));
+    assert_eq!(fetches(), before + 3);
+}
@@ -0,0 +1,22 @@
+use crate::harness::mount;
+use leptos_book::testing::c15_testing::TodoApp;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+// ANCHOR: dom_test
+#[wasm_bindgen_test]
+async fn todos_can_be_added_and_completed() {
+    let app = mount(TodoApp);
+    assert_eq!(app.texts(".remaining"), ["0 remaining"]);
+
+    app.fill("input[type=text]", "Buy bread", "input").await;
+    app.click("button[type=submit]").await;
+    app.fill("input[type=text]", "Buy milk", "input").await;
+    app.click("button[type=submit]").await;
+    assert_eq!(app.texts("li"), ["Buy bread", "Buy milk"]);
+    assert_eq!(app.texts(".remaining"), ["2 remaining"]);
+
+    app.click("li:first-child input[type=checkbox]").await;
+    assert_eq!(app.texts("li.completed"), ["Buy bread"]);
+    assert_eq!(app.texts(".remaining"), ["1 remaining"]);
+}
+// ANCHOR_END: dom_test
@@ -0,0 +1,37 @@
+use crate::harness::{mount, wait};
+use leptos_book::r#async::c12_transition::{App, SuspenseTabs};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+async fn transition_keeps_showing_the_old_tab_while_loading() {
+    let app = mount(App);
+    assert_eq!(app.texts("p"), ["Loading initial data..."]);
+    assert!(app.texts(".buttons")[0].ends_with("Hang on..."));
+
+    wait(1_100).await;
+    assert_eq!(app.texts("p"), ["Alice"]);
+    assert!(app.texts(".buttons")[0].ends_with("Ready."));
+
+    app.click("button:nth-of-type(2)").await;
+    // the stale content stays visible, and `pending` says it's stale
+    assert_eq!(app.texts("p"), ["Alice"]);
+    assert!(app.texts(".buttons")[0].ends_with("Hang on..."));
+    assert_eq!(app.texts(".selected"), ["Tab B"]);
+
+    wait(1_100).await;
+    assert_eq!(app.texts("p"), ["Bob"]);
+    assert!(app.texts(".buttons")[0].ends_with("Ready."));
+}
+
+#[wasm_bindgen_test]
+async fn suspense_falls_back_every_time() {
+    let app = mount(SuspenseTabs);
+    wait(1_100).await;
+    assert_eq!(app.texts("p"), ["Alice"]);
+
+    app.click("button:nth-of-type(3)").await;
+    assert_eq!(app.texts("p"), ["Loading..."]);
+
+    wait(1_100).await;
+    assert_eq!(app.texts("p"), ["Carol"]);
+}
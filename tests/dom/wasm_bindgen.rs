@@ -0,0 +1,40 @@
+use crate::harness::{mount, wait};
+use leptos::prelude::*;
+use leptos_book::interop::c30_wasm_bindgen::{
+    describe_point, greet, max_of_two, origin_point, Greeting, Point, Ticker,
+};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn imported_functions_can_be_called_from_rust() {
+    assert_eq!(greet("Leptos"), "Hello, Leptos!");
+    assert_eq!(max_of_two(1.5, -3.0), 1.5);
+}
+
+#[wasm_bindgen_test]
+async fn components_can_call_javascript_reactively() {
+    let app = mount(Greeting);
+    assert_eq!(app.texts("p"), ["Hello, world!"]);
+    app.fill("input", "Leptos", "input").await;
+    assert_eq!(app.texts("p"), ["Hello, Leptos!"]);
+}
+
+#[wasm_bindgen_test]
+fn structs_cross_the_boundary_as_objects() {
+    assert_eq!(describe_point(Point { x: 3, y: -4 }), "(3, -4)");
+    assert_eq!(origin_point().unwrap(), Point { x: 0, y: 0 });
+}
+
+#[wasm_bindgen_test]
+async fn the_closure_lives_as_long_as_the_component() {
+    let app = mount(|| view! { <Ticker interval=10/> });
+    wait(55).await;
+    let text = app.texts("p").remove(0);
+    let ticks: u32 = text.trim_start_matches("Ticks: ").parse().unwrap();
+    assert!(ticks >= 2, "{text}");
+
+    // unmounting clears the interval before the `Closure` is dropped, so
+    // nothing calls it afterwards
+    drop(app);
+    wait(30).await;
+}
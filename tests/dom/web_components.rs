@@ -0,0 +1,53 @@
+use leptos::{prelude::*, task::tick};
+use leptos_book::interop::c36_web_components::register;
+use wasm_bindgen::{prelude::*, JsCast};
+use wasm_bindgen_test::wasm_bindgen_test;
+use web_sys::{CustomEvent, HtmlElement};
+
+#[wasm_bindgen_test]
+async fn custom_elements_render_the_component() {
+    register();
+    let page = document().create_element("div").unwrap();
+    document().body().unwrap().append_child(&page).unwrap();
+    // the way any other page would use it, without knowing it's Leptos
+    page.set_inner_html(r#"<click-counter label="Likes" start="3"></click-counter>"#);
+    let counter = page.query_selector("click-counter").unwrap().unwrap();
+    let button = || page.query_selector("button").unwrap().unwrap();
+    assert_eq!(button().text_content().unwrap(), "Likes: 3");
+
+    // observed attributes update the view
+    counter.set_attribute("label", "Stars").unwrap();
+    tick().await;
+    assert_eq!(button().text_content().unwrap(), "Stars: 3");
+
+    // and the page hears about clicks through an event
+    let heard = js_sys::Array::new();
+    let listener = Closure::<dyn Fn(CustomEvent)>::new({
+        let heard = heard.clone();
+        move |ev: CustomEvent| {
+            heard.push(&ev.detail());
+        }
+    });
+    page.add_event_listener_with_callback("count-changed", listener.as_ref().unchecked_ref())
+        .unwrap();
+    button().unchecked_into::<HtmlElement>().click();
+    tick().await;
+    assert_eq!(button().text_content().unwrap(), "Stars: 4");
+    assert_eq!(heard.to_vec(), [JsValue::from(4)]);
+
+    // removing the element unmounts the component
+    counter.remove();
+    assert_eq!(counter.child_element_count(), 0);
+    page.remove();
+}
+
+#[wasm_bindgen_test]
+fn elements_already_on_the_page_are_upgraded() {
+    let page = document().create_element("div").unwrap();
+    document().body().unwrap().append_child(&page).unwrap();
+    page.set_inner_html("<click-counter></click-counter>");
+    // if the tests above haven't defined it yet, this upgrades the element
+    register();
+    assert_eq!(page.text_content().unwrap(), "Clicks: 0");
+    page.remove();
+}
@@ -0,0 +1,60 @@
+use crate::harness::{mount, wait, Mounted};
+use leptos::{prelude::*, task::tick};
+use leptos_book::interop::c31_web_sys::{
+    use_media_query, ColorScheme, KeyLogger, KeyLoggerHelper, PageInfo, Reveal,
+};
+use wasm_bindgen_test::wasm_bindgen_test;
+use web_sys::{KeyboardEvent, KeyboardEventInit};
+
+fn press(key: &str) {
+    let init = KeyboardEventInit::new();
+    init.set_key(key);
+    let event = KeyboardEvent::new_with_keyboard_event_init_dict("keydown", &init).unwrap();
+    window().dispatch_event(&event).unwrap();
+}
+
+#[wasm_bindgen_test]
+fn window_and_document_are_reachable() {
+    document().set_title("web-sys");
+    let app = mount(PageInfo);
+    assert_ne!(app.texts(".width"), ["0"]);
+    assert_eq!(app.texts(".title"), ["web-sys"]);
+}
+
+#[wasm_bindgen_test]
+fn media_queries_are_read_into_a_signal() {
+    let app = mount(|| {
+        let wide = use_media_query("(min-width: 1px)");
+        let print = use_media_query("print");
+        view! { <p>{move || format!("{} {}", wide.get(), print.get())}</p> }
+    });
+    assert_eq!(app.texts("p"), ["true false"]);
+    // the listeners are removed along with the component
+    drop(app);
+    assert_eq!(mount(ColorScheme).texts("p").len(), 1);
+}
+
+#[wasm_bindgen_test]
+async fn observed_elements_are_revealed_once_visible() {
+    let app = mount(|| view! { <Reveal>"Hello"</Reveal> });
+    assert!(!app.find("section").class_list().contains("visible"));
+    wait(50).await;
+    assert!(app.find("section").class_list().contains("visible"));
+}
+
+async fn logs_keys_until_unmounted(app: Mounted) {
+    press("a");
+    tick().await;
+    assert_eq!(app.texts("p"), ["Last key: a"]);
+
+    drop(app);
+    // nothing calls the dropped closure, so this doesn't throw
+    press("b");
+    tick().await;
+}
+
+#[wasm_bindgen_test]
+async fn window_listeners_are_removed_on_cleanup() {
+    logs_keys_until_unmounted(mount(KeyLogger)).await;
+    logs_keys_until_unmounted(mount(KeyLoggerHelper)).await;
+}
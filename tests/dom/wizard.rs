@@ -0,0 +1,65 @@
+use crate::harness::{go_to, mount, pathname, wait, RestoreUrl};
+use leptos_book::router::c25_wizard::App;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+async fn each_step_is_checked_before_the_next() {
+    let _url = RestoreUrl::new();
+    go_to("/signup");
+    let app = mount(App);
+    wait(10).await;
+    assert_eq!(pathname(), "/signup/account");
+    assert_eq!(app.texts("h3"), ["Account"]);
+
+    // an empty step stays where it is, and says what's wrong
+    app.click("button[type='submit']").await;
+    assert_eq!(pathname(), "/signup/account");
+    assert_eq!(app.texts(".errors li").len(), 2);
+
+    app.fill("input[name='email']", "ada@example.com", "input")
+        .await;
+    app.fill("input[name='password']", "correct horse", "input")
+        .await;
+    app.click("button[type='submit']").await;
+    wait(10).await;
+    assert_eq!(pathname(), "/signup/profile");
+    assert!(app.texts(".errors li").is_empty());
+
+    // going back shows what was typed, because it's kept in the store
+    app.click("form a").await;
+    wait(10).await;
+    assert_eq!(pathname(), "/signup/account");
+    assert_eq!(app.prop("input[name='email']", "value"), "ada@example.com");
+    app.click("button[type='submit']").await;
+    wait(10).await;
+
+    app.fill("input[name='name']", "Ada", "input").await;
+    app.click("button[type='submit']").await;
+    wait(10).await;
+    assert_eq!(pathname(), "/signup/confirm");
+    assert_eq!(app.texts("dd"), ["ada@example.com", "Ada"]);
+
+    app.click("button[type='submit']").await;
+    assert_eq!(
+        app.texts(".errors li"),
+        ["Agree to the terms to create your account."]
+    );
+    app.click("input[name='agreed']").await;
+    app.click("button[type='submit']").await;
+    assert_eq!(app.texts(".done"), ["Welcome, Ada!"]);
+}
+
+#[wasm_bindgen_test]
+async fn skipping_ahead_goes_back_to_the_first_incomplete_step() {
+    let _url = RestoreUrl::new();
+    go_to("/signup/confirm");
+    let app = mount(App);
+    wait(10).await;
+    assert_eq!(pathname(), "/signup/account");
+    assert_eq!(app.texts("h3"), ["Account"]);
+
+    // the links to each step are checked the same way
+    app.click(".steps a[href='/signup/profile']").await;
+    wait(10).await;
+    assert_eq!(pathname(), "/signup/account");
+}
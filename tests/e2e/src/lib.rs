@@ -0,0 +1,214 @@
+//! A test harness that drives the book's tutorial apps in a browser.
+//!
+//! `cargo xtask e2e` builds each app into `target/e2e/<name>` (or
+//! `$E2E_SITE/<name>`) and then runs these tests. [`Session::open`] serves one
+//! of those directories on a local port, falling back to its `index.html` for
+//! any other path so that the router can handle deep links, and opens it in a
+//! headless browser through the WebDriver server at `$WEBDRIVER_URL`
+//! (`http://localhost:4444` by default), such as `chromedriver --port=4444`
+//! or `geckodriver`.
+//!
+//! Apps with server functions are served by the tests themselves, with
+//! [`Session::serve`]. Their wasm is still built into `target/e2e/<name>`,
+//! for the test to serve alongside the app.
+
+use axum::Router;
+use fantoccini::{elements::Element, Client, ClientBuilder, Locator};
+use std::{
+    env,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+use tower_http::services::{ServeDir, ServeFile};
+
+/// How long to wait for the page to catch up before failing an assertion.
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A browser session with one of the apps open.
+pub struct Session {
+    client: Client,
+    origin: String,
+}
+
+impl Session {
+    /// Serves the app built into the directory `app`, and opens its root.
+    pub async fn open(app: &str) -> Self {
+        let dir = site_dir().join(app);
+        assert!(
+            dir.join("index.html").is_file(),
+            "{} hasn't been built: run these tests with `cargo xtask e2e`",
+            dir.display()
+        );
+        let router = Router::new()
+            .fallback_service(ServeDir::new(&dir).fallback(ServeFile::new(dir.join("index.html"))));
+        Self::connect(router, true).await
+    }
+
+    /// Serves a server-rendered app with `router`, and opens its root.
+    pub async fn serve(router: Router) -> Self {
+        Self::connect(router, true).await
+    }
+
+    /// Like [`Session::serve`], but in a browser with JavaScript turned off,
+    /// which only ever sees the HTML that the server sends.
+    pub async fn serve_without_js(router: Router) -> Self {
+        Self::connect(router, false).await
+    }
+
+    async fn connect(router: Router, javascript: bool) -> Self {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let origin = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+
+        let webdriver =
+            env::var("WEBDRIVER_URL").unwrap_or_else(|_| "http://localhost:4444".into());
+        // Chrome's content setting allows scripts with 1, and blocks them with 2
+        let chrome_js = if javascript { 1 } else { 2 };
+        let capabilities = serde_json::json!({
+            "goog:chromeOptions": {
+                "args": ["--headless=new"],
+                "prefs": { "profile.managed_default_content_settings.javascript": chrome_js },
+            },
+            "moz:firefoxOptions": {
+                "args": ["-headless"],
+                "prefs": { "javascript.enabled": javascript },
+            },
+        });
+        let client = ClientBuilder::rustls()
+            .expect("rustls")
+            .capabilities(capabilities.as_object().unwrap().clone())
+            .connect(&webdriver)
+            .await
+            .unwrap_or_else(|e| panic!("couldn't connect to WebDriver at {webdriver}: {e}"));
+
+        let session = Self { client, origin };
+        session.goto("/").await;
+        session
+    }
+
+    /// Loads `path` from scratch, as if it had been typed into the address bar.
+    pub async fn goto(&self, path: &str) {
+        self.client
+            .goto(&format!("{}{path}", self.origin))
+            .await
+            .unwrap();
+    }
+
+    /// Reloads the current page, the way the browser's reload button does.
+    pub async fn reload(&self) {
+        self.client.refresh().await.unwrap();
+    }
+
+    /// The path of the page the browser is on.
+    pub async fn path(&self) -> String {
+        self.client.current_url().await.unwrap().path().to_string()
+    }
+
+    /// Clicks the first element that matches `selector`.
+    pub async fn click(&self, selector: &str) {
+        self.find(Locator::Css(selector))
+            .await
+            .click()
+            .await
+            .unwrap();
+    }
+
+    /// Clicks the link with the text `text`.
+    pub async fn click_link(&self, text: &str) {
+        self.find(Locator::LinkText(text))
+            .await
+            .click()
+            .await
+            .unwrap();
+    }
+
+    /// Clicks the button with the text `text`.
+    pub async fn click_button(&self, text: &str) {
+        let xpath = format!("//button[normalize-space() = '{text}']");
+        self.find(Locator::XPath(&xpath))
+            .await
+            .click()
+            .await
+            .unwrap();
+    }
+
+    /// Types `text` into the first input that matches `selector`.
+    pub async fn fill(&self, selector: &str, text: &str) {
+        self.find(Locator::Css(selector))
+            .await
+            .send_keys(text)
+            .await
+            .unwrap();
+    }
+
+    /// Waits for an element that matches `selector` to appear.
+    pub async fn wait_for(&self, selector: &str) {
+        self.find(Locator::Css(selector)).await;
+    }
+
+    /// Runs `script` in the page, returning what it returns.
+    pub async fn execute(&self, script: &str) -> serde_json::Value {
+        self.client.execute(script, Vec::new()).await.unwrap()
+    }
+
+    /// Waits until the text of the elements matching `selector` is `expected`,
+    /// and panics with what they say instead if that doesn't happen.
+    pub async fn assert_texts<const N: usize>(&self, selector: &str, expected: [&str; N]) {
+        let start = Instant::now();
+        loop {
+            let texts = self.texts(selector).await;
+            if texts == expected {
+                return;
+            }
+            if start.elapsed() > TIMEOUT {
+                panic!("expected `{selector}` to be {expected:?}, found {texts:?}");
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    }
+
+    /// Waits until the path of the page is `expected`.
+    pub async fn assert_path(&self, expected: &str) {
+        let start = Instant::now();
+        loop {
+            let path = self.path().await;
+            if path == expected {
+                return;
+            }
+            if start.elapsed() > TIMEOUT {
+                panic!("expected to be on {expected}, found {path}");
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    }
+
+    /// Ends the browser session.
+    pub async fn close(self) {
+        self.client.close().await.unwrap();
+    }
+
+    // the app renders once its wasm has loaded, so wait for elements to appear
+    async fn find(&self, locator: Locator<'_>) -> Element {
+        self.client
+            .wait()
+            .at_most(TIMEOUT)
+            .for_element(locator)
+            .await
+            .unwrap()
+    }
+
+    async fn texts(&self, selector: &str) -> Vec<String> {
+        let mut texts = Vec::new();
+        for element in self.client.find_all(Locator::Css(selector)).await.unwrap() {
+            texts.push(element.text().await.unwrap());
+        }
+        texts
+    }
+}
+
+/// The directory that `cargo xtask e2e` builds the apps into.
+pub fn site_dir() -> PathBuf {
+    env::var_os("E2E_SITE")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("../../target/e2e"))
+}
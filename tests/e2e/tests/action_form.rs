@@ -0,0 +1,52 @@
+//! The todo list from "`<ActionForm/>`s", which works with or without JS.
+
+use e2e_tests::{site_dir, Session};
+use leptos::prelude::LeptosOptions;
+use leptos_book::progressive_enhancement::c28_action_form::router;
+use tower_http::services::ServeDir;
+
+const FORM: &str = "form input[name=title]";
+const SUBMIT: &str = "form input[type=submit]";
+
+fn app() -> axum::Router {
+    let pkg = site_dir().join("action_form");
+    assert!(
+        pkg.join("action_form.js").is_file(),
+        "{} hasn't been built: run these tests with `cargo xtask e2e`",
+        pkg.display()
+    );
+    let options = LeptosOptions::builder().output_name("action_form").build();
+    router(options).nest_service("/pkg", ServeDir::new(pkg))
+}
+
+// the todos are kept in one list for the whole process, so both ways of
+// submitting the form are checked one after the other
+#[tokio::test]
+#[ignore = "needs a WebDriver server: run with `cargo xtask e2e`"]
+async fn todos_can_be_added_with_and_without_js() {
+    // without JS, the form is a plain POST, and the server sends the browser
+    // back to the page, which now lists the todo
+    let page = Session::serve_without_js(app()).await;
+    page.fill(FORM, "Walk the dog").await;
+    page.click(SUBMIT).await;
+    page.assert_texts("ul li", ["Walk the dog"]).await;
+    page.fill(FORM, " ").await;
+    page.click(SUBMIT).await;
+    page.assert_texts(
+        "p.error",
+        ["error running server function: A todo needs a title."],
+    )
+    .await;
+    page.close().await;
+
+    // with JS, the action calls the server function without leaving the page
+    let page = Session::serve(app()).await;
+    page.wait_for("footer[data-hydrated]").await;
+    page.execute("window.stayed = true").await;
+    page.fill(FORM, "Feed the cat").await;
+    page.click(SUBMIT).await;
+    page.assert_texts("ul li", ["Walk the dog", "Feed the cat"])
+        .await;
+    assert_eq!(page.execute("return window.stayed").await, true);
+    page.close().await;
+}
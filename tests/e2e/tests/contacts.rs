@@ -0,0 +1,49 @@
+//! The contact app from "Nested Routing".
+
+use e2e_tests::Session;
+
+#[tokio::test]
+#[ignore = "needs a WebDriver server: run with `cargo xtask e2e`"]
+async fn links_navigate_through_the_nested_routes() {
+    let app = Session::open("contacts").await;
+    app.assert_texts("main h3", ["Home"]).await;
+
+    app.click_link("Contacts").await;
+    app.assert_path("/contacts").await;
+    app.assert_texts(".select-user", ["Select a user to view contact info."])
+        .await;
+
+    app.click_link("Bob").await;
+    app.assert_path("/contacts/bob").await;
+    app.assert_texts("main h4", ["Bob"]).await;
+    app.assert_texts(".tab", ["(Contact Info)"]).await;
+    app.assert_texts(".select-user", []).await;
+
+    app.click_link("Conversations").await;
+    app.assert_path("/contacts/bob/conversations").await;
+    app.assert_texts(".tab", ["(Conversations)"]).await;
+    app.assert_texts(".tabs a[aria-current=page]", ["Conversations"])
+        .await;
+
+    app.click_link("Alice").await;
+    app.assert_path("/contacts/alice").await;
+    app.assert_texts("main h4", ["Alice"]).await;
+    app.close().await;
+}
+
+#[tokio::test]
+#[ignore = "needs a WebDriver server: run with `cargo xtask e2e`"]
+async fn deep_links_render_their_route() {
+    let app = Session::open("contacts").await;
+
+    app.goto("/contacts/steve/conversations").await;
+    app.assert_texts("main h4", ["Steve"]).await;
+    app.assert_texts(".tab", ["(Conversations)"]).await;
+
+    app.goto("/contacts/zed").await;
+    app.assert_texts("main h4", ["User not found."]).await;
+
+    app.goto("/nowhere").await;
+    app.assert_texts("main", ["Not found."]).await;
+    app.close().await;
+}
@@ -0,0 +1,39 @@
+//! The counter collection from "Iteration".
+
+use e2e_tests::Session;
+
+#[tokio::test]
+#[ignore = "needs a WebDriver server: run with `cargo xtask e2e`"]
+async fn static_counters_count_clicks() {
+    let app = Session::open("counters").await;
+    let counters = ".static-list button";
+    app.assert_texts(counters, ["1", "2", "3", "4", "5"]).await;
+
+    app.click(".static-list li:nth-child(2) button").await;
+    app.click(".static-list li:nth-child(2) button").await;
+    app.assert_texts(counters, ["1", "4", "3", "4", "5"]).await;
+    app.close().await;
+}
+
+#[tokio::test]
+#[ignore = "needs a WebDriver server: run with `cargo xtask e2e`"]
+async fn dynamic_counters_can_be_added_and_removed() {
+    let app = Session::open("counters").await;
+    let counters = ".dynamic-list li button:first-child";
+    app.assert_texts(counters, ["1", "2", "3", "4", "5"]).await;
+
+    app.click_button("Add Counter").await;
+    app.assert_texts(counters, ["1", "2", "3", "4", "5", "6"])
+        .await;
+
+    // each row keeps its own count as the rows around it are removed
+    app.click(".dynamic-list li:nth-child(3) button:first-child")
+        .await;
+    app.click(".dynamic-list li:nth-child(2) button:nth-child(2)")
+        .await;
+    app.assert_texts(counters, ["1", "4", "4", "5", "6"]).await;
+    app.click(".dynamic-list li:first-child button:nth-child(2)")
+        .await;
+    app.assert_texts(counters, ["4", "4", "5", "6"]).await;
+    app.close().await;
+}
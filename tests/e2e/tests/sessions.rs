@@ -0,0 +1,53 @@
+//! The login form from "Sessions and Authentication".
+//!
+//! The SSR tests send the session cookie back by hand. This checks that a
+//! real browser keeps the cookie it's given, sends it with every request, and
+//! deletes it again when the user logs out.
+
+use e2e_tests::Session;
+use leptos::prelude::LeptosOptions;
+use leptos_book::server::c29_sessions::router;
+
+fn app() -> axum::Router {
+    router(LeptosOptions::builder().output_name("sessions").build())
+}
+
+// the login form is an `<ActionForm/>`, which is a plain HTML form until the
+// wasm loads, so the browser doesn't need any of the app's JS for this
+#[tokio::test]
+#[ignore = "needs a WebDriver server: run with `cargo xtask e2e`"]
+async fn logging_in_lasts_until_logging_out() {
+    let page = Session::serve_without_js(app()).await;
+
+    // the account page sends visitors who aren't logged in to the login form
+    page.goto("/account").await;
+    page.assert_path("/").await;
+
+    page.fill("input[name=username]", "alice").await;
+    page.fill("input[name=password]", "nope").await;
+    page.click("input[type=submit]").await;
+    page.assert_texts(
+        "p.error",
+        ["error running server function: Wrong username or password."],
+    )
+    .await;
+
+    page.fill("input[name=username]", "alice").await;
+    page.fill("input[name=password]", "hunter2").await;
+    page.click("input[type=submit]").await;
+    page.assert_path("/account").await;
+    page.assert_texts("p", ["Welcome back, alice!"]).await;
+
+    // the cookie is sent again with the next request, so the page still knows
+    // who's logged in
+    page.reload().await;
+    page.assert_path("/account").await;
+    page.assert_texts("p", ["Welcome back, alice!"]).await;
+
+    // logging out deletes the session, so the account page is closed again
+    page.click("input[value='Log out']").await;
+    page.assert_path("/").await;
+    page.goto("/account").await;
+    page.assert_path("/").await;
+    page.close().await;
+}
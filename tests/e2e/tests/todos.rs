@@ -0,0 +1,23 @@
+//! The todo app from "Testing Your Components".
+
+use e2e_tests::Session;
+
+// ANCHOR: e2e_test
+#[tokio::test]
+#[ignore = "needs a WebDriver server: run with `cargo xtask e2e`"]
+async fn todos_can_be_added_and_completed() {
+    let app = Session::open("todos").await;
+    app.assert_texts(".remaining", ["0 remaining"]).await;
+
+    app.fill("input[type=text]", "Buy bread").await;
+    app.click_button("Add").await;
+    app.fill("input[type=text]", "Buy milk").await;
+    app.click_button("Add").await;
+    app.assert_texts("li", ["Buy bread", "Buy milk"]).await;
+
+    app.click("li:first-child input[type=checkbox]").await;
+    app.assert_texts("li.completed", ["Buy bread"]).await;
+    app.assert_texts(".remaining", ["1 remaining"]).await;
+    app.close().await;
+}
+// ANCHOR_END: e2e_test
@@ -0,0 +1,88 @@
+use js_sys::{Function, Reflect};
+use leptos::{mount::hydrate_from, prelude::*, task::tick};
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+use web_sys::HtmlElement;
+
+/// A component hydrated over server-rendered HTML in its own container.
+///
+/// Everything logged with `console.error` while it is alive is recorded. The
+/// container is removed, and `console.error` restored, when this is dropped.
+pub struct Hydrated {
+    container: HtmlElement,
+    errors: Rc<RefCell<Vec<String>>>,
+    console: JsValue,
+    original_error: JsValue,
+    _capture: Closure<dyn Fn(JsValue)>,
+    _handle: Option<Box<dyn std::any::Any>>,
+}
+
+/// Hydrates the view over `html`, in a fresh `<div>` appended to `<body>`.
+///
+/// A mismatch between `html` and the view panics, as it does in an app.
+pub fn hydrate<F, N>(html: &str, f: F) -> Hydrated
+where
+    F: FnOnce() -> N + 'static,
+    N: IntoView + 'static,
+{
+    let container = document()
+        .create_element("div")
+        .unwrap()
+        .unchecked_into::<HtmlElement>();
+    container.set_inner_html(html);
+    document().body().unwrap().append_child(&container).unwrap();
+
+    // record errors as well as logging them as usual
+    let console = Reflect::get(&js_sys::global(), &"console".into()).unwrap();
+    let original_error = Reflect::get(&console, &"error".into()).unwrap();
+    let errors = Rc::new(RefCell::new(Vec::new()));
+    let capture = Closure::<dyn Fn(JsValue)>::new({
+        let errors = Rc::clone(&errors);
+        let original_error = original_error.clone().unchecked_into::<Function>();
+        move |message: JsValue| {
+            errors.borrow_mut().push(
+                message
+                    .as_string()
+                    .unwrap_or_else(|| format!("{message:?}")),
+            );
+            _ = original_error.call1(&JsValue::NULL, &message);
+        }
+    });
+    Reflect::set(&console, &"error".into(), capture.as_ref()).unwrap();
+
+    let mut hydrated = Hydrated {
+        container: container.clone(),
+        errors,
+        console,
+        original_error,
+        _capture: capture,
+        _handle: None,
+    };
+    hydrated._handle = Some(Box::new(hydrate_from(container, f)));
+    hydrated
+}
+
+impl Hydrated {
+    /// Returns the text content of every element matching the selector.
+    pub fn texts(&self, selector: &str) -> Vec<String> {
+        let nodes = self.container.query_selector_all(selector).unwrap();
+        (0..nodes.length())
+            .filter_map(|idx| nodes.item(idx))
+            .map(|node| node.text_content().unwrap_or_default())
+            .collect()
+    }
+
+    /// Waits for effects to run and their updates to be applied to the DOM,
+    /// then returns everything logged as an error so far.
+    pub async fn settle(&self) -> Vec<String> {
+        tick().await;
+        self.errors.borrow().clone()
+    }
+}
+
+impl Drop for Hydrated {
+    fn drop(&mut self) {
+        _ = Reflect::set(&self.console, &"error".into(), &self.original_error);
+        self.container.remove();
+    }
+}
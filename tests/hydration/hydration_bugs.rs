@@ -0,0 +1,29 @@
+use crate::harness::hydrate;
+use leptos_book::ssr::c24_hydration_bugs::{MatchedList, MismatchedList, StorageLogger};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+#[should_panic(expected = "Unrecoverable hydration error")]
+fn mismatched_list_fails_to_hydrate() {
+    // the server rendered no items, but the browser expects three
+    hydrate(include_str!("fixtures/mismatch.html"), MismatchedList);
+}
+
+#[wasm_bindgen_test]
+async fn matched_list_hydrates_then_fills_in() {
+    let hydrated = hydrate(include_str!("fixtures/mismatch_fixed.html"), MatchedList);
+    assert!(hydrated.texts("span").is_empty());
+
+    assert_eq!(hydrated.settle().await, Vec::<String>::new());
+    assert_eq!(hydrated.texts("span"), ["0", "1", "2"]);
+}
+
+#[wasm_bindgen_test]
+async fn client_only_effect_hydrates_cleanly() {
+    let hydrated = hydrate(
+        include_str!("fixtures/client_only_effect.html"),
+        StorageLogger,
+    );
+    assert_eq!(hydrated.settle().await, Vec::<String>::new());
+    assert_eq!(hydrated.texts("p"), ["Check the console!"]);
+}
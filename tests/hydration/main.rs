@@ -0,0 +1,19 @@
+//! Hydration tests for the examples in "Hydration Bugs".
+//!
+//! Each test takes the HTML that the server renders for an example (saved in
+//! `fixtures/` by the `hydration_fixtures` test in `tests/ssr`), hydrates the
+//! same component over it in a headless browser, and checks what the
+//! framework logged. They only build for `wasm32` with the `hydrate` feature:
+//!
+//! ```sh
+//! wasm-pack test --headless --firefox --features hydrate --test hydration
+//! ```
+#![cfg(all(target_arch = "wasm32", feature = "hydrate"))]
+
+use wasm_bindgen_test::wasm_bindgen_test_configure;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+mod harness;
+
+mod hydration_bugs;
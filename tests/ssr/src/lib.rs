@@ -0,0 +1,194 @@
+//! A test harness that serves the book's examples with axum.
+//!
+//! Each test builds an [`App`] around one of the example components, which
+//! registers its routes (and any server functions) with `leptos_routes`, then
+//! sends requests to it with [`App::get`] and [`App::post`], or the way a
+//! browser would with [`App::visit`] and [`App::submit`]. No sockets are
+//! involved: requests are passed directly to the axum router.
+
+use axum::{
+    body::{self, Body},
+    http::{header, HeaderMap, Method, Request, StatusCode},
+    Router,
+};
+use futures::StreamExt;
+use leptos::{config::LeptosOptions, prelude::*};
+use leptos_axum::{generate_route_list, LeptosRoutes};
+use tower::ServiceExt;
+
+/// An example app, served by axum.
+pub struct App {
+    router: Router,
+}
+
+/// The parts of an HTTP response that the tests make assertions about.
+#[derive(Debug)]
+pub struct Response {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: String,
+}
+
+impl App {
+    /// Serves the given root component, wrapped in a minimal HTML shell.
+    pub fn new<F, V>(app: F) -> Self
+    where
+        F: Fn() -> V + Clone + Send + Sync + 'static,
+        V: IntoView + 'static,
+    {
+        Self::with_shell(app.clone(), move |_| shell(app.clone()))
+    }
+
+    /// Serves the given root component in an example's own `shell`, which
+    /// renders the whole HTML document, for examples that need to put
+    /// something in its `<head>`.
+    pub fn with_shell<F, V, S, SV>(app: F, shell: S) -> Self
+    where
+        F: Fn() -> V + Clone + Send + Sync + 'static,
+        V: IntoView + 'static,
+        S: Fn(LeptosOptions) -> SV + Clone + Send + Sync + 'static,
+        SV: IntoView + 'static,
+    {
+        let options = LeptosOptions::builder().output_name("book").build();
+        let routes = generate_route_list(app);
+        let router = Router::new()
+            .leptos_routes(&options, routes, {
+                let options = options.clone();
+                move || shell(options.clone())
+            })
+            .with_state(options);
+        Self { router }
+    }
+
+    /// Serves an example's own router, for examples that show how to set one
+    /// up with `leptos_routes`.
+    pub fn from_router(router: Router) -> Self {
+        Self { router }
+    }
+
+    /// Sends a `GET` request to the given path.
+    pub async fn get(&self, path: &str) -> Response {
+        self.send(Request::get(path).body(Body::empty()).unwrap())
+            .await
+    }
+
+    /// Sends a `POST` request with a URL-encoded form body, which is how
+    /// server functions are called by default.
+    pub async fn post(&self, path: &str, form: &str) -> Response {
+        self.send(
+            Request::post(path)
+                .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+                .body(Body::from(form.to_string()))
+                .unwrap(),
+        )
+        .await
+    }
+
+    /// Requests a page the way a browser does when following a link, which
+    /// gets a real redirect rather than one for the client-side router to
+    /// follow.
+    pub async fn visit(&self, path: &str) -> Response {
+        self.send(browser_request(Method::GET, path, "")).await
+    }
+
+    /// Submits a plain HTML `<form>` (or an `<ActionForm/>` before the WASM
+    /// has loaded) the way a browser does, which expects an HTML page or a
+    /// redirect back, rather than the server function's output.
+    pub async fn submit(&self, path: &str, form: &str) -> Response {
+        self.send(browser_request(Method::POST, path, form)).await
+    }
+
+    /// Sends a `GET` request to the given path, and returns the body in the
+    /// chunks it was streamed in, for checking what arrives when.
+    pub async fn chunks(&self, path: &str) -> Vec<String> {
+        self.stream(Request::get(path).body(Body::empty()).unwrap())
+            .await
+    }
+
+    /// Sends an arbitrary request, and returns the body in the chunks it was
+    /// streamed in.
+    pub async fn stream(&self, req: Request<Body>) -> Vec<String> {
+        let res = self.router.clone().oneshot(req).await.unwrap();
+        res.into_body()
+            .into_data_stream()
+            .map(|chunk| String::from_utf8(chunk.unwrap().to_vec()).unwrap())
+            .collect()
+            .await
+    }
+
+    /// Sends an arbitrary request.
+    pub async fn send(&self, req: Request<Body>) -> Response {
+        let res = self.router.clone().oneshot(req).await.unwrap();
+        let status = res.status();
+        let headers = res.headers().clone();
+        let bytes = body::to_bytes(res.into_body(), usize::MAX).await.unwrap();
+        Response {
+            status,
+            headers,
+            body: String::from_utf8(bytes.to_vec()).unwrap(),
+        }
+    }
+}
+
+/// Builds the request that [`App::visit`] (for a `GET`) or [`App::submit`]
+/// (for a `POST`) sends, for tests that need to add headers to it, such as a
+/// cookie.
+pub fn browser_request(method: Method, path: &str, form: &str) -> Request<Body> {
+    let mut req = Request::builder()
+        .method(method.clone())
+        .uri(path)
+        .header(header::ACCEPT, "text/html");
+    if method == Method::POST {
+        req = req.header(header::CONTENT_TYPE, "application/x-www-form-urlencoded");
+    }
+    req.body(Body::from(form.to_string())).unwrap()
+}
+
+fn shell<F, V>(app: F) -> impl IntoView
+where
+    F: Fn() -> V,
+    V: IntoView + 'static,
+{
+    view! {
+        <!DOCTYPE html>
+        <html lang="en">
+            <head>
+                <meta charset="utf-8"/>
+            </head>
+            <body>{app()}</body>
+        </html>
+    }
+}
+
+impl Response {
+    /// Returns the text content of the first `<tag>` element in the body,
+    /// with all tags and hydration markers stripped out, which is easier to
+    /// make assertions about than the raw HTML.
+    ///
+    /// Panics if there is no such element.
+    pub fn text_of(&self, tag: &str) -> String {
+        let open = format!("<{tag}");
+        let close = format!("</{tag}>");
+        let start = self
+            .body
+            .find(&open)
+            .unwrap_or_else(|| panic!("no <{tag}> in {}", self.body));
+        let (_, inner) = self.body[start..].split_once('>').unwrap();
+        let inner = inner.split_once(&close).map_or(inner, |(inner, _)| inner);
+        strip_tags(inner)
+    }
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
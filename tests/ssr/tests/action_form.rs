@@ -0,0 +1,76 @@
+use axum::{
+    body::Body,
+    http::{header, HeaderValue, Method, Request, StatusCode},
+};
+use leptos::server_fn::ServerFn;
+use leptos_book::progressive_enhancement::c28_action_form::{AddTodo, App, VeryImportantFn};
+use ssr_tests::{browser_request, App as TestApp};
+
+const PAGE: &str = "http://localhost/";
+
+/// Submits the form the way a browser without JS would: a plain `POST` from
+/// the page, which expects HTML back.
+fn submit(form: &str) -> Request<Body> {
+    let mut req = browser_request(Method::POST, AddTodo::PATH, form);
+    req.headers_mut()
+        .insert(header::REFERER, HeaderValue::from_static(PAGE));
+    req
+}
+
+#[tokio::test]
+async fn the_form_posts_to_the_server_function() {
+    let res = TestApp::new(App).get("/").await;
+    assert!(
+        res.body
+            .contains(&format!(r#"action="{}" method="post""#, AddTodo::PATH)),
+        "{}",
+        res.body
+    );
+    assert!(res.body.contains(r#"<input type="text" name="title">"#));
+}
+
+#[tokio::test]
+async fn without_js_the_browser_is_sent_back_to_the_page() {
+    let app = TestApp::new(App);
+    let res = app.send(submit("title=Walk+the+dog")).await;
+    assert_eq!(res.status, StatusCode::FOUND);
+    let location = res.headers[header::LOCATION].to_str().unwrap();
+    assert!(
+        location.starts_with(PAGE) && !location.contains("__err"),
+        "{location}"
+    );
+
+    // which now lists the new todo
+    let res = app.get("/").await;
+    assert!(res.text_of("ul").contains("Walk the dog"), "{}", res.body);
+}
+
+#[tokio::test]
+async fn without_js_errors_are_sent_back_in_the_url() {
+    let app = TestApp::new(App);
+    let res = app.send(submit("title=")).await;
+    assert_eq!(res.status, StatusCode::FOUND);
+    let location = res.headers[header::LOCATION].to_str().unwrap();
+    assert!(
+        location.starts_with(&format!("{PAGE}?__path=")),
+        "{location}"
+    );
+
+    // the action picks the error up from there when the page is rendered
+    let res = app
+        .get(location.strip_prefix("http://localhost").unwrap())
+        .await;
+    assert!(
+        res.text_of("p") == "error running server function: A todo needs a title.",
+        "{}",
+        res.body
+    );
+}
+
+#[tokio::test]
+async fn nested_fields_are_named_with_brackets() {
+    let app = TestApp::new(App);
+    let form = "hefty_arg%5Bfirst_name%5D=leptos&hefty_arg%5Blast_name%5D=closures-everywhere";
+    let res = app.post(VeryImportantFn::PATH, form).await;
+    assert_eq!(res.body, r#""leptos closures-everywhere""#);
+}
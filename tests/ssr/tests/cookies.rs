@@ -0,0 +1,64 @@
+use axum::{
+    body::Body,
+    http::{header, Request},
+};
+use leptos::server_fn::ServerFn;
+use leptos_book::server::c27_cookies::{App, ClearTheme, GetTheme, SetTheme};
+use ssr_tests::App as TestApp;
+
+#[tokio::test]
+async fn cookies_are_set_with_their_attributes() {
+    let res = TestApp::new(App).post(SetTheme::PATH, "theme=dark").await;
+    assert!(res.status.is_success(), "{res:?}");
+    let cookie = res.headers[header::SET_COOKIE].to_str().unwrap();
+    assert!(cookie.starts_with("theme=dark;"), "{cookie}");
+    for attribute in [
+        "HttpOnly",
+        "Secure",
+        "SameSite=Lax",
+        "Path=/",
+        "Max-Age=31536000",
+    ] {
+        assert!(cookie.contains(attribute), "no {attribute} in {cookie}");
+    }
+}
+
+#[tokio::test]
+async fn cookies_are_removed_by_expiring_them() {
+    let res = TestApp::new(App).post(ClearTheme::PATH, "").await;
+    let cookie = res.headers[header::SET_COOKIE].to_str().unwrap();
+    assert!(cookie.starts_with("theme=;"), "{cookie}");
+    assert!(cookie.contains("Max-Age=0"), "{cookie}");
+    assert!(cookie.contains("Path=/"), "{cookie}");
+}
+
+#[tokio::test]
+async fn cookies_are_read_from_the_request() {
+    let app = TestApp::new(App);
+    let with_cookies = |cookies: &'static str| {
+        Request::post(GetTheme::PATH)
+            .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .header(header::COOKIE, cookies)
+            .body(Body::empty())
+            .unwrap()
+    };
+
+    let res = app.send(with_cookies("session=abc; theme=dark")).await;
+    assert_eq!(res.body, r#""dark""#);
+
+    let res = app.send(with_cookies("session=abc")).await;
+    assert_eq!(res.body, r#""light""#);
+}
+
+#[tokio::test]
+async fn pages_can_read_cookies_while_rendering() {
+    let res = TestApp::new(App)
+        .send(
+            Request::get("/")
+                .header(header::COOKIE, "theme=dark")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await;
+    assert!(res.body.contains(r#"data-theme="dark""#), "{res:?}");
+}
@@ -0,0 +1,86 @@
+use axum::{
+    body::Body,
+    http::{header, Request},
+};
+use leptos::server_fn::ServerFn;
+use leptos_book::server::c25_encodings::{
+    App, CountTo, Download, Notifications, SaveSettings, Settings, UploadSize,
+};
+use ssr_tests::App as TestApp;
+
+#[tokio::test]
+async fn cbor_arguments_can_be_nested() {
+    let args = SaveSettings {
+        settings: Settings {
+            theme: "dark".to_string(),
+            tags: vec!["rust".to_string(), "web".to_string()],
+            notifications: Notifications {
+                email: true,
+                push: false,
+            },
+        },
+    };
+    let mut body = Vec::new();
+    ciborium::into_writer(&args, &mut body).unwrap();
+
+    let res = TestApp::new(App)
+        .send(
+            Request::post(SaveSettings::PATH)
+                .header(header::CONTENT_TYPE, "application/cbor")
+                .body(Body::from(body))
+                .unwrap(),
+        )
+        .await;
+    assert!(res.status.is_success(), "{res:?}");
+    assert_eq!(res.body, r#""Saved 2 tags for the dark theme""#);
+}
+
+#[tokio::test]
+async fn multipart_uploads_are_read_field_by_field() {
+    let body = "--boundary\r\n\
+        Content-Disposition: form-data; name=\"file\"; filename=\"hello.txt\"\r\n\
+        Content-Type: text/plain\r\n\r\n\
+        Hello, world!\r\n\
+        --boundary--\r\n";
+
+    let res = TestApp::new(App)
+        .send(
+            Request::post(UploadSize::PATH)
+                .header(
+                    header::CONTENT_TYPE,
+                    "multipart/form-data; boundary=boundary",
+                )
+                .body(Body::from(body))
+                .unwrap(),
+        )
+        .await;
+    assert!(res.status.is_success(), "{res:?}");
+    assert_eq!(res.body, "13");
+}
+
+#[tokio::test]
+async fn text_streams_arrive_line_by_line() {
+    let chunks = TestApp::new(App)
+        .stream(
+            Request::post(CountTo::PATH)
+                .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+                .body(Body::from("to=3"))
+                .unwrap(),
+        )
+        .await;
+    assert_eq!(chunks, ["1\n", "2\n", "3\n"]);
+}
+
+#[tokio::test]
+async fn byte_streams_arrive_chunk_by_chunk() {
+    let chunks = TestApp::new(App)
+        .stream(
+            Request::post(Download::PATH)
+                .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+                .body(Body::from("size=2500"))
+                .unwrap(),
+        )
+        .await;
+    let sizes = chunks.iter().map(String::len).collect::<Vec<_>>();
+    assert_eq!(sizes, [1024, 1024, 452]);
+}
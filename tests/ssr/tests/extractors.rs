@@ -0,0 +1,78 @@
+use axum::{
+    body::Body,
+    extract::ConnectInfo,
+    http::{header, Request},
+};
+use leptos::server_fn::ServerFn;
+use leptos_book::server::{
+    c25_server_functions::App,
+    c26_extractors::{AxumExtract, ClientAddress, SecretCount, UserAgent},
+};
+use ssr_tests::App as TestApp;
+use std::net::SocketAddr;
+
+/// Starts a `POST` request to the given path, for a server function that
+/// takes no arguments.
+fn post(path: &str) -> axum::http::request::Builder {
+    Request::post(path).header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+}
+
+#[tokio::test]
+async fn extractors_read_the_method_and_query() {
+    let app = TestApp::new(App);
+    let res = app
+        .post(&format!("{}?q=leptos", AxumExtract::PATH), "")
+        .await;
+    assert_eq!(res.body, r#""POST and leptos""#);
+
+    // a failed extraction is returned as an error
+    let res = app.post(AxumExtract::PATH, "").await;
+    assert!(res.status.is_server_error(), "{res:?}");
+}
+
+#[tokio::test]
+async fn extractors_read_headers() {
+    let app = TestApp::new(App);
+    let req = post(UserAgent::PATH)
+        .header(header::USER_AGENT, "book-tests")
+        .body(Body::empty())
+        .unwrap();
+    assert_eq!(app.send(req).await.body, r#""book-tests""#);
+}
+
+#[tokio::test]
+async fn connect_info_needs_to_be_provided_by_the_server() {
+    let app = TestApp::new(App);
+
+    // there's no socket in these tests, so nothing provides `ConnectInfo`
+    let res = app.post(ClientAddress::PATH, "").await;
+    assert!(res.status.is_server_error(), "{res:?}");
+
+    // ...unless we add it to the request ourselves, the way
+    // `into_make_service_with_connect_info` would
+    let mut req = post(ClientAddress::PATH).body(Body::empty()).unwrap();
+    req.extensions_mut()
+        .insert(ConnectInfo(SocketAddr::from(([127, 0, 0, 1], 3000))));
+    assert_eq!(app.send(req).await.body, r#""127.0.0.1""#);
+}
+
+#[tokio::test]
+async fn custom_extractors_can_reject_requests() {
+    let app = TestApp::new(App);
+
+    let res = app.post(SecretCount::PATH, "").await;
+    assert!(res.status.is_server_error(), "{res:?}");
+    assert!(res.body.contains("missing API key"), "{res:?}");
+
+    let req = post(SecretCount::PATH)
+        .header("x-api-key", "hunter3")
+        .body(Body::empty())
+        .unwrap();
+    assert!(app.send(req).await.body.contains("wrong API key"));
+
+    let req = post(SecretCount::PATH)
+        .header("x-api-key", "hunter2")
+        .body(Body::empty())
+        .unwrap();
+    assert_eq!(app.send(req).await.body, "42");
+}
@@ -0,0 +1,60 @@
+use leptos_book::router::c20_form::{App, SearchApp};
+use ssr_tests::App as TestApp;
+
+/// Returns the text of every `<li>` in the body.
+fn items(body: &str) -> Vec<&str> {
+    body.split("<li>")
+        .skip(1)
+        .filter_map(|rest| rest.split_once("</li>"))
+        .map(|(item, _)| item)
+        .collect()
+}
+
+#[tokio::test]
+async fn get_forms_are_driven_by_the_query_string() {
+    let app = TestApp::new(SearchApp);
+
+    // submitting the form is a GET to the same URL with `?q=`, so the
+    // server renders the same results the client-side navigation would
+    let res = app.get("/search?q=an").await;
+    assert_eq!(items(&res.body), ["banana", "mango"]);
+    assert!(res
+        .body
+        .contains(r#"<input type="search" name="q" value="an">"#));
+
+    let res = app.get("/search").await;
+    assert_eq!(
+        items(&res.body),
+        ["apple", "banana", "cherry", "grape", "mango"]
+    );
+}
+
+#[tokio::test]
+async fn form_actions_resolve_against_the_route() {
+    let app = TestApp::new(SearchApp);
+
+    let res = app.get("/search?q=an").await;
+    assert!(res.body.contains(r#"<form method="GET" action="/search">"#));
+
+    // `replace` only changes how the client navigates
+    let res = app.get("/live?q=an").await;
+    assert!(res.body.contains(r#"<form method="GET" action="/live">"#));
+    assert_eq!(items(&res.body), ["banana", "mango"]);
+
+    // a plain <form> is left for the browser, which submits it to the
+    // current URL with a full page load
+    let res = app.get("/plain?q=an").await;
+    assert!(res.body.contains(r#"<form method="GET" action="">"#));
+    assert_eq!(items(&res.body), ["banana", "mango"]);
+}
+
+#[tokio::test]
+async fn the_sandbox_reads_every_field_from_the_query() {
+    let app = TestApp::new(App);
+    let res = app.get("/?name=Bob&number=3&select=B").await;
+    assert_eq!(res.text_of("table"), "nameBobnumber3selectB");
+    assert!(res
+        .body
+        .contains(r#"<input type="text" name="name" value="Bob">"#));
+    assert!(res.body.contains("<option selected>B</option>"));
+}
@@ -0,0 +1,44 @@
+//! Renders the examples that `tests/hydration` hydrates in the browser, and
+//! checks that the HTML saved in `tests/hydration/fixtures` is what the server
+//! renders today.
+//!
+//! If an example changes, update the fixtures with
+//!
+//! ```sh
+//! BLESS=1 cargo test -p ssr-tests --test hydration_fixtures
+//! ```
+
+use leptos::prelude::*;
+use leptos_book::ssr::c24_hydration_bugs::{MatchedList, MismatchedList, StorageLogger};
+use std::{env, fs, path::Path};
+
+fn render<V: IntoView>(view: impl FnOnce() -> V) -> String {
+    let owner = Owner::new();
+    owner.with(|| view().into_view().to_html())
+}
+
+#[test]
+fn fixtures_are_up_to_date() {
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("../hydration/fixtures");
+    let bless = env::var_os("BLESS").is_some();
+
+    let examples = [
+        ("mismatch", render(MismatchedList)),
+        ("mismatch_fixed", render(MatchedList)),
+        ("client_only_effect", render(StorageLogger)),
+    ];
+    let mut stale = Vec::new();
+    for (name, html) in examples {
+        let path = fixtures.join(format!("{name}.html"));
+        if bless {
+            fs::write(&path, html).unwrap();
+        } else if fs::read_to_string(&path).ok().as_deref() != Some(html.as_str()) {
+            stale.push(name);
+        }
+    }
+    assert!(
+        stale.is_empty(),
+        "out-of-date hydration fixtures: {}; rerun with BLESS=1 to update them",
+        stale.join(", ")
+    );
+}
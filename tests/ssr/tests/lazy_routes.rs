@@ -0,0 +1,23 @@
+use leptos_book::router::c21_lazy_routes::App;
+use ssr_tests::App as TestApp;
+
+#[tokio::test]
+async fn lazy_routes_are_rendered_on_the_server() {
+    let res = TestApp::new(App).get("/stats").await;
+    assert!(res.status.is_success(), "{res:?}");
+    assert_eq!(res.text_of("h2"), "Stats");
+    // the data and the lazy function are both loaded before the page is done
+    assert!(
+        res.body
+            .contains("<p>845 downloads, at most 340 in a day</p>"),
+        "{res:?}"
+    );
+}
+
+#[tokio::test]
+async fn other_routes_are_unaffected() {
+    let res = TestApp::new(App).get("/").await;
+    assert_eq!(res.text_of("main"), "Home");
+    // the progress bar is only shown while the router is loading a page
+    assert!(!res.body.contains("<progress"), "{res:?}");
+}
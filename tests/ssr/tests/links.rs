@@ -0,0 +1,78 @@
+use leptos_book::router::c19_a::App;
+use ssr_tests::App as TestApp;
+
+/// Returns the `href` and `aria-current` of every link in the body, and its
+/// text.
+fn links(body: &str) -> Vec<(String, Option<String>, String)> {
+    let attr = |tag: &str, name: &str| {
+        let (_, rest) = tag.split_once(&format!(" {name}=\""))?;
+        rest.split_once('"').map(|(value, _)| value.to_string())
+    };
+    body.split("<a ")
+        .skip(1)
+        .map(|rest| {
+            let (tag, rest) = rest.split_once('>').unwrap();
+            let tag = format!(" {tag}");
+            let (text, _) = rest.split_once("</a>").unwrap();
+            (
+                attr(&tag, "href").unwrap(),
+                attr(&tag, "aria-current"),
+                text.to_string(),
+            )
+        })
+        .collect()
+}
+
+fn current(links: &[(String, Option<String>, String)]) -> Vec<&str> {
+    links
+        .iter()
+        .filter(|(_, current, _)| current.as_deref() == Some("page"))
+        .map(|(_, _, text)| text.as_str())
+        .collect()
+}
+
+#[tokio::test]
+async fn links_to_the_current_page_are_marked_current() {
+    let app = TestApp::new(App);
+
+    let res = app.get("/").await;
+    assert_eq!(current(&links(&res.body)), ["Home"]);
+
+    // "Contacts" is active for any page nested inside /contacts, but the
+    // `exact` "Contact Info" tab is only active on /contacts/alice itself
+    let res = app.get("/contacts/alice").await;
+    assert_eq!(
+        current(&links(&res.body)),
+        ["Contacts", "Alice", "Contact Info"]
+    );
+    let res = app.get("/contacts/alice/conversations").await;
+    assert_eq!(
+        current(&links(&res.body)),
+        ["Contacts", "Alice", "Conversations"]
+    );
+}
+
+#[tokio::test]
+async fn relative_links_resolve_against_their_route() {
+    let app = TestApp::new(App);
+    let res = app.get("/contacts/alice").await;
+    let hrefs = links(&res.body)
+        .into_iter()
+        .map(|(href, _, _)| href)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        hrefs,
+        [
+            "/",
+            "/contacts",
+            "/sign-in",
+            "/contacts/alice",
+            "/contacts/bob",
+            "/contacts/steve",
+            "/contacts/alice",
+            "/contacts/alice/conversations",
+            // the plain <a> is left for the browser to resolve
+            "conversations",
+        ]
+    );
+}
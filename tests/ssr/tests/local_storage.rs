@@ -0,0 +1,10 @@
+use axum::http::StatusCode;
+use leptos_book::reactivity::c18_local_storage::Preferences;
+use ssr_tests::App as TestApp;
+
+#[tokio::test]
+async fn the_server_renders_the_defaults_without_storage() {
+    let res = TestApp::new(Preferences).get("/").await;
+    assert_eq!(res.status, StatusCode::OK, "{res:?}");
+    assert_eq!(res.text_of("p"), "Hello! Visits: 0");
+}
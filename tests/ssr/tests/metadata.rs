@@ -0,0 +1,38 @@
+use leptos_book::interlude::c21_metadata::{shell, App};
+use ssr_tests::App as TestApp;
+
+fn app() -> TestApp {
+    TestApp::with_shell(App, shell)
+}
+
+#[tokio::test]
+async fn metadata_is_rendered_into_the_head() {
+    let res = app().get("/").await;
+    let (head, _) = res.body.split_once("</head>").unwrap();
+    for tag in [
+        "<title>Clicked 0 times — My Awesome Site</title>",
+        r#"<meta name="description" content="Everything you need to know about metadata.">"#,
+        r#"<link id="leptos" rel="stylesheet" href="/pkg/site.css">"#,
+        r#"<link href="/favicon.ico" rel="icon">"#,
+    ] {
+        assert!(head.contains(tag), "no {tag} in {head}");
+    }
+}
+
+#[tokio::test]
+async fn titles_can_follow_the_route() {
+    let res = app().get("/posts/hello-world").await;
+    assert_eq!(res.text_of("title"), "Post: hello-world — My Awesome Site");
+    assert_eq!(res.text_of("h1"), "hello-world");
+}
+
+#[tokio::test]
+async fn html_attributes_are_set_on_the_document() {
+    let res = app().get("/he").await;
+    assert!(
+        res.body
+            .contains(r#"<html lang="he" dir="rtl" data-theme="dark""#),
+        "{}",
+        res.body
+    );
+}
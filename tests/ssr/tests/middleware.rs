@@ -0,0 +1,44 @@
+use axum::{
+    body::Body,
+    http::{header, Request, StatusCode},
+};
+use leptos::server_fn::ServerFn;
+use leptos_book::server::c25_middleware::{logged, App, Greet, Secret};
+use ssr_tests::App as TestApp;
+
+fn call_secret(key: Option<&str>) -> Request<Body> {
+    let mut req = Request::post(Secret::PATH)
+        .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded");
+    if let Some(key) = key {
+        req = req.header("x-api-key", key);
+    }
+    req.body(Body::empty()).unwrap()
+}
+
+#[tokio::test]
+async fn layers_run_before_the_server_function() {
+    let app = TestApp::new(App);
+    let res = app.post(Greet::PATH, "name=Ferris").await;
+    assert_eq!(res.body, r#""Hello, Ferris!""#);
+    assert!(logged().contains(&format!("POST {}", Greet::PATH)));
+
+    // `secret` doesn't have the logging layer
+    app.send(call_secret(Some("hunter2"))).await;
+    assert!(!logged().contains(&format!("POST {}", Secret::PATH)));
+}
+
+#[tokio::test]
+async fn layers_can_reject_requests() {
+    let app = TestApp::new(App);
+
+    let res = app.send(call_secret(None)).await;
+    assert_eq!(res.status, StatusCode::UNAUTHORIZED);
+    assert_eq!(res.body, "Missing or invalid API key");
+
+    let res = app.send(call_secret(Some("letmein"))).await;
+    assert_eq!(res.status, StatusCode::UNAUTHORIZED);
+
+    let res = app.send(call_secret(Some("hunter2"))).await;
+    assert_eq!(res.status, StatusCode::OK);
+    assert_eq!(res.body, r#""The cake is a lie.""#);
+}
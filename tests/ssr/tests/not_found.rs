@@ -0,0 +1,33 @@
+use axum::http::StatusCode;
+use leptos::config::LeptosOptions;
+use leptos_book::server::c27_not_found::router;
+use ssr_tests::App as TestApp;
+
+fn app() -> TestApp {
+    TestApp::from_router(router(LeptosOptions::builder().output_name("book").build()))
+}
+
+#[tokio::test]
+async fn paths_without_a_route_are_not_found() {
+    let app = app();
+    assert_eq!(app.get("/").await.status, StatusCode::OK);
+
+    // rendered by the app, with the `<Routes/>` fallback
+    let res = app.get("/no/such/page").await;
+    assert_eq!(res.status, StatusCode::NOT_FOUND, "{res:?}");
+    assert_eq!(res.text_of("h1"), "Not Found");
+}
+
+#[tokio::test]
+async fn missing_data_is_not_found() {
+    let app = app();
+
+    let res = app.get("/posts/1").await;
+    assert_eq!(res.status, StatusCode::OK, "{res:?}");
+    assert_eq!(res.text_of("h1"), "Hello, World!");
+
+    // the path matches a route, but the post doesn't exist
+    let res = app.get("/posts/2").await;
+    assert_eq!(res.status, StatusCode::NOT_FOUND, "{res:?}");
+    assert_eq!(res.text_of("h1"), "Not Found");
+}
@@ -0,0 +1,51 @@
+use leptos_book::router::c24_pagination::App;
+use ssr_tests::App as TestApp;
+
+/// Returns the text of every `<li>` in the body.
+fn items(body: &str) -> Vec<&str> {
+    body.split("<li>")
+        .skip(1)
+        .filter_map(|rest| rest.split_once("</li>"))
+        .map(|(item, _)| item)
+        .collect()
+}
+
+#[tokio::test]
+async fn the_server_renders_the_page_in_the_url() {
+    let app = TestApp::new(App);
+
+    let res = app.get("/articles?page=2").await;
+    assert_eq!(
+        items(&res.body),
+        [
+            "Article 6",
+            "Article 7",
+            "Article 8",
+            "Article 9",
+            "Article 10"
+        ]
+    );
+    assert_eq!(res.text_of("span"), "Page 2 of 5");
+    assert!(res.body.contains(r#"href="/articles?page=1""#));
+    assert!(res.body.contains(r#"href="/articles?page=3""#));
+
+    // the last page is short, and has no "Next" link
+    let res = app.get("/articles?page=5").await;
+    assert_eq!(items(&res.body), ["Article 21", "Article 22", "Article 23"]);
+    assert!(!res.body.contains("Next"));
+}
+
+#[tokio::test]
+async fn missing_and_invalid_pages_are_the_first() {
+    let app = TestApp::new(App);
+    for path in ["/articles", "/articles?page=0", "/articles?page=oops"] {
+        let res = app.get(path).await;
+        assert_eq!(res.text_of("span"), "Page 1 of 5", "{path}");
+        assert_eq!(items(&res.body)[0], "Article 1");
+        assert!(!res.body.contains("Previous"));
+    }
+
+    // past the end is empty, rather than an error
+    let res = app.get("/articles?page=99").await;
+    assert!(items(&res.body).is_empty());
+}
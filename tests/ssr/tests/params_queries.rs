@@ -0,0 +1,39 @@
+use leptos_book::router::c18_params_queries::App;
+use ssr_tests::App as TestApp;
+
+#[tokio::test]
+async fn typed_params_and_queries_are_parsed_from_the_url() {
+    let app = TestApp::new(App);
+
+    let res = app.get("/contacts/3?q=Foo").await;
+    assert_eq!(res.text_of("h4"), "Contact #3");
+    assert_eq!(res.text_of("main"), "Contact #3Some(3)Search: Foo");
+
+    // the query is optional, so leaving it out isn't an error
+    let res = app.get("/contacts/3").await;
+    assert_eq!(res.text_of("main").trim_end(), "Contact #3Some(3)Search:");
+}
+
+#[tokio::test]
+async fn invalid_and_missing_params_are_errors() {
+    let app = TestApp::new(App);
+
+    let res = app.get("/contacts/bob").await;
+    assert_eq!(
+        res.text_of("h4"),
+        "Invalid contact ID: failed to deserialize parameters"
+    );
+
+    let res = app.get("/search").await;
+    assert_eq!(res.text_of("p"), "Error: could not find parameter q");
+
+    let res = app.get("/search?q=Foo").await;
+    assert_eq!(res.text_of("p"), "Results for \"Foo\"");
+}
+
+#[tokio::test]
+async fn untyped_maps_hold_the_raw_strings() {
+    let app = TestApp::new(App);
+    let res = app.get("/untyped/contacts/bob?q=Foo").await;
+    assert_eq!(res.text_of("main"), "bobSearch: Foo");
+}
@@ -0,0 +1,27 @@
+use leptos_book::r#async::c10_resources::{
+    AwaitResource, LocalResourceNew, OnceResourceNew, ResourceNew,
+};
+use ssr_tests::App as TestApp;
+
+#[tokio::test]
+async fn the_server_waits_for_resources_inside_suspense() {
+    let app = TestApp::new(AwaitResource);
+    let res = app.get("/").await;
+    // the fallback is sent first, then replaced by the loaded content
+    assert_eq!(res.text_of("body"), "Loading...");
+    assert_eq!(res.text_of("template"), "Server returned 10");
+
+    let app = TestApp::new(ResourceNew);
+    assert_eq!(app.get("/").await.text_of("template"), "0");
+
+    let app = TestApp::new(OnceResourceNew);
+    assert_eq!(app.get("/").await.text_of("template"), "10");
+}
+
+#[tokio::test]
+async fn local_resources_only_load_in_the_browser() {
+    let app = TestApp::new(LocalResourceNew);
+    let res = app.get("/").await;
+    assert_eq!(res.text_of("p"), "");
+    assert!(!res.body.contains("<template"), "{}", res.body);
+}
@@ -0,0 +1,60 @@
+use axum::http::{header, StatusCode};
+use leptos::server_fn::ServerFn;
+use leptos_book::server::c27_response::{App, ExportTodos, Login, TeaAndCookies};
+use ssr_tests::App as TestApp;
+
+#[tokio::test]
+async fn server_functions_can_set_the_status_and_headers() {
+    let app = TestApp::new(App);
+    let res = app.post(TeaAndCookies::PATH, "").await;
+    assert_eq!(res.status, StatusCode::IM_A_TEAPOT);
+    assert_eq!(res.headers[header::SET_COOKIE], "biscuits=yes");
+}
+
+#[tokio::test]
+async fn redirects_are_followed_by_the_browser_or_the_client() {
+    let app = TestApp::new(App);
+    let form = "username=alice&password=hunter2";
+
+    // a plain <form> gets a real redirect...
+    let res = app.submit(Login::PATH, form).await;
+    assert_eq!(res.status, StatusCode::FOUND);
+    assert_eq!(res.headers[header::LOCATION], "/");
+
+    // ...while the server function client is told where to navigate, and
+    // does that itself
+    let res = app.post(Login::PATH, form).await;
+    assert_eq!(res.status, StatusCode::OK);
+    assert_eq!(res.headers[header::LOCATION], "/");
+    assert!(res.headers.contains_key("serverfnredirect"));
+
+    // errors don't redirect
+    let res = app
+        .submit(Login::PATH, "username=alice&password=nope")
+        .await;
+    assert!(!res.headers.contains_key("serverfnredirect"));
+    assert!(res.body.contains("Password does not match."), "{res:?}");
+}
+
+#[tokio::test]
+async fn server_functions_can_return_other_content_types() {
+    let app = TestApp::new(App);
+    let res = app.post(ExportTodos::PATH, "").await;
+    assert_eq!(res.status, StatusCode::OK);
+    assert_eq!(res.headers[header::CONTENT_TYPE], "text/csv");
+    assert_eq!(
+        res.headers[header::CONTENT_DISPOSITION],
+        r#"attachment; filename="todos.csv""#
+    );
+    assert_eq!(res.body, "id,title,completed\n0,Buy milk,false\n");
+}
+
+#[tokio::test]
+async fn components_can_set_the_status_while_rendering() {
+    let app = TestApp::new(App);
+    assert_eq!(app.get("/").await.status, StatusCode::OK);
+
+    let res = app.get("/missing").await;
+    assert_eq!(res.status, StatusCode::NOT_FOUND);
+    assert_eq!(res.text_of("h1"), "Not Found");
+}
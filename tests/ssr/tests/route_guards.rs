@@ -0,0 +1,28 @@
+use axum::http::{header, StatusCode};
+use leptos_book::router::c22_route_guards::App;
+use ssr_tests::App as TestApp;
+
+#[tokio::test]
+async fn guarded_pages_redirect_to_the_login_page() {
+    let app = TestApp::new(App);
+
+    // the wrapper component says where to come back to...
+    let res = app.visit("/dashboard").await;
+    assert_eq!(res.status, StatusCode::FOUND, "{res:?}");
+    assert_eq!(res.headers[header::LOCATION], "/login?next=/dashboard");
+
+    // ...and `<ProtectedRoute/>` just redirects
+    let res = app.visit("/settings").await;
+    assert_eq!(res.status, StatusCode::FOUND, "{res:?}");
+    assert_eq!(res.headers[header::LOCATION], "/login");
+}
+
+#[tokio::test]
+async fn other_pages_are_not_guarded() {
+    let app = TestApp::new(App);
+    for (path, heading) in [("/", "Home"), ("/login", "Log In")] {
+        let res = app.visit(path).await;
+        assert!(res.status.is_success(), "{res:?}");
+        assert_eq!(res.text_of("h2"), heading);
+    }
+}
@@ -0,0 +1,24 @@
+use leptos_book::router::c16_routes::App;
+use ssr_tests::App as TestApp;
+
+#[tokio::test]
+async fn each_path_renders_its_route() {
+    let app = TestApp::new(App);
+
+    let home = app.get("/").await;
+    assert!(home.status.is_success());
+    assert_eq!(home.text_of("main"), "Home");
+
+    let users = app.get("/users").await;
+    assert_eq!(users.text_of("main"), "Users");
+
+    let profile = app.get("/users/3").await;
+    assert_eq!(profile.text_of("main"), "User 3");
+}
+
+#[tokio::test]
+async fn unmatched_paths_fall_through_to_the_wildcard() {
+    let app = TestApp::new(App);
+    let res = app.get("/blahblah").await;
+    assert_eq!(res.text_of("main"), "Not Found");
+}
@@ -0,0 +1,59 @@
+use axum::http::StatusCode;
+use leptos::{
+    prelude::{FromServerFnError, ServerFnErrorErr},
+    server_fn::ServerFn,
+};
+use leptos_book::server::c25_errors::{App, BankError, Withdraw};
+use ssr_tests::{App as TestApp, Response};
+
+async fn withdraw(amount: &str) -> Response {
+    TestApp::new(App)
+        .post(Withdraw::PATH, &format!("amount={amount}"))
+        .await
+}
+
+/// Reads the error back from the response, the way the client does.
+fn error(res: Response) -> BankError {
+    BankError::de(res.body.into())
+}
+
+#[tokio::test]
+async fn successful_calls_return_the_value() {
+    let res = withdraw("30").await;
+    assert_eq!(res.status, StatusCode::OK);
+    assert_eq!(res.body, "70");
+}
+
+#[tokio::test]
+async fn custom_errors_set_their_own_status() {
+    let res = withdraw("500").await;
+    assert_eq!(res.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(error(res), BankError::InsufficientFunds { balance: 100 });
+
+    let res = withdraw("0").await;
+    assert_eq!(res.status, StatusCode::BAD_REQUEST);
+    assert_eq!(error(res), BankError::InvalidAmount);
+}
+
+#[tokio::test]
+async fn server_fn_errors_are_converted_into_the_custom_type() {
+    let res = withdraw("lots").await;
+    assert!(res.status.is_server_error(), "{res:?}");
+    assert!(
+        matches!(
+            error(res),
+            BankError::ServerFnError(ServerFnErrorErr::Args(_))
+        ),
+        "the arguments couldn't be deserialized"
+    );
+}
+
+#[tokio::test]
+async fn error_boundaries_render_custom_errors_with_their_status() {
+    let res = TestApp::new(App).get("/overdraw").await;
+    assert_eq!(res.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(
+        res.text_of("ul"),
+        "You only have $100. Try a smaller amount."
+    );
+}
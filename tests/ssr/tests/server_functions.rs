@@ -0,0 +1,69 @@
+use axum::{body::Body, http::Request};
+use leptos::{serde_json, server_fn::ServerFn};
+use leptos_book::server::c25_server_functions::{
+    AddTodo, App, CountTodos, GetTodos, RenameTodo, Todo, ToggleTodo,
+};
+use ssr_tests::App as TestApp;
+
+/// Adds a todo through its server function, returning its ID.
+async fn add(app: &TestApp, title: &str) -> usize {
+    let res = app.post(AddTodo::PATH, &format!("title={title}")).await;
+    assert!(res.status.is_success(), "{res:?}");
+    res.body.parse().unwrap()
+}
+
+#[tokio::test]
+async fn server_functions_are_called_with_a_post() {
+    let app = TestApp::new(App);
+    assert!(AddTodo::PATH.starts_with("/api/add_todo"));
+
+    // the arguments are URL-encoded...
+    let id = add(&app, "Buy+milk").await;
+
+    // ...and the return value is JSON
+    let res = app.post(ToggleTodo::PATH, &format!("id={id}")).await;
+    let todo = Todo {
+        id,
+        title: "Buy milk".to_string(),
+        completed: true,
+    };
+    assert_eq!(serde_json::from_str::<Todo>(&res.body).unwrap(), todo);
+
+    let res = app.post(GetTodos::PATH, "completed=true").await;
+    let todos = serde_json::from_str::<Vec<Todo>>(&res.body).unwrap();
+    assert!(todos.contains(&todo), "{todos:?}");
+    assert!(todos.iter().all(|todo| todo.completed), "{todos:?}");
+}
+
+#[tokio::test]
+async fn errors_are_sent_back_to_the_caller() {
+    let app = TestApp::new(App);
+
+    let res = app.post(ToggleTodo::PATH, "id=1000").await;
+    assert!(res.status.is_server_error(), "{res:?}");
+    assert!(res.body.contains("no todo with ID 1000"), "{res:?}");
+
+    // errors returned with `?` are converted into a `ServerFnError`
+    let res = app.post(RenameTodo::PATH, "id=first&title=Nope").await;
+    assert!(res.status.is_server_error(), "{res:?}");
+    assert!(res.body.contains("invalid digit"), "{res:?}");
+
+    // as are arguments that can't be deserialized
+    let res = app.post(ToggleTodo::PATH, "id=first").await;
+    assert!(!res.status.is_success(), "{res:?}");
+}
+
+#[tokio::test]
+async fn the_macro_sets_the_path_and_encoding() {
+    let app = TestApp::new(App);
+
+    let res = app.post("/greetings/hello", "name=Alice").await;
+    assert_eq!(res.body, r#""Hello, Alice!""#);
+
+    add(&app, "Count+me").await;
+    let res = app
+        .send(Request::get(CountTodos::PATH).body(Body::empty()).unwrap())
+        .await;
+    assert!(res.status.is_success(), "{res:?}");
+    assert!(res.body.parse::<usize>().unwrap() >= 1);
+}
@@ -0,0 +1,93 @@
+use axum::{
+    body::Body,
+    http::{header, Method, Request, StatusCode},
+};
+use leptos::{config::LeptosOptions, server_fn::ServerFn};
+use leptos_book::server::c29_sessions::{router, Login, Logout};
+use ssr_tests::{browser_request, App as TestApp, Response};
+
+fn app() -> TestApp {
+    TestApp::from_router(router(LeptosOptions::builder().output_name("book").build()))
+}
+
+/// Sends a request the way a browser would, from a plain HTML page, with the
+/// session cookie.
+fn with_cookie(method: Method, path: &str, form: &str, cookie: &str) -> Request<Body> {
+    let mut req = browser_request(method, path, form);
+    req.headers_mut()
+        .insert(header::COOKIE, cookie.parse().unwrap());
+    req
+}
+
+/// Returns the `name=value` part of the cookie the response sets.
+fn session_cookie(res: &Response) -> String {
+    let cookie = res.headers[header::SET_COOKIE].to_str().unwrap();
+    cookie.split(';').next().unwrap().to_string()
+}
+
+async fn log_in(app: &TestApp) -> String {
+    let form = "username=alice&password=hunter2";
+    let res = app.submit(Login::PATH, form).await;
+    assert_eq!(res.status, StatusCode::FOUND, "{res:?}");
+    assert_eq!(res.headers[header::LOCATION], "/account");
+    session_cookie(&res)
+}
+
+#[tokio::test]
+async fn the_account_page_redirects_without_a_session() {
+    let res = app().visit("/account").await;
+    assert_eq!(res.status, StatusCode::FOUND, "{res:?}");
+    assert_eq!(res.headers[header::LOCATION], "/");
+}
+
+#[tokio::test]
+async fn a_wrong_password_doesnt_start_a_session() {
+    let form = "username=alice&password=nope";
+    let res = app().submit(Login::PATH, form).await;
+    assert!(res.body.contains("Wrong username or password."), "{res:?}");
+    assert!(!res.headers.contains_key(header::SET_COOKIE), "{res:?}");
+}
+
+#[tokio::test]
+async fn the_session_is_kept_between_requests() {
+    let app = app();
+    let cookie = log_in(&app).await;
+    assert!(cookie.starts_with("id="), "{cookie}");
+
+    // every request with the cookie is part of the same session
+    for _ in 0..2 {
+        let res = app
+            .send(with_cookie(Method::GET, "/account", "", &cookie))
+            .await;
+        assert_eq!(res.status, StatusCode::OK, "{res:?}");
+        assert_eq!(res.text_of("p"), "Welcome back, alice!");
+    }
+
+    // but someone else's cookie isn't
+    let res = app
+        .send(with_cookie(Method::GET, "/account", "", "id=forged"))
+        .await;
+    assert_eq!(res.status, StatusCode::FOUND, "{res:?}");
+}
+
+#[tokio::test]
+async fn logging_out_ends_the_session() {
+    let app = app();
+    let cookie = log_in(&app).await;
+
+    let res = app
+        .send(with_cookie(Method::POST, Logout::PATH, "", &cookie))
+        .await;
+    assert_eq!(res.status, StatusCode::FOUND, "{res:?}");
+    assert_eq!(res.headers[header::LOCATION], "/");
+    // the browser is told to delete the cookie...
+    let removal = res.headers[header::SET_COOKIE].to_str().unwrap();
+    assert!(removal.contains("Max-Age=0"), "{removal}");
+
+    // ...and even if it's sent again, the session it belonged to is gone
+    let res = app
+        .send(with_cookie(Method::GET, "/account", "", &cookie))
+        .await;
+    assert_eq!(res.status, StatusCode::FOUND, "{res:?}");
+    assert_eq!(res.headers[header::LOCATION], "/");
+}
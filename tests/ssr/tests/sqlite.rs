@@ -0,0 +1,100 @@
+use leptos::{config::LeptosOptions, serde_json, server_fn::ServerFn};
+use leptos_book::server::c25_sqlite::{
+    connect, router, AddTodo, DeleteTodo, ListTodos, Todo, ToggleTodo,
+};
+use ssr_tests::App as TestApp;
+
+/// Serves the todo app with a new, empty database.
+async fn app() -> TestApp {
+    let pool = connect("sqlite::memory:").await.unwrap();
+    let options = LeptosOptions::builder().output_name("book").build();
+    TestApp::from_router(router(options, pool))
+}
+
+async fn todos(app: &TestApp) -> Vec<Todo> {
+    let res = app.post(ListTodos::PATH, "").await;
+    assert!(res.status.is_success(), "{res:?}");
+    serde_json::from_str(&res.body).unwrap()
+}
+
+fn todo(id: i64, title: &str, completed: bool) -> Todo {
+    Todo {
+        id,
+        title: title.to_string(),
+        completed,
+    }
+}
+
+#[tokio::test]
+async fn todos_are_added_and_listed() {
+    let app = app().await;
+    assert_eq!(todos(&app).await, []);
+
+    app.post(AddTodo::PATH, "title=Buy+milk").await;
+    app.post(AddTodo::PATH, "title=Walk+the+dog").await;
+    assert_eq!(
+        todos(&app).await,
+        [todo(1, "Buy milk", false), todo(2, "Walk the dog", false)]
+    );
+
+    // the page is rendered with them, too
+    let res = app.get("/").await;
+    assert!(res.body.contains("Buy milk"), "{res:?}");
+    assert!(res.body.contains("Walk the dog"), "{res:?}");
+}
+
+#[tokio::test]
+async fn todos_need_a_title() {
+    let app = app().await;
+    let res = app.post(AddTodo::PATH, "title=++").await;
+    assert!(res.body.contains("A todo needs a title."), "{res:?}");
+    assert_eq!(todos(&app).await, []);
+}
+
+#[tokio::test]
+async fn todos_are_toggled() {
+    let app = app().await;
+    app.post(AddTodo::PATH, "title=Buy+milk").await;
+
+    app.post(ToggleTodo::PATH, "id=1").await;
+    assert_eq!(todos(&app).await, [todo(1, "Buy milk", true)]);
+    let res = app.get("/").await;
+    assert!(res.body.contains(r#"<li class="completed">"#), "{res:?}");
+
+    app.post(ToggleTodo::PATH, "id=1").await;
+    assert_eq!(todos(&app).await, [todo(1, "Buy milk", false)]);
+}
+
+#[tokio::test]
+async fn todos_are_deleted() {
+    let app = app().await;
+    app.post(AddTodo::PATH, "title=Buy+milk").await;
+    app.post(AddTodo::PATH, "title=Walk+the+dog").await;
+
+    app.post(DeleteTodo::PATH, "id=1").await;
+    assert_eq!(todos(&app).await, [todo(2, "Walk the dog", false)]);
+}
+
+#[tokio::test]
+async fn missing_todos_are_an_error() {
+    let app = app().await;
+    for path in [ToggleTodo::PATH, DeleteTodo::PATH] {
+        let res = app.post(path, "id=42").await;
+        assert!(
+            res.body.contains("There's no todo with that ID."),
+            "{res:?}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn forms_work_without_wasm() {
+    let app = app().await;
+
+    // a plain form is sent back to the page it came from...
+    let res = app.submit(AddTodo::PATH, "title=Buy+milk").await;
+    assert!(res.status.is_redirection(), "{res:?}");
+
+    // ...which shows the change
+    assert_eq!(todos(&app).await, [todo(1, "Buy milk", false)]);
+}
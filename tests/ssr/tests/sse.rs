@@ -0,0 +1,33 @@
+use axum::{
+    body::Body,
+    http::{header, Request},
+};
+use leptos::config::LeptosOptions;
+use leptos_book::server::c28_sse::router;
+use ssr_tests::App as TestApp;
+
+fn app() -> TestApp {
+    TestApp::from_router(router(LeptosOptions::builder().output_name("book").build()))
+}
+
+#[tokio::test]
+async fn events_are_sent_one_at_a_time() {
+    let chunks = app()
+        .stream(Request::get("/countdown").body(Body::empty()).unwrap())
+        .await;
+    assert_eq!(
+        chunks,
+        [
+            "data: 3\n\n",
+            "data: 2\n\n",
+            "data: 1\n\n",
+            "event: liftoff\ndata: Liftoff!\n\n",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn the_stream_is_an_event_stream() {
+    let res = app().get("/countdown").await;
+    assert_eq!(res.headers[header::CONTENT_TYPE], "text/event-stream");
+}
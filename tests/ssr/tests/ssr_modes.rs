@@ -0,0 +1,71 @@
+use leptos::config::LeptosOptions;
+use leptos_book::ssr::c23_ssr_modes::router;
+use ssr_tests::App as TestApp;
+
+const FALLBACK: &str = "Loading the post...";
+const POST: &str = "Streaming HTML is fun.";
+const FOOTER: &str = "Thanks for reading!";
+
+fn app() -> TestApp {
+    TestApp::from_router(router(LeptosOptions::builder().output_name("book").build()))
+}
+
+/// Returns the index of the first chunk that contains `text`.
+fn chunk_with(chunks: &[String], text: &str) -> Option<usize> {
+    chunks.iter().position(|chunk| chunk.contains(text))
+}
+
+#[tokio::test]
+async fn out_of_order_sends_the_fallback_with_the_rest_of_the_page() {
+    let chunks = app().chunks("/out-of-order").await;
+    let footer = chunk_with(&chunks, FOOTER).unwrap();
+    // the whole page arrives at once, with the fallback in place of the post...
+    assert_eq!(chunk_with(&chunks, FALLBACK), Some(footer));
+    // ...which is streamed in a later chunk, to be swapped in
+    assert!(chunk_with(&chunks, POST).unwrap() > footer, "{chunks:#?}");
+}
+
+#[tokio::test]
+async fn in_order_sends_the_page_up_to_the_suspense_first() {
+    let chunks = app().chunks("/in-order").await;
+    let heading = chunk_with(&chunks, "My Blog").unwrap();
+    let post = chunk_with(&chunks, POST).unwrap();
+    // the page is sent in order: everything before the `<Suspense/>`...
+    assert!(heading < post, "{chunks:#?}");
+    // ...then the post, as soon as it loads, followed by the rest
+    assert!(chunk_with(&chunks, FOOTER).unwrap() >= post);
+    // the fallback is never sent at all
+    assert_eq!(chunk_with(&chunks, FALLBACK), None);
+}
+
+#[tokio::test]
+async fn async_waits_to_send_the_whole_page_at_once() {
+    let chunks = app().chunks("/async").await;
+    let heading = chunk_with(&chunks, "My Blog").unwrap();
+    assert_eq!(chunk_with(&chunks, POST), Some(heading), "{chunks:#?}");
+    assert_eq!(chunk_with(&chunks, FOOTER), Some(heading));
+    assert_eq!(chunk_with(&chunks, FALLBACK), None);
+}
+
+#[tokio::test]
+async fn blocking_resources_are_in_the_first_chunk() {
+    let chunks = app().chunks("/post").await;
+    // the title and description are in the `<head>`, for crawlers and link
+    // previews that don't run any JS...
+    let (head, body) = chunks[0].split_once("</head>").unwrap();
+    assert!(head.contains("<title>Blocking Resources</title>"), "{head}");
+    assert!(
+        head.contains(r#"<meta name="description" content="Waiting for the data that matters.">"#),
+        "{head}"
+    );
+    // ...and so is the post itself, rather than being streamed in later
+    assert!(
+        body.contains("<article>Some data is worth waiting for.</article>"),
+        "{body}"
+    );
+    assert!(!body.contains("<template"), "{body}");
+
+    // the comments don't block anything, so they're still streamed
+    assert!(body.contains("Loading comments..."), "{body}");
+    assert!(chunk_with(&chunks, "Great post!").unwrap() > 0);
+}
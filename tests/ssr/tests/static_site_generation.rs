@@ -0,0 +1,53 @@
+use leptos::config::LeptosOptions;
+use leptos_book::ssr::c25_static_site_generation::router;
+use ssr_tests::App as TestApp;
+use std::{fs, path::PathBuf};
+
+/// Generates the site into a new directory of its own, so that each test
+/// starts without any pages on disk.
+async fn generate(name: &str) -> (TestApp, PathBuf) {
+    let site_root = std::env::temp_dir().join("leptos-book-ssg").join(name);
+    _ = fs::remove_dir_all(&site_root);
+    let options = LeptosOptions::builder()
+        .output_name("book")
+        .site_root(site_root.to_str().unwrap())
+        .build();
+    (TestApp::from_router(router(options).await), site_root)
+}
+
+#[tokio::test]
+async fn every_static_route_is_written_to_disk() {
+    let (_, site_root) = generate("written").await;
+
+    let home = fs::read_to_string(site_root.join("index.html")).unwrap();
+    assert!(home.starts_with("<!DOCTYPE html>"), "{home}");
+    assert!(home.contains("<h1>My Blog</h1>"), "{home}");
+    assert!(
+        home.contains(r#"<a href="/posts/hello-world">Hello, World!</a>"#),
+        "{home}"
+    );
+
+    // one file for each slug from `prerender_params`
+    let post = fs::read_to_string(site_root.join("posts/hello-world.html")).unwrap();
+    assert!(post.contains("<h1>Hello, World!</h1>"), "{post}");
+    assert!(
+        post.contains("<article>This is the first post.</article>"),
+        "{post}"
+    );
+    let post = fs::read_to_string(site_root.join("posts/static-pages.html")).unwrap();
+    assert!(post.contains("<h1>Static Pages</h1>"), "{post}");
+}
+
+#[tokio::test]
+async fn static_pages_are_served_from_disk() {
+    let (app, site_root) = generate("served").await;
+
+    // if the file changes, so does the page, because it isn't rendered again
+    let path = site_root.join("posts/hello-world.html");
+    let html = fs::read_to_string(&path).unwrap();
+    fs::write(&path, html.replace("the first post", "an edited post")).unwrap();
+
+    let res = app.get("/posts/hello-world").await;
+    assert!(res.status.is_success(), "{res:?}");
+    assert_eq!(res.text_of("article"), "This is an edited post.");
+}
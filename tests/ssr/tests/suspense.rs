@@ -0,0 +1,24 @@
+use leptos_book::r#async::c11_suspense::{NestedSuspense, SuspendBoth};
+use ssr_tests::App as TestApp;
+use std::time::{Duration, Instant};
+
+#[tokio::test]
+async fn the_outer_suspense_does_not_wait_for_the_inner_one() {
+    let res = TestApp::new(NestedSuspense).get("/").await;
+    assert_eq!(res.text_of("body"), "Loading A...");
+    // the outer content arrives with the inner fallback, and the inner
+    // content arrives in a chunk of its own
+    assert_eq!(res.text_of("template"), "A: 10Loading B...");
+    assert!(res
+        .body
+        .contains(r#"<template id="1-1-f"><p>B: <!>100</p>"#));
+}
+
+#[tokio::test]
+async fn awaiting_two_resources_waits_for_the_slower_one() {
+    let app = TestApp::new(SuspendBoth);
+    let start = Instant::now();
+    let res = app.get("/").await;
+    assert_eq!(res.text_of("template"), "A: 10B: 100");
+    assert!(start.elapsed() < Duration::from_millis(2_900));
+}
@@ -0,0 +1,43 @@
+use axum::http::{header, StatusCode};
+use leptos_book::router::c16_trailing_slashes::App;
+use ssr_tests::{App as TestApp, Response};
+
+/// Requests a page the way a browser does, so that redirects are real ones.
+async fn visit(path: &str) -> Response {
+    TestApp::new(App).visit(path).await
+}
+
+async fn assert_renders(path: &str, text: &str) {
+    let res = visit(path).await;
+    assert_eq!(res.status, StatusCode::OK, "{path}: {res:?}");
+    assert_eq!(res.text_of("main"), text, "{path}");
+}
+
+async fn assert_not_found(path: &str) {
+    let res = visit(path).await;
+    assert_eq!(res.status, StatusCode::NOT_FOUND, "{path}: {res:?}");
+}
+
+#[tokio::test]
+async fn paths_match_exactly_as_written() {
+    assert_renders("/about", "About").await;
+    assert_not_found("/about/").await;
+
+    assert_renders("/docs/", "Docs").await;
+    assert_not_found("/docs").await;
+}
+
+#[tokio::test]
+async fn a_route_can_redirect_to_the_other_form() {
+    assert_renders("/blog", "Blog").await;
+
+    let res = visit("/blog/").await;
+    assert_eq!(res.status, StatusCode::FOUND, "{res:?}");
+    assert_eq!(res.headers[header::LOCATION], "/blog");
+}
+
+#[tokio::test]
+async fn both_forms_can_match() {
+    assert_renders("/contact", "Contact").await;
+    assert_renders("/contact/", "Contact").await;
+}
@@ -0,0 +1,93 @@
+use axum::{
+    body::Body,
+    http::{header, Request},
+};
+use leptos::server_fn::ServerFn;
+use leptos_book::server::c25_uploads::{upload_dir, FileUpload, UploadFile, UploadProgress};
+use ssr_tests::App as TestApp;
+
+/// A `multipart/form-data` request with one file in it, like a browser sends
+/// for a form with an `<input type="file">`.
+fn upload(file_name: &str, contents: &str) -> Request<Body> {
+    let body = format!(
+        "--boundary\r\n\
+        Content-Disposition: form-data; name=\"file\"; filename=\"{file_name}\"\r\n\
+        Content-Type: text/plain\r\n\r\n\
+        {contents}\r\n\
+        --boundary--\r\n"
+    );
+    Request::post(UploadFile::PATH)
+        .header(
+            header::CONTENT_TYPE,
+            "multipart/form-data; boundary=boundary",
+        )
+        .body(Body::from(body))
+        .unwrap()
+}
+
+fn progress(file_name: &str) -> Request<Body> {
+    Request::post(UploadProgress::PATH)
+        .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(format!("name={file_name}")))
+        .unwrap()
+}
+
+#[tokio::test]
+async fn uploads_are_saved_to_disk() {
+    let res = TestApp::new(FileUpload)
+        .send(upload("notes.txt", "Remember the milk."))
+        .await;
+    assert!(res.status.is_success(), "{res:?}");
+    assert_eq!(res.body, "18");
+
+    let saved = std::fs::read_to_string(upload_dir().join("notes.txt")).unwrap();
+    assert_eq!(saved, "Remember the milk.");
+}
+
+#[tokio::test]
+async fn file_names_cant_leave_the_upload_directory() {
+    let res = TestApp::new(FileUpload)
+        .send(upload("../../escaped.txt", "Nice try."))
+        .await;
+    assert!(res.status.is_success(), "{res:?}");
+
+    let saved = std::fs::read_to_string(upload_dir().join("escaped.txt")).unwrap();
+    assert_eq!(saved, "Nice try.");
+}
+
+#[tokio::test]
+async fn progress_is_streamed_while_uploading() {
+    let app = TestApp::new(FileUpload);
+    let contents = "a".repeat(100_000);
+
+    let (lines, res) = tokio::join!(
+        app.stream(progress("big.txt")),
+        app.send(upload("big.txt", &contents)),
+    );
+    assert_eq!(res.body, "100000");
+
+    let bytes: Vec<usize> = lines
+        .concat()
+        .lines()
+        .map(|line| line.parse().unwrap())
+        .collect();
+    // the progress only goes up, and ends when the whole file is saved
+    assert!(bytes.is_sorted(), "{bytes:?}");
+    assert_eq!(bytes.last(), Some(&100_000));
+}
+
+#[tokio::test]
+async fn uploading_the_same_name_again_follows_the_new_upload() {
+    let app = TestApp::new(FileUpload);
+    let res = app.send(upload("twice.txt", "First.")).await;
+    assert_eq!(res.body, "6");
+
+    // asked for before the second upload starts, the progress isn't left
+    // over from the first one, which would end it straight away
+    let (lines, res) = tokio::join!(
+        app.stream(progress("twice.txt")),
+        app.send(upload("twice.txt", "The second time.")),
+    );
+    assert_eq!(res.body, "16");
+    assert_eq!(lines.concat().lines().last(), Some("16"));
+}
@@ -0,0 +1,44 @@
+use futures::{SinkExt, StreamExt};
+use leptos::config::LeptosOptions;
+use leptos_book::server::c28_websockets::router;
+use ssr_tests::App as TestApp;
+use tokio::net::TcpListener;
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+
+fn options() -> LeptosOptions {
+    LeptosOptions::builder().output_name("book").build()
+}
+
+/// Serves the chat on a free local port, returning the WebSocket's URL.
+async fn serve() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, router(options())).await.unwrap() });
+    format!("ws://{addr}/ws")
+}
+
+#[tokio::test]
+async fn messages_are_sent_to_everyone_in_the_room() {
+    let url = serve().await;
+    let (mut alice, _) = connect_async(&url).await.unwrap();
+    let (mut bob, _) = connect_async(&url).await.unwrap();
+
+    alice.send(Message::text("Hi, Bob!")).await.unwrap();
+    // the sender gets its own message back, as well as everyone else
+    for socket in [&mut alice, &mut bob] {
+        let msg = socket.next().await.unwrap().unwrap();
+        assert_eq!(msg.into_text().unwrap(), "Hi, Bob!");
+    }
+
+    bob.send(Message::text("Hi, Alice!")).await.unwrap();
+    let msg = alice.next().await.unwrap().unwrap();
+    assert_eq!(msg.into_text().unwrap(), "Hi, Alice!");
+}
+
+#[tokio::test]
+async fn the_page_is_served_next_to_the_socket() {
+    let app = TestApp::from_router(router(options()));
+    let res = app.get("/").await;
+    assert!(res.status.is_success(), "{res:?}");
+    assert!(res.body.contains(r#"<ul class="messages">"#), "{res:?}");
+}
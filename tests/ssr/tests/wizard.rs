@@ -0,0 +1,26 @@
+use axum::http::{header, StatusCode};
+use leptos_book::router::c25_wizard::App;
+use ssr_tests::App as TestApp;
+
+#[tokio::test]
+async fn the_form_starts_at_the_first_step() {
+    let app = TestApp::new(App);
+
+    let res = app.visit("/signup").await;
+    assert_eq!(res.status, StatusCode::FOUND, "{res:?}");
+    assert_eq!(res.headers[header::LOCATION], "/signup/account");
+
+    let res = app.visit("/signup/account").await;
+    assert!(res.status.is_success(), "{res:?}");
+    assert_eq!(res.text_of("h3"), "Account");
+}
+
+#[tokio::test]
+async fn later_steps_redirect_until_the_earlier_ones_are_done() {
+    let app = TestApp::new(App);
+    for step in ["profile", "confirm"] {
+        let res = app.visit(&format!("/signup/{step}")).await;
+        assert_eq!(res.status, StatusCode::FOUND, "{res:?}");
+        assert_eq!(res.headers[header::LOCATION], "/signup/account");
+    }
+}
@@ -0,0 +1,270 @@
+//! Checks the book's fences against the list of known failures.
+//!
+//! All of the fences are checked as one crate, but the compiler doesn't
+//! report some errors (like borrow-checking errors) until a crate's other
+//! errors are fixed. So the fences that fail are taken out, and the rest are
+//! checked again, until none of them fail. Otherwise fixing one fence could
+//! make others that have nothing to do with it start failing.
+
+use crate::{fences, write_crate, Fence};
+use mdbook_anchors::files_with_extension;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// Checks every fence in the book at `root` (the directory with the book's
+/// `Cargo.toml` and `src`), printing a summary and any problems.
+///
+/// Returns an error if a fence fails that isn't listed in
+/// `tools/extract-fences/known-failures.txt`, or if one that is listed no
+/// longer fails. With `bless`, rewrites the list to match instead.
+pub fn run(root: &Path, bless: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let src_dir = root.join("src");
+    let known_failures_path = root.join("tools/extract-fences/known-failures.txt");
+
+    let mut all = Vec::new();
+    for chapter in files_with_extension(&src_dir, "md")? {
+        let markdown = fs::read_to_string(&chapter)?;
+        all.extend(fences(chapter.strip_prefix(&src_dir)?, &markdown));
+    }
+
+    let crate_dir = root.join("target/fences");
+    let errors = check_until_clean(&all, |fences| {
+        let written = write_crate(&crate_dir, &root.join("Cargo.toml"), fences)?;
+        check(&crate_dir, &root.join("target"), &written)
+    })?;
+
+    // a fence fails if it isn't valid Rust, or if the compiler rejects it
+    let mut failures = BTreeMap::new();
+    for fence in &all {
+        let reason = if fence.module_source().is_none() {
+            Some("not valid Rust".to_string())
+        } else {
+            errors.get(&(fence.chapter.clone(), fence.line)).cloned()
+        };
+        if let Some(reason) = reason {
+            failures.insert(key(fence), (fence, reason));
+        }
+    }
+
+    if bless {
+        let mut list = String::from(
+            "# Fences that are known not to compile. Maintained by `cargo run -p extract-fences -- --bless`.\n",
+        );
+        for ((chapter, hash), (fence, _)) in &failures {
+            let first_line = fence
+                .code
+                .lines()
+                .find(|line| !line.trim().is_empty())
+                .unwrap_or_default();
+            list.push_str(&format!("{chapter} {hash} # {}\n", first_line.trim()));
+        }
+        fs::write(&known_failures_path, list)?;
+        println!(
+            "{} fences checked, {} known failures",
+            all.len(),
+            failures.len()
+        );
+        return Ok(());
+    }
+
+    let known = fs::read_to_string(&known_failures_path)?
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            match (parts.next(), parts.next()) {
+                (Some(chapter), Some(hash)) if !chapter.starts_with('#') => {
+                    Some((chapter.to_string(), hash.to_string()))
+                }
+                _ => None,
+            }
+        })
+        .collect::<BTreeSet<_>>();
+
+    let new_failures = failures
+        .iter()
+        .filter(|(key, _)| !known.contains(*key))
+        .collect::<Vec<_>>();
+    let compiling = all.iter().map(key).collect::<BTreeSet<_>>();
+    let fixed = known
+        .iter()
+        .filter(|key| !failures.contains_key(*key))
+        .collect::<Vec<_>>();
+
+    for (_, (fence, reason)) in &new_failures {
+        eprintln!("{}:{}: {reason}", fence.chapter.display(), fence.line);
+    }
+    for (chapter, hash) in &fixed {
+        if compiling.contains(&(chapter.clone(), hash.clone())) {
+            eprintln!(
+                "{chapter}: fence {hash} compiles now, and can be removed from known-failures.txt"
+            );
+        } else {
+            eprintln!("{chapter}: fence {hash} no longer exists, and can be removed from known-failures.txt");
+        }
+    }
+    println!(
+        "{} fences checked: {} known failures, {} new failures, {} fixed",
+        all.len(),
+        failures.len() - new_failures.len(),
+        new_failures.len(),
+        fixed.len()
+    );
+    if new_failures.is_empty() && fixed.is_empty() {
+        Ok(())
+    } else {
+        Err("fence check failed (run with --bless to accept the current state)".into())
+    }
+}
+
+fn key(fence: &Fence) -> (String, String) {
+    (
+        fence.chapter.to_string_lossy().replace('\\', "/"),
+        fence.hash(),
+    )
+}
+
+/// Checks `fences` with `check`, then checks the ones that didn't fail again,
+/// until none of them fail, so that each fence's errors are found whether or
+/// not the others have any. Returns the first error for each fence, by
+/// chapter and line.
+fn check_until_clean(
+    fences: &[Fence],
+    mut check: impl FnMut(&[Fence]) -> Result<Errors, Box<dyn std::error::Error>>,
+) -> Result<Errors, Box<dyn std::error::Error>> {
+    let mut errors = Errors::new();
+    let mut remaining = fences.to_vec();
+    loop {
+        let found = check(&remaining)?;
+        if found.is_empty() {
+            return Ok(errors);
+        }
+        remaining.retain(|fence| !found.contains_key(&(fence.chapter.clone(), fence.line)));
+        errors.extend(found);
+    }
+}
+
+/// The first error for each fence, by chapter and line.
+type Errors = BTreeMap<(PathBuf, usize), String>;
+
+/// Checks the synthetic crate, and returns the first error for each fence, by
+/// chapter and line.
+fn check(
+    crate_dir: &Path,
+    target_dir: &Path,
+    written: &[(&Fence, PathBuf, usize)],
+) -> Result<Errors, Box<dyn std::error::Error>> {
+    let output = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".into()))
+        .args(["check", "--quiet", "--message-format=json"])
+        .current_dir(crate_dir)
+        .env("CARGO_TARGET_DIR", target_dir)
+        .output()?;
+    let errors = fence_errors(crate_dir, written, &output.stdout);
+
+    // cargo can also fail before it gets to the fences, for example if it
+    // can't resolve the dependencies, and that mustn't look like every fence
+    // compiling
+    if !output.status.success() && errors.is_empty() {
+        return Err(format!(
+            "cargo check failed without an error in any fence:\n{}",
+            String::from_utf8_lossy(&output.stderr).trim_end()
+        )
+        .into());
+    }
+    Ok(errors)
+}
+
+/// The first error for each fence in cargo's JSON messages.
+fn fence_errors(crate_dir: &Path, written: &[(&Fence, PathBuf, usize)], stdout: &[u8]) -> Errors {
+    let mut errors = Errors::new();
+    for line in String::from_utf8_lossy(stdout).lines() {
+        let Ok(message) = serde_json::from_str::<serde_json::Value>(line) else {
+            continue;
+        };
+        let message = &message["message"];
+        if message["level"] != "error" {
+            continue;
+        }
+        let Some(span) = message["spans"]
+            .as_array()
+            .and_then(|spans| spans.iter().find(|span| span["is_primary"] == true))
+        else {
+            continue;
+        };
+        let file = crate_dir.join(span["file_name"].as_str().unwrap_or_default());
+        let Some((fence, _, start)) = written.iter().find(|(_, path, _)| *path == file) else {
+            continue;
+        };
+        let line = span["line_start"].as_u64().unwrap_or_default() as usize;
+        let markdown_line = fence.line + 1 + line.saturating_sub(*start);
+        errors
+            .entry((fence.chapter.clone(), fence.line))
+            .or_insert_with(|| {
+                format!(
+                    "line {markdown_line}: {}",
+                    message["message"].as_str().unwrap_or_default()
+                )
+            });
+    }
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_errors_that_other_errors_hide() {
+        let fence = |line: usize| Fence {
+            chapter: "view/example.md".into(),
+            line,
+            code: format!("fn fence_{line}() {{}}"),
+        };
+        let fences = [fence(1), fence(5), fence(9)];
+        // like the compiler, only reports the error in the fence on line 5
+        // once the one on line 1 has been fixed
+        let mut rounds = 0;
+        let errors = check_until_clean(&fences, |fences| {
+            rounds += 1;
+            let lines = fences.iter().map(|fence| fence.line).collect::<Vec<_>>();
+            let failing = if lines.contains(&1) { 1 } else { 5 };
+            Ok(lines
+                .into_iter()
+                .filter(|line| *line == failing)
+                .map(|line| (("view/example.md".into(), line), "error".to_string()))
+                .collect())
+        })
+        .unwrap();
+        assert_eq!(
+            errors.keys().map(|(_, line)| *line).collect::<Vec<_>>(),
+            [1, 5]
+        );
+        assert_eq!(rounds, 3);
+    }
+
+    #[test]
+    fn cargo_failing_outside_the_fences_is_an_error() {
+        let dir = env::temp_dir().join(format!("extract-fences-{}", std::process::id()));
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(dir.join("src/lib.rs"), "").unwrap();
+        // a dependency that doesn't exist, so cargo fails before it checks anything
+        fs::write(
+            dir.join("Cargo.toml"),
+            "[package]\nname = \"broken\"\nversion = \"0.0.0\"\nedition = \"2021\"\n\
+             [workspace]\n[dependencies]\nmissing = { path = \"missing\" }\n",
+        )
+        .unwrap();
+
+        let result = check(&dir, &dir.join("target"), &[]);
+        fs::remove_dir_all(&dir).unwrap();
+        let error = result.unwrap_err().to_string();
+        assert!(
+            error.contains("failed without an error in any fence"),
+            "{error}"
+        );
+        assert!(error.contains("missing"), "{error}");
+    }
+}
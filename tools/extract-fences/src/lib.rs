@@ -0,0 +1,283 @@
+//! Extracts the Rust code blocks written directly in the book's markdown, so
+//! that they can be compile-checked like the included example code.
+//!
+//! Most of the book's code is included from compiled modules with
+//! `{{#include}}`, but some chapters still have code pasted directly into
+//! fences. This crate pulls out each of those fences and wraps it in a module
+//! of a synthetic crate that depends on the same versions of Leptos as the
+//! book (see [`write_crate`]). [`check::run`] checks that crate, and compares
+//! the fences that fail against a list of known failures.
+
+pub mod check;
+
+use pulldown_cmark::{CodeBlockKind, Event, Parser, Tag};
+use std::{
+    fmt::Write as _,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// A ```` ```rust ```` code block written directly in a chapter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fence {
+    /// The chapter, relative to the book's source directory.
+    pub chapter: PathBuf,
+    /// The line of the chapter on which the opening fence appears, from 1.
+    pub line: usize,
+    /// The code inside the fence, with mdbook's hidden-line markers removed.
+    pub code: String,
+}
+
+/// How a fence's code is placed in the synthetic crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shape {
+    /// The code is a list of items, like a Rust source file.
+    Items,
+    /// The code is a list of statements, possibly ending in an expression,
+    /// which are wrapped in a function.
+    Statements,
+    /// The code isn't valid Rust at all (for example, a fragment of a `view!`).
+    Invalid,
+}
+
+/// Finds the fences in a chapter that should be compile-checked.
+///
+/// This skips fences that aren't Rust, that include their code from
+/// elsewhere, or that are marked `ignore`, `compile_fail`, or `compile_error`.
+pub fn fences(chapter: &Path, markdown: &str) -> Vec<Fence> {
+    let mut fences = Vec::new();
+    let mut current: Option<Fence> = None;
+    for (event, range) in Parser::new(markdown).into_offset_iter() {
+        match event {
+            Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(info))) if is_checked(&info) => {
+                current = Some(Fence {
+                    chapter: chapter.to_path_buf(),
+                    line: markdown[..range.start].matches('\n').count() + 1,
+                    code: String::new(),
+                });
+            }
+            Event::Text(text) => {
+                if let Some(fence) = &mut current {
+                    fence.code.push_str(&text);
+                }
+            }
+            Event::End(Tag::CodeBlock(_)) => {
+                if let Some(mut fence) = current.take() {
+                    if !fence.code.contains("{{#include")
+                        && !fence.code.contains("{{#rustdoc_include")
+                    {
+                        fence.code = unhide_lines(&fence.code);
+                        fences.push(fence);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    fences
+}
+
+fn is_checked(info: &str) -> bool {
+    let mut attrs = info.split(|c: char| c == ',' || c.is_whitespace());
+    attrs.next() == Some("rust")
+        && !attrs.any(|attr| matches!(attr, "ignore" | "compile_fail" | "compile_error"))
+}
+
+// mdbook hides lines that begin with `# `, but still treats them as part of the code
+fn unhide_lines(code: &str) -> String {
+    code.lines()
+        .map(|line| {
+            let trimmed = line.trim_start();
+            match trimmed.strip_prefix("# ") {
+                Some(rest) if !rest.starts_with('[') => rest.to_string(),
+                _ if trimmed == "#" => String::new(),
+                _ => line.to_string(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl Fence {
+    /// A short, stable identifier for the fence's code, which survives the
+    /// fence moving around within its chapter.
+    pub fn hash(&self) -> String {
+        // FNV-1a, which unlike `DefaultHasher` is stable between releases
+        let mut hash: u64 = 0xcbf29ce484222325;
+        for byte in self.code.trim().bytes() {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+        format!("{hash:016x}")
+    }
+
+    /// How the fence's code should be placed in the synthetic crate.
+    pub fn shape(&self) -> Shape {
+        if syn::parse_file(&self.code).is_ok() {
+            Shape::Items
+        } else if syn::parse_str::<syn::Block>(&format!("{{\n{}\n}}", self.code)).is_ok() {
+            Shape::Statements
+        } else {
+            Shape::Invalid
+        }
+    }
+
+    /// The name of the fence's module in the synthetic crate.
+    pub fn module_name(&self) -> String {
+        let chapter = self
+            .chapter
+            .with_extension("")
+            .to_string_lossy()
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_lowercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect::<String>();
+        format!("{chapter}_line_{}", self.line)
+    }
+
+    /// The source of the fence's module, and the line of that source on
+    /// which the fence's code begins, from 1.
+    ///
+    /// Returns `None` if the fence isn't valid Rust.
+    pub fn module_source(&self) -> Option<(String, usize)> {
+        let mut source =
+            String::from("#![allow(unused, non_snake_case)]\nuse leptos::prelude::*;\n\n");
+        match self.shape() {
+            Shape::Items => {
+                let start = source.lines().count() + 1;
+                source.push_str(&self.code);
+                Some((source, start))
+            }
+            Shape::Statements => {
+                source.push_str("fn fence() -> impl Sized {\n");
+                let start = source.lines().count() + 1;
+                source.push_str(&self.code);
+                source.push_str("\n}\n");
+                Some((source, start))
+            }
+            Shape::Invalid => None,
+        }
+    }
+}
+
+/// Writes a crate to `dir` with one module for each fence that is valid Rust.
+///
+/// The crate takes its `[dependencies]` from the book's manifest at `manifest`,
+/// so that the fences are checked against the same versions of Leptos as the
+/// rest of the book's code. Returns the fences that were written, with the
+/// path of each module's source file and the line on which its code starts.
+pub fn write_crate<'a>(
+    dir: &Path,
+    manifest: &Path,
+    fences: &'a [Fence],
+) -> io::Result<Vec<(&'a Fence, PathBuf, usize)>> {
+    let book: toml::Table = fs::read_to_string(manifest)?
+        .parse()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let mut crate_manifest = toml::Table::new();
+    crate_manifest.insert(
+        "package".into(),
+        toml::Value::Table(toml::toml! {
+            name = "book-fences"
+            version = "0.0.0"
+            edition = "2021"
+            publish = false
+        }),
+    );
+    // keep the crate out of the book's workspace
+    crate_manifest.insert("workspace".into(), toml::Value::Table(Default::default()));
+    if let Some(dependencies) = book.get("dependencies") {
+        crate_manifest.insert("dependencies".into(), dependencies.clone());
+    }
+
+    let src = dir.join("src");
+    if src.exists() {
+        fs::remove_dir_all(&src)?;
+    }
+    fs::create_dir_all(&src)?;
+    fs::write(dir.join("Cargo.toml"), crate_manifest.to_string())?;
+
+    let mut lib = String::new();
+    let mut written = Vec::new();
+    for fence in fences {
+        if let Some((source, start)) = fence.module_source() {
+            let name = fence.module_name();
+            let path = src.join(format!("{name}.rs"));
+            fs::write(&path, source)?;
+            _ = writeln!(lib, "mod {name};");
+            written.push((fence, path, start));
+        }
+    }
+    fs::write(src.join("lib.rs"), lib)?;
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skips_included_ignored_and_non_rust_fences() {
+        let markdown = "\
+# Chapter
+
+```rust
+let (count, set_count) = signal(0);
+```
+
+```rust
+{{#include c01_basic_component.rs:counter}}
+```
+
+```rust,ignore
+<For each=data key=|row| row.key let:child/>
+```
+
+```sh
+cargo leptos watch
+```
+
+> ```rust
+> # use leptos::prelude::*;
+> fn quoted() {}
+> ```
+";
+        let fences = fences(Path::new("view/example.md"), markdown);
+        assert_eq!(
+            fences,
+            [
+                Fence {
+                    chapter: "view/example.md".into(),
+                    line: 3,
+                    code: "let (count, set_count) = signal(0);".into(),
+                },
+                Fence {
+                    chapter: "view/example.md".into(),
+                    line: 19,
+                    code: "use leptos::prelude::*;\nfn quoted() {}".into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn wraps_statements_in_a_function() {
+        let fence = |code: &str| Fence {
+            chapter: "view/example.md".into(),
+            line: 1,
+            code: code.into(),
+        };
+        assert_eq!(fence("fn main() {}").shape(), Shape::Items);
+        assert_eq!(fence("let x = 1;\nx + 1").shape(), Shape::Statements);
+        assert_eq!(fence("<p>{value}</p>").shape(), Shape::Invalid);
+
+        let (source, start) = fence("let x = 1;").module_source().unwrap();
+        assert_eq!(source.lines().nth(start - 1), Some("let x = 1;"));
+        assert!(source.contains("fn fence() -> impl Sized {\nlet x = 1;\n}"));
+    }
+}
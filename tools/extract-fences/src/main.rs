@@ -0,0 +1,23 @@
+//! Compile-checks the Rust fences written directly in the book's markdown.
+//!
+//! ```sh
+//! cargo run -p extract-fences [-- --bless]
+//! ```
+//!
+//! Many of the older fences don't compile against the current version of
+//! Leptos, either because they're written against an old API or because
+//! they're fragments that were never meant to compile on their own. Those are
+//! listed in `known-failures.txt`. This fails if a fence that isn't on that
+//! list fails to compile, or if one that is on the list now compiles, so that
+//! the list only ever shrinks. `--bless` rewrites the list to match.
+
+use std::{env, path::Path, process};
+
+fn main() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+    let bless = env::args().any(|arg| arg == "--bless");
+    if let Err(e) = extract_fences::check::run(&root, bless) {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+}
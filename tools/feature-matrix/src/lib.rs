@@ -0,0 +1,173 @@
+//! The rendering modes that the book's example code is compiled in.
+//!
+//! Leptos apps are built differently depending on how they're rendered: a
+//! client-side-rendered app is compiled to WASM with the `csr` feature, a
+//! server-rendered app is compiled once for the server with `ssr` and once for
+//! the browser with `hydrate`, and so on. Snippets that compile in one mode
+//! can fail in another, so the example crate is checked in each [`Mode`] that
+//! readers will actually use.
+//!
+//! Modules that only make sense in some modes are gated on the corresponding
+//! feature, like `#[cfg(feature = "islands")]`. The same goes for the server
+//! integrations: code that uses Axum or Actix is gated on the `axum` or
+//! `actix` feature, and `ssr` on its own is framework-agnostic.
+//!
+//! Snippets that are written differently on nightly Rust have a second
+//! variant, with the same name plus a `_nightly` suffix on its anchor, gated
+//! on the `nightly` feature. The `nightly` mode compiles those instead of the
+//! stable ones.
+
+use std::{
+    env,
+    ffi::OsString,
+    path::Path,
+    process::{Command, ExitStatus},
+};
+
+const WASM: &str = "wasm32-unknown-unknown";
+
+/// One configuration of an example crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mode {
+    /// The name used to select this mode on the command line.
+    pub name: &'static str,
+    /// The crate compiled in this mode.
+    pub package: &'static str,
+    /// The features of the crate enabled in this mode.
+    pub features: &'static [&'static str],
+    /// The target this mode is compiled for, if not the host.
+    pub target: Option<&'static str>,
+    /// The toolchain this mode is compiled with, if not the default one.
+    pub toolchain: Option<&'static str>,
+}
+
+/// Every mode the example crate is compiled in.
+pub const MODES: &[Mode] = &[
+    // none of the crate's features, which is how a plain `cargo test -p
+    // leptos-book` builds it: the server functions' bodies are still compiled
+    // for the tests, so anything they use has to be too
+    Mode {
+        name: "default",
+        package: "leptos-book",
+        features: &[],
+        target: None,
+        toolchain: None,
+    },
+    Mode {
+        name: "csr",
+        package: "leptos-book",
+        features: &["csr", "graphql", "grpc", "split"],
+        target: Some(WASM),
+        toolchain: None,
+    },
+    // the server examples use axum, unless they're the Actix variants, and
+    // the todo app also needs its database
+    Mode {
+        name: "ssr",
+        package: "leptos-book",
+        features: &["ssr", "axum", "graphql", "grpc", "split", "sqlite"],
+        target: None,
+        toolchain: None,
+    },
+    Mode {
+        name: "actix",
+        package: "leptos-book",
+        features: &["ssr", "actix"],
+        target: None,
+        toolchain: None,
+    },
+    Mode {
+        name: "hydrate",
+        package: "leptos-book",
+        features: &["hydrate", "graphql", "grpc", "split"],
+        target: Some(WASM),
+        toolchain: None,
+    },
+    Mode {
+        name: "islands-ssr",
+        package: "leptos-book",
+        features: &["ssr", "islands"],
+        target: None,
+        toolchain: None,
+    },
+    Mode {
+        name: "islands-hydrate",
+        package: "leptos-book",
+        features: &["hydrate", "islands"],
+        target: Some(WASM),
+        toolchain: None,
+    },
+    // snippets with a `_nightly` variant are compiled in this mode instead
+    Mode {
+        name: "nightly",
+        package: "leptos-book",
+        features: &["ssr", "nightly"],
+        target: None,
+        toolchain: Some("nightly"),
+    },
+    // the Tailwind example in "Interlude: Styling" is a crate of its own,
+    // built by Trunk with `csr`, or by cargo-leptos with `ssr` and `hydrate`
+    Mode {
+        name: "tailwind-csr",
+        package: "tailwind-example",
+        features: &["csr"],
+        target: Some(WASM),
+        toolchain: None,
+    },
+    Mode {
+        name: "tailwind-ssr",
+        package: "tailwind-example",
+        features: &["ssr"],
+        target: None,
+        toolchain: None,
+    },
+    Mode {
+        name: "tailwind-hydrate",
+        package: "tailwind-example",
+        features: &["hydrate"],
+        target: Some(WASM),
+        toolchain: None,
+    },
+];
+
+impl Mode {
+    /// Finds a mode by name.
+    pub fn named(name: &str) -> Option<Mode> {
+        MODES.iter().copied().find(|mode| mode.name == name)
+    }
+
+    /// The `cargo` invocation for this mode.
+    ///
+    /// Modes that run on the host run the crate's tests. WASM modes compile
+    /// the library and the DOM tests, which need a browser to actually run.
+    pub fn command(&self, workspace: &Path) -> Command {
+        let mut cmd = match self.toolchain {
+            // `+toolchain` is handled by the rustup proxy, not by cargo itself
+            Some(toolchain) => {
+                let mut cmd = Command::new("cargo");
+                cmd.arg(format!("+{toolchain}"));
+                cmd
+            }
+            None => Command::new(env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"))),
+        };
+        cmd.current_dir(workspace);
+        match self.target {
+            None => {
+                cmd.arg("test");
+            }
+            Some(target) => {
+                cmd.args(["check", "--tests", "--target", target]);
+            }
+        }
+        cmd.args(["-p", self.package, "--no-default-features"]);
+        if !self.features.is_empty() {
+            cmd.arg("--features").arg(self.features.join(","));
+        }
+        cmd
+    }
+
+    /// Compiles (and if possible, tests) the example crate in this mode.
+    pub fn run(&self, workspace: &Path) -> std::io::Result<ExitStatus> {
+        self.command(workspace).status()
+    }
+}
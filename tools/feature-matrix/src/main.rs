@@ -0,0 +1,51 @@
+//! Compiles the book's example code in each rendering mode.
+//!
+//! ```sh
+//! cargo run -p feature-matrix [-- MODE...]
+//! ```
+//!
+//! With no arguments, every mode is checked. The WASM modes need the
+//! `wasm32-unknown-unknown` target to be installed.
+
+use feature_matrix::{Mode, MODES};
+use std::{env, path::Path, process};
+
+fn main() {
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+    let names = env::args().skip(1).collect::<Vec<_>>();
+    let modes = if names.is_empty() {
+        MODES.to_vec()
+    } else {
+        names
+            .iter()
+            .map(|name| {
+                Mode::named(name).unwrap_or_else(|| {
+                    let known = MODES.iter().map(|mode| mode.name).collect::<Vec<_>>();
+                    eprintln!(
+                        "unknown mode `{name}`; expected one of {}",
+                        known.join(", ")
+                    );
+                    process::exit(2);
+                })
+            })
+            .collect()
+    };
+
+    let mut failed = Vec::new();
+    for mode in &modes {
+        eprintln!("==> {} ({})", mode.name, mode.features.join(", "));
+        match mode.run(&workspace) {
+            Ok(status) if status.success() => {}
+            Ok(_) => failed.push(mode.name),
+            Err(e) => {
+                eprintln!("failed to run cargo: {e}");
+                failed.push(mode.name);
+            }
+        }
+    }
+
+    if !failed.is_empty() {
+        eprintln!("failed in: {}", failed.join(", "));
+        process::exit(1);
+    }
+}
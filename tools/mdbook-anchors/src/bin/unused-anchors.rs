@@ -0,0 +1,35 @@
+//! Lists the anchors in the example code that no chapter includes.
+//!
+//! ```sh
+//! cargo run -p mdbook-anchors --bin unused-anchors [BOOK_ROOT]
+//! ```
+//!
+//! Checks the `.rs` files in the book's `src`, `tests` and `examples`
+//! directories, and exits with an error if it finds any unused anchors.
+
+use mdbook_anchors::unused_anchors;
+use std::{env, path::PathBuf, process};
+
+fn main() {
+    let root = env::args()
+        .nth(1)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("."));
+    let src_dir = root.join("src");
+    let source_dirs = [root.join("src"), root.join("tests"), root.join("examples")];
+
+    match unused_anchors(&src_dir, &source_dirs) {
+        Ok(unused) if unused.is_empty() => {}
+        Ok(unused) => {
+            for anchor in &unused {
+                eprintln!("{anchor}");
+            }
+            eprintln!("found {} unused anchor(s)", unused.len());
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    }
+}
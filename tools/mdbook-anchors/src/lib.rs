@@ -0,0 +1,332 @@
+//! Checks that the book's `{{#include}}` directives point at something.
+//!
+//! mdbook resolves `{{#include file.rs:anchor}}` by copying the lines between
+//! `ANCHOR: anchor` and `ANCHOR_END: anchor`. If the file exists but the
+//! anchor doesn't, it quietly includes nothing at all, which means that
+//! renaming an anchor in the example code leaves an empty code block in the
+//! published book. This crate finds those directives, so that the build can
+//! fail instead.
+//!
+//! It also works in the other direction, finding anchors in the example code
+//! that no chapter ever includes (see [`unused_anchors`]).
+
+use regex::Regex;
+use std::{
+    collections::HashSet,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    sync::LazyLock,
+};
+
+// these match the patterns mdbook itself uses for links and anchors
+static INCLUDE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?x)\\\{\{\#.*\}\}|\{\{\s*\#(?:include|rustdoc_include)\s+([^}]+)\}\}").unwrap()
+});
+static ANCHOR_START: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"ANCHOR:\s*(?P<anchor_name>[\w_-]+)").unwrap());
+static ANCHOR_END: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"ANCHOR_END:\s*(?P<anchor_name>[\w_-]+)").unwrap());
+
+/// An `{{#include}}` or `{{#rustdoc_include}}` directive in a chapter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Include {
+    /// The path to the included file, relative to the chapter.
+    pub path: PathBuf,
+    /// The anchor, if the directive selects one rather than a line range.
+    pub anchor: Option<String>,
+    /// The line of the chapter on which the directive appears, from 1.
+    pub line: usize,
+}
+
+/// Finds every include directive in a chapter's markdown.
+///
+/// Escaped directives (`\{{#include ...}}`) are skipped, as they are by mdbook.
+pub fn includes(markdown: &str) -> Vec<Include> {
+    markdown
+        .lines()
+        .enumerate()
+        .flat_map(|(idx, line)| {
+            INCLUDE.captures_iter(line).filter_map(move |cap| {
+                let args = cap.get(1)?.as_str().trim();
+                let (path, selector) = match args.split_once(':') {
+                    Some((path, selector)) => (path, Some(selector)),
+                    None => (args, None),
+                };
+                // `file.rs:10:20` and `file.rs::20` are line ranges, not anchors
+                let anchor = selector
+                    .map(|selector| selector.split(':').next().unwrap_or_default())
+                    .filter(|selector| !selector.is_empty() && selector.parse::<usize>().is_err())
+                    .map(str::to_string);
+                Some(Include {
+                    path: PathBuf::from(path.trim()),
+                    anchor,
+                    line: idx + 1,
+                })
+            })
+        })
+        .collect()
+}
+
+/// An anchor defined in a source file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Anchor {
+    /// The name of the anchor.
+    pub name: String,
+    /// The line on which `ANCHOR:` appears, from 1.
+    pub line: usize,
+    /// Whether there is a matching `ANCHOR_END:` after it.
+    pub closed: bool,
+}
+
+/// Finds every anchor defined in a source file.
+pub fn anchors(source: &str) -> Vec<Anchor> {
+    let mut anchors: Vec<Anchor> = Vec::new();
+    for (idx, line) in source.lines().enumerate() {
+        if let Some(cap) = ANCHOR_END.captures(line) {
+            let name = &cap["anchor_name"];
+            if let Some(anchor) = anchors
+                .iter_mut()
+                .rev()
+                .find(|anchor| anchor.name == name && !anchor.closed)
+            {
+                anchor.closed = true;
+            }
+        } else if let Some(cap) = ANCHOR_START.captures(line) {
+            anchors.push(Anchor {
+                name: cap["anchor_name"].to_string(),
+                line: idx + 1,
+                closed: false,
+            });
+        }
+    }
+    anchors
+}
+
+/// Something wrong with an include directive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Problem {
+    /// The chapter that contains the directive.
+    pub chapter: PathBuf,
+    /// The directive itself.
+    pub include: Include,
+    /// What is wrong with it.
+    pub kind: ProblemKind,
+}
+
+/// The ways in which an include directive can be broken.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProblemKind {
+    /// The included file doesn't exist.
+    MissingFile,
+    /// The file exists, but doesn't define the anchor.
+    MissingAnchor,
+    /// The file defines the anchor, but never ends it, so mdbook would
+    /// include the rest of the file.
+    UnclosedAnchor,
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Problem {
+            chapter,
+            include,
+            kind,
+        } = self;
+        write!(f, "{}:{}: ", chapter.display(), include.line)?;
+        let anchor = include.anchor.as_deref().unwrap_or_default();
+        match kind {
+            ProblemKind::MissingFile => {
+                write!(f, "included file {} does not exist", include.path.display())
+            }
+            ProblemKind::MissingAnchor => write!(
+                f,
+                "{} has no anchor named `{anchor}`",
+                include.path.display()
+            ),
+            ProblemKind::UnclosedAnchor => write!(
+                f,
+                "anchor `{anchor}` in {} has no ANCHOR_END",
+                include.path.display()
+            ),
+        }
+    }
+}
+
+/// Checks every include directive in a chapter.
+///
+/// `chapter` is the path of the chapter's markdown file, relative to `src_dir`,
+/// the book's source directory.
+pub fn check_chapter(src_dir: &Path, chapter: &Path, markdown: &str) -> Vec<Problem> {
+    let chapter_dir = chapter.parent().unwrap_or(Path::new(""));
+    includes(markdown)
+        .into_iter()
+        .filter_map(|include| {
+            let kind = check_include(&src_dir.join(chapter_dir), &include)?;
+            Some(Problem {
+                chapter: chapter.to_path_buf(),
+                include,
+                kind,
+            })
+        })
+        .collect()
+}
+
+fn check_include(dir: &Path, include: &Include) -> Option<ProblemKind> {
+    let Ok(source) = fs::read_to_string(dir.join(&include.path)) else {
+        return Some(ProblemKind::MissingFile);
+    };
+    let name = include.anchor.as_deref()?;
+    match anchors(&source)
+        .into_iter()
+        .find(|anchor| anchor.name == name)
+    {
+        None => Some(ProblemKind::MissingAnchor),
+        Some(anchor) if !anchor.closed => Some(ProblemKind::UnclosedAnchor),
+        Some(_) => None,
+    }
+}
+
+/// An anchor that no chapter includes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnusedAnchor {
+    /// The file that defines the anchor.
+    pub file: PathBuf,
+    /// The anchor itself.
+    pub anchor: Anchor,
+}
+
+impl fmt::Display for UnusedAnchor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}: anchor `{}` is never included",
+            self.file.display(),
+            self.anchor.line,
+            self.anchor.name
+        )
+    }
+}
+
+/// Finds the anchors in the `.rs` files under `source_dirs` that are not
+/// included by any chapter in `src_dir`, the book's source directory.
+pub fn unused_anchors(src_dir: &Path, source_dirs: &[PathBuf]) -> io::Result<Vec<UnusedAnchor>> {
+    let mut included = HashSet::new();
+    for chapter in files_with_extension(src_dir, "md")? {
+        let chapter_dir = chapter.parent().unwrap_or(src_dir);
+        for include in includes(&fs::read_to_string(&chapter)?) {
+            if let (Some(anchor), Ok(file)) = (
+                include.anchor,
+                fs::canonicalize(chapter_dir.join(&include.path)),
+            ) {
+                included.insert((file, anchor));
+            }
+        }
+    }
+
+    let mut unused = Vec::new();
+    for dir in source_dirs {
+        for file in files_with_extension(dir, "rs")? {
+            let canonical = fs::canonicalize(&file)?;
+            for anchor in anchors(&fs::read_to_string(&file)?) {
+                if !included.contains(&(canonical.clone(), anchor.name.clone())) {
+                    unused.push(UnusedAnchor {
+                        file: file.clone(),
+                        anchor,
+                    });
+                }
+            }
+        }
+    }
+    Ok(unused)
+}
+
+/// Returns every file in `dir` or its subdirectories with the given extension,
+/// in a consistent order.
+pub fn files_with_extension(dir: &Path, extension: &str) -> io::Result<Vec<PathBuf>> {
+    let mut found = Vec::new();
+    let mut dirs = vec![dir.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else if path.extension().is_some_and(|ext| ext == extension) {
+                found.push(path);
+            }
+        }
+    }
+    found.sort();
+    Ok(found)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_includes_with_and_without_anchors() {
+        let markdown = "\
+```rust
+{{#include c05_complex_iteration.rs:stores}}
+```
+
+{{#include ../tests/compile_fail/missing_move.stderr}}
+{{#rustdoc_include main.rs:3:10}}
+\\{{#include escaped.rs:anchor}}
+";
+        assert_eq!(
+            includes(markdown),
+            [
+                Include {
+                    path: "c05_complex_iteration.rs".into(),
+                    anchor: Some("stores".into()),
+                    line: 2,
+                },
+                Include {
+                    path: "../tests/compile_fail/missing_move.stderr".into(),
+                    anchor: None,
+                    line: 5,
+                },
+                Include {
+                    path: "main.rs".into(),
+                    anchor: None,
+                    line: 6,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn finds_nested_and_unclosed_anchors() {
+        let source = "\
+// ANCHOR: outer
+fn outer() {
+    // ANCHOR: inner
+    inner();
+    // ANCHOR_END: inner
+}
+// ANCHOR_END: outer
+// ANCHOR: unclosed
+";
+        assert_eq!(
+            anchors(source),
+            [
+                Anchor {
+                    name: "outer".into(),
+                    line: 1,
+                    closed: true,
+                },
+                Anchor {
+                    name: "inner".into(),
+                    line: 3,
+                    closed: true,
+                },
+                Anchor {
+                    name: "unclosed".into(),
+                    line: 8,
+                    closed: false,
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,66 @@
+//! The `anchors` mdbook preprocessor.
+//!
+//! This runs before mdbook's own `links` preprocessor, and fails the build if
+//! any chapter includes a file or an anchor that doesn't exist. It doesn't
+//! change the book at all.
+
+use mdbook::{
+    book::Book,
+    errors::{Error, Result},
+    preprocess::{CmdPreprocessor, Preprocessor, PreprocessorContext},
+    BookItem,
+};
+use mdbook_anchors::check_chapter;
+use std::{io, process};
+
+struct CheckAnchors;
+
+impl Preprocessor for CheckAnchors {
+    fn name(&self) -> &str {
+        "anchors"
+    }
+
+    fn run(&self, ctx: &PreprocessorContext, book: Book) -> Result<Book> {
+        let src_dir = ctx.root.join(&ctx.config.book.src);
+        let mut problems = Vec::new();
+        for item in book.iter() {
+            if let BookItem::Chapter(chapter) = item {
+                if let Some(path) = &chapter.source_path {
+                    problems.extend(check_chapter(&src_dir, path, &chapter.content));
+                }
+            }
+        }
+        if problems.is_empty() {
+            Ok(book)
+        } else {
+            for problem in &problems {
+                eprintln!("{problem}");
+            }
+            Err(Error::msg(format!(
+                "found {} broken include(s)",
+                problems.len()
+            )))
+        }
+    }
+}
+
+fn main() {
+    let preprocessor = CheckAnchors;
+
+    // the check doesn't depend on the output format
+    if std::env::args().nth(1).as_deref() == Some("supports") {
+        return;
+    }
+
+    if let Err(e) = run(&preprocessor) {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+}
+
+fn run(preprocessor: &dyn Preprocessor) -> Result<()> {
+    let (ctx, book) = CmdPreprocessor::parse_input(io::stdin())?;
+    let book = preprocessor.run(&ctx, book)?;
+    serde_json::to_writer(io::stdout(), &book)?;
+    Ok(())
+}
@@ -0,0 +1,62 @@
+//! Checks the anchors and includes in the book itself, so that mistakes fail
+//! `cargo test` as well as `mdbook build`.
+
+use mdbook_anchors::{check_chapter, files_with_extension, unused_anchors};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+fn book_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../..")
+}
+
+#[test]
+fn every_include_resolves() {
+    let src_dir = book_root().join("src");
+    let problems = files_with_extension(&src_dir, "md")
+        .unwrap()
+        .iter()
+        .flat_map(|path| {
+            let markdown = fs::read_to_string(path).unwrap();
+            check_chapter(&src_dir, path.strip_prefix(&src_dir).unwrap(), &markdown)
+        })
+        .map(|problem| problem.to_string())
+        .collect::<Vec<_>>();
+    assert!(problems.is_empty(), "{}", problems.join("\n"));
+}
+
+#[test]
+fn reports_missing_anchors_and_files() {
+    let src_dir = book_root().join("src");
+    let markdown = "\
+{{#include c05_complex_iteration.rs:stores}}
+{{#include c05_complex_iteration.rs:option_5}}
+{{#include c99_missing.rs:stores}}
+";
+    let problems = check_chapter(&src_dir, Path::new("view/04b_iteration.md"), markdown)
+        .iter()
+        .map(|problem| problem.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        problems,
+        [
+            "view/04b_iteration.md:2: c05_complex_iteration.rs has no anchor named `option_5`",
+            "view/04b_iteration.md:3: included file c99_missing.rs does not exist",
+        ]
+    );
+}
+
+#[test]
+fn every_anchor_is_included() {
+    let root = book_root();
+    let unused = unused_anchors(
+        &root.join("src"),
+        &[root.join("src"), root.join("tests"), root.join("examples")],
+    )
+    .unwrap()
+    .iter()
+    .map(|anchor| anchor.to_string())
+    .collect::<Vec<_>>();
+    assert!(unused.is_empty(), "{}", unused.join("\n"));
+}
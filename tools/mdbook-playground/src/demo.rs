@@ -0,0 +1,118 @@
+//! Live demos of the example code, embedded in the book.
+//!
+//! A chapter asks for a demo with `{{#demo c01_basic_component.rs:counter}}`,
+//! which [`embed_demos`] replaces with an `<iframe>`. The page in the iframe
+//! is the same example that the snippet's "Run this example" button opens,
+//! compiled to WebAssembly by `cargo xtask demos` and written to
+//! [`demo_dir`] in the built book.
+
+use crate::example;
+use regex::Regex;
+use std::{
+    io,
+    path::{Path, PathBuf},
+    sync::LazyLock,
+};
+
+static DEMO: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\\\{\{\#.*\}\}|\{\{\s*\#demo\s+([^}]+)\}\}").unwrap());
+
+/// A `{{#demo}}` directive in a chapter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Demo {
+    /// The path to the example module, relative to the chapter.
+    pub path: PathBuf,
+    /// The anchor of the snippet to demo; the demo mounts its component.
+    pub anchor: String,
+    /// The line of the chapter on which the directive appears, from 1.
+    pub line: usize,
+}
+
+/// Finds every demo directive in a chapter's markdown.
+pub fn demos(markdown: &str) -> Vec<Demo> {
+    markdown
+        .lines()
+        .enumerate()
+        .flat_map(|(idx, line)| {
+            DEMO.captures_iter(line).filter_map(move |cap| {
+                let (path, anchor) = cap.get(1)?.as_str().trim().split_once(':')?;
+                Some(Demo {
+                    path: PathBuf::from(path.trim()),
+                    anchor: anchor.trim().to_string(),
+                    line: idx + 1,
+                })
+            })
+        })
+        .collect()
+}
+
+/// Where a demo is written, relative to the built book.
+///
+/// Demos live alongside the chapter's page, so the counter demo in
+/// `view/01_basic_component.md` is in `view/demos/01_basic_component/counter`.
+pub fn demo_dir(chapter: &Path, anchor: &str) -> PathBuf {
+    let stem = chapter.file_stem().unwrap_or_default();
+    chapter
+        .parent()
+        .unwrap_or(Path::new(""))
+        .join("demos")
+        .join(stem)
+        .join(anchor)
+}
+
+/// Replaces each demo directive in a chapter with an iframe showing the demo.
+///
+/// This fails if the directive's anchor doesn't have a component to mount.
+pub fn embed_demos(
+    src_dir: &Path,
+    chapter: &Path,
+    markdown: &str,
+    manifest: &toml::Table,
+) -> io::Result<String> {
+    let chapter_dir = chapter.parent().unwrap_or(Path::new(""));
+    let mut problems = Vec::new();
+    let markdown = DEMO.replace_all(markdown, |cap: &regex::Captures| {
+        let Some(args) = cap.get(1) else {
+            // an escaped directive, which mdbook's `links` preprocessor unescapes
+            return cap[0].to_string();
+        };
+        let directive = demos(&cap[0]).pop();
+        let component = directive.as_ref().and_then(|demo| {
+            let file = src_dir.join(chapter_dir).join(&demo.path);
+            match example(&file, &demo.anchor, manifest) {
+                Ok(example) => example.map(|example| example.component),
+                Err(e) => {
+                    problems.push(format!("{}: {e}", args.as_str().trim()));
+                    None
+                }
+            }
+        });
+        match (directive, component) {
+            (Some(demo), Some(component)) => {
+                let dir = demo_dir(chapter, &demo.anchor);
+                let src = dir.strip_prefix(chapter_dir).unwrap_or(&dir);
+                format!(
+                    "<iframe class=\"demo\" src=\"{}/index.html\" title=\"A live demo of {component}\" \
+                     width=\"100%\" height=\"300px\"></iframe>",
+                    src.display()
+                )
+            }
+            _ => {
+                problems.push(format!(
+                    "`{}` doesn't name a snippet with a component to demo",
+                    args.as_str().trim()
+                ));
+                String::new()
+            }
+        }
+    });
+
+    if problems.is_empty() {
+        Ok(markdown.into_owned())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            problems.join("\n"),
+        ))
+    }
+}
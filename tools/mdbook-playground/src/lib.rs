@@ -0,0 +1,834 @@
+//! Turns the snippets included into the book into runnable examples.
+//!
+//! A chapter usually includes just one component from an example module, with
+//! `{{#include c05_complex_iteration.rs:change_key}}`. That snippet won't run
+//! on its own, but the module it comes from will: it has the imports, the
+//! helper types, and the component itself, and all that's missing is a `main`
+//! to mount it. [`example`] assembles that program, and [`add_run_buttons`]
+//! puts a "Run this example" button after each snippet that opens it in a new
+//! CodeSandbox.
+//!
+//! Each chapter with a runnable snippet can also be downloaded as a starter
+//! project, which `cargo xtask build-book` writes next to the chapter's page
+//! (see [`Example::write_starter`]). The chapters about the server, whose
+//! snippets can't run in the browser alone, get a `cargo leptos` project
+//! instead (see [`server_example`]). A few are also embedded in the book as
+//! live demos (see [`demo`]).
+//!
+//! The Rust playground would be the obvious place to send these, but it only
+//! has the most popular crates from crates.io, which doesn't include Leptos.
+
+pub mod demo;
+
+use mdbook_anchors::{anchors, includes};
+use pulldown_cmark::{CodeBlockKind, Event, Parser, Tag};
+use serde_json::json;
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+use syn::{spanned::Spanned, Item, ItemFn};
+use zip::{write::SimpleFileOptions, ZipWriter};
+
+/// Where the button sends the example. This creates a new sandbox from the
+/// files in the form, and opens it.
+pub const DEFINE_URL: &str = "https://codesandbox.io/api/v1/sandboxes/define";
+
+/// A runnable example: the files of a Trunk project that mounts a component,
+/// or of a `cargo leptos` project that renders it on the server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    /// The component that `main` mounts, or that the server renders.
+    pub component: String,
+    /// The project's files, keyed by their path within the project.
+    pub files: BTreeMap<String, String>,
+    /// Whether this is a `cargo leptos` project, rather than a Trunk one.
+    pub server: bool,
+}
+
+impl Example {
+    /// The example's `src/main.rs`.
+    pub fn main_rs(&self) -> &str {
+        &self.files["src/main.rs"]
+    }
+
+    /// The command that runs the example, from the project's directory.
+    pub fn run_command(&self) -> &'static str {
+        if self.server {
+            "cargo leptos watch"
+        } else {
+            "trunk serve --open"
+        }
+    }
+
+    /// The files, compressed in the form expected by CodeSandbox's define API.
+    pub fn parameters(&self) -> String {
+        let files = self
+            .files
+            .iter()
+            .map(|(path, content)| (path.clone(), json!({ "content": content })))
+            .collect::<serde_json::Map<_, _>>();
+        let parameters = json!({ "files": files }).to_string();
+        // the same URL-safe variant of base64 as codesandbox's own `getParameters`
+        lz_str::compress_to_base64(parameters.as_str())
+            .replace('+', "-")
+            .replace('/', "_")
+            .trim_end_matches('=')
+            .to_string()
+    }
+
+    /// Writes the example's files into `dir`.
+    pub fn write_to(&self, dir: &Path) -> io::Result<()> {
+        for (file, content) in &self.files {
+            let path = dir.join(file);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(path, content)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the example as a zip of a standalone project, in a directory
+    /// called `name`.
+    ///
+    /// This leaves out the CodeSandbox configuration, and adds a README that
+    /// explains how to run it.
+    pub fn write_starter(&self, path: &Path, name: &str) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut zip = ZipWriter::new(fs::File::create(path)?);
+        let options = SimpleFileOptions::default();
+        let readme = if self.server {
+            format!(
+                "# {name}\n\n\
+                 The example from this chapter of the Leptos book, rendering `{}` on \
+                 the server and hydrating it in the browser.\n\n\
+                 Run it with [cargo-leptos](https://github.com/leptos-rs/cargo-leptos), \
+                 then open <http://127.0.0.1:3000>:\n\n\
+                 ```sh\n\
+                 cargo install cargo-leptos --locked\n\
+                 {}\n\
+                 ```\n",
+                self.component,
+                self.run_command()
+            )
+        } else {
+            format!(
+                "# {name}\n\n\
+                 The example from this chapter of the Leptos book, mounting `{}`.\n\n\
+                 Run it with [Trunk](https://trunkrs.dev):\n\n\
+                 ```sh\n\
+                 cargo install trunk\n\
+                 {}\n\
+                 ```\n",
+                self.component,
+                self.run_command()
+            )
+        };
+        let files = self
+            .files
+            .iter()
+            .filter(|(path, _)| !path.starts_with(".codesandbox/"))
+            .map(|(path, content)| (path.as_str(), content.as_str()))
+            .chain([("README.md", readme.as_str())]);
+        for (file, content) in files {
+            zip.start_file(format!("{name}/{file}"), options)?;
+            zip.write_all(content.as_bytes())?;
+        }
+        zip.finish()?;
+        Ok(())
+    }
+
+    /// An HTML form with a button that opens the example in a new tab.
+    pub fn button(&self) -> String {
+        format!(
+            "<form class=\"run-example\" action=\"{DEFINE_URL}\" method=\"POST\" target=\"_blank\">\n\
+             <input type=\"hidden\" name=\"parameters\" value=\"{}\">\n\
+             <button type=\"submit\" title=\"Open {} in CodeSandbox\">Run this example</button>\n\
+             </form>",
+            self.parameters(),
+            self.component
+        )
+    }
+}
+
+/// Assembles the runnable example for a snippet that includes `anchor` from
+/// `file`.
+///
+/// The example mounts the component that contains the anchor, or the first
+/// component inside it. Returns `None` if there isn't one, for snippets that
+/// only include imports or types. `manifest` is the book's `Cargo.toml`, whose
+/// dependencies the example shares.
+pub fn example(file: &Path, anchor: &str, manifest: &toml::Table) -> io::Result<Option<Example>> {
+    let source = fs::read_to_string(file)?;
+    let parsed = syn::parse_file(&source).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: {e}", file.display()),
+        )
+    })?;
+
+    let Some(start) = anchors(&source)
+        .into_iter()
+        .find(|a| a.name == anchor)
+        .map(|a| a.line)
+    else {
+        return Ok(None);
+    };
+    let end = anchor_end(&source, anchor, start);
+
+    let components = parsed
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Fn(f) if f.attrs.iter().any(|attr| attr.path().is_ident("component")) => Some(f),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let Some(entry) = components
+        .iter()
+        .find(|f| lines(f).contains(&start))
+        .or_else(|| {
+            components
+                .iter()
+                .find(|f| (start..=end).contains(&f.span().start().line))
+        })
+    else {
+        return Ok(None);
+    };
+    let component = entry.sig.ident.to_string();
+    let nightly = entry.attrs.iter().any(is_nightly_cfg);
+
+    let mut main_rs = inline_sleep(&module_source(&source, &parsed.items));
+    let has_main = parsed
+        .items
+        .iter()
+        .any(|item| matches!(item, Item::Fn(f) if f.sig.ident == "main"));
+    if !has_main {
+        main_rs.push_str(&format!(
+            "\nfn main() {{\n    leptos::mount::mount_to_body({component});\n}}\n"
+        ));
+    }
+
+    let mut files = BTreeMap::new();
+    files.insert("src/main.rs".into(), main_rs);
+    files.insert("Cargo.toml".into(), example_manifest(manifest, nightly));
+    files.insert("rust-toolchain.toml".into(), toolchain(nightly));
+    files.insert(
+        "index.html".into(),
+        "<!DOCTYPE html>\n<html>\n  <head></head>\n  <body></body>\n</html>\n".into(),
+    );
+    files.insert(
+        ".codesandbox/tasks.json".into(),
+        serde_json::to_string_pretty(&json!({
+            "setupTasks": [{ "name": "Install Trunk", "command": "cargo install trunk" }],
+            "tasks": {
+                "serve": {
+                    "name": "trunk serve",
+                    "command": "trunk serve --address 0.0.0.0 --port 8080",
+                    "runAtStart": true,
+                    "preview": { "port": 8080 }
+                }
+            }
+        }))
+        .unwrap(),
+    );
+
+    Ok(Some(Example {
+        component,
+        files,
+        server: false,
+    }))
+}
+
+/// Assembles a `cargo leptos` project for a snippet that includes `anchor`
+/// from `file`, for snippets that need a server to run.
+///
+/// The module becomes the project's `app` module. The server renders its
+/// `App` component (or the one the anchor is in, if it has no `App`) with the
+/// module's own `router` and `shell`, if it has them, and the browser
+/// hydrates it.
+///
+/// Returns `None` if the module has no component, or can't be built on its
+/// own: if it refers to the rest of the book's crate, or is only compiled
+/// with one of the server integrations, like the examples of extractors,
+/// which the browser's build doesn't have.
+pub fn server_example(
+    file: &Path,
+    anchor: &str,
+    manifest: &toml::Table,
+) -> io::Result<Option<Example>> {
+    let source = fs::read_to_string(file)?;
+    if only_on_the_server(file)? {
+        return Ok(None);
+    }
+    let parsed = syn::parse_file(&source).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: {e}", file.display()),
+        )
+    })?;
+    let Some(start) = anchors(&source)
+        .into_iter()
+        .find(|a| a.name == anchor)
+        .map(|a| a.line)
+    else {
+        return Ok(None);
+    };
+    let end = anchor_end(&source, anchor, start);
+
+    let functions = parsed
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Fn(f) => Some(f),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let has_fn = |name: &str| functions.iter().any(|f| f.sig.ident == name);
+    let components = functions
+        .iter()
+        .filter(|f| f.attrs.iter().any(|attr| attr.path().is_ident("component")))
+        .collect::<Vec<_>>();
+    let Some(entry) = components
+        .iter()
+        .find(|f| f.sig.ident == "App")
+        .or_else(|| components.iter().find(|f| lines(f).contains(&start)))
+        .or_else(|| {
+            components
+                .iter()
+                .find(|f| (start..=end).contains(&f.span().start().line))
+        })
+    else {
+        return Ok(None);
+    };
+    let component = entry.sig.ident.to_string();
+    let nightly = entry.attrs.iter().any(is_nightly_cfg);
+
+    let app_rs = module_source(&source, &parsed.items);
+    // an inline module can refer to its parent, but nothing else can
+    let outside = app_rs.lines().any(|line| {
+        line.contains("crate::") || (!line.starts_with(' ') && line.contains("super::"))
+    });
+    if outside {
+        return Ok(None);
+    }
+
+    let mut lib_rs = String::from("pub mod app;\n");
+    if !has_fn("hydrate") {
+        lib_rs.push_str(&SERVER_HYDRATE.replace("{component}", &component));
+    }
+    let has_router = functions
+        .iter()
+        .any(|f| f.sig.ident == "router" && f.sig.inputs.len() == 1);
+    let router = if has_router {
+        "    let app = router(options);\n"
+    } else if has_fn("shell") {
+        SERVER_ROUTER
+    } else {
+        SERVER_ROUTER_AND_SHELL
+    };
+    let main_rs = SERVER_MAIN
+        .replace("{router}", router)
+        .replace("{component}", &component);
+
+    let mut files = BTreeMap::new();
+    files.insert("src/app.rs".into(), app_rs);
+    files.insert("src/lib.rs".into(), lib_rs);
+    files.insert("src/main.rs".into(), main_rs);
+    files.insert("Cargo.toml".into(), server_manifest(manifest, nightly));
+    files.insert("rust-toolchain.toml".into(), toolchain(nightly));
+
+    Ok(Some(Example {
+        component,
+        files,
+        server: true,
+    }))
+}
+
+/// The browser's entry point of a [`server_example`], for modules that don't
+/// have their own.
+const SERVER_HYDRATE: &str = r#"
+#[cfg(feature = "hydrate")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+pub fn hydrate() {
+    leptos::mount::hydrate_body(app::{component});
+}
+"#;
+
+/// The server of a [`server_example`], where `{router}` sets `app` to the
+/// app's router.
+const SERVER_MAIN: &str = r#"#[cfg(feature = "axum")]
+#[tokio::main]
+async fn main() {
+    use leptos::prelude::*;
+    use leptos_book_example::app::*;
+    use tower_http::services::ServeDir;
+
+    // `cargo leptos` passes in the settings from `[package.metadata.leptos]`
+    let options = get_configuration(None).unwrap().leptos_options;
+    let addr = options.site_addr;
+    let site_root = options.site_root.to_string();
+
+{router}
+    // the WASM and JS that `cargo leptos` builds for the browser
+    let app = app.fallback_service(ServeDir::new(site_root));
+    let listener = tokio::net::TcpListener::bind(addr).await.unwrap();
+    println!("listening on http://{addr}");
+    axum::serve(listener, app).await.unwrap();
+}
+
+// the browser's build is the library, which starts at `hydrate`
+#[cfg(not(feature = "axum"))]
+fn main() {}
+"#;
+
+/// The router of a [`server_example`] whose module has a `shell` but no
+/// `router`.
+const SERVER_ROUTER: &str = r#"    use leptos_axum::{generate_route_list, LeptosRoutes};
+
+    let routes = generate_route_list({component});
+    let app = axum::Router::new()
+        .leptos_routes(&options, routes, {
+            let options = options.clone();
+            move || shell(options.clone())
+        })
+        .with_state(options);
+"#;
+
+/// The router of a [`server_example`] whose module has neither, with a shell
+/// of its own.
+const SERVER_ROUTER_AND_SHELL: &str = r#"    use leptos_axum::{generate_route_list, LeptosRoutes};
+
+    let routes = generate_route_list({component});
+    let app = axum::Router::new()
+        .leptos_routes(&options, routes, {
+            let options = options.clone();
+            move || {
+                let options = options.clone();
+                view! {
+                    <!DOCTYPE html>
+                    <html lang="en">
+                        <head>
+                            <meta charset="utf-8"/>
+                            <AutoReload options=options.clone()/>
+                            <HydrationScripts options/>
+                        </head>
+                        <body>
+                            <{component}/>
+                        </body>
+                    </html>
+                }
+            }
+        })
+        .with_state(options);
+"#;
+
+// whether the module's parent only declares it with one of the server
+// integrations' features, like `#[cfg(feature = "axum")]`
+fn only_on_the_server(file: &Path) -> io::Result<bool> {
+    let parent = file.with_file_name("mod.rs");
+    let (Some(name), true) = (file.file_stem(), parent.is_file()) else {
+        return Ok(false);
+    };
+    let declaration = format!("pub mod {};", name.to_string_lossy());
+    let parent = fs::read_to_string(parent)?;
+    let lines = parent.lines().collect::<Vec<_>>();
+    Ok(lines
+        .iter()
+        .position(|line| line.trim() == declaration)
+        .is_some_and(|idx| idx > 0 && lines[idx - 1].trim_start().starts_with("#[cfg(")))
+}
+
+/// Adds a "Run this example" button after every Rust code block in a chapter
+/// that includes an anchor from the book's own example code, and a link to
+/// the chapter's starter project at the end (see [`starter_path`]).
+///
+/// Chapters whose snippets are all marked `rust,norun` get the link too, if
+/// one of those snippets comes from a module with server functions (see
+/// [`server_example`]).
+///
+/// `chapter` is the path of the chapter's markdown file, relative to `src_dir`,
+/// the book's source directory. Includes from outside `src_dir`, like the
+/// examples that deliberately fail to compile, don't get a button, and nor do
+/// code blocks marked `rust,norun`.
+pub fn add_run_buttons(
+    src_dir: &Path,
+    chapter: &Path,
+    markdown: &str,
+    manifest: &toml::Table,
+) -> io::Result<String> {
+    let examples = snippet_examples(src_dir, chapter, markdown, manifest)?;
+    let starter = match examples.first() {
+        Some((_, example)) => Some(example.run_command()),
+        None => server_starter(src_dir, chapter, markdown, manifest)?
+            .as_ref()
+            .map(Example::run_command),
+    };
+
+    let mut markdown = markdown.to_string();
+    for (offset, example) in examples.into_iter().rev() {
+        let separator = if markdown[..offset].ends_with('\n') {
+            "\n"
+        } else {
+            "\n\n"
+        };
+        markdown.insert_str(offset, &format!("{separator}{}\n", example.button()));
+    }
+    if let Some(command) = starter {
+        let file_name = starter_path(chapter);
+        let file_name = file_name.file_name().unwrap().to_string_lossy();
+        markdown.push_str(&format!(
+            "\n\n[Download this chapter’s example as a starter project.]({file_name}) \
+             Unzip it and run `{command}` inside.\n"
+        ));
+    }
+    Ok(markdown)
+}
+
+/// The example for a chapter's starter project: the first snippet in the
+/// chapter that has one, or else the first `norun` snippet that has a
+/// [`server_example`].
+pub fn chapter_example(
+    src_dir: &Path,
+    chapter: &Path,
+    markdown: &str,
+    manifest: &toml::Table,
+) -> io::Result<Option<Example>> {
+    match chapter_examples(src_dir, chapter, markdown, manifest)?
+        .into_iter()
+        .next()
+    {
+        Some(example) => Ok(Some(example)),
+        None => server_starter(src_dir, chapter, markdown, manifest),
+    }
+}
+
+/// Every runnable example in a chapter, in the order of their snippets.
+pub fn chapter_examples(
+    src_dir: &Path,
+    chapter: &Path,
+    markdown: &str,
+    manifest: &toml::Table,
+) -> io::Result<Vec<Example>> {
+    Ok(snippet_examples(src_dir, chapter, markdown, manifest)?
+        .into_iter()
+        .map(|(_, example)| example)
+        .collect())
+}
+
+/// Where a chapter's starter project is written, relative to the built book.
+///
+/// This is alongside the chapter's page, so `view/04b_iteration.md` has its
+/// starter at `view/04b_iteration.zip`.
+pub fn starter_path(chapter: &Path) -> PathBuf {
+    chapter.with_extension("zip")
+}
+
+// every snippet with an example, and the offset of the end of its code block
+fn snippet_examples(
+    src_dir: &Path,
+    chapter: &Path,
+    markdown: &str,
+    manifest: &toml::Table,
+) -> io::Result<Vec<(usize, Example)>> {
+    let mut examples = Vec::new();
+    for snippet in snippets(src_dir, chapter, markdown)? {
+        if snippet.norun {
+            continue;
+        }
+        if let Some(example) = example(&snippet.file, &snippet.anchor, manifest)? {
+            examples.push((snippet.end, example));
+        }
+    }
+    Ok(examples)
+}
+
+// the server example of the first `norun` snippet that has one
+fn server_starter(
+    src_dir: &Path,
+    chapter: &Path,
+    markdown: &str,
+    manifest: &toml::Table,
+) -> io::Result<Option<Example>> {
+    for snippet in snippets(src_dir, chapter, markdown)? {
+        if !snippet.norun {
+            continue;
+        }
+        if let Some(example) = server_example(&snippet.file, &snippet.anchor, manifest)? {
+            return Ok(Some(example));
+        }
+    }
+    Ok(None)
+}
+
+/// A Rust code block that includes an anchor from the book's example code.
+struct Snippet {
+    /// The offset of the end of the code block.
+    end: usize,
+    /// Whether it's marked `rust,norun`.
+    norun: bool,
+    file: PathBuf,
+    anchor: String,
+}
+
+fn snippets(src_dir: &Path, chapter: &Path, markdown: &str) -> io::Result<Vec<Snippet>> {
+    let chapter_dir = chapter.parent().unwrap_or(Path::new(""));
+    let src_dir = fs::canonicalize(src_dir)?;
+
+    let mut snippets = Vec::new();
+    for (event, range) in Parser::new(markdown).into_offset_iter() {
+        let Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(info))) = event else {
+            continue;
+        };
+        // `norun` is mdbook's own way of saying that a snippet shouldn't be run
+        let mut attrs = info.split(|c: char| c == ',' || c.is_whitespace());
+        if attrs.next() != Some("rust") {
+            continue;
+        }
+        let norun = attrs.any(|attr| attr == "norun");
+        let first = includes(&markdown[range.clone()])
+            .into_iter()
+            .find_map(|include| Some((include.anchor?, include.path)));
+        let Some((anchor, path)) = first else {
+            continue;
+        };
+        let Some(file) = resolve(&src_dir, &src_dir.join(chapter_dir).join(path)) else {
+            continue;
+        };
+        snippets.push(Snippet {
+            end: range.end,
+            norun,
+            file,
+            anchor,
+        });
+    }
+    Ok(snippets)
+}
+
+// the included file, if it exists and is part of the book's own source
+fn resolve(src_dir: &Path, path: &Path) -> Option<PathBuf> {
+    let path = fs::canonicalize(path).ok()?;
+    path.starts_with(src_dir).then_some(path)
+}
+
+fn anchor_end(source: &str, anchor: &str, start: usize) -> usize {
+    source
+        .lines()
+        .enumerate()
+        .skip(start)
+        .find(|(_, line)| {
+            line.split_once("ANCHOR_END:")
+                .is_some_and(|(_, name)| name.trim() == anchor)
+        })
+        .map_or(usize::MAX, |(idx, _)| idx + 1)
+}
+
+fn lines(item: &ItemFn) -> std::ops::RangeInclusive<usize> {
+    let span = item.span();
+    span.start().line..=span.end().line
+}
+
+fn is_nightly_cfg(attr: &syn::Attribute) -> bool {
+    attr.path().is_ident("cfg")
+        && attr
+            .meta
+            .require_list()
+            .is_ok_and(|list| list.tokens.to_string() == "feature = \"nightly\"")
+}
+
+// the module minus its docs, its tests, and the anchor comments
+fn module_source(source: &str, items: &[Item]) -> String {
+    let tests = items
+        .iter()
+        .filter(|item| {
+            let attrs = match item {
+                Item::Mod(m) => &m.attrs,
+                Item::Fn(f) => &f.attrs,
+                _ => return false,
+            };
+            attrs.iter().any(|attr| {
+                attr.path().is_ident("cfg")
+                    && attr
+                        .meta
+                        .require_list()
+                        .is_ok_and(|list| list.tokens.to_string() == "test")
+            })
+        })
+        .map(|item| {
+            let span = item.span();
+            span.start().line..=span.end().line
+        })
+        .collect::<Vec<_>>();
+
+    let mut out = String::new();
+    let mut blank = true;
+    for (idx, line) in source.lines().enumerate() {
+        let trimmed = line.trim_start();
+        if tests.iter().any(|lines| lines.contains(&(idx + 1)))
+            || trimmed.starts_with("//!")
+            || trimmed.starts_with("// ANCHOR:")
+            || trimmed.starts_with("// ANCHOR_END:")
+        {
+            continue;
+        }
+        // don't leave runs of blank lines where things were removed
+        let is_blank = trimmed.is_empty();
+        if !(is_blank && blank) {
+            out.push_str(line);
+            out.push('\n');
+        }
+        blank = is_blank;
+    }
+    out
+}
+
+/// The browser version of the `sleep` helper in `src/async/mod.rs`, which
+/// the async examples import from their parent module.
+pub const SLEEP: &str = "\
+/// Waits for `duration` with a browser timer.
+async fn sleep(duration: std::time::Duration) {
+    send_wrapper::SendWrapper::new(gloo_timers::future::sleep(duration)).await;
+}
+";
+
+// the example is a crate of its own, so it has no parent module to import
+// `sleep` from
+fn inline_sleep(main_rs: &str) -> String {
+    let mut out = String::new();
+    for line in main_rs.lines() {
+        match line.trim() {
+            "use super::sleep;" | "use crate::r#async::sleep;" => out.push_str(SLEEP),
+            _ => {
+                out.push_str(line);
+                out.push('\n');
+            }
+        }
+    }
+    out
+}
+
+fn toolchain(nightly: bool) -> String {
+    format!(
+        "[toolchain]\nchannel = \"{}\"\ntargets = [\"wasm32-unknown-unknown\"]\n",
+        if nightly { "nightly" } else { "stable" }
+    )
+}
+
+fn example_manifest(book: &toml::Table, nightly: bool) -> String {
+    let mut dependencies = book_dependencies(book);
+    // the examples run in the browser, so they always use client-side rendering
+    if let Some(leptos) = dependencies.get_mut("leptos") {
+        let mut table = match leptos {
+            toml::Value::String(version) => {
+                let mut table = toml::Table::new();
+                table.insert("version".into(), version.clone().into());
+                table
+            }
+            toml::Value::Table(table) => table.clone(),
+            _ => toml::Table::new(),
+        };
+        table.insert("features".into(), toml::Value::Array(vec!["csr".into()]));
+        *leptos = toml::Value::Table(table);
+    }
+
+    let mut manifest = package();
+    manifest.insert("dependencies".into(), toml::Value::Table(dependencies));
+    let features = book_features(book, &[], nightly);
+    if !features.is_empty() {
+        manifest.insert("features".into(), toml::Value::Table(features));
+    }
+    manifest.to_string()
+}
+
+// a `cargo leptos` project, which builds the binary with `axum` for the
+// server, and the library with `hydrate` for the browser
+fn server_manifest(book: &toml::Table, nightly: bool) -> String {
+    let mut dependencies = book_dependencies(book);
+    // the server's `main` needs a runtime and a socket, and serves files
+    add_features(&mut dependencies, "tokio", &["net", "rt-multi-thread"]);
+    add_features(&mut dependencies, "tower-http", &["fs"]);
+
+    let mut manifest = package();
+    manifest.insert(
+        "lib".into(),
+        toml::Value::Table(toml::toml! {
+            crate-type = ["cdylib", "rlib"]
+        }),
+    );
+    manifest.insert("dependencies".into(), toml::Value::Table(dependencies));
+    manifest.insert(
+        "features".into(),
+        toml::Value::Table(book_features(book, &["hydrate", "ssr", "axum"], nightly)),
+    );
+    let leptos = toml::toml! {
+        output-name = "leptos-book-example"
+        site-root = "target/site"
+        site-pkg-dir = "pkg"
+        site-addr = "127.0.0.1:3000"
+        reload-port = 3001
+        bin-features = ["axum"]
+        bin-default-features = false
+        lib-features = ["hydrate"]
+        lib-default-features = false
+    };
+    let mut metadata = toml::Table::new();
+    metadata.insert("leptos".into(), toml::Value::Table(leptos));
+    manifest
+        .get_mut("package")
+        .and_then(toml::Value::as_table_mut)
+        .unwrap()
+        .insert("metadata".into(), toml::Value::Table(metadata));
+    manifest.to_string()
+}
+
+fn package() -> toml::Table {
+    let mut manifest = toml::Table::new();
+    manifest.insert(
+        "package".into(),
+        toml::Value::Table(toml::toml! {
+            name = "leptos-book-example"
+            version = "0.1.0"
+            edition = "2021"
+        }),
+    );
+    manifest
+}
+
+fn book_dependencies(book: &toml::Table) -> toml::Table {
+    book.get("dependencies")
+        .and_then(toml::Value::as_table)
+        .cloned()
+        .unwrap_or_default()
+}
+
+// the book's features called `names`, and `nightly`, which is on by default
+// for the examples that need it
+fn book_features(book: &toml::Table, names: &[&str], nightly: bool) -> toml::Table {
+    let mut features = toml::Table::new();
+    for name in names.iter().chain(["nightly"].iter()) {
+        if let Some(feature) = book.get("features").and_then(|f| f.get(*name)) {
+            features.insert(name.to_string(), feature.clone());
+        }
+    }
+    if nightly && features.contains_key("nightly") {
+        features.insert("default".into(), toml::Value::Array(vec!["nightly".into()]));
+    }
+    features
+}
+
+fn add_features(dependencies: &mut toml::Table, name: &str, added: &[&str]) {
+    let Some(toml::Value::Table(dependency)) = dependencies.get_mut(name) else {
+        return;
+    };
+    let features = dependency
+        .entry("features")
+        .or_insert_with(|| toml::Value::Array(Vec::new()));
+    if let toml::Value::Array(features) = features {
+        features.extend(added.iter().map(|&feature| feature.into()));
+    }
+}
@@ -0,0 +1,76 @@
+//! The `playground` mdbook preprocessor.
+//!
+//! This runs before mdbook's own `links` preprocessor, while the snippets are
+//! still `{{#include}}` directives, and adds a "Run this example" button after
+//! each one that comes from the book's example code. It also replaces each
+//! `{{#demo}}` directive with the live demo it names.
+
+use mdbook::{
+    book::Book,
+    errors::{Error, Result},
+    preprocess::{CmdPreprocessor, Preprocessor, PreprocessorContext},
+    BookItem,
+};
+use mdbook_playground::{add_run_buttons, demo::embed_demos};
+use std::{fs, io, process};
+
+struct Playground;
+
+impl Preprocessor for Playground {
+    fn name(&self) -> &str {
+        "playground"
+    }
+
+    fn run(&self, ctx: &PreprocessorContext, mut book: Book) -> Result<Book> {
+        let src_dir = ctx.root.join(&ctx.config.book.src);
+        let manifest: toml::Table = fs::read_to_string(ctx.root.join("Cargo.toml"))?
+            .parse()
+            .map_err(Error::msg)?;
+
+        let mut result = Ok(());
+        book.for_each_mut(|item| {
+            if let BookItem::Chapter(chapter) = item {
+                if let Some(path) = &chapter.source_path {
+                    let content = add_run_buttons(&src_dir, path, &chapter.content, &manifest)
+                        .and_then(|content| embed_demos(&src_dir, path, &content, &manifest));
+                    match content {
+                        Ok(content) => chapter.content = content,
+                        Err(e) => result = Err(Error::msg(format!("{}: {e}", path.display()))),
+                    }
+                }
+            }
+        });
+        result.map(|()| book)
+    }
+
+    fn supports_renderer(&self, renderer: &str) -> bool {
+        // the button is an HTML form
+        renderer == "html"
+    }
+}
+
+fn main() {
+    let preprocessor = Playground;
+
+    let mut args = std::env::args().skip(1);
+    if args.next().as_deref() == Some("supports") {
+        let renderer = args.next().unwrap_or_default();
+        process::exit(if preprocessor.supports_renderer(&renderer) {
+            0
+        } else {
+            1
+        });
+    }
+
+    if let Err(e) = run(&preprocessor) {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+}
+
+fn run(preprocessor: &dyn Preprocessor) -> Result<()> {
+    let (ctx, book) = CmdPreprocessor::parse_input(io::stdin())?;
+    let book = preprocessor.run(&ctx, book)?;
+    serde_json::to_writer(io::stdout(), &book)?;
+    Ok(())
+}
@@ -0,0 +1,320 @@
+//! Assembles the examples from the book's own chapters and example code.
+
+use mdbook_playground::{
+    add_run_buttons, chapter_example, chapter_examples,
+    demo::{demo_dir, demos, embed_demos, Demo},
+    example, starter_path, Example, SLEEP,
+};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+fn book_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../..")
+}
+
+fn manifest() -> toml::Table {
+    fs::read_to_string(book_root().join("Cargo.toml"))
+        .unwrap()
+        .parse()
+        .unwrap()
+}
+
+fn example_for(file: &str, anchor: &str) -> Option<Example> {
+    example(&book_root().join("src").join(file), anchor, &manifest()).unwrap()
+}
+
+/// Every runnable example in the book, with the chapter it's in.
+fn book_examples() -> Vec<(PathBuf, Example)> {
+    let src_dir = book_root().join("src");
+    let mut examples = Vec::new();
+    for path in mdbook_anchors::files_with_extension(&src_dir, "md").unwrap() {
+        let chapter = path.strip_prefix(&src_dir).unwrap();
+        let markdown = fs::read_to_string(&path).unwrap();
+        for example in chapter_examples(&src_dir, chapter, &markdown, &manifest()).unwrap() {
+            examples.push((chapter.to_path_buf(), example));
+        }
+    }
+    examples
+}
+
+#[test]
+fn mounts_the_component_that_contains_the_anchor() {
+    let example = example_for("view/c05_complex_iteration.rs", "change_key").unwrap();
+    assert_eq!(example.component, "ChangeKey");
+
+    let main_rs = example.main_rs();
+    syn::parse_file(main_rs).unwrap();
+    assert!(main_rs.contains("leptos::mount::mount_to_body(ChangeKey);"));
+    assert!(main_rs.contains("fn initial_rows()"), "{main_rs}");
+    assert!(!main_rs.contains("ANCHOR"), "{main_rs}");
+    assert!(!main_rs.contains("mod tests"), "{main_rs}");
+    assert!(example.files["Cargo.toml"].contains("reactive_stores"));
+}
+
+#[test]
+fn snippets_without_a_component_have_no_example() {
+    assert_eq!(example_for("router/c16_routes.rs", "imports"), None);
+    assert_eq!(
+        example_for("view/c05_complex_iteration.rs", "database_entry"),
+        None
+    );
+}
+
+#[test]
+fn nightly_variants_run_on_nightly() {
+    let stable = example_for("view/c01_basic_component.rs", "counter").unwrap();
+    let nightly = example_for("view/c01_basic_component.rs", "counter_nightly").unwrap();
+
+    // the module already has a `main`, so it isn't given another one
+    assert_eq!(stable.main_rs().matches("fn main()").count(), 1);
+    assert!(stable.files["rust-toolchain.toml"].contains("\"stable\""));
+    assert!(!stable.files["Cargo.toml"].contains("default"));
+    assert!(nightly.files["rust-toolchain.toml"].contains("\"nightly\""));
+    assert!(nightly.files["Cargo.toml"].contains("default = [\"nightly\"]"));
+}
+
+#[test]
+fn parameters_decompress_to_the_files() {
+    let example = example_for("router/c16_routes.rs", "app").unwrap();
+    let parameters = example.parameters().replace('-', "+").replace('_', "/");
+    let json = String::from_utf16(&lz_str::decompress_from_base64(&parameters).unwrap()).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        json["files"]["src/main.rs"]["content"].as_str(),
+        Some(example.main_rs())
+    );
+}
+
+#[test]
+fn adds_buttons_after_included_snippets() {
+    let src_dir = book_root().join("src");
+    let buttons = |chapter: &str| {
+        let markdown = fs::read_to_string(src_dir.join(chapter)).unwrap();
+        add_run_buttons(&src_dir, Path::new(chapter), &markdown, &manifest())
+            .unwrap()
+            .matches("Run this example")
+            .count()
+    };
+
+    // every option has a button, but the snippets of types don't
+    assert_eq!(buttons("view/04b_iteration.md"), 5);
+    // the examples that don't compile are included from outside `src`
+    assert_eq!(buttons("appendix_compiler_errors.md"), 0);
+    // and these need a server, so they're marked `norun`
+    assert_eq!(buttons("ssr/24_hydration_bugs.md"), 0);
+    assert_eq!(buttons("server/25_server_functions.md"), 0);
+    assert_eq!(buttons("progressive_enhancement/action_form.md"), 0);
+}
+
+#[test]
+fn no_example_needs_a_server() {
+    // the examples are only rendered in the browser, so a server function
+    // would have nothing to call
+    for (chapter, example) in book_examples() {
+        assert!(
+            !example.main_rs().contains("#[server"),
+            "{}: `{}` needs a server, so its snippet should be marked `norun`",
+            chapter.display(),
+            example.component
+        );
+    }
+}
+
+#[test]
+fn starter_projects_are_zipped_without_sandbox_config() {
+    let src_dir = book_root().join("src");
+    let chapter = Path::new("view/04b_iteration.md");
+    let markdown = fs::read_to_string(src_dir.join(chapter)).unwrap();
+    let example = chapter_example(&src_dir, chapter, &markdown, &manifest())
+        .unwrap()
+        .unwrap();
+    // the first runnable snippet in the chapter
+    assert_eq!(example.component, "App");
+
+    let path = std::env::temp_dir()
+        .join("mdbook-playground-test")
+        .join(starter_path(chapter));
+    example.write_starter(&path, "04b_iteration").unwrap();
+    let mut zip = zip::ZipArchive::new(fs::File::open(&path).unwrap()).unwrap();
+    let mut names = zip.file_names().collect::<Vec<_>>();
+    names.sort();
+    assert_eq!(
+        names,
+        [
+            "04b_iteration/Cargo.toml",
+            "04b_iteration/README.md",
+            "04b_iteration/index.html",
+            "04b_iteration/rust-toolchain.toml",
+            "04b_iteration/src/main.rs",
+        ]
+    );
+    let mut main_rs = String::new();
+    std::io::Read::read_to_string(
+        &mut zip.by_name("04b_iteration/src/main.rs").unwrap(),
+        &mut main_rs,
+    )
+    .unwrap();
+    assert_eq!(main_rs, example.main_rs());
+}
+
+#[test]
+fn server_chapters_get_a_cargo_leptos_starter() {
+    let src_dir = book_root().join("src");
+    let starter = |chapter: &str| {
+        let markdown = fs::read_to_string(src_dir.join(chapter)).unwrap();
+        chapter_example(&src_dir, Path::new(chapter), &markdown, &manifest()).unwrap()
+    };
+
+    let example = starter("server/25_server_functions.md").unwrap();
+    assert!(example.server);
+    assert_eq!(example.component, "App");
+    assert_eq!(
+        example.files.keys().collect::<Vec<_>>(),
+        [
+            "Cargo.toml",
+            "rust-toolchain.toml",
+            "src/app.rs",
+            "src/lib.rs",
+            "src/main.rs"
+        ]
+    );
+    for file in ["src/app.rs", "src/lib.rs", "src/main.rs"] {
+        syn::parse_file(&example.files[file]).unwrap();
+    }
+    // the module's own router registers the routes and server functions...
+    assert!(example.main_rs().contains("let app = router(options);"));
+    assert!(example.files["src/lib.rs"].contains("hydrate_body(app::App)"));
+    let cargo_toml: toml::Table = example.files["Cargo.toml"].parse().unwrap();
+    assert_eq!(
+        cargo_toml["package"]["metadata"]["leptos"]["bin-features"],
+        toml::Value::Array(vec!["axum".into()])
+    );
+    assert!(cargo_toml["features"].get("hydrate").is_some());
+
+    // ...while a module without one is given a router and a shell
+    let example = starter("ssr/24_hydration_bugs.md").unwrap();
+    assert!(
+        example.main_rs().contains("leptos_routes"),
+        "{}",
+        example.main_rs()
+    );
+    assert!(example.main_rs().contains("<HydrationScripts options/>"));
+
+    // the extractors only compile with `axum`, so the browser can't build them
+    assert_eq!(starter("server/26_extractors.md"), None);
+
+    let markdown = fs::read_to_string(src_dir.join("server/25_server_functions.md")).unwrap();
+    let markdown = add_run_buttons(
+        &src_dir,
+        Path::new("server/25_server_functions.md"),
+        &markdown,
+        &manifest(),
+    )
+    .unwrap();
+    assert!(markdown.contains("run `cargo leptos watch` inside"));
+}
+
+#[test]
+fn demos_are_embedded_next_to_their_chapter() {
+    let src_dir = book_root().join("src");
+    let chapter = Path::new("view/01_basic_component.md");
+    let markdown = "\
+{{#demo c01_basic_component.rs:counter}}
+\\{{#demo c01_basic_component.rs:counter}}
+";
+    assert_eq!(
+        demos(markdown),
+        [Demo {
+            path: "c01_basic_component.rs".into(),
+            anchor: "counter".into(),
+            line: 1,
+        }]
+    );
+    assert_eq!(
+        demo_dir(chapter, "counter"),
+        Path::new("view/demos/01_basic_component/counter")
+    );
+
+    let embedded = embed_demos(&src_dir, chapter, markdown, &manifest()).unwrap();
+    assert_eq!(
+        embedded.lines().collect::<Vec<_>>(),
+        [
+            "<iframe class=\"demo\" src=\"demos/01_basic_component/counter/index.html\" \
+             title=\"A live demo of App\" width=\"100%\" height=\"300px\"></iframe>",
+            "\\{{#demo c01_basic_component.rs:counter}}",
+        ]
+    );
+
+    // a demo has to have a component to mount
+    let error = embed_demos(
+        &src_dir,
+        Path::new("router/16_routes.md"),
+        "{{#demo c16_routes.rs:imports}}",
+        &manifest(),
+    )
+    .unwrap_err();
+    assert!(
+        error.to_string().contains("c16_routes.rs:imports"),
+        "{error}"
+    );
+}
+
+#[test]
+fn examples_stand_on_their_own() {
+    // each example is a crate of its own, with nothing above its `main.rs`
+    // and no files next to it
+    let is_mod_file = |line: &str| {
+        let line = line.trim();
+        (line.starts_with("mod ") || line.starts_with("pub mod ")) && line.ends_with(';')
+    };
+    let outside = book_examples()
+        .into_iter()
+        .flat_map(|(chapter, example)| {
+            example
+                .main_rs()
+                .lines()
+                .filter(|line| {
+                    line.contains("super::") || line.contains("crate::") || is_mod_file(line)
+                })
+                .map(|line| {
+                    format!(
+                        "{}: {}: {}",
+                        chapter.display(),
+                        example.component,
+                        line.trim()
+                    )
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    assert!(
+        outside.is_empty(),
+        "examples refer outside their module:\n{}",
+        outside.join("\n")
+    );
+}
+
+#[test]
+fn the_inlined_sleep_is_the_browser_one() {
+    let helpers = fs::read_to_string(book_root().join("src/async/mod.rs")).unwrap();
+    let browser = helpers
+        .split("#[cfg(not(feature = \"ssr\"))]\n")
+        .nth(1)
+        .unwrap();
+    let body = |source: &str| {
+        let start = source.find("async fn sleep").unwrap();
+        let end = start + source[start..].find("\n}\n").unwrap();
+        source[start..end]
+            .split_once('{')
+            .unwrap()
+            .1
+            .trim()
+            .to_string()
+    };
+    assert_eq!(body(SLEEP), body(browser));
+
+    let example = example_for("async/c11_suspense.rs", "suspense").unwrap();
+    assert!(example.main_rs().contains(SLEEP));
+}
@@ -0,0 +1,86 @@
+//! Runs the benchmark in `benches/iteration.rs`, and writes its results into
+//! `benches/iteration.md`, the table that "Iterating over More Complex Data
+//! with `<For/>`" includes.
+//!
+//! Criterion saves each measurement under `target/criterion`, named after the
+//! benchmark with its `/` replaced by `_`. Each time in the table is the mean
+//! time of the option, relative to Option 2's.
+
+use crate::{check_status, Result};
+use std::{env, fs, path::Path, process::Command};
+
+/// Each option's name in the benchmark, and its row in the table.
+const OPTIONS: [(&str, &str); 4] = [
+    ("change_key", "1: Changing the key"),
+    ("nested_signals", "2: Nested signals"),
+    ("memoized_slices", "3: Memoized slices"),
+    ("stores", "4: Stores"),
+];
+
+/// Each measurement's name in the benchmark, and its column in the table.
+const MEASUREMENTS: [(&str, &str); 3] = [
+    ("render", "Render"),
+    ("update_all", "Update every row"),
+    ("update_one", "Update one row"),
+];
+
+/// The option the others are compared to.
+const BASELINE: &str = "nested_signals";
+
+pub fn run(root: &Path) -> Result {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let status = Command::new(cargo)
+        .args(["bench", "--bench", "iteration"])
+        .current_dir(root)
+        .status()?;
+    check_status("cargo bench", status)?;
+
+    let criterion_dir = env::var_os("CARGO_TARGET_DIR")
+        .map_or_else(|| root.join("target"), |dir| root.join(dir))
+        .join("criterion");
+    let mean = |measurement: &str, option: &str| -> Result<f64> {
+        let path = criterion_dir
+            .join(format!("{measurement}_{option}"))
+            .join("new/estimates.json");
+        let estimates: serde_json::Value = serde_json::from_str(&fs::read_to_string(&path)?)?;
+        estimates["mean"]["point_estimate"]
+            .as_f64()
+            .ok_or_else(|| format!("{} has no mean", path.display()).into())
+    };
+
+    let mut table = String::from(
+        "<!-- Written by `cargo xtask bench-table`, from `benches/iteration.rs`. -->\n\n",
+    );
+    table.push_str("| Option |");
+    for (_, column) in MEASUREMENTS {
+        table.push_str(&format!(" {column} |"));
+    }
+    table.push_str("\n| ------ |");
+    for _ in MEASUREMENTS {
+        table.push_str(" -----: |");
+    }
+    table.push('\n');
+    for (option, row) in OPTIONS {
+        table.push_str(&format!("| {row} |"));
+        for (measurement, _) in MEASUREMENTS {
+            let relative = mean(measurement, option)? / mean(measurement, BASELINE)?;
+            table.push_str(&format!(" {} |", times(relative)));
+        }
+        table.push('\n');
+    }
+
+    let path = root.join("benches/iteration.md");
+    fs::write(&path, table)?;
+    println!("wrote {}", path.display());
+    Ok(())
+}
+
+/// A ratio to one decimal place, or a whole number from 10 up.
+fn times(ratio: f64) -> String {
+    let rounded = if ratio >= 10.0 {
+        format!("{ratio:.0}")
+    } else {
+        format!("{ratio:.1}")
+    };
+    format!("{}×", rounded.trim_end_matches(".0"))
+}
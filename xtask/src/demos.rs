@@ -0,0 +1,176 @@
+//! Builds the live demos embedded in the book (see `mdbook_playground::demo`).
+//!
+//! Each demo is its example project, compiled for `wasm32-unknown-unknown`
+//! and bound with `wasm-bindgen`, next to a page that loads it. The
+//! `wasm-bindgen` CLI has to be exactly the same version as the library the
+//! demos were built with, so if the one on `PATH` isn't, the right version is
+//! installed into `target/demos/tools` the first time it's needed.
+
+use crate::{check_status, Result};
+use mdbook_anchors::files_with_extension;
+use mdbook_playground::{
+    demo::{demo_dir, demos},
+    example,
+};
+use std::{
+    env,
+    ffi::{OsStr, OsString},
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+const INDEX_HTML: &str = r#"<!DOCTYPE html>
+<html>
+  <head>
+    <meta charset="utf-8">
+  </head>
+  <body>
+    <script type="module">
+      import init from "{base}demo.js";
+      init();
+    </script>
+  </body>
+</html>
+"#;
+
+/// Builds every demo that the book asks for into `dest_dir`, the built book.
+pub fn build(root: &Path, dest_dir: &Path) -> Result {
+    let src_dir = root.join("src");
+    let builder = Builder::new(root)?;
+
+    let mut count = 0;
+    for path in files_with_extension(&src_dir, "md")? {
+        let chapter = path.strip_prefix(&src_dir)?;
+        let chapter_dir = chapter.parent().unwrap_or(Path::new(""));
+        for demo in demos(&fs::read_to_string(&path)?) {
+            let file = src_dir.join(chapter_dir).join(&demo.path);
+            let dir = demo_dir(chapter, &demo.anchor);
+            builder
+                .build(&file, &demo.anchor, &dir, &dest_dir.join(&dir), "./")
+                .map_err(|e| format!("{}:{}: {e}", chapter.display(), demo.line))?;
+            count += 1;
+        }
+    }
+    println!("built {count} demos into {}", dest_dir.display());
+    Ok(())
+}
+
+/// Compiles the examples in the book's sources into pages that run them.
+pub struct Builder {
+    work_dir: PathBuf,
+    manifest: toml::Table,
+    cargo: OsString,
+}
+
+impl Builder {
+    pub fn new(root: &Path) -> Result<Self> {
+        Ok(Self {
+            work_dir: root.join("target/demos"),
+            manifest: fs::read_to_string(root.join("Cargo.toml"))?.parse()?,
+            cargo: env::var_os("CARGO").unwrap_or_else(|| "cargo".into()),
+        })
+    }
+
+    /// Builds the example at `anchor` in `file` into `out_dir`, as an
+    /// `index.html` plus the bound wasm. `name` picks the project directory
+    /// under `target/demos/projects`, and `base` is where the page loads the
+    /// wasm from: `./` for a page that stays put, `/` for an app served with
+    /// a fallback to its `index.html`.
+    pub fn build(
+        &self,
+        file: &Path,
+        anchor: &str,
+        name: &Path,
+        out_dir: &Path,
+        base: &str,
+    ) -> Result {
+        let example = example(file, anchor, &self.manifest)?
+            .ok_or_else(|| format!("`{anchor}` has no component to demo"))?;
+
+        let project = self.work_dir.join("projects").join(name);
+        example.write_to(&project)?;
+        // the projects are under the book's workspace, but not part of it
+        let cargo_toml = project.join("Cargo.toml");
+        let mut contents = fs::read_to_string(&cargo_toml)?;
+        contents.push_str("\n[workspace]\n");
+        fs::write(&cargo_toml, contents)?;
+
+        // go through rustup's `cargo`, so that the whole build uses the
+        // project's own toolchain file rather than the xtask's toolchain
+        let status = Command::new("cargo")
+            .args(["build", "--release", "--target", "wasm32-unknown-unknown"])
+            .current_dir(&project)
+            .env("CARGO_TARGET_DIR", self.work_dir.join("target"))
+            .env_remove("RUSTUP_TOOLCHAIN")
+            .env_remove("CARGO")
+            .env_remove("RUSTC")
+            .status()?;
+        check_status(&format!("building the {anchor} demo"), status)?;
+
+        let wasm = self
+            .work_dir
+            .join("target/wasm32-unknown-unknown/release/leptos-book-example.wasm");
+        self.bind(&wasm, &project.join("Cargo.lock"), "demo", out_dir)?;
+        fs::write(
+            out_dir.join("index.html"),
+            INDEX_HTML.replace("{base}", base),
+        )?;
+        Ok(())
+    }
+
+    /// Generates the JS bindings for `wasm` into `out_dir`, as
+    /// `<out_name>.js` and `<out_name>_bg.wasm`, with the version of
+    /// `wasm-bindgen` that `lockfile` says it was built with.
+    pub fn bind(&self, wasm: &Path, lockfile: &Path, out_name: &str, out_dir: &Path) -> Result {
+        let version = locked_version(lockfile, "wasm-bindgen")?;
+        let status = Command::new(wasm_bindgen(&self.cargo, &self.work_dir, &version)?)
+            .args(["--target", "web", "--no-typescript", "--out-name", out_name])
+            .arg("--out-dir")
+            .arg(out_dir)
+            .arg(wasm)
+            .status()?;
+        check_status("wasm-bindgen", status)
+    }
+}
+
+// the version of a package in a lockfile
+fn locked_version(lockfile: &Path, package: &str) -> Result<String> {
+    let lock: toml::Table = fs::read_to_string(lockfile)?.parse()?;
+    lock.get("package")
+        .and_then(toml::Value::as_array)
+        .into_iter()
+        .flatten()
+        .find(|p| p.get("name").and_then(toml::Value::as_str) == Some(package))
+        .and_then(|p| p.get("version")?.as_str())
+        .map(str::to_string)
+        .ok_or_else(|| format!("{} doesn't depend on {package}", lockfile.display()).into())
+}
+
+// a `wasm-bindgen` CLI of the given version, installing it if necessary
+fn wasm_bindgen(cargo: &OsStr, work_dir: &Path, version: &str) -> Result<PathBuf> {
+    let installed = work_dir.join("tools/bin/wasm-bindgen");
+    for candidate in [PathBuf::from("wasm-bindgen"), installed.clone()] {
+        let output = Command::new(&candidate).arg("--version").output();
+        if let Ok(output) = output {
+            if String::from_utf8_lossy(&output.stdout).trim() == format!("wasm-bindgen {version}") {
+                return Ok(candidate);
+            }
+        }
+    }
+
+    eprintln!("installing wasm-bindgen-cli {version}");
+    let status = Command::new(cargo)
+        .args([
+            "install",
+            "wasm-bindgen-cli",
+            "--locked",
+            "--version",
+            version,
+        ])
+        .arg("--root")
+        .arg(work_dir.join("tools"))
+        .status()?;
+    check_status("cargo install wasm-bindgen-cli", status)?;
+    Ok(installed)
+}
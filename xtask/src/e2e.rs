@@ -0,0 +1,69 @@
+//! Builds the book's tutorial apps and runs the browser tests in `tests/e2e`
+//! against them.
+//!
+//! The apps are built the same way as the live demos, into `target/e2e`, but
+//! load their wasm from `/` so that any path can be served their page. The
+//! app with server functions is built from the book crate with `hydrate`
+//! instead, and served by its test. The
+//! tests need a WebDriver server to talk to, at `$WEBDRIVER_URL` or
+//! `http://localhost:4444`.
+
+use crate::{check_status, demos::Builder, Result};
+use std::{env, path::Path, process::Command};
+
+/// Each app's directory under `target/e2e`, and the example it's built from.
+const APPS: [(&str, &str, &str); 3] = [
+    ("counters", "view/c04_iteration.rs", "app"),
+    ("contacts", "router/c17_nested_routing.rs", "app"),
+    ("todos", "testing/c15_testing.rs", "todo_app"),
+];
+
+/// The directory of the app that's hydrated from the book crate's own
+/// `hydrate` entry point, in `progressive_enhancement::c28_action_form`.
+const HYDRATED: &str = "action_form";
+
+pub fn run(root: &Path, args: &[String]) -> Result {
+    let site = root.join("target/e2e");
+    let builder = Builder::new(root)?;
+    for (name, file, anchor) in APPS {
+        let dir = Path::new("e2e").join(name);
+        builder.build(
+            &root.join("src").join(file),
+            anchor,
+            &dir,
+            &site.join(name),
+            "/",
+        )?;
+    }
+
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+
+    // the server-rendered app is the book crate itself, which the test serves
+    // along with this build of it for the browser
+    let status = Command::new(&cargo)
+        .args(["rustc", "--package", "leptos-book", "--lib", "--release"])
+        .args([
+            "--target",
+            "wasm32-unknown-unknown",
+            "--features",
+            "hydrate",
+        ])
+        .args(["--crate-type", "cdylib"])
+        .current_dir(root)
+        .status()?;
+    check_status("building the book for hydration", status)?;
+    builder.bind(
+        &root.join("target/wasm32-unknown-unknown/release/leptos_book.wasm"),
+        &root.join("Cargo.lock"),
+        HYDRATED,
+        &site.join(HYDRATED),
+    )?;
+
+    let status = Command::new(cargo)
+        .args(["test", "--package", "e2e-tests", "--", "--ignored"])
+        .args(args)
+        .current_dir(root)
+        .env("E2E_SITE", &site)
+        .status()?;
+    check_status("the end-to-end tests", status)
+}
@@ -0,0 +1,211 @@
+//! Runs the book's build and verification steps.
+//!
+//! ```sh
+//! cargo xtask <COMMAND>
+//! ```
+//!
+//! See [`USAGE`] for the commands.
+
+mod bench;
+mod demos;
+mod e2e;
+
+use feature_matrix::{Mode, MODES};
+use mdbook_anchors::{check_chapter, files_with_extension, unused_anchors};
+use mdbook_playground::{chapter_example, starter_path};
+use std::{
+    env,
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+    process::{self, Command},
+};
+
+type Result<T = ()> = std::result::Result<T, Box<dyn Error>>;
+
+const USAGE: &str = "\
+Usage: cargo xtask <COMMAND>
+
+Commands:
+  check-anchors              check that every include resolves, and every anchor is included
+  extract-fences [--bless]   compile-check the Rust fences written directly in the markdown
+  test-all-features [MODE]   compile and test the examples in each rendering mode
+  build-book [ARGS]          build the book with `mdbook build`, its starters and its demos
+  starters [DIR]             write each chapter's starter project into the built book
+  demos [DIR]                build the live demos embedded in the book into the built book
+  ci                         run all of the above, plus `cargo test --workspace`
+  e2e [ARGS]                 build the tutorial apps and drive them in a browser over WebDriver
+  bench-table                run the iteration benchmark, and write its table for the book
+  help                       print this message
+";
+
+fn main() {
+    let mut args = env::args().skip(1);
+    let command = args.next().unwrap_or_else(|| "help".into());
+    let args = args.collect::<Vec<_>>();
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_path_buf();
+
+    let result = match command.as_str() {
+        "check-anchors" => check_anchors(&root),
+        "extract-fences" => extract_fences(&root, &args),
+        "test-all-features" => test_all_features(&root, &args),
+        "build-book" => build_book(&root, &args),
+        "starters" => starters(&root, &book_dir(&root, args.first().map(PathBuf::from))),
+        "demos" => demos::build(&root, &book_dir(&root, args.first().map(PathBuf::from))),
+        "bench-table" => bench::run(&root),
+        "e2e" => e2e::run(&root, &args),
+        "ci" => ci(&root),
+        "help" | "--help" | "-h" => {
+            print!("{USAGE}");
+            Ok(())
+        }
+        other => Err(format!("unknown command `{other}`\n\n{USAGE}").into()),
+    };
+
+    if let Err(e) = result {
+        eprintln!("error: {e}");
+        process::exit(1);
+    }
+}
+
+fn check_anchors(root: &Path) -> Result {
+    let src_dir = root.join("src");
+    let mut problems = Vec::new();
+    for chapter in files_with_extension(&src_dir, "md")? {
+        let markdown = fs::read_to_string(&chapter)?;
+        for problem in check_chapter(&src_dir, chapter.strip_prefix(&src_dir)?, &markdown) {
+            problems.push(problem.to_string());
+        }
+    }
+    for anchor in unused_anchors(
+        &src_dir,
+        &[root.join("src"), root.join("tests"), root.join("examples")],
+    )? {
+        problems.push(anchor.to_string());
+    }
+
+    if problems.is_empty() {
+        println!("all includes and anchors are in use");
+        Ok(())
+    } else {
+        for problem in &problems {
+            eprintln!("{problem}");
+        }
+        Err(format!("found {} problem(s) with anchors", problems.len()).into())
+    }
+}
+
+fn extract_fences(root: &Path, args: &[String]) -> Result {
+    let bless = args.iter().any(|arg| arg == "--bless");
+    extract_fences::check::run(root, bless)
+}
+
+fn test_all_features(root: &Path, args: &[String]) -> Result {
+    let modes = if args.is_empty() {
+        MODES.to_vec()
+    } else {
+        args.iter()
+            .map(|name| Mode::named(name).ok_or_else(|| format!("unknown mode `{name}`")))
+            .collect::<std::result::Result<Vec<_>, _>>()?
+    };
+
+    let mut failed = Vec::new();
+    for mode in modes {
+        eprintln!("==> {} ({})", mode.name, mode.features.join(", "));
+        if !mode.run(root)?.success() {
+            failed.push(mode.name);
+        }
+    }
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("failed in: {}", failed.join(", ")).into())
+    }
+}
+
+fn build_book(root: &Path, args: &[String]) -> Result {
+    let status = Command::new("mdbook")
+        .arg("build")
+        .args(args)
+        .current_dir(root)
+        .status()
+        .map_err(|e| {
+            format!("couldn't run mdbook ({e}); install it with `cargo install mdbook`")
+        })?;
+    check_status("mdbook build", status)?;
+
+    let dest_dir = args
+        .iter()
+        .position(|arg| arg == "-d" || arg == "--dest-dir")
+        .and_then(|idx| args.get(idx + 1))
+        .map(PathBuf::from);
+    let dest_dir = book_dir(root, dest_dir);
+    starters(root, &dest_dir)?;
+    demos::build(root, &dest_dir)
+}
+
+// like mdbook, relative paths are relative to the book's root
+fn book_dir(root: &Path, dest_dir: Option<PathBuf>) -> PathBuf {
+    root.join(dest_dir.unwrap_or_else(|| "book".into()))
+}
+
+fn starters(root: &Path, dest_dir: &Path) -> Result {
+    let src_dir = root.join("src");
+    let manifest: toml::Table = fs::read_to_string(root.join("Cargo.toml"))?.parse()?;
+
+    let mut count = 0;
+    for path in files_with_extension(&src_dir, "md")? {
+        let chapter = path.strip_prefix(&src_dir)?;
+        let markdown = fs::read_to_string(&path)?;
+        if let Some(example) = chapter_example(&src_dir, chapter, &markdown, &manifest)? {
+            let name = chapter.file_stem().unwrap().to_string_lossy();
+            example.write_starter(&dest_dir.join(starter_path(chapter)), &name)?;
+            count += 1;
+        }
+    }
+    println!("wrote {count} starter projects to {}", dest_dir.display());
+    Ok(())
+}
+
+fn ci(root: &Path) -> Result {
+    let steps: [(&str, &dyn Fn() -> Result); 5] = [
+        ("check-anchors", &|| check_anchors(root)),
+        ("cargo test --workspace", &|| cargo_test(root)),
+        ("extract-fences", &|| extract_fences(root, &[])),
+        ("test-all-features", &|| test_all_features(root, &[])),
+        ("build-book", &|| {
+            build_book(root, &["--dest-dir".into(), target_book(root)])
+        }),
+    ];
+    for (name, step) in steps {
+        eprintln!("==> {name}");
+        step().map_err(|e| format!("{name}: {e}"))?;
+    }
+    Ok(())
+}
+
+fn cargo_test(root: &Path) -> Result {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let status = Command::new(cargo)
+        .args(["test", "--workspace"])
+        .current_dir(root)
+        .status()?;
+    check_status("cargo test", status)
+}
+
+// build into `target` so that checking the book doesn't touch a published copy in `book`
+fn target_book(root: &Path) -> String {
+    let dir: PathBuf = root.join("target/book");
+    dir.to_string_lossy().into_owned()
+}
+
+fn check_status(name: &str, status: process::ExitStatus) -> Result {
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!("{name} exited with {status}").into())
+    }
+}